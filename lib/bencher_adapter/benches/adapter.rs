@@ -1,4 +1,20 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+#![allow(clippy::expect_used)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Write,
+    fs::File,
+    io::{BufReader, Read},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use criterion::{
+    criterion_group, criterion_main,
+    measurement::{Measurement, ValueFormatter},
+    BenchmarkId, Criterion, Throughput,
+};
 
 use bencher_adapter::{Adaptable, Settings};
 use bencher_json::project::report::Adapter;
@@ -82,12 +98,254 @@ fn adapter_rust_bench(c: &mut Criterion) {
     });
 }
 
+// Very large benchmark harness output is mostly logs and raw samples, not results.
+const LARGE_BENCHMARK_COUNT: usize = 1_000;
+const LARGE_LINE_COUNT: usize = 1_000;
+
+fn large_rust_result() -> String {
+    (0..LARGE_BENCHMARK_COUNT).fold(String::new(), |mut result, i| {
+        for j in 0..LARGE_LINE_COUNT {
+            let _ = writeln!(result, "[INFO] tests::benchmark_{i} log line {j}");
+        }
+        let _ = writeln!(
+            result,
+            "test tests::benchmark_{i} ... bench: {i} ns/iter (+/- 1)"
+        );
+        result
+    })
+}
+
+fn large_java_jmh_result() -> String {
+    let raw_data = vec!["1.0"; LARGE_LINE_COUNT].join(", ");
+    let benchmarks = (0..LARGE_BENCHMARK_COUNT)
+        .map(|i| {
+            format!(
+                r#"{{"benchmark": "org.example.Bench.bench{i}", "primaryMetric": {{"score": {i}.0, "scoreConfidence": [{i}.0, {i}.0], "scoreUnit": "ns/op", "rawData": [[{raw_data}]]}}, "secondaryMetrics": {{"gc.alloc.rate": {{"score": {i}.0, "scoreUnit": "MB/sec", "rawData": [[{raw_data}]]}}}}}}"#
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", benchmarks.join(",\n"))
+}
+
+fn write_large_result(name: &str, result: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("bencher_adapter_{name}"));
+    std::fs::write(&path, result).expect("Failed to write large result");
+    path
+}
+
+fn large_results() -> [(Adapter, PathBuf); 2] {
+    [
+        (
+            Adapter::RustBench,
+            write_large_result("rust_bench.txt", &large_rust_result()),
+        ),
+        (
+            Adapter::JavaJmh,
+            write_large_result("java_jmh.json", &large_java_jmh_result()),
+        ),
+    ]
+}
+
+fn convert_file(adapter: Adapter, path: &PathBuf) {
+    let mut input = String::new();
+    File::open(path)
+        .expect("Failed to open large result")
+        .read_to_string(&mut input)
+        .expect("Failed to read large result");
+    adapter
+        .convert(&input, Settings::default())
+        .expect("Failed to convert large result");
+}
+
+fn convert_reader_file(adapter: Adapter, path: &PathBuf) {
+    let reader = BufReader::new(File::open(path).expect("Failed to open large result"));
+    adapter
+        .convert_reader(reader, Settings::default())
+        .expect("Failed to read large result")
+        .expect("Failed to convert large result");
+}
+
+type LargeBenchmark = (BenchmarkId, Box<dyn Fn()>);
+
+fn large_benchmarks() -> Vec<LargeBenchmark> {
+    let mut benchmarks: Vec<LargeBenchmark> = Vec::new();
+    for (adapter, path) in large_results() {
+        let convert_path = path.clone();
+        benchmarks.push((
+            BenchmarkId::new(format!("{adapter:?}"), "convert"),
+            Box::new(move || convert_file(adapter, &convert_path)),
+        ));
+        let convert_reader_path = path.clone();
+        benchmarks.push((
+            BenchmarkId::new(format!("{adapter:?}"), "convert_reader"),
+            Box::new(move || convert_reader_file(adapter, &convert_reader_path)),
+        ));
+        benchmarks.push((
+            BenchmarkId::new("Magic", format!("{adapter:?} convert_reader")),
+            Box::new(move || convert_reader_file(Adapter::Magic, &path)),
+        ));
+    }
+    benchmarks
+}
+
+fn adapter_large_time(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Adapter Large Output (Time)");
+    group.sample_size(10);
+    for (id, convert) in large_benchmarks() {
+        group.bench_function(id, |b| b.iter(&convert));
+    }
+    group.finish();
+}
+
+fn adapter_large_memory(c: &mut Criterion<PeakAllocated>) {
+    let mut group = c.benchmark_group("Adapter Large Output (Peak Memory)");
+    group.sample_size(10);
+    for (id, convert) in large_benchmarks() {
+        group.bench_function(id, |b| {
+            b.iter_custom(|iters| {
+                let mut peak = 0;
+                for _ in 0..iters {
+                    let start = ALLOCATOR.reset_peak();
+                    convert();
+                    peak = peak.max(ALLOCATOR.peak_since(start));
+                }
+                // Criterion divides the measured value by the number of iterations,
+                // but the peak for all of the iterations is the same as the peak for just one.
+                peak * usize::try_from(iters).unwrap_or(usize::MAX)
+            });
+        });
+    }
+    group.finish();
+}
+
+// Track the peak number of bytes allocated at once,
+// in order to show the memory used by parsing all of the output from a string
+// versus incrementally from a reader.
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator {
+    current: AtomicUsize::new(0),
+    peak: AtomicUsize::new(0),
+};
+
+struct PeakAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[allow(unsafe_code)]
+// SAFETY: All allocation is delegated to the system allocator.
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The layout is passed through unchanged to the system allocator.
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let current = self.current.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            self.peak.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The pointer was allocated by the system allocator with this same layout.
+        unsafe {
+            System.dealloc(ptr, layout);
+        }
+        self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+impl PeakAllocator {
+    fn reset_peak(&self) -> usize {
+        let current = self.current.load(Ordering::Relaxed);
+        self.peak.store(current, Ordering::Relaxed);
+        current
+    }
+
+    fn peak_since(&self, start: usize) -> usize {
+        self.peak.load(Ordering::Relaxed).saturating_sub(start)
+    }
+}
+
+struct PeakAllocated;
+
+impl Measurement for PeakAllocated {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATOR.reset_peak()
+    }
+
+    fn end(&self, i: Self::Intermediate) -> Self::Value {
+        ALLOCATOR.peak_since(i)
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &BytesFormatter
+    }
+}
+
+struct BytesFormatter;
+
+impl ValueFormatter for BytesFormatter {
+    fn scale_values(&self, typical_value: f64, values: &mut [f64]) -> &'static str {
+        let (factor, unit) = if typical_value < 1024.0 {
+            (1.0, "B")
+        } else if typical_value < 1024.0 * 1024.0 {
+            (1024.0, "KiB")
+        } else {
+            (1024.0 * 1024.0, "MiB")
+        };
+        for value in values {
+            *value /= factor;
+        }
+        unit
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        _throughput: &Throughput,
+        _values: &mut [f64],
+    ) -> &'static str {
+        "B/B"
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "B"
+    }
+}
+
 criterion_group!(
     benches,
     adapter_magic_json,
     adapter_json,
     adapter_magic_rust,
     adapter_rust,
-    adapter_rust_bench
+    adapter_rust_bench,
+    adapter_large_time
 );
-criterion_main!(benches);
+criterion_group! {
+    name = memory_benches;
+    config = Criterion::default()
+        .with_measurement(PeakAllocated)
+        .without_plots()
+        .warm_up_time(Duration::from_millis(1))
+        // The measurement is bytes not time, so only run the minimum number of iterations.
+        .measurement_time(Duration::from_millis(1));
+    targets = adapter_large_memory
+}
+criterion_main!(benches, memory_benches);
@@ -131,9 +131,7 @@ pub(crate) mod test_c_sharp_dot_net {
 
         let results = opt_convert_file_path::<AdapterCSharpDotNet>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_c_sharp_dot_net(&results);
@@ -1,3 +1,5 @@
+use std::{collections::VecDeque, io::BufRead};
+
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     character::complete::{anychar, space0, space1},
@@ -7,17 +9,29 @@ use nom::{
     IResult,
 };
 use ordered_float::OrderedFloat;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_number_as_f64, parse_u64, parse_units, NomError, Units,
+        from_xml_result, latency_as_nanos, parse_number_as_f64, parse_u64, parse_units,
+        xml_attribute, NomError, Units,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterError, Settings,
 };
 
 const CATCH2_METRICS_LINE_COUNT: usize = 5;
 
+// The elements of the XML reporter (`--reporter xml`)
+const XML_TEST_CASE: &[u8] = b"TestCase";
+const XML_SECTION: &[u8] = b"Section";
+const XML_BENCHMARK_RESULTS: &[u8] = b"BenchmarkResults";
+const XML_MEAN: &[u8] = b"mean";
+const XML_STANDARD_DEVIATION: &[u8] = b"standardDeviation";
+
 pub struct AdapterCppCatch2;
 
 impl Adaptable for AdapterCppCatch2 {
@@ -27,31 +41,183 @@ impl Adaptable for AdapterCppCatch2 {
             Some(JsonAverage::Median) => return None,
         }
 
-        let mut benchmark_metrics = Vec::new();
-        let mut test_case = None;
-        let lines = input.lines().collect::<Vec<_>>();
-        for lines in lines.windows(CATCH2_METRICS_LINE_COUNT) {
-            let Ok(lines) = lines.try_into() else {
-                debug_assert!(
-                    false,
-                    "Windows struct should always be convertible to array of the same size."
-                );
-                continue;
-            };
-            if let Some(name) = parse_catch2_test_case(lines) {
-                test_case = Some(name);
-                continue;
-            }
-            let Some(name) = test_case.clone() else {
-                continue;
-            };
-            if let Some((benchmark_name, metrics)) = parse_catch2_lines(name, lines) {
-                benchmark_metrics.push((benchmark_name, metrics));
-            }
+        if is_xml(input.as_bytes()) {
+            parse_catch2_xml(input.as_bytes()).ok()?
+        } else {
+            parse_catch2_lines_window(input.lines().map(Ok)).ok()?
+        }
+    }
+
+    fn parse_reader<R: BufRead>(
+        mut reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return Ok(None),
+        }
+
+        let results = if is_xml(reader.fill_buf()?) {
+            from_xml_result(parse_catch2_xml(reader))?
+        } else {
+            parse_catch2_lines_window(reader.lines())?
+        };
+        Ok(results.map(|results| vec![results]))
+    }
+
+    fn sniff(prefix: &str, settings: Settings) -> bool {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return false,
+        }
+
+        // The prefix of the XML reporter output is very unlikely to be a complete document.
+        if is_xml(prefix.as_bytes()) {
+            prefix.contains("<Catch") && prefix.contains("<BenchmarkResults")
+        } else {
+            Self::parse(prefix, settings).is_some()
+        }
+    }
+}
+
+fn is_xml(input: &[u8]) -> bool {
+    input.trim_ascii_start().starts_with(b"<")
+}
+
+// Only the last few lines are kept in memory,
+// so this works the same for a string slice or a reader.
+fn parse_catch2_lines_window<L>(
+    lines: impl Iterator<Item = std::io::Result<L>>,
+) -> Result<Option<AdapterResults>, AdapterError>
+where
+    L: AsRef<str>,
+{
+    let mut benchmark_metrics = Vec::new();
+    let mut test_case = None;
+    let mut window = VecDeque::with_capacity(CATCH2_METRICS_LINE_COUNT);
+    for line in lines {
+        if window.len() == CATCH2_METRICS_LINE_COUNT {
+            window.pop_front();
+        }
+        window.push_back(line?);
+        let Some(lines) = window
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<&str>>()
+            .try_into()
+            .ok()
+        else {
+            continue;
+        };
+        if let Some(name) = parse_catch2_test_case(lines) {
+            test_case = Some(name);
+            continue;
+        }
+        let Some(name) = test_case.clone() else {
+            continue;
+        };
+        if let Some((benchmark_name, metrics)) = parse_catch2_lines(name, lines) {
+            benchmark_metrics.push((benchmark_name, metrics));
         }
+    }
+
+    Ok(AdapterResults::new_latency(benchmark_metrics))
+}
+
+#[derive(Debug, Default)]
+struct XmlBenchmark {
+    name: String,
+    mean: Option<f64>,
+    std_dev: Option<f64>,
+}
 
-        AdapterResults::new_latency(benchmark_metrics)
+// The benchmark name is built the same way as the console reporter:
+// the test case name, then any section names, and then the benchmark name.
+// Only the current benchmark is kept in memory,
+// so this works the same for a string slice or a reader.
+fn parse_catch2_xml<R: BufRead>(reader: R) -> Result<Option<AdapterResults>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut benchmark_metrics = Vec::new();
+    let mut test_case = Vec::new();
+    let mut benchmark: Option<XmlBenchmark> = None;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(start) => match start.local_name().as_ref() {
+                XML_TEST_CASE => {
+                    test_case.clear();
+                    test_case.extend(xml_attribute(&start, "name")?);
+                },
+                XML_SECTION => test_case.extend(xml_attribute(&start, "name")?),
+                XML_BENCHMARK_RESULTS => {
+                    benchmark = Some(XmlBenchmark {
+                        name: xml_attribute(&start, "name")?.unwrap_or_default(),
+                        ..Default::default()
+                    });
+                },
+                _ => {},
+            },
+            Event::Empty(start) => {
+                if let Some(benchmark) = benchmark.as_mut() {
+                    match start.local_name().as_ref() {
+                        XML_MEAN => benchmark.mean = parse_xml_value(&start)?,
+                        XML_STANDARD_DEVIATION => benchmark.std_dev = parse_xml_value(&start)?,
+                        _ => {},
+                    }
+                }
+            },
+            Event::End(end) => match end.local_name().as_ref() {
+                XML_SECTION => {
+                    test_case.pop();
+                },
+                XML_BENCHMARK_RESULTS => {
+                    if let Some(benchmark) = benchmark.take() {
+                        let Some(benchmark_metric) = xml_benchmark_metric(&test_case, benchmark)
+                        else {
+                            return Ok(None);
+                        };
+                        benchmark_metrics.push(benchmark_metric);
+                    }
+                },
+                _ => {},
+            },
+            Event::Eof => break,
+            Event::Text(_)
+            | Event::CData(_)
+            | Event::Comment(_)
+            | Event::Decl(_)
+            | Event::PI(_)
+            | Event::DocType(_) => {},
+        }
+        buf.clear();
     }
+
+    Ok(AdapterResults::new_latency(benchmark_metrics))
+}
+
+fn parse_xml_value(start: &BytesStart) -> Result<Option<f64>, quick_xml::Error> {
+    Ok(xml_attribute(start, "value")?.and_then(|value| value.parse().ok()))
+}
+
+// All of the XML reporter values are in nanoseconds.
+fn xml_benchmark_metric(
+    test_case: &[String],
+    benchmark: XmlBenchmark,
+) -> Option<(BenchmarkName, JsonNewMetric)> {
+    let XmlBenchmark {
+        name,
+        mean,
+        std_dev,
+    } = benchmark;
+    let benchmark_name = format!("{}: {name}", test_case.join(" ")).parse().ok()?;
+    let mean = OrderedFloat::from(mean?);
+    let std_dev = OrderedFloat::from(std_dev?);
+    let json_metric = JsonNewMetric {
+        value: mean,
+        lower_value: Some(mean - std_dev),
+        upper_value: Some(mean + std_dev),
+    };
+    Some((benchmark_name, json_metric))
 }
 
 fn parse_catch2_test_case(lines: [&str; CATCH2_METRICS_LINE_COUNT]) -> Option<String> {
@@ -193,7 +359,9 @@ pub(crate) mod test_cpp_catch2 {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, convert_reader_file_path, opt_convert_file_path, validate_latency,
+        },
         AdapterResults, Settings,
     };
 
@@ -240,9 +408,7 @@ pub(crate) mod test_cpp_catch2 {
         let file_path = "./tool_output/cpp/catch2/four.txt";
         let results = opt_convert_file_path::<AdapterCppCatch2>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_cpp_catch2(&results);
@@ -251,9 +417,7 @@ pub(crate) mod test_cpp_catch2 {
             None,
             opt_convert_file_path::<AdapterCppCatch2>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -264,6 +428,13 @@ pub(crate) mod test_cpp_catch2 {
         validate_adapter_cpp_catch2(&results);
     }
 
+    #[test]
+    fn test_adapter_cpp_catch2_reader() {
+        let results =
+            convert_reader_file_path::<AdapterCppCatch2>("./tool_output/cpp/catch2/four.txt");
+        validate_adapter_cpp_catch2(&results);
+    }
+
     pub fn validate_adapter_cpp_catch2(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 4);
 
@@ -280,6 +451,55 @@ pub(crate) mod test_cpp_catch2 {
         validate_latency(metrics, 3789.0, Some(3427.0), Some(4151.0));
     }
 
+    #[test]
+    fn test_adapter_cpp_catch2_xml() {
+        let results = convert_cpp_catch2("xml");
+        validate_adapter_cpp_catch2_xml(&results);
+
+        assert_eq!(
+            None,
+            opt_convert_file_path::<AdapterCppCatch2>(
+                "./tool_output/cpp/catch2/xml.txt",
+                Settings::new(Some(JsonAverage::Median))
+            )
+        );
+    }
+
+    #[test]
+    fn test_adapter_cpp_catch2_xml_reader() {
+        let results =
+            convert_reader_file_path::<AdapterCppCatch2>("./tool_output/cpp/catch2/xml.txt");
+        validate_adapter_cpp_catch2_xml(&results);
+    }
+
+    pub fn validate_adapter_cpp_catch2_xml(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("Fibonacci: Fibonacci 10").unwrap();
+        validate_latency(
+            metrics,
+            99.7385,
+            Some(99.7385 - 1.00538),
+            Some(99.7385 + 1.00538),
+        );
+
+        let metrics = results.get("Fibonacci: Fibonacci 20").unwrap();
+        validate_latency(
+            metrics,
+            12_215.8,
+            Some(12_215.8 - 162.118),
+            Some(12_215.8 + 162.118),
+        );
+
+        let metrics = results.get("Fibonacci Memoized: Fibonacci 20").unwrap();
+        validate_latency(
+            metrics,
+            17.0122,
+            Some(17.0122 - 0.155_643),
+            Some(17.0122 + 0.155_643),
+        );
+    }
+
     #[test]
     fn test_adapter_cpp_catch2_two() {
         let results = convert_cpp_catch2("two");
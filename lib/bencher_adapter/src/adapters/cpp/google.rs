@@ -1,26 +1,68 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
-use rust_decimal::Decimal;
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::BufRead,
+};
+
+use bencher_json::{
+    project::{metric::MeasureNameId, report::JsonAverage},
+    BenchmarkName, JsonAny, JsonNewMetric,
+};
+use ordered_float::OrderedFloat;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::Deserialize;
 
 use crate::{
-    adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
+    adapters::util::{from_json_reader, latency_as_nanos, SampleStats, Units},
+    results::adapter_results::{AdapterResults, GoogleMeasure},
     Adaptable, AdapterError, Settings,
 };
 
+// The aggregates of repeated runs (ie `--benchmark_repetitions`)
+const AGGREGATE_RUN_TYPE: &str = "aggregate";
+const MEAN_AGGREGATE: &str = "mean";
+const MEDIAN_AGGREGATE: &str = "median";
+const STDDEV_AGGREGATE: &str = "stddev";
+// Every other numeric field of a benchmark is a user counter
+const NON_COUNTER_FIELDS: [&str; 10] = [
+    "family_index",
+    "per_family_instance_index",
+    "repetitions",
+    "repetition_index",
+    "threads",
+    "iterations",
+    "cpu_time",
+    "cpu_coefficient",
+    "real_coefficient",
+    "rms",
+];
+
 pub struct AdapterCppGoogle;
 
 impl Adaptable for AdapterCppGoogle {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
-
         serde_json::from_str::<Google>(input)
             .ok()?
-            .try_into()
-            .ok()?
+            .into_results(settings.average)
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        // Only the fields used for each benchmark are kept,
+        // so the results are much smaller than the raw output.
+        let Some(google) = from_json_reader::<_, Google>(reader)? else {
+            return Ok(None);
+        };
+        Ok(google
+            .into_results(settings.average)
+            .map(|results| vec![results]))
+    }
+
+    fn sniff(prefix: &str, _settings: Settings) -> bool {
+        prefix.trim_start().starts_with('{')
+            && prefix.contains("\"context\"")
+            && prefix.contains("\"benchmarks\"")
     }
 }
 
@@ -37,44 +79,212 @@ pub struct Context {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Benchmark {
-    pub name: BenchmarkName,
-    #[serde(with = "rust_decimal::serde::float")]
-    pub real_time: Decimal,
-    pub time_unit: Units,
+    pub name: String,
+    pub run_name: Option<String>,
+    pub run_type: Option<String>,
+    pub aggregate_name: Option<String>,
+    #[serde(default)]
+    pub error_occurred: bool,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub real_time: Option<Decimal>,
+    pub time_unit: Option<Units>,
+    #[serde(flatten)]
+    pub counters: BTreeMap<String, serde_json::Value>,
 }
 
-impl TryFrom<Google> for Option<AdapterResults> {
-    type Error = AdapterError;
-
-    fn try_from(google: Google) -> Result<Self, Self::Error> {
-        let mut benchmark_metrics = Vec::with_capacity(google.benchmarks.len());
-        for benchmark in google.benchmarks {
-            let Benchmark {
-                name,
-                real_time,
-                time_unit,
-            } = benchmark;
-            let value = latency_as_nanos(real_time, time_unit);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: None,
-                upper_value: None,
-            };
-
-            benchmark_metrics.push((name, json_metric));
+// All of the repetitions and aggregates of a single benchmark
+#[derive(Debug, Default)]
+struct Run {
+    iterations: Vec<Benchmark>,
+    aggregates: Vec<Benchmark>,
+}
+
+impl Google {
+    fn into_results(self, average: Option<JsonAverage>) -> Option<AdapterResults> {
+        let mut run_names = HashMap::new();
+        let mut runs: Vec<(String, Run)> = Vec::new();
+        for benchmark in self.benchmarks {
+            if benchmark.error_occurred {
+                continue;
+            }
+            let run_name = benchmark.run_name();
+            let index = *run_names.entry(run_name.clone()).or_insert_with(|| {
+                runs.push((run_name, Run::default()));
+                runs.len() - 1
+            });
+            let (_, run) = runs.get_mut(index)?;
+            if benchmark.is_aggregate() {
+                run.aggregates.push(benchmark);
+            } else {
+                run.iterations.push(benchmark);
+            }
+        }
+
+        let mut benchmark_metrics = Vec::with_capacity(runs.len());
+        for (run_name, run) in runs {
+            let measures = run.into_measures(average)?;
+            if measures.is_empty() {
+                continue;
+            }
+            let benchmark_name: BenchmarkName = run_name.parse().ok()?;
+            benchmark_metrics.push((benchmark_name, measures));
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        AdapterResults::new_google(benchmark_metrics)
+    }
+}
+
+impl Benchmark {
+    fn run_name(&self) -> String {
+        if let Some(run_name) = &self.run_name {
+            return run_name.clone();
+        }
+        // Older versions do not have a run name,
+        // and the name of an aggregate has the aggregate name as a suffix.
+        self.aggregate_name
+            .as_ref()
+            .and_then(|aggregate_name| {
+                self.name
+                    .strip_suffix(aggregate_name.as_str())?
+                    .strip_suffix('_')
+            })
+            .unwrap_or(&self.name)
+            .to_owned()
+    }
+
+    fn is_aggregate(&self) -> bool {
+        self.run_type.as_deref() == Some(AGGREGATE_RUN_TYPE) || self.aggregate_name.is_some()
+    }
+
+    fn is_aggregate_name(&self, aggregate_name: &str) -> bool {
+        self.aggregate_name.as_deref() == Some(aggregate_name)
+    }
+
+    fn latency(&self) -> Option<OrderedFloat<f64>> {
+        Some(latency_as_nanos(self.real_time?, self.time_unit?))
+    }
+
+    fn counter(&self, counter: &str) -> Option<f64> {
+        if NON_COUNTER_FIELDS.contains(&counter) {
+            return None;
+        }
+        self.counters.get(counter)?.as_f64()
+    }
+
+    fn counter_names(&self) -> impl Iterator<Item = &str> {
+        self.counters
+            .keys()
+            .map(String::as_str)
+            .filter(|counter| self.counter(counter).is_some())
+    }
+}
+
+impl Run {
+    // The `mean` (or `median`) aggregate is bounded by the `stddev` aggregate.
+    // Without aggregates, the repetitions are summarized the same as any other adapter.
+    // A single run has no bounds, and it can only be used as the mean.
+    fn into_measures(self, average: Option<JsonAverage>) -> Option<Vec<GoogleMeasure>> {
+        let Self {
+            iterations,
+            aggregates,
+        } = self;
+        let central_aggregate = match average.unwrap_or_default() {
+            JsonAverage::Mean => MEAN_AGGREGATE,
+            JsonAverage::Median => MEDIAN_AGGREGATE,
+        };
+        if let Some(central) = aggregates
+            .iter()
+            .find(|aggregate| aggregate.is_aggregate_name(central_aggregate))
+        {
+            let stddev = aggregates
+                .iter()
+                .find(|aggregate| aggregate.is_aggregate_name(STDDEV_AGGREGATE));
+            return Some(aggregate_measures(central, stddev));
+        }
+
+        match (iterations.as_slice(), average) {
+            ([], _) => Some(Vec::new()),
+            ([iteration], Some(JsonAverage::Mean) | None) => {
+                Some(aggregate_measures(iteration, None))
+            },
+            ([_], Some(JsonAverage::Median)) => None,
+            (iterations, _) => repetition_measures(iterations, average),
+        }
+    }
+}
+
+fn aggregate_measures(central: &Benchmark, stddev: Option<&Benchmark>) -> Vec<GoogleMeasure> {
+    let mut measures = Vec::new();
+    if let Some(latency) = central.latency() {
+        let spread = stddev.and_then(Benchmark::latency);
+        measures.push(GoogleMeasure::Latency(bounded_metric(latency, spread)));
+    }
+    for counter in central.counter_names() {
+        let (Ok(measure), Some(value)) =
+            (counter.parse::<MeasureNameId>(), central.counter(counter))
+        else {
+            continue;
+        };
+        let spread = stddev
+            .and_then(|stddev| stddev.counter(counter))
+            .map(Into::into);
+        measures.push(GoogleMeasure::Counter(
+            measure,
+            bounded_metric(value.into(), spread),
+        ));
+    }
+    measures
+}
+
+fn repetition_measures(
+    iterations: &[Benchmark],
+    average: Option<JsonAverage>,
+) -> Option<Vec<GoogleMeasure>> {
+    let first = iterations.first()?;
+    let mut measures = Vec::new();
+    if let Some(units) = first.time_unit {
+        let times = iterations
+            .iter()
+            .filter_map(|iteration| iteration.real_time?.to_f64())
+            .collect::<Vec<_>>();
+        if let Some(stats) = SampleStats::new(&times) {
+            measures.push(GoogleMeasure::Latency(stats.latency_metric(average, units)));
+        }
+    }
+    for counter in first.counter_names() {
+        let Ok(measure) = counter.parse::<MeasureNameId>() else {
+            continue;
+        };
+        let values = iterations
+            .iter()
+            .filter_map(|iteration| iteration.counter(counter))
+            .collect::<Vec<_>>();
+        if let Some(stats) = SampleStats::new(&values) {
+            measures.push(GoogleMeasure::Counter(measure, stats.metric(average)));
+        }
+    }
+    Some(measures)
+}
+
+fn bounded_metric(value: OrderedFloat<f64>, spread: Option<OrderedFloat<f64>>) -> JsonNewMetric {
+    JsonNewMetric {
+        value,
+        lower_value: spread.map(|spread| value - spread),
+        upper_value: spread.map(|spread| value + spread),
     }
 }
 
 #[cfg(test)]
 pub(crate) mod test_cpp_google {
     use bencher_json::project::report::JsonAverage;
+    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, convert_reader_file_path, opt_convert_file_path, validate_latency,
+        },
+        results::adapter_metrics::AdapterMetrics,
         AdapterResults, Settings,
     };
 
@@ -90,9 +300,7 @@ pub(crate) mod test_cpp_google {
         let file_path = "./tool_output/cpp/google/two.txt";
         let results = opt_convert_file_path::<AdapterCppGoogle>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_cpp_google(&results);
@@ -101,9 +309,7 @@ pub(crate) mod test_cpp_google {
             None,
             opt_convert_file_path::<AdapterCppGoogle>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -114,6 +320,87 @@ pub(crate) mod test_cpp_google {
         validate_adapter_cpp_google(&results);
     }
 
+    #[test]
+    fn test_adapter_cpp_google_reader() {
+        let results =
+            convert_reader_file_path::<AdapterCppGoogle>("./tool_output/cpp/google/two.txt");
+        validate_adapter_cpp_google(&results);
+    }
+
+    #[test]
+    fn test_adapter_cpp_google_repetitions() {
+        let results = convert_cpp_google("repetitions");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib_10").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_google_metric(metrics, "latency", 220.0, Some(210.0), Some(230.0));
+        validate_google_metric(
+            metrics,
+            "bytes_per_second",
+            1.1e9,
+            Some(1.1e9 - 1e8),
+            Some(1.1e9 + 1e8),
+        );
+        validate_google_metric(metrics, "fibs", 10.0, Some(10.0), Some(10.0));
+
+        // Without aggregates, the repetitions are summarized
+        let metrics = results.get("fib_20").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        let stddev = 7_000_000.0f64.sqrt() * 1_000.0;
+        validate_latency(
+            metrics,
+            29_000_000.0,
+            Some(29_000_000.0 - stddev),
+            Some(29_000_000.0 + stddev),
+        );
+
+        assert!(results.get("fib_error").is_none());
+    }
+
+    #[test]
+    fn test_adapter_cpp_google_repetitions_median() {
+        let results = opt_convert_file_path::<AdapterCppGoogle>(
+            "./tool_output/cpp/google/repetitions.txt",
+            Settings::new(Some(JsonAverage::Median)),
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib_10").unwrap();
+        validate_google_metric(metrics, "latency", 220.0, Some(210.0), Some(230.0));
+
+        let metrics = results.get("fib_20").unwrap();
+        validate_latency(
+            metrics,
+            28_000_000.0,
+            Some(25_500_000.0),
+            Some(30_500_000.0),
+        );
+    }
+
+    #[test]
+    fn test_adapter_cpp_google_repetitions_reader() {
+        let results = convert_reader_file_path::<AdapterCppGoogle>(
+            "./tool_output/cpp/google/repetitions.txt",
+        );
+        assert_eq!(results.inner.len(), 2);
+        assert_eq!(results.get("fib_10").unwrap().inner.len(), 3);
+    }
+
+    fn validate_google_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value));
+        assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
+        assert_eq!(metric.upper_value, upper_value.map(OrderedFloat::from));
+    }
+
     pub fn validate_adapter_cpp_google(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 2);
 
@@ -1,7 +1,13 @@
 pub mod catch2;
 pub mod google;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use std::io::BufRead;
+
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    adapters::magic::parse_reader_prefix, Adaptable, AdapterError, AdapterResults, Settings,
+};
 use catch2::AdapterCppCatch2;
 use google::AdapterCppGoogle;
 
@@ -12,6 +18,13 @@ impl Adaptable for AdapterCpp {
         AdapterCppCatch2::parse(input, settings)
             .or_else(|| AdapterCppGoogle::parse(input, settings))
     }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        parse_reader_prefix::<Self, R>(reader, settings, &[Adapter::CppCatch2, Adapter::CppGoogle])
+    }
 }
 
 #[cfg(test)]
@@ -1,77 +1,129 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use std::{collections::HashMap, io::BufRead};
+
+use bencher_json::{
+    project::{metric::Mean, report::JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use nom::{
-    branch::alt,
-    bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
-    combinator::{eof, map, map_res},
-    multi::many_till,
-    sequence::tuple,
+    bytes::complete::take_till1,
+    character::complete::{space0, space1},
+    combinator::{eof, map_res},
+    multi::many1,
+    sequence::{preceded, tuple},
     IResult,
 };
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_u64, NomError, Units,
     },
-    results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    results::adapter_results::{AdapterResults, GoMeasure},
+    Adaptable, AdapterError, Settings,
 };
 
 pub struct AdapterGoBench;
 
 impl Adaptable for AdapterGoBench {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::parse_iterations(input, settings).and_then(AdapterResults::mean)
+    }
+
+    fn parse_iterations(input: &str, settings: Settings) -> Option<Vec<AdapterResults>> {
         match settings.average {
             Some(JsonAverage::Mean) | None => {},
             Some(JsonAverage::Median) => return None,
         }
 
-        let mut benchmark_metrics = Vec::new();
+        parse_go_lines(input.lines().map(Ok)).ok()?
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return Ok(None),
+        }
+
+        parse_go_lines(reader.lines())
+    }
+}
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_go(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
+fn parse_go_lines<L>(
+    lines: impl Iterator<Item = std::io::Result<L>>,
+) -> Result<Option<Vec<AdapterResults>>, AdapterError>
+where
+    L: AsRef<str>,
+{
+    // With `-count=N` each benchmark is reported `N` times,
+    // so each repetition of a benchmark is its own iteration.
+    let mut iterations: Vec<Vec<(BenchmarkName, Vec<GoMeasure>)>> = Vec::new();
+    let mut repetitions: HashMap<BenchmarkName, usize> = HashMap::new();
+    for line in lines {
+        if let Ok((remainder, (benchmark_name, measures))) = parse_go(line?.as_ref()) {
+            if remainder.is_empty() {
+                let repetition = repetitions.entry(benchmark_name.clone()).or_default();
+                if let Some(iteration) = iterations.get_mut(*repetition) {
+                    iteration.push((benchmark_name, measures));
+                } else {
+                    iterations.push(vec![(benchmark_name, measures)]);
                 }
+                *repetition += 1;
             }
         }
+    }
 
-        AdapterResults::new_latency(benchmark_metrics)
+    if iterations.is_empty() {
+        return Ok(None);
     }
+    Ok(iterations.into_iter().map(AdapterResults::new_go).collect())
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
+fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, Vec<GoMeasure>)> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
             space1,
             parse_u64,
-            space1,
-            parse_go_bench,
-            alt((
-                map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
-            )),
+            many1(preceded(space1, parse_go_measure)),
+            space0,
+            eof,
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
+        |(name, _, _iter, measures, _, _)| -> Result<(BenchmarkName, Vec<GoMeasure>), NomError> {
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            Ok((benchmark_name, measures))
         },
     )(input)
 }
 
-fn parse_go_bench(input: &str) -> IResult<&str, JsonNewMetric> {
+// Every benchmark result is a value and unit pair.
+// The known units are from `-benchmem` and `b.SetBytes`,
+// and any other units are custom metrics from `b.ReportMetric`.
+fn parse_go_measure(input: &str) -> IResult<&str, GoMeasure> {
     map_res(
-        tuple((parse_f64, space1, parse_units, tag("/op"))),
-        |(duration, _, units, _)| -> Result<JsonNewMetric, NomError> {
-            let value = latency_as_nanos(duration, units);
-            Ok(JsonNewMetric {
+        tuple((parse_f64, space1, take_till1(|c: char| c.is_whitespace()))),
+        |(value, _, units)| -> Result<GoMeasure, NomError> {
+            let json_metric = |value| JsonNewMetric {
                 value,
                 lower_value: None,
                 upper_value: None,
+            };
+            Ok(match units {
+                "B/op" => GoMeasure::AllocBytes(json_metric(value.into())),
+                "allocs/op" => GoMeasure::AllocCount(json_metric(value.into())),
+                "MB/s" => GoMeasure::ByteThroughput(json_metric(value.into())),
+                _ => {
+                    if let Some(time_units) = units
+                        .strip_suffix("/op")
+                        .and_then(|time_units| time_units.parse::<Units>().ok())
+                    {
+                        GoMeasure::Latency(json_metric(latency_as_nanos(value, time_units)))
+                    } else {
+                        let measure = units.parse().map_err(|_e| nom_error(units))?;
+                        GoMeasure::Custom(measure, json_metric(value.into()))
+                    }
+                },
             })
         },
     )(input)
@@ -80,11 +132,19 @@ fn parse_go_bench(input: &str) -> IResult<&str, JsonNewMetric> {
 #[cfg(test)]
 pub(crate) mod test_go_bench {
     use bencher_json::{project::report::JsonAverage, JsonNewMetric};
+    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
-        AdapterResults, Settings,
+        adapters::{
+            magic::AdapterMagic,
+            test_util::{
+                convert_file_path, convert_reader_file_path, opt_convert_file_path,
+                validate_latency,
+            },
+        },
+        results::{adapter_metrics::AdapterMetrics, adapter_results::GoMeasure},
+        Adaptable, AdapterResults, Settings,
     };
 
     use super::{parse_go, AdapterGoBench};
@@ -95,6 +155,7 @@ pub(crate) mod test_go_bench {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_parse_go() {
         for (index, (expected, input)) in [
             (
@@ -102,11 +163,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -116,11 +177,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -130,11 +191,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -144,11 +205,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -158,11 +219,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.456.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
@@ -172,11 +233,21 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20WithAuxMetric-8".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 25_829.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![
+                            GoMeasure::Latency(JsonNewMetric {
+                                value: 25_829.0.into(),
+                                lower_value: None,
+                                upper_value: None,
+                            }),
+                            GoMeasure::Custom(
+                                "auxMetricUnits".parse().unwrap(),
+                                JsonNewMetric {
+                                    value: 4.0.into(),
+                                    lower_value: None,
+                                    upper_value: None,
+                                },
+                            ),
+                        ],
                     ),
                 )),
                 "BenchmarkFib20WithAuxMetric-8              46714             25829 ns/op                 4.000 auxMetricUnits",
@@ -194,9 +265,7 @@ pub(crate) mod test_go_bench {
         let file_path = "./tool_output/go/bench/five.txt";
         let results = opt_convert_file_path::<AdapterGoBench>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_go_bench(&results);
@@ -205,9 +274,7 @@ pub(crate) mod test_go_bench {
             None,
             opt_convert_file_path::<AdapterGoBench>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -218,6 +285,12 @@ pub(crate) mod test_go_bench {
         validate_adapter_go_bench(&results);
     }
 
+    #[test]
+    fn test_adapter_go_bench_reader() {
+        let results = convert_reader_file_path::<AdapterGoBench>("./tool_output/go/bench/five.txt");
+        validate_adapter_go_bench(&results);
+    }
+
     pub fn validate_adapter_go_bench(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 5);
 
@@ -255,6 +328,89 @@ pub(crate) mod test_go_bench {
         validate_latency(metrics, 26264.0, None, None);
 
         let metrics = results.get("BenchmarkFib20WithAuxMetric-8").unwrap();
-        validate_latency(metrics, 25829.0, None, None);
+        assert_eq!(metrics.inner.len(), 2);
+        validate_go_metric(metrics, "latency", 25829.0);
+        validate_go_metric(metrics, "auxMetricUnits", 4.0);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_benchmem() {
+        let results = convert_go_bench("benchmem");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("BenchmarkHandler-4").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_go_metric(metrics, "latency", 9874.0);
+        validate_go_metric(metrics, "alloc-bytes", 4320.0);
+        validate_go_metric(metrics, "alloc-count", 52.0);
+
+        let metrics = results.get("BenchmarkEncode/small-4").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_go_metric(metrics, "latency", 458.3);
+        validate_go_metric(metrics, "byte-throughput", 279.29);
+        validate_go_metric(metrics, "alloc-bytes", 128.0);
+        validate_go_metric(metrics, "alloc-count", 2.0);
+
+        let metrics = results.get("BenchmarkServe-4").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_go_metric(metrics, "latency", 104_521.0);
+        validate_go_metric(metrics, "req/s", 9567.0);
+        validate_go_metric(metrics, "alloc-bytes", 2048.0);
+        validate_go_metric(metrics, "alloc-count", 21.0);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_count() {
+        let contents = std::fs::read_to_string("./tool_output/go/bench/count.txt").unwrap();
+        let iterations = AdapterGoBench::parse_iterations(&contents, Settings::default()).unwrap();
+        assert_eq!(iterations.len(), 3);
+        for (results, (fib_10, fib_20)) in
+            iterations
+                .iter()
+                .zip([(210.0, 26000.0), (212.0, 26200.0), (214.0, 26400.0)])
+        {
+            assert_eq!(results.inner.len(), 2);
+
+            let metrics = results.get("BenchmarkFib10-8").unwrap();
+            validate_go_metric(metrics, "latency", fib_10);
+            validate_go_metric(metrics, "alloc-bytes", 0.0);
+            validate_go_metric(metrics, "alloc-count", 0.0);
+
+            let metrics = results.get("BenchmarkFib20-8").unwrap();
+            validate_go_metric(metrics, "latency", fib_20);
+            validate_go_metric(metrics, "alloc-bytes", 16.0);
+            validate_go_metric(metrics, "alloc-count", 1.0);
+        }
+
+        // Without iterations, the repetitions are averaged
+        let results = convert_go_bench("count");
+        let metrics = results.get("BenchmarkFib10-8").unwrap();
+        validate_go_metric(metrics, "latency", 212.0);
+        let metrics = results.get("BenchmarkFib20-8").unwrap();
+        validate_go_metric(metrics, "latency", 26200.0);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_count_reader() {
+        let contents = std::fs::read_to_string("./tool_output/go/bench/count.txt").unwrap();
+        let iterations = AdapterGoBench::parse_iterations(&contents, Settings::default()).unwrap();
+        let reader_iterations =
+            AdapterGoBench::parse_reader(contents.as_bytes(), Settings::default())
+                .unwrap()
+                .unwrap();
+        assert_eq!(reader_iterations, iterations);
+
+        // Like magic parsing, magic reading averages the repetitions
+        let results = AdapterMagic::parse_reader(contents.as_bytes(), Settings::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(results, vec![convert_go_bench("count")]);
+    }
+
+    fn validate_go_metric(metrics: &AdapterMetrics, key: &str, value: f64) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value));
+        assert_eq!(metric.lower_value, None);
+        assert_eq!(metric.upper_value, None);
     }
 }
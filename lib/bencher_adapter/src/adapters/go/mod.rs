@@ -1,6 +1,8 @@
 pub mod bench;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use std::io::BufRead;
+
+use crate::{Adaptable, AdapterError, AdapterResults, Settings};
 use bench::AdapterGoBench;
 
 pub struct AdapterGo;
@@ -9,6 +11,17 @@ impl Adaptable for AdapterGo {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterGoBench::parse(input, settings)
     }
+
+    fn parse_iterations(input: &str, settings: Settings) -> Option<Vec<AdapterResults>> {
+        AdapterGoBench::parse_iterations(input, settings)
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        AdapterGoBench::parse_reader(reader, settings)
+    }
 }
 
 #[cfg(test)]
@@ -1,10 +1,15 @@
+use std::{fmt, io::BufRead};
+
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
 
 use crate::{
-    adapters::util::{latency_as_nanos, throughput_as_secs},
+    adapters::util::{from_json_reader, latency_as_nanos, throughput_as_secs},
     results::adapter_results::{AdapterMeasure, AdapterResults},
     Adaptable, AdapterError, Settings,
 };
@@ -20,6 +25,29 @@ impl Adaptable for AdapterJavaJmh {
 
         serde_json::from_str::<Jmh>(input).ok()?.try_into().ok()?
     }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return Ok(None),
+        }
+
+        Ok(from_json_reader::<_, JmhStream>(reader)?
+            .and_then(|JmhStream(benchmark_metrics)| AdapterResults::new(benchmark_metrics))
+            .map(|results| vec![results]))
+    }
+
+    fn sniff(prefix: &str, settings: Settings) -> bool {
+        match settings.average {
+            Some(JsonAverage::Mean) | None => {},
+            Some(JsonAverage::Median) => return false,
+        }
+
+        prefix.trim_start().starts_with('[') && prefix.contains("\"primaryMetric\"")
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,57 +81,98 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
     type Error = AdapterError;
 
     fn try_from(jmh: Jmh) -> Result<Self, Self::Error> {
-        let mut benchmark_metrics = Vec::with_capacity(jmh.0.len());
-        for benchmark in jmh.0 {
-            let Benchmark {
-                benchmark: benchmark_name,
-                primary_metric,
-                ..
-            } = benchmark;
-            let PrimaryMetric {
-                score,
-                score_confidence,
-                score_unit,
-            } = primary_metric;
-
-            let measure = if let Some((unit, slash_op)) = score_unit.split_once("/op") {
-                if !slash_op.is_empty() {
-                    return Err(AdapterError::BenchmarkUnits(slash_op.into()));
-                }
-
-                let time_unit = unit.parse()?;
-                let value = latency_as_nanos(score, time_unit);
-                let lower_value = latency_as_nanos(score_confidence.0, time_unit);
-                let upper_value = latency_as_nanos(score_confidence.1, time_unit);
-                let json_metric = JsonNewMetric {
-                    value,
-                    lower_value: Some(lower_value),
-                    upper_value: Some(upper_value),
-                };
-                AdapterMeasure::Latency(json_metric)
-            } else if let Some((ops_slash, unit)) = score_unit.split_once("ops/") {
-                if !ops_slash.is_empty() {
-                    return Err(AdapterError::BenchmarkUnits(ops_slash.into()));
-                }
-
-                let time_unit = unit.parse()?;
-                let value = throughput_as_secs(score, time_unit);
-                let lower_value = throughput_as_secs(score_confidence.0, time_unit);
-                let upper_value = throughput_as_secs(score_confidence.1, time_unit);
-                let json_metric = JsonNewMetric {
-                    value,
-                    lower_value: Some(lower_value),
-                    upper_value: Some(upper_value),
-                };
-                AdapterMeasure::Throughput(json_metric)
-            } else {
-                return Err(AdapterError::BenchmarkUnits(score_unit));
+        let benchmark_metrics = jmh
+            .0
+            .into_iter()
+            .map(Benchmark::into_measure)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AdapterResults::new(benchmark_metrics))
+    }
+}
+
+impl Benchmark {
+    fn into_measure(self) -> Result<(BenchmarkName, AdapterMeasure), AdapterError> {
+        let Benchmark {
+            benchmark: benchmark_name,
+            primary_metric,
+            ..
+        } = self;
+        let PrimaryMetric {
+            score,
+            score_confidence,
+            score_unit,
+        } = primary_metric;
+
+        let measure = if let Some((unit, slash_op)) = score_unit.split_once("/op") {
+            if !slash_op.is_empty() {
+                return Err(AdapterError::BenchmarkUnits(slash_op.into()));
+            }
+
+            let time_unit = unit.parse()?;
+            let value = latency_as_nanos(score, time_unit);
+            let lower_value = latency_as_nanos(score_confidence.0, time_unit);
+            let upper_value = latency_as_nanos(score_confidence.1, time_unit);
+            let json_metric = JsonNewMetric {
+                value,
+                lower_value: Some(lower_value),
+                upper_value: Some(upper_value),
             };
+            AdapterMeasure::Latency(json_metric)
+        } else if let Some((ops_slash, unit)) = score_unit.split_once("ops/") {
+            if !ops_slash.is_empty() {
+                return Err(AdapterError::BenchmarkUnits(ops_slash.into()));
+            }
+
+            let time_unit = unit.parse()?;
+            let value = throughput_as_secs(score, time_unit);
+            let lower_value = throughput_as_secs(score_confidence.0, time_unit);
+            let upper_value = throughput_as_secs(score_confidence.1, time_unit);
+            let json_metric = JsonNewMetric {
+                value,
+                lower_value: Some(lower_value),
+                upper_value: Some(upper_value),
+            };
+            AdapterMeasure::Throughput(json_metric)
+        } else {
+            return Err(AdapterError::BenchmarkUnits(score_unit));
+        };
 
-            benchmark_metrics.push((benchmark_name, measure));
-        }
+        Ok((benchmark_name, measure))
+    }
+}
 
-        Ok(AdapterResults::new(benchmark_metrics))
+// Each benchmark is converted as soon as it is deserialized,
+// so only one benchmark's secondary metrics are ever held in memory.
+struct JmhStream(Vec<(BenchmarkName, AdapterMeasure)>);
+
+impl<'de> Deserialize<'de> for JmhStream {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(JmhStreamVisitor)
+    }
+}
+
+struct JmhStreamVisitor;
+
+impl<'de> Visitor<'de> for JmhStreamVisitor {
+    type Value = JmhStream;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of JMH benchmarks")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut benchmark_metrics = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(benchmark) = seq.next_element::<Benchmark>()? {
+            let benchmark_metric = benchmark.into_measure().map_err(de::Error::custom)?;
+            benchmark_metrics.push(benchmark_metric);
+        }
+        Ok(JmhStream(benchmark_metrics))
     }
 }
 
@@ -114,7 +183,8 @@ pub(crate) mod test_java_jmh {
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, opt_convert_file_path, validate_latency, validate_throughput,
+            convert_file_path, convert_reader_file_path, opt_convert_file_path, validate_latency,
+            validate_throughput,
         },
         AdapterResults, Settings,
     };
@@ -163,9 +233,7 @@ pub(crate) mod test_java_jmh {
         let file_path = "./tool_output/java/jmh/six.json";
         let results = opt_convert_file_path::<AdapterJavaJmh>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_java_jmh(&results);
@@ -174,9 +242,7 @@ pub(crate) mod test_java_jmh {
             None,
             opt_convert_file_path::<AdapterJavaJmh>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -187,6 +253,12 @@ pub(crate) mod test_java_jmh {
         validate_adapter_java_jmh(&results);
     }
 
+    #[test]
+    fn test_adapter_java_jmh_six_reader() {
+        let results = convert_reader_file_path::<AdapterJavaJmh>("./tool_output/java/jmh/six.json");
+        validate_adapter_java_jmh(&results);
+    }
+
     pub fn validate_adapter_java_jmh(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 6);
 
@@ -1,6 +1,8 @@
 pub mod jmh;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use std::io::BufRead;
+
+use crate::{Adaptable, AdapterError, AdapterResults, Settings};
 use jmh::AdapterJavaJmh;
 
 pub struct AdapterJava;
@@ -9,6 +11,13 @@ impl Adaptable for AdapterJava {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterJavaJmh::parse(input, settings)
     }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        AdapterJavaJmh::parse_reader(reader, settings)
+    }
 }
 
 #[cfg(test)]
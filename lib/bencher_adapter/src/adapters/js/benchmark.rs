@@ -108,17 +108,13 @@ pub(crate) mod test_js_benchmark {
             None,
             opt_convert_file_path::<AdapterJsBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterJsBenchmark>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_js_benchmark(&results);
@@ -103,9 +103,7 @@ pub(crate) mod test_js_time {
             None,
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
@@ -113,9 +111,7 @@ pub(crate) mod test_js_time {
             None,
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
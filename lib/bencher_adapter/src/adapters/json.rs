@@ -1,4 +1,9 @@
-use crate::{results::adapter_results::AdapterResults, Adaptable, Settings};
+use std::io::BufRead;
+
+use crate::{
+    adapters::util::from_json_reader, results::adapter_results::AdapterResults, Adaptable,
+    AdapterError, Settings,
+};
 
 pub struct AdapterJson;
 
@@ -6,6 +11,19 @@ impl Adaptable for AdapterJson {
     fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
         serde_json::from_str(input).ok()
     }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        _settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        Ok(from_json_reader(reader)?.map(|results| vec![results]))
+    }
+
+    // Bencher Metric Format JSON is always an object,
+    // but so is the JSON output of many other benchmark harnesses.
+    fn sniff(prefix: &str, _settings: Settings) -> bool {
+        prefix.trim_start().starts_with('{')
+    }
 }
 
 #[cfg(test)]
@@ -15,7 +33,7 @@ pub(crate) mod test_json {
 
     use super::AdapterJson;
     use crate::{
-        adapters::test_util::{convert_file_path, validate_latency},
+        adapters::test_util::{convert_file_path, convert_reader_file_path, validate_latency},
         results::adapter_results::AdapterResults,
     };
 
@@ -30,6 +48,13 @@ pub(crate) mod test_json {
         validate_adapter_json_latency(&results);
     }
 
+    #[test]
+    fn test_adapter_json_latency_reader() {
+        let results =
+            convert_reader_file_path::<AdapterJson>("./tool_output/json/report_latency.json");
+        validate_adapter_json_latency(&results);
+    }
+
     pub fn validate_adapter_json_latency(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 3);
 
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use bencher_json::{BenchmarkName, JsonNewMetric};
+use serde::{de::IgnoredAny, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    adapters::util::{latency_as_nanos, SampleStats, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+const BENCHMARK_GROUP: &str = "BenchmarkGroup";
+const TRIAL: &str = "Trial";
+const TRIAL_ESTIMATE: &str = "TrialEstimate";
+
+// Nested benchmark group keys are joined into a single benchmark name
+const GROUP_SEPARATOR: &str = "/";
+
+pub struct AdapterJuliaBenchmarkTools;
+
+impl Adaptable for AdapterJuliaBenchmarkTools {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<BenchmarkTools>(input)
+            .ok()?
+            .convert(settings)
+    }
+}
+
+// `BenchmarkTools.save` writes the package versions followed by the saved values
+// https://github.com/JuliaCI/BenchmarkTools.jl/blob/v1.5.0/src/serialization.jl
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkTools(IgnoredAny, Vec<BenchmarkToolsValue>);
+
+// Each value is tagged with its Julia type name (ie `["Trial", {...}]`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkToolsValue(String, Value);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkGroup {
+    pub data: HashMap<String, BenchmarkToolsValue>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Trial {
+    // Times are always in nanoseconds
+    pub times: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TrialEstimate {
+    pub time: f64,
+}
+
+impl BenchmarkTools {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::new();
+        for value in self.1 {
+            value.convert(None, settings, &mut benchmark_metrics)?;
+        }
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+}
+
+impl BenchmarkToolsValue {
+    fn convert(
+        self,
+        name: Option<&str>,
+        settings: Settings,
+        benchmark_metrics: &mut Vec<(BenchmarkName, JsonNewMetric)>,
+    ) -> Option<()> {
+        let Self(type_name, value) = self;
+        match type_name.as_str() {
+            BENCHMARK_GROUP => {
+                let group: BenchmarkGroup = serde_json::from_value(value).ok()?;
+                for (key, value) in group.data {
+                    let name = if let Some(name) = name {
+                        format!("{name}{GROUP_SEPARATOR}{key}")
+                    } else {
+                        key
+                    };
+                    value.convert(Some(&name), settings, benchmark_metrics)?;
+                }
+            },
+            TRIAL => {
+                let trial: Trial = serde_json::from_value(value).ok()?;
+                let benchmark_name = name?.parse().ok()?;
+                // A trial without any samples is skipped
+                if let Some(stats) = SampleStats::new(&trial.times) {
+                    let json_metric = stats.latency_metric(settings.average, Units::Nano);
+                    benchmark_metrics.push((benchmark_name, json_metric));
+                }
+            },
+            TRIAL_ESTIMATE => {
+                // An estimate has already been reduced to a single time,
+                // so there are no per-run values to choose an average from.
+                let estimate: TrialEstimate = serde_json::from_value(value).ok()?;
+                let benchmark_name = name?.parse().ok()?;
+                let json_metric = JsonNewMetric {
+                    value: latency_as_nanos(estimate.time, Units::Nano),
+                    lower_value: None,
+                    upper_value: None,
+                };
+                benchmark_metrics.push((benchmark_name, json_metric));
+            },
+            // Other saved values (ie `Parameters`) are not benchmark results
+            _ => {},
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_julia_benchmark_tools {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::AdapterJuliaBenchmarkTools;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/julia/benchmark_tools/{suffix}.json")
+    }
+
+    #[test]
+    fn test_adapter_julia_benchmark_tools_suite() {
+        let results = convert_file_path::<AdapterJuliaBenchmarkTools>(&file_path("suite"));
+        validate_adapter_julia_benchmark_tools(&results);
+    }
+
+    pub fn validate_adapter_julia_benchmark_tools(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/recursive").unwrap();
+        validate_latency(
+            metrics,
+            18_000.0,
+            Some(17_209.430_584_957_907),
+            Some(18_790.569_415_042_093),
+        );
+
+        let metrics = results.get("fib/iterative").unwrap();
+        validate_latency(
+            metrics,
+            20.25,
+            Some(19.604_502_775_632_096),
+            Some(20.895_497_224_367_904),
+        );
+
+        let metrics = results.get("sort").unwrap();
+        validate_latency(metrics, 1_200.0, Some(1_100.0), Some(1_300.0));
+    }
+
+    #[test]
+    fn test_adapter_julia_benchmark_tools_suite_median() {
+        let results = convert_file_path_median::<AdapterJuliaBenchmarkTools>(&file_path("suite"));
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/recursive").unwrap();
+        validate_latency(metrics, 18_000.0, Some(17_000.0), Some(19_000.0));
+
+        let metrics = results.get("fib/iterative").unwrap();
+        validate_latency(metrics, 20.25, Some(19.5), Some(21.0));
+
+        let metrics = results.get("sort").unwrap();
+        validate_latency(metrics, 1_200.0, Some(1_100.0), Some(1_300.0));
+    }
+
+    #[test]
+    fn test_adapter_julia_benchmark_tools_estimate() {
+        let results = convert_file_path::<AdapterJuliaBenchmarkTools>(&file_path("estimate"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("sum").unwrap();
+        validate_latency(metrics, 152.5, None, None);
+    }
+}
@@ -0,0 +1,27 @@
+pub mod benchmark_tools;
+
+use crate::{Adaptable, AdapterResults, Settings};
+use benchmark_tools::AdapterJuliaBenchmarkTools;
+
+pub struct AdapterJulia;
+
+impl Adaptable for AdapterJulia {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        AdapterJuliaBenchmarkTools::parse(input, settings)
+    }
+}
+
+#[cfg(test)]
+mod test_julia {
+    use super::AdapterJulia;
+    use crate::adapters::{
+        julia::benchmark_tools::test_julia_benchmark_tools, test_util::convert_file_path,
+    };
+
+    #[test]
+    fn test_adapter_julia_benchmark_tools() {
+        let results =
+            convert_file_path::<AdapterJulia>("./tool_output/julia/benchmark_tools/suite.json");
+        test_julia_benchmark_tools::validate_adapter_julia_benchmark_tools(&results);
+    }
+}
@@ -0,0 +1,179 @@
+use std::io::BufRead;
+
+use bencher_json::{BenchmarkName, JsonNewMetric};
+use quick_xml::{events::Event, Reader};
+
+use crate::{
+    adapters::util::{from_xml_result, latency_as_nanos, xml_attribute, Units},
+    Adaptable, AdapterError, AdapterResults, Settings,
+};
+
+const TEST_CASE: &[u8] = b"testcase";
+// A test case with any of these children did not pass, so its time is not a benchmark.
+const NOT_PASSED: [&[u8]; 3] = [b"failure", b"error", b"skipped"];
+
+pub struct AdapterJunit;
+
+impl Adaptable for AdapterJunit {
+    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
+        parse_junit_xml(input.as_bytes()).ok()?
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        _settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        Ok(from_xml_result(parse_junit_xml(reader))?.map(|results| vec![results]))
+    }
+
+    fn sniff(prefix: &str, _settings: Settings) -> bool {
+        prefix.trim_start().starts_with('<')
+            && prefix.contains("<testsuite")
+            && prefix.contains("<testcase")
+    }
+}
+
+#[derive(Debug)]
+struct TestCase {
+    name: Option<BenchmarkName>,
+    time: Option<f64>,
+    passed: bool,
+}
+
+impl TestCase {
+    fn into_metric(self) -> Option<(BenchmarkName, JsonNewMetric)> {
+        let Self { name, time, passed } = self;
+        if !passed {
+            return None;
+        }
+        let json_metric = JsonNewMetric {
+            value: latency_as_nanos(time?, Units::Sec),
+            lower_value: None,
+            upper_value: None,
+        };
+        Some((name?, json_metric))
+    }
+}
+
+// Only the current test case is kept in memory,
+// so this works the same for a string slice or a reader.
+fn parse_junit_xml<R: BufRead>(reader: R) -> Result<Option<AdapterResults>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut benchmark_metrics = Vec::new();
+    let mut test_case = None;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(start) if start.local_name().as_ref() == TEST_CASE => {
+                test_case = Some(parse_test_case(&start)?);
+            },
+            Event::Empty(start) if start.local_name().as_ref() == TEST_CASE => {
+                benchmark_metrics.extend(parse_test_case(&start)?.into_metric());
+            },
+            Event::Start(start) | Event::Empty(start) => {
+                if let Some(test_case) = test_case.as_mut() {
+                    if NOT_PASSED.contains(&start.local_name().as_ref()) {
+                        test_case.passed = false;
+                    }
+                }
+            },
+            Event::End(end) => {
+                if end.local_name().as_ref() == TEST_CASE {
+                    benchmark_metrics.extend(test_case.take().and_then(TestCase::into_metric));
+                }
+            },
+            Event::Eof => break,
+            Event::Text(_)
+            | Event::CData(_)
+            | Event::Comment(_)
+            | Event::Decl(_)
+            | Event::PI(_)
+            | Event::DocType(_) => {},
+        }
+        buf.clear();
+    }
+
+    Ok(AdapterResults::new_latency(benchmark_metrics))
+}
+
+// The benchmark name is the class name and the test case name joined by a `.`,
+// which is how most JUnit XML tools display a test case.
+fn parse_test_case(start: &quick_xml::events::BytesStart) -> Result<TestCase, quick_xml::Error> {
+    let name = xml_attribute(start, "name")?;
+    let name = match xml_attribute(start, "classname")? {
+        Some(classname) if !classname.is_empty() => name.map(|name| format!("{classname}.{name}")),
+        Some(_) | None => name,
+    }
+    .and_then(|name| name.parse().ok());
+    let time = xml_attribute(start, "time")?.and_then(|time| time.trim().parse().ok());
+    Ok(TestCase {
+        name,
+        time,
+        passed: true,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test_junit {
+    use pretty_assertions::assert_eq;
+
+    use super::AdapterJunit;
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_reader_file_path, validate_latency},
+        Adaptable, AdapterResults, Settings,
+    };
+
+    fn convert_junit(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/junit/{suffix}.xml");
+        convert_file_path::<AdapterJunit>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_junit_pytest() {
+        let results = convert_junit("pytest");
+        validate_adapter_junit_pytest(&results);
+    }
+
+    #[test]
+    fn test_adapter_junit_pytest_reader() {
+        let results = convert_reader_file_path::<AdapterJunit>("./tool_output/junit/pytest.xml");
+        validate_adapter_junit_pytest(&results);
+    }
+
+    #[test]
+    fn test_adapter_junit_catch2() {
+        let results = convert_junit("catch2");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench.global.Fibonacci").unwrap();
+        validate_latency(metrics, 3_066_000.0, None, None);
+
+        let metrics = results.get("bench.global.Fibonacci/Memoized").unwrap();
+        validate_latency(metrics, 2_000.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_junit_invalid() {
+        assert_eq!(
+            None,
+            AdapterJunit::parse("<testsuites><testcase", Settings::default())
+        );
+        assert_eq!(
+            None,
+            AdapterJunit::parse("test_fib ... ok", Settings::default())
+        );
+    }
+
+    pub fn validate_adapter_junit_pytest(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("tests.test_fib.test_fib_10").unwrap();
+        validate_latency(metrics, 125_000_000.0, None, None);
+
+        let metrics = results.get("tests.test_fib.test_fib_20").unwrap();
+        validate_latency(metrics, 1_250_000_000.0, None, None);
+
+        let metrics = results.get("test_no_class").unwrap();
+        validate_latency(metrics, 157_000_000.0, None, None);
+    }
+}
@@ -1,9 +1,17 @@
+use std::io::{self, BufRead, Cursor, Read};
+
+use bencher_json::project::{metric::Mean, report::Adapter};
+
 use crate::{
-    results::adapter_results::AdapterResults, Adaptable, AdapterCSharp, AdapterCpp, AdapterGo,
-    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, AdapterShell,
-    Settings,
+    results::adapter_results::AdapterResults, Adaptable, AdapterCSharp, AdapterCpp, AdapterError,
+    AdapterGo, AdapterJava, AdapterJs, AdapterJson, AdapterJulia, AdapterJunit, AdapterOpenMetrics,
+    AdapterPython, AdapterRuby, AdapterRust, AdapterShell, Settings,
 };
 
+/// The maximum number of bytes at the start of the output used to detect the adapter
+/// when parsing from a reader.
+pub const MAGIC_PREFIX_LEN: usize = 64 * 1024;
+
 pub struct AdapterMagic;
 
 impl Adaptable for AdapterMagic {
@@ -18,27 +26,133 @@ impl Adaptable for AdapterMagic {
             .or_else(|| AdapterRuby::parse(input, settings))
             .or_else(|| AdapterRust::parse(input, settings))
             .or_else(|| AdapterShell::parse(input, settings))
+            .or_else(|| AdapterJulia::parse(input, settings))
+            .or_else(|| AdapterOpenMetrics::parse(input, settings))
+            .or_else(|| AdapterJunit::parse(input, settings))
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        // Sniff in the same order as `parse`
+        let iterations = parse_reader_prefix::<Self, R>(
+            reader,
+            settings,
+            &[
+                Adapter::Json,
+                Adapter::CSharp,
+                Adapter::CppCatch2,
+                Adapter::CppGoogle,
+                Adapter::GoBench,
+                Adapter::JavaJmh,
+                Adapter::Js,
+                Adapter::Python,
+                Adapter::Ruby,
+                Adapter::RustBench,
+                Adapter::Rust,
+                Adapter::Shell,
+                Adapter::Julia,
+                Adapter::OpenMetrics,
+                Adapter::Junit,
+            ],
+        )?;
+        // Like `parse`, multiple iterations (ie Go `-count`) are averaged.
+        Ok(iterations
+            .and_then(AdapterResults::mean)
+            .map(|results| vec![results]))
+    }
+}
+
+// Detect which of the `adapters` to use from only the start of the output,
+// trying them in the same order as `A::parse`.
+// If the first detected adapter is incremental, then stream all of the output through it.
+// If all of the output fits in the prefix or the first detected adapter is not incremental,
+// then fall back to reading all of the output into memory and parsing it with `A`.
+// Output that is not detected from its prefix may still use a different adapter than `A::parse`.
+pub(crate) fn parse_reader_prefix<A, R>(
+    mut reader: R,
+    settings: Settings,
+    adapters: &[Adapter],
+) -> Result<Option<Vec<AdapterResults>>, AdapterError>
+where
+    A: Adaptable,
+    R: BufRead,
+{
+    let mut prefix = Vec::with_capacity(MAGIC_PREFIX_LEN);
+    (&mut reader)
+        .take(MAGIC_PREFIX_LEN as u64)
+        .read_to_end(&mut prefix)?;
+    // Finish the current line, so the prefix never ends partway through a line or character.
+    reader.read_until(b'\n', &mut prefix)?;
+    let mut prefix =
+        String::from_utf8(prefix).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if reader.fill_buf()?.is_empty() {
+        return Ok(A::parse_iterations(&prefix, settings));
+    }
+
+    let detected = adapters
+        .iter()
+        .find(|adapter| adapter.detect(&prefix, settings))
+        .filter(|adapter| is_incremental(**adapter));
+    if let Some(adapter) = detected {
+        // The reader is boxed, so the nested language adapters do not nest the reader type forever.
+        let reader: Box<dyn BufRead + '_> = Box::new(Cursor::new(prefix).chain(reader));
+        adapter.convert_reader(reader, settings)
+    } else {
+        reader.read_to_string(&mut prefix)?;
+        Ok(A::parse_iterations(&prefix, settings))
     }
 }
 
+// Adapters that parse their output incrementally and can be reliably detected from a prefix.
+// Bencher Metric Format JSON is incremental, but its prefix may be for another JSON adapter.
+fn is_incremental(adapter: Adapter) -> bool {
+    matches!(
+        adapter,
+        Adapter::CppCatch2
+            | Adapter::CppGoogle
+            | Adapter::GoBench
+            | Adapter::JavaJmh
+            | Adapter::RustBench
+            | Adapter::Junit
+    )
+}
+
 #[cfg(test)]
 mod test_magic {
-    use super::AdapterMagic;
-    use crate::adapters::{
-        c_sharp::{dot_net::test_c_sharp_dot_net, AdapterCSharp},
-        cpp::{catch2::test_cpp_catch2, google::test_cpp_google},
-        go::bench::test_go_bench,
-        java::jmh::test_java_jmh,
-        js::{benchmark::test_js_benchmark, time::test_js_time},
-        json::test_json,
-        python::{asv::test_python_asv, pytest::test_python_pytest},
-        ruby::benchmark::test_ruby_benchmark,
-        rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+    use std::fmt::Write;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{AdapterMagic, MAGIC_PREFIX_LEN};
+    use crate::{
+        adapters::{
+            c_sharp::{dot_net::test_c_sharp_dot_net, AdapterCSharp},
+            cpp::{catch2::test_cpp_catch2, google::test_cpp_google},
+            go::bench::test_go_bench,
+            java::jmh::test_java_jmh,
+            js::{benchmark::test_js_benchmark, time::test_js_time},
+            json::test_json,
+            julia::benchmark_tools::test_julia_benchmark_tools,
+            junit::test_junit,
+            open_metrics::test_open_metrics,
+            python::{
+                asv::test_python_asv, pyperf::test_python_pyperf, pytest::test_python_pytest,
+            },
+            ruby::benchmark::test_ruby_benchmark,
+            rust::{
+                bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+                iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
+            },
+            shell::{
+                hyperfine::test_shell_hyperfine, k6::test_shell_k6, vegeta::test_shell_vegeta,
+                wrk::test_shell_wrk,
+            },
+            test_util::{convert_file_path, convert_reader_file_path, validate_latency},
         },
-        shell::hyperfine::test_shell_hyperfine,
-        test_util::convert_file_path,
+        Adaptable, AdapterResults, Settings,
     };
 
     #[test]
@@ -71,6 +185,12 @@ mod test_magic {
         test_cpp_catch2::validate_adapter_cpp_catch2(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_cpp_catch2_xml() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/cpp/catch2/xml.txt");
+        test_cpp_catch2::validate_adapter_cpp_catch2_xml(&results);
+    }
+
     #[test]
     fn test_adapter_magic_go_bench() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/go/bench/five.txt");
@@ -107,6 +227,12 @@ mod test_magic {
         test_python_pytest::validate_adapter_python_pytest(&results);
     }
 
+    #[test]
+    fn test_adapter_python_pyperf() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/python/pyperf/two.json");
+        test_python_pyperf::validate_adapter_python_pyperf(&results);
+    }
+
     #[test]
     fn test_adapter_ruby_benchmark() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/ruby/benchmark/five.txt");
@@ -143,9 +269,168 @@ mod test_magic {
         );
     }
 
+    #[test]
+    fn test_adapter_magic_rust_divan() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/rust/divan/many.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
+
     #[test]
     fn test_adapter_magic_shell_hyperfine() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_magic_shell_k6() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/k6/summary.json");
+        test_shell_k6::validate_adapter_shell_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_shell_wrk() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/wrk/latency.txt");
+        test_shell_wrk::validate_adapter_shell_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_shell_vegeta() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/vegeta/report.json");
+        test_shell_vegeta::validate_adapter_shell_vegeta(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_julia_benchmark_tools() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/julia/benchmark_tools/suite.json");
+        test_julia_benchmark_tools::validate_adapter_julia_benchmark_tools(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_open_metrics() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/open_metrics/scrape.txt");
+        test_open_metrics::validate_adapter_open_metrics(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_junit() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/junit/pytest.xml");
+        test_junit::validate_adapter_junit_pytest(&results);
+    }
+
+    fn parse_reader(input: &str) -> AdapterResults {
+        let mut iterations = AdapterMagic::parse_reader(input.as_bytes(), Settings::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(iterations.len(), 1);
+        iterations.pop().unwrap()
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_java_jmh() {
+        let results = convert_reader_file_path::<AdapterMagic>("./tool_output/java/jmh/six.json");
+        test_java_jmh::validate_adapter_java_jmh(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_rust_bench_large() {
+        let input = (0..10_000).fold(String::new(), |mut input, i| {
+            let _ = writeln!(
+                input,
+                "test tests::benchmark_{i} ... bench: {i} ns/iter (+/- 1)"
+            );
+            input
+        });
+        assert!(input.len() > MAGIC_PREFIX_LEN);
+
+        let results = parse_reader(&input);
+        assert_eq!(results.inner.len(), 10_000);
+        let metrics = results.get("tests::benchmark_9999").unwrap();
+        validate_latency(metrics, 9999.0, Some(9998.0), Some(10_000.0));
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_junit_large() {
+        let test_cases = (0..2_000).fold(String::new(), |mut test_cases, i| {
+            let _ = writeln!(
+                test_cases,
+                r#"    <testcase classname="tests.Bench" name="bench_{i}" time="{i}" />"#
+            );
+            test_cases
+        });
+        let input = format!(
+            "<testsuites>\n  <testsuite name=\"bench\">\n{test_cases}  </testsuite>\n</testsuites>\n"
+        );
+        assert!(input.len() > MAGIC_PREFIX_LEN);
+
+        let results = parse_reader(&input);
+        assert_eq!(results.inner.len(), 2_000);
+        let metrics = results.get("tests.Bench.bench_1999").unwrap();
+        validate_latency(metrics, 1_999_000_000_000.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_java_jmh_large() {
+        let benchmarks = (0..2_000)
+            .map(|i| {
+                format!(
+                    r#"{{"benchmark": "org.example.Bench.bench{i}", "primaryMetric": {{"score": {i}.0, "scoreConfidence": [{i}.0, {i}.0], "scoreUnit": "ns/op"}}, "secondaryMetrics": {{}}}}"#
+                )
+            })
+            .collect::<Vec<_>>();
+        let input = format!("[{}]", benchmarks.join(",\n"));
+        assert!(input.len() > MAGIC_PREFIX_LEN);
+
+        let results = parse_reader(&input);
+        assert_eq!(results.inner.len(), 2_000);
+        let metrics = results.get("org.example.Bench.bench1999").unwrap();
+        validate_latency(metrics, 1999.0, Some(1999.0), Some(1999.0));
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_json_large() {
+        let benchmarks = (0..2_000)
+            .map(|i| {
+                format!(
+                    r#""tests::benchmark_{i}": {{"latency": {{"value": {i}.0, "lower_value": {i}.0, "upper_value": {i}.0}}}}"#
+                )
+            })
+            .collect::<Vec<_>>();
+        let input = format!("{{{}}}", benchmarks.join(",\n"));
+        assert!(input.len() > MAGIC_PREFIX_LEN);
+
+        // Bencher Metric Format JSON is not detected from the prefix,
+        // so all of the output is parsed at once.
+        let results = parse_reader(&input);
+        assert_eq!(results.inner.len(), 2_000);
+        let metrics = results.get("tests::benchmark_1999").unwrap();
+        validate_latency(metrics, 1999.0, Some(1999.0), Some(1999.0));
+    }
+
+    #[test]
+    fn test_adapter_magic_reader_json_order() {
+        // Benchmarks named like the C++ Google Benchmark JSON fields
+        let benchmarks = ["context", "benchmarks"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .chain((0..2_000).map(|i| format!("tests::benchmark_{i}")))
+            .map(|name| {
+                format!(
+                    r#""{name}": {{"latency": {{"value": 1.0, "lower_value": 1.0, "upper_value": 1.0}}}}"#
+                )
+            })
+            .collect::<Vec<_>>();
+        let input = format!("{{{}}}", benchmarks.join(",\n"));
+        assert!(input.len() > MAGIC_PREFIX_LEN);
+
+        // Like `parse`, Bencher Metric Format JSON is tried first.
+        let results = parse_reader(&input);
+        assert_eq!(
+            results,
+            AdapterMagic::parse(&input, Settings::default()).unwrap()
+        );
+        assert_eq!(results.inner.len(), 2_002);
+        let metrics = results.get("context").unwrap();
+        validate_latency(metrics, 1.0, Some(1.0), Some(1.0));
+    }
 }
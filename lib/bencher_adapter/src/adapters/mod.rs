@@ -6,7 +6,10 @@ pub mod go;
 pub mod java;
 pub mod js;
 pub mod json;
+pub mod julia;
+pub mod junit;
 pub mod magic;
+pub mod open_metrics;
 pub mod python;
 pub mod ruby;
 pub mod rust;
@@ -51,13 +54,26 @@ pub(crate) mod test_util {
     where
         A: Adaptable,
     {
-        let settings = Settings {
-            average: Some(JsonAverage::Median),
-        };
+        let settings = Settings::new(Some(JsonAverage::Median));
         opt_convert_file_path::<A>(file_path, settings)
             .unwrap_or_else(|| panic!("Failed to convert contents of {file_path}"))
     }
 
+    pub fn convert_reader_file_path<A>(file_path: &str) -> AdapterResults
+    where
+        A: Adaptable,
+    {
+        let file = std::fs::File::open(file_path)
+            .unwrap_or_else(|e| panic!("Failed to open test file {file_path}: {e}"));
+        let mut iterations = A::parse_reader(std::io::BufReader::new(file), Settings::default())
+            .unwrap_or_else(|e| panic!("Failed to read test file {file_path}: {e}"))
+            .unwrap_or_else(|| panic!("Failed to convert contents of {file_path}"));
+        assert_eq!(iterations.len(), 1, "Multiple iterations in {file_path}");
+        iterations
+            .pop()
+            .unwrap_or_else(|| panic!("Failed to convert contents of {file_path}"))
+    }
+
     pub fn opt_convert_file_path<A>(file_path: &str, settings: Settings) -> Option<AdapterResults>
     where
         A: Adaptable,
@@ -0,0 +1,491 @@
+use std::collections::HashMap;
+
+use bencher_json::{project::metric::MeasureNameId, BenchmarkName, JsonNewMetric};
+use ordered_float::OrderedFloat;
+
+use crate::{results::adapter_results::ResultsMap, Adaptable, AdapterResults, Settings};
+
+// The labels that are part of the metric type itself and never part of the benchmark name
+const QUANTILE_LABEL: &str = "quantile";
+const BUCKET_LABEL: &str = "le";
+
+const GAUGE_MEASURE: &str = "gauge";
+const COUNTER_MEASURE: &str = "counter";
+// Histogram buckets are estimated as these quantiles
+const HISTOGRAM_QUANTILES: [f64; 3] = [0.5, 0.95, 0.99];
+
+pub struct AdapterOpenMetrics;
+
+impl Adaptable for AdapterOpenMetrics {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        let mut metric_types = HashMap::new();
+        let mut benchmark_metrics = Vec::new();
+        let mut histograms: HashMap<String, Vec<(f64, f64)>> = HashMap::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if comment.trim() == "EOF" {
+                    break;
+                } else if let Some((family, metric_type)) = parse_type(comment) {
+                    metric_types.insert(family, metric_type);
+                }
+                continue;
+            }
+
+            let sample = parse_sample(line)?;
+            let (metric_type, family, suffix) = sample_family(&metric_types, sample.name);
+            let benchmark_name = sample.benchmark_name(family, settings.labels);
+            match (metric_type, suffix) {
+                (MetricType::Gauge | MetricType::Unknown, None) => {
+                    benchmark_metrics.push((benchmark_name, GAUGE_MEASURE.into(), sample.value));
+                },
+                (MetricType::Counter, None | Some(Suffix::Total)) => {
+                    benchmark_metrics.push((benchmark_name, COUNTER_MEASURE.into(), sample.value));
+                },
+                (MetricType::Summary, None) => {
+                    if let Some(quantile) = sample.label(QUANTILE_LABEL) {
+                        let quantile = quantile.parse().ok()?;
+                        benchmark_metrics.push((
+                            benchmark_name,
+                            quantile_measure(quantile),
+                            sample.value,
+                        ));
+                    }
+                },
+                (MetricType::Histogram, Some(Suffix::Bucket)) => {
+                    let upper_bound = parse_value(sample.label(BUCKET_LABEL)?)?;
+                    histograms
+                        .entry(benchmark_name)
+                        .or_default()
+                        .push((upper_bound, sample.value));
+                },
+                // The `_sum`, `_count`, and `_created` samples are not tracked
+                // along with any other metric types (ie `info` and `stateset`).
+                _ => {},
+            }
+        }
+
+        for (benchmark_name, mut buckets) in histograms {
+            buckets.sort_by_key(|&(upper_bound, _)| OrderedFloat(upper_bound));
+            for quantile in HISTOGRAM_QUANTILES {
+                if let Some(value) = histogram_quantile(quantile, &buckets) {
+                    benchmark_metrics.push((
+                        benchmark_name.clone(),
+                        quantile_measure(quantile),
+                        value,
+                    ));
+                }
+            }
+        }
+
+        let mut results_map = ResultsMap::new();
+        for (benchmark_name, measure, value) in benchmark_metrics {
+            if !value.is_finite() {
+                continue;
+            }
+            let benchmark_name: BenchmarkName = benchmark_name.parse().ok()?;
+            let measure: MeasureNameId = measure.parse().ok()?;
+            results_map.entry(benchmark_name).or_default().inner.insert(
+                measure,
+                JsonNewMetric {
+                    value: value.into(),
+                    lower_value: None,
+                    upper_value: None,
+                },
+            );
+        }
+
+        (!results_map.is_empty()).then(|| results_map.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    Unknown,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Suffix {
+    Total,
+    Bucket,
+    Sum,
+    Count,
+    Created,
+}
+
+impl Suffix {
+    const ALL: [(Self, &'static str); 5] = [
+        (Self::Total, "_total"),
+        (Self::Bucket, "_bucket"),
+        (Self::Sum, "_sum"),
+        (Self::Count, "_count"),
+        (Self::Created, "_created"),
+    ];
+}
+
+#[derive(Debug, PartialEq)]
+struct Sample<'i> {
+    name: &'i str,
+    labels: Vec<(&'i str, String)>,
+    value: f64,
+}
+
+impl Sample<'_> {
+    fn label(&self, key: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .find_map(|(k, v)| (*k == key).then_some(v.as_str()))
+    }
+
+    fn benchmark_name(&self, family: &str, labels: Option<&[String]>) -> String {
+        let name_labels = if let Some(labels) = labels {
+            labels
+                .iter()
+                .filter_map(|key| self.label(key).map(|value| (key.as_str(), value)))
+                .collect::<Vec<_>>()
+        } else {
+            self.labels
+                .iter()
+                .filter(|(key, _)| *key != QUANTILE_LABEL && *key != BUCKET_LABEL)
+                .map(|(key, value)| (*key, value.as_str()))
+                .collect()
+        };
+        if name_labels.is_empty() {
+            family.to_owned()
+        } else {
+            format!(
+                "{family}{{{}}}",
+                name_labels
+                    .into_iter()
+                    .map(|(key, value)| format!("{key}=\"{value}\""))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        }
+    }
+}
+
+fn parse_type(comment: &str) -> Option<(String, MetricType)> {
+    let mut parts = comment.split_whitespace();
+    if parts.next()? != "TYPE" {
+        return None;
+    }
+    let family = parts.next()?;
+    let metric_type = match parts.next()? {
+        "counter" => MetricType::Counter,
+        "gauge" => MetricType::Gauge,
+        "histogram" => MetricType::Histogram,
+        "summary" => MetricType::Summary,
+        "unknown" | "untyped" => MetricType::Unknown,
+        _ => MetricType::Other,
+    };
+    Some((family.to_owned(), metric_type))
+}
+
+// A sample line is `name{label="value",...} value [timestamp] [# exemplar]`
+fn parse_sample(line: &str) -> Option<Sample<'_>> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let (name, remainder) = line.split_at(name_end);
+    if !is_metric_name(name) {
+        return None;
+    }
+
+    let (labels, remainder) = if let Some(remainder) = remainder.strip_prefix('{') {
+        parse_labels(remainder)?
+    } else {
+        (Vec::new(), remainder)
+    };
+
+    let value = parse_value(remainder.split_whitespace().next()?)?;
+    Some(Sample {
+        name,
+        labels,
+        value,
+    })
+}
+
+fn is_metric_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+// Parse the labels after the opening brace, returning the labels and the remainder after the closing brace
+fn parse_labels(input: &str) -> Option<(Vec<(&str, String)>, &str)> {
+    let mut labels = Vec::new();
+    let mut remainder = input.trim_start();
+    loop {
+        if let Some(rest) = remainder.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+        let (key, rest) = remainder.split_once('=')?;
+        let key = key.trim();
+        if !is_metric_name(key) {
+            return None;
+        }
+        let (value, rest) = parse_label_value(rest.trim_start().strip_prefix('"')?)?;
+        labels.push((key, value));
+        let rest = rest.trim_start();
+        remainder = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+}
+
+// Parse an escaped label value after the opening quote, returning the value and the remainder after the closing quote
+fn parse_label_value(input: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, input.get(index + 1..)?)),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                escaped => value.push(escaped),
+            },
+            _ => value.push(c),
+        }
+    }
+    None
+}
+
+fn parse_value(value: &str) -> Option<f64> {
+    match value {
+        "+Inf" | "Inf" => Some(f64::INFINITY),
+        "-Inf" => Some(f64::NEG_INFINITY),
+        "NaN" => Some(f64::NAN),
+        _ => value.parse().ok(),
+    }
+}
+
+fn sample_family<'i>(
+    metric_types: &HashMap<String, MetricType>,
+    name: &'i str,
+) -> (MetricType, &'i str, Option<Suffix>) {
+    if let Some(&metric_type) = metric_types.get(name) {
+        return (metric_type, name, None);
+    }
+    for (suffix, suffix_str) in Suffix::ALL {
+        if let Some(family) = name.strip_suffix(suffix_str) {
+            if let Some(&metric_type) = metric_types.get(family) {
+                return (metric_type, family, Some(suffix));
+            }
+        }
+    }
+    (MetricType::Unknown, name, None)
+}
+
+// Quantiles are named as percentiles, for example `0.95` is `p95` and `0.999` is `p99.9`
+fn quantile_measure(quantile: f64) -> String {
+    let percentile = (quantile * 100.0 * 1_000.0).round() / 1_000.0;
+    format!("p{percentile}")
+}
+
+// Estimate a quantile from the cumulative histogram buckets, using linear interpolation within a bucket.
+// This is the same estimate as the Prometheus `histogram_quantile` function.
+fn histogram_quantile(quantile: f64, buckets: &[(f64, f64)]) -> Option<f64> {
+    let &(last_upper_bound, total) = buckets.last()?;
+    if last_upper_bound != f64::INFINITY || total <= 0.0 {
+        return None;
+    }
+
+    let rank = quantile * total;
+    let mut lower_bound = 0.0;
+    let mut lower_count = 0.0;
+    for &(upper_bound, count) in buckets {
+        if count >= rank {
+            if upper_bound == f64::INFINITY {
+                // The quantile is in the overflow bucket, so use the highest finite bound
+                return Some(lower_bound);
+            }
+            let bucket_count = count - lower_count;
+            if bucket_count <= 0.0 {
+                return Some(upper_bound);
+            }
+            return Some(
+                lower_bound + (upper_bound - lower_bound) * (rank - lower_count) / bucket_count,
+            );
+        }
+        lower_bound = upper_bound;
+        lower_count = count;
+    }
+    None
+}
+
+#[cfg(test)]
+pub(crate) mod test_open_metrics {
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use super::{histogram_quantile, parse_sample, quantile_measure, AdapterOpenMetrics, Sample};
+    use crate::{
+        adapters::test_util::convert_file_path, results::adapter_metrics::AdapterMetrics,
+        Adaptable, AdapterResults, Settings,
+    };
+
+    fn convert_open_metrics(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/open_metrics/{suffix}.txt");
+        convert_file_path::<AdapterOpenMetrics>(&file_path)
+    }
+
+    fn validate_metric(metrics: &AdapterMetrics, key: &str, value: f64) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value));
+        assert_eq!(metric.lower_value, None);
+        assert_eq!(metric.upper_value, None);
+    }
+
+    #[test]
+    fn test_parse_sample() {
+        assert_eq!(
+            parse_sample("load_test_rps 1027"),
+            Some(Sample {
+                name: "load_test_rps",
+                labels: Vec::new(),
+                value: 1027.0,
+            })
+        );
+        assert_eq!(
+            parse_sample(
+                r#"http_requests_total{method="POST",path="/a \"b\"\\c"} 1.5e3 1395066363000"#
+            ),
+            Some(Sample {
+                name: "http_requests_total",
+                labels: vec![
+                    ("method", "POST".to_owned()),
+                    ("path", r#"/a "b"\c"#.to_owned())
+                ],
+                value: 1500.0,
+            })
+        );
+        assert_eq!(
+            parse_sample(r#"rpc_seconds_bucket{le="+Inf", } 17 # {trace_id="abc"} 0.5"#),
+            Some(Sample {
+                name: "rpc_seconds_bucket",
+                labels: vec![("le", "+Inf".to_owned())],
+                value: 17.0,
+            })
+        );
+        assert_eq!(parse_sample("BenchmarkFib10-8 5000000 325 ns/op"), None);
+        assert_eq!(parse_sample(r#"unclosed{method="GET" 1"#), None);
+    }
+
+    #[test]
+    fn test_quantile_measure() {
+        assert_eq!(quantile_measure(0.5), "p50");
+        assert_eq!(quantile_measure(0.95), "p95");
+        assert_eq!(quantile_measure(0.99), "p99");
+        assert_eq!(quantile_measure(0.999), "p99.9");
+    }
+
+    #[test]
+    fn test_histogram_quantile() {
+        let buckets = [
+            (0.1, 50.0),
+            (0.5, 90.0),
+            (1.0, 100.0),
+            (f64::INFINITY, 100.0),
+        ];
+        assert_eq!(histogram_quantile(0.5, &buckets), Some(0.1));
+        assert_eq!(
+            histogram_quantile(0.7, &buckets),
+            Some(0.300_000_000_000_000_04)
+        );
+        assert_eq!(histogram_quantile(0.95, &buckets), Some(0.75));
+
+        let overflow = [(0.1, 5.0), (f64::INFINITY, 10.0)];
+        assert_eq!(histogram_quantile(0.99, &overflow), Some(0.1));
+
+        assert_eq!(histogram_quantile(0.5, &[(0.1, 5.0)]), None);
+        assert_eq!(histogram_quantile(0.5, &[(f64::INFINITY, 0.0)]), None);
+    }
+
+    #[test]
+    fn test_adapter_open_metrics() {
+        let results = convert_open_metrics("scrape");
+        validate_adapter_open_metrics(&results);
+    }
+
+    pub fn validate_adapter_open_metrics(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 6);
+
+        let metrics = results.get("load_test_virtual_users").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_metric(metrics, "gauge", 50.0);
+
+        let metrics = results
+            .get(r#"http_requests{method="GET",path="/login"}"#)
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_metric(metrics, "counter", 1027.0);
+
+        let metrics = results
+            .get(r#"http_requests{method="POST",path="/login"}"#)
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_metric(metrics, "counter", 311.0);
+
+        let metrics = results.get("rpc_duration_seconds").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_metric(metrics, "p50", 0.012);
+        validate_metric(metrics, "p90", 0.031);
+        validate_metric(metrics, "p99", 0.087);
+
+        let metrics = results
+            .get(r#"http_request_duration_seconds{path="/login"}"#)
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_metric(metrics, "p50", 0.1);
+        validate_metric(metrics, "p95", 0.75);
+        validate_metric(metrics, "p99", 0.95);
+
+        let metrics = results.get("untyped_latency_ms").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_metric(metrics, "gauge", 12.5);
+    }
+
+    #[test]
+    fn test_adapter_open_metrics_labels() {
+        let contents = std::fs::read_to_string("./tool_output/open_metrics/scrape.txt").unwrap();
+        let labels = ["path".to_owned()];
+        let results =
+            AdapterOpenMetrics::parse(&contents, Settings::default().with_labels(Some(&labels)))
+                .unwrap();
+        assert_eq!(results.inner.len(), 5);
+
+        // Both methods are now the same benchmark, so the last sample wins
+        let metrics = results.get(r#"http_requests{path="/login"}"#).unwrap();
+        validate_metric(metrics, "counter", 311.0);
+
+        let metrics = results
+            .get(r#"http_request_duration_seconds{path="/login"}"#)
+            .unwrap();
+        validate_metric(metrics, "p95", 0.75);
+
+        let labels = [];
+        let results =
+            AdapterOpenMetrics::parse(&contents, Settings::default().with_labels(Some(&labels)))
+                .unwrap();
+        assert_eq!(results.inner.len(), 5);
+        assert!(results.get("http_request_duration_seconds").is_some());
+    }
+
+    #[test]
+    fn test_adapter_open_metrics_invalid() {
+        let contents = std::fs::read_to_string("./tool_output/go/bench/five.txt").unwrap();
+        assert_eq!(
+            AdapterOpenMetrics::parse(&contents, Settings::default()),
+            None
+        );
+    }
+}
@@ -100,17 +100,13 @@ pub(crate) mod test_python_asv {
             None,
             opt_convert_file_path::<AdapterPythonAsv>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterPythonAsv>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_python_asv(&results);
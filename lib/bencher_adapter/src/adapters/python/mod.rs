@@ -1,8 +1,10 @@
 pub mod asv;
+pub mod pyperf;
 pub mod pytest;
 
 use crate::{Adaptable, AdapterResults, Settings};
 use asv::AdapterPythonAsv;
+use pyperf::AdapterPythonPyperf;
 use pytest::AdapterPythonPytest;
 
 pub struct AdapterPython;
@@ -11,6 +13,7 @@ impl Adaptable for AdapterPython {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterPythonAsv::parse(input, settings)
             .or_else(|| AdapterPythonPytest::parse(input, settings))
+            .or_else(|| AdapterPythonPyperf::parse(input, settings))
     }
 }
 
@@ -18,7 +21,7 @@ impl Adaptable for AdapterPython {
 mod test_go {
     use super::AdapterPython;
     use crate::adapters::{
-        python::asv::test_python_asv, python::pytest::test_python_pytest,
+        python::{asv::test_python_asv, pyperf::test_python_pyperf, pytest::test_python_pytest},
         test_util::convert_file_path,
     };
 
@@ -33,4 +36,10 @@ mod test_go {
         let results = convert_file_path::<AdapterPython>("./tool_output/python/pytest/four.json");
         test_python_pytest::validate_adapter_python_pytest(&results);
     }
+
+    #[test]
+    fn test_adapter_python_pyperf() {
+        let results = convert_file_path::<AdapterPython>("./tool_output/python/pyperf/two.json");
+        test_python_pyperf::validate_adapter_python_pyperf(&results);
+    }
 }
@@ -0,0 +1,153 @@
+use bencher_json::BenchmarkName;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{SampleStats, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+// Only timing benchmarks are supported
+const PYPERF_UNIT_SECOND: &str = "second";
+
+pub struct AdapterPythonPyperf;
+
+impl Adaptable for AdapterPythonPyperf {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<Pyperf>(input)
+            .ok()?
+            .convert(settings)
+    }
+}
+
+// https://pyperf.readthedocs.io/en/latest/api.html#pyperf-json-format
+#[derive(Debug, Clone, Deserialize)]
+pub struct Pyperf {
+    pub version: String,
+    // Metadata that is common to all benchmarks
+    #[serde(default)]
+    pub metadata: PyperfMetadata,
+    pub benchmarks: Vec<PyperfBenchmark>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PyperfMetadata {
+    pub name: Option<String>,
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PyperfBenchmark {
+    #[serde(default)]
+    pub metadata: PyperfMetadata,
+    pub runs: Vec<PyperfRun>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PyperfRun {
+    // Calibration runs only have warmups
+    #[serde(default)]
+    pub values: Vec<f64>,
+}
+
+impl Pyperf {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let Self {
+            metadata: common,
+            benchmarks,
+            ..
+        } = self;
+        let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
+        for benchmark in benchmarks {
+            let PyperfBenchmark { metadata, runs } = benchmark;
+            let name = metadata.name.as_ref().or(common.name.as_ref())?;
+            let unit = metadata
+                .unit
+                .as_deref()
+                .or(common.unit.as_deref())
+                .unwrap_or(PYPERF_UNIT_SECOND);
+            if unit != PYPERF_UNIT_SECOND {
+                continue;
+            }
+
+            // Values are already normalized to the time per loop iteration
+            let values = runs
+                .into_iter()
+                .flat_map(|run| run.values)
+                .collect::<Vec<_>>();
+            let Some(stats) = SampleStats::new(&values) else {
+                continue;
+            };
+
+            let benchmark_name: BenchmarkName = name.parse().ok()?;
+            let json_metric = stats.latency_metric(settings.average, Units::Sec);
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_python_pyperf {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::AdapterPythonPyperf;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/python/pyperf/{suffix}.json")
+    }
+
+    #[test]
+    fn test_adapter_python_pyperf_two() {
+        let results = convert_file_path::<AdapterPythonPyperf>(&file_path("two"));
+        validate_adapter_python_pyperf(&results);
+    }
+
+    pub fn validate_adapter_python_pyperf(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("json_dumps").unwrap();
+        validate_latency(
+            metrics,
+            49_500.000_000_000_01,
+            Some(47_629.171_306_613_04),
+            Some(51_370.828_693_386_975),
+        );
+
+        let metrics = results.get("json_loads").unwrap();
+        validate_latency(
+            metrics,
+            20_833.333_333_333_33,
+            Some(19_361.373_188_945_356),
+            Some(22_305.293_477_721_3),
+        );
+    }
+
+    #[test]
+    fn test_adapter_python_pyperf_two_median() {
+        let results = convert_file_path_median::<AdapterPythonPyperf>(&file_path("two"));
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("json_dumps").unwrap();
+        validate_latency(metrics, 49_500.0, Some(47_000.0), Some(52_000.0));
+
+        let metrics = results.get("json_loads").unwrap();
+        validate_latency(metrics, 20_500.0, Some(18_750.0), Some(22_250.0));
+    }
+
+    #[test]
+    fn test_adapter_python_pyperf_one() {
+        // The benchmark name is in the common metadata
+        let results = convert_file_path::<AdapterPythonPyperf>(&file_path("one"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("timeit").unwrap();
+        validate_latency(metrics, 250.0, Some(240.0), Some(260.0));
+    }
+}
@@ -171,9 +171,7 @@ pub(crate) mod test_python_pytest {
 
         let results = opt_convert_file_path::<AdapterPythonPytest>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_python_pytest(&results);
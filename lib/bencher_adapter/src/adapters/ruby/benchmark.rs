@@ -121,9 +121,7 @@ pub(crate) mod test_ruby_benchmark {
             None,
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
@@ -131,9 +129,7 @@ pub(crate) mod test_ruby_benchmark {
             None,
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
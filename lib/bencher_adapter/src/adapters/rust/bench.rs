@@ -1,3 +1,5 @@
+use std::io::BufRead;
+
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     bytes::complete::{tag, take_until1},
@@ -12,7 +14,7 @@ use crate::{
         latency_as_nanos, parse_benchmark_name, parse_number_as_f64, parse_units, NomError,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, AdapterError, Settings,
 };
 
 pub struct AdapterRustBench;
@@ -24,18 +26,39 @@ impl Adaptable for AdapterRustBench {
             Some(JsonAverage::Mean) => return None,
         }
 
-        let mut benchmark_metrics = Vec::new();
+        parse_cargo_lines(input.lines().map(Ok)).ok()?
+    }
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_cargo(line) {
-                if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
-                }
-            }
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        match settings.average {
+            Some(JsonAverage::Median) | None => {},
+            Some(JsonAverage::Mean) => return Ok(None),
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        Ok(parse_cargo_lines(reader.lines())?.map(|results| vec![results]))
+    }
+}
+
+fn parse_cargo_lines<L>(
+    lines: impl Iterator<Item = std::io::Result<L>>,
+) -> Result<Option<AdapterResults>, AdapterError>
+where
+    L: AsRef<str>,
+{
+    let mut benchmark_metrics = Vec::new();
+
+    for line in lines {
+        if let Ok((remainder, benchmark_metric)) = parse_cargo(line?.as_ref()) {
+            if remainder.is_empty() {
+                benchmark_metrics.push(benchmark_metric);
+            }
+        }
     }
+
+    Ok(AdapterResults::new_latency(benchmark_metrics))
 }
 
 fn parse_cargo(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
@@ -93,7 +116,9 @@ pub(crate) mod test_rust_bench {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, convert_reader_file_path, opt_convert_file_path, validate_latency,
+        },
         AdapterResults, Settings,
     };
 
@@ -125,17 +150,13 @@ pub(crate) mod test_rust_bench {
             None,
             opt_convert_file_path::<AdapterRustBench>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterRustBench>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_rust_bench(&results);
@@ -197,6 +218,13 @@ pub(crate) mod test_rust_bench {
         validate_adapter_rust_bench(&results);
     }
 
+    #[test]
+    fn test_adapter_rust_many_reader() {
+        let results =
+            convert_reader_file_path::<AdapterRustBench>("./tool_output/rust/bench/many.txt");
+        validate_adapter_rust_bench(&results);
+    }
+
     pub fn validate_adapter_rust_bench(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 6);
         validate_bench_metrics(results, "tests::benchmark");
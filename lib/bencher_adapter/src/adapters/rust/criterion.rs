@@ -175,9 +175,7 @@ pub(crate) mod test_rust_criterion {
         let file_path = "./tool_output/rust/criterion/many.txt";
         let results = opt_convert_file_path::<AdapterRustCriterion>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_rust_criterion(&results);
@@ -186,9 +184,7 @@ pub(crate) mod test_rust_criterion {
             None,
             opt_convert_file_path::<AdapterRustCriterion>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
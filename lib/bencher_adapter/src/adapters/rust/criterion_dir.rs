@@ -0,0 +1,316 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bencher_json::{
+    project::{
+        measure::built_in::{self, BuiltInMeasure},
+        report::JsonAverage,
+    },
+    BenchmarkName, JsonNewMetric,
+};
+use serde::{de::IgnoredAny, Deserialize};
+
+use crate::{
+    results::{adapter_metrics::AdapterMetrics, adapter_results::ResultsMap},
+    AdapterError, AdapterResults, Settings,
+};
+
+const NEW_DIR: &str = "new";
+const BENCHMARK_JSON: &str = "benchmark.json";
+const ESTIMATES_JSON: &str = "estimates.json";
+
+const NANOS_PER_SECOND: f64 = 1_000_000_000.0;
+
+/// Criterion on-disk results
+///
+/// Instead of parsing the human readable output of Criterion,
+/// walk its output directory and read the `new/benchmark.json`
+/// and `new/estimates.json` files for each benchmark.
+/// Criterion never removes the results of benchmarks that are no longer run,
+/// so when a `since` time is given only the estimates saved at or after it are read.
+pub struct AdapterRustCriterionDir;
+
+#[derive(Debug, Deserialize)]
+struct CriterionBenchmark {
+    full_id: String,
+    throughput: Option<CriterionThroughput>,
+}
+
+// There is no built-in Measure for byte throughput, so only elements are tracked.
+#[derive(Debug, Clone, Copy, Deserialize)]
+enum CriterionThroughput {
+    Bytes(IgnoredAny),
+    BytesDecimal(IgnoredAny),
+    Elements(u64),
+}
+
+#[derive(Debug, Deserialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+    median: CriterionEstimate,
+    slope: Option<CriterionEstimate>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct CriterionEstimate {
+    confidence_interval: CriterionConfidenceInterval,
+    point_estimate: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct CriterionConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+impl AdapterRustCriterionDir {
+    pub fn parse_dir(
+        dir: &Path,
+        since: Option<SystemTime>,
+        settings: Settings,
+    ) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut new_dirs = Vec::new();
+        find_new_dirs(dir, since.map(whole_seconds), &mut new_dirs)?;
+
+        let mut results_map = ResultsMap::new();
+        for new_dir in new_dirs {
+            let benchmark: CriterionBenchmark = read_json(&new_dir.join(BENCHMARK_JSON))?;
+            let estimates: CriterionEstimates = read_json(&new_dir.join(ESTIMATES_JSON))?;
+
+            let benchmark_name: BenchmarkName = benchmark.full_id.parse()?;
+            let estimate = estimates.average(settings.average);
+            let mut metrics = HashMap::new();
+            metrics.insert(
+                built_in::default::Latency::name_id(),
+                estimate.latency_metric(),
+            );
+            if let Some(CriterionThroughput::Elements(elements)) = benchmark.throughput {
+                metrics.insert(
+                    built_in::default::Throughput::name_id(),
+                    estimate.throughput_metric(elements),
+                );
+            }
+            results_map.insert(benchmark_name, AdapterMetrics { inner: metrics });
+        }
+
+        Ok((!results_map.is_empty()).then(|| results_map.into()))
+    }
+}
+
+fn find_new_dirs(
+    dir: &Path,
+    since: Option<SystemTime>,
+    new_dirs: &mut Vec<PathBuf>,
+) -> Result<(), AdapterError> {
+    let read_dir = std::fs::read_dir(dir).map_err(|error| AdapterError::CriterionDir {
+        path: dir.to_path_buf(),
+        error,
+    })?;
+    for entry in read_dir {
+        let path = entry
+            .map_err(|error| AdapterError::CriterionDir {
+                path: dir.to_path_buf(),
+                error,
+            })?
+            .path();
+        if !path.is_dir() {
+            continue;
+        }
+        // Criterion also saves the `base` and `change` results from prior runs,
+        // only the `new` results are for the current run.
+        if path.file_name().is_some_and(|name| name == NEW_DIR) {
+            let estimates_json = path.join(ESTIMATES_JSON);
+            if path.join(BENCHMARK_JSON).is_file()
+                && estimates_json.is_file()
+                && is_modified_since(&estimates_json, since)?
+            {
+                new_dirs.push(path);
+            }
+        } else {
+            find_new_dirs(&path, since, new_dirs)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_modified_since(path: &Path, since: Option<SystemTime>) -> Result<bool, AdapterError> {
+    let Some(since) = since else {
+        return Ok(true);
+    };
+    let modified = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|error| AdapterError::CriterionDir {
+            path: path.to_path_buf(),
+            error,
+        })?;
+    Ok(modified >= since)
+}
+
+// Some file systems only store modification times to the second,
+// so round down to avoid skipping estimates saved in the same second.
+fn whole_seconds(time: SystemTime) -> SystemTime {
+    time.duration_since(UNIX_EPOCH).map_or(time, |duration| {
+        UNIX_EPOCH + Duration::from_secs(duration.as_secs())
+    })
+}
+
+fn read_json<T>(path: &Path) -> Result<T, AdapterError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let contents = std::fs::read_to_string(path).map_err(|error| AdapterError::CriterionDir {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    serde_json::from_str(&contents).map_err(|error| AdapterError::CriterionJson {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+impl CriterionEstimates {
+    // Criterion reports the slope estimate as its `time`, if it is available.
+    // Otherwise, it falls back to the mean.
+    fn average(&self, average: Option<JsonAverage>) -> CriterionEstimate {
+        match average {
+            None => self.slope.unwrap_or(self.mean),
+            Some(JsonAverage::Mean) => self.mean,
+            Some(JsonAverage::Median) => self.median,
+        }
+    }
+}
+
+impl CriterionEstimate {
+    fn latency_metric(self) -> JsonNewMetric {
+        JsonNewMetric {
+            value: self.point_estimate.into(),
+            lower_value: Some(self.confidence_interval.lower_bound.into()),
+            upper_value: Some(self.confidence_interval.upper_bound.into()),
+        }
+    }
+
+    // Criterion estimates are in nanoseconds per iteration,
+    // so the fastest time is the upper bound for throughput.
+    #[allow(clippy::cast_precision_loss)]
+    fn throughput_metric(self, elements: u64) -> JsonNewMetric {
+        let per_second = |nanos: f64| elements as f64 * NANOS_PER_SECOND / nanos;
+        JsonNewMetric {
+            value: per_second(self.point_estimate).into(),
+            lower_value: Some(per_second(self.confidence_interval.upper_bound).into()),
+            upper_value: Some(per_second(self.confidence_interval.lower_bound).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_rust_criterion_dir {
+    use std::{
+        path::Path,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use bencher_json::project::report::JsonAverage;
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{validate_latency, validate_metric},
+        AdapterError, Settings,
+    };
+
+    use super::AdapterRustCriterionDir;
+
+    const CRITERION_DIR: &str = "./tool_output/rust/criterion/dir";
+
+    fn parse_criterion_dir(average: Option<JsonAverage>) -> crate::AdapterResults {
+        AdapterRustCriterionDir::parse_dir(Path::new(CRITERION_DIR), None, Settings::new(average))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_dir() {
+        let results = parse_criterion_dir(None);
+        assert_eq!(results.inner.len(), 3);
+
+        // No slope for flat sampling, so fall back to the mean
+        let metrics = results.get("file").unwrap();
+        validate_latency(metrics, 323.9, Some(320.62), Some(327.55));
+
+        // Benchmark group with a parameterized ID uses the slope
+        let metrics = results.get("fib/recursive/20").unwrap();
+        validate_latency(metrics, 18019.0, Some(16652.0), Some(19562.0));
+
+        let metrics = results.get("fib/iterative/20").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        let latency = metrics.get("latency").unwrap();
+        assert_eq!(latency.value, OrderedFloat::from(20.0));
+        assert_eq!(latency.lower_value, Some(OrderedFloat::from(16.0)));
+        assert_eq!(latency.upper_value, Some(OrderedFloat::from(25.0)));
+        let throughput = metrics.get("throughput").unwrap();
+        assert_eq!(throughput.value, OrderedFloat::from(50_000_000.0));
+        assert_eq!(
+            throughput.lower_value,
+            Some(OrderedFloat::from(40_000_000.0))
+        );
+        assert_eq!(
+            throughput.upper_value,
+            Some(OrderedFloat::from(62_500_000.0))
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_dir_average() {
+        let results = parse_criterion_dir(Some(JsonAverage::Mean));
+        let metrics = results.get("fib/recursive/20").unwrap();
+        validate_latency(metrics, 18100.0, Some(16700.0), Some(19600.0));
+
+        let results = parse_criterion_dir(Some(JsonAverage::Median));
+        let metrics = results.get("fib/recursive/20").unwrap();
+        validate_metric(metrics, "latency", 18000.0, Some(16600.0), Some(19500.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_dir_empty() {
+        let results = AdapterRustCriterionDir::parse_dir(
+            Path::new("./tool_output/rust/bench"),
+            None,
+            Settings::default(),
+        )
+        .unwrap();
+        assert_eq!(results, None);
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_dir_missing() {
+        let error = AdapterRustCriterionDir::parse_dir(
+            Path::new("./tool_output/rust/criterion/missing"),
+            None,
+            Settings::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, AdapterError::CriterionDir { .. }));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_dir_since() {
+        let parse_since = |since| {
+            AdapterRustCriterionDir::parse_dir(
+                Path::new(CRITERION_DIR),
+                Some(since),
+                Settings::default(),
+            )
+            .unwrap()
+        };
+
+        let results = parse_since(UNIX_EPOCH).unwrap();
+        assert_eq!(results.inner.len(), 3);
+
+        // Stale estimates from before the command started are skipped
+        let results = parse_since(SystemTime::now() + Duration::from_secs(60));
+        assert_eq!(results, None);
+    }
+}
@@ -0,0 +1,480 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{eof, map, opt},
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{latency_as_nanos, parse_f64, parse_units},
+    results::adapter_results::{AdapterResults, DivanMeasure},
+    Adaptable, Settings,
+};
+
+pub struct AdapterRustDivan;
+
+const SEPARATOR: char = '│';
+// fastest, slowest, median, mean, samples, iters
+const COLUMNS: usize = 6;
+
+impl Adaptable for AdapterRustDivan {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        let average = settings.average.unwrap_or(JsonAverage::Mean);
+
+        let mut benchmark_metrics = Vec::new();
+
+        let mut table: Option<Table> = None;
+        for line in input.lines() {
+            if let Some(header) = Table::parse_header(line) {
+                if let Some(table) = table.replace(header) {
+                    table.finish(&mut benchmark_metrics);
+                }
+                continue;
+            }
+
+            if let Some(current_table) = table.as_mut() {
+                if current_table.parse_row(line, average).is_none() {
+                    if let Some(table) = table.take() {
+                        table.finish(&mut benchmark_metrics);
+                    }
+                }
+            }
+        }
+        if let Some(table) = table {
+            table.finish(&mut benchmark_metrics);
+        }
+
+        AdapterResults::new_divan(benchmark_metrics)
+    }
+}
+
+// A divan output table with a tree of benchmark names in the first column.
+// For example:
+// example           fastest       │ slowest       │ median        │ mean          │ samples │ iters
+// ├─ add            0.166 ns      │ 0.5 ns        │ 0.174 ns      │ 0.18 ns       │ 100     │ 819200
+// ╰─ fibonacci                    │               │               │               │         │
+//    ╰─ 16          2.58 µs       │ 4.5 µs        │ 2.62 µs       │ 2.7 µs        │ 100     │ 200
+struct Table {
+    root: String,
+    // The character offset of the `fastest` column
+    offset: usize,
+    path: Vec<String>,
+    section: Option<AllocSection>,
+    benchmark: Option<(BenchmarkName, Vec<DivanMeasure>)>,
+    benchmarks: Vec<(BenchmarkName, Vec<DivanMeasure>)>,
+}
+
+#[derive(Clone, Copy)]
+enum AllocSection {
+    MaxAlloc,
+    Alloc,
+    Dealloc,
+    Grow,
+    Shrink,
+}
+
+#[derive(Clone, Copy)]
+enum Column {
+    Latency,
+    Throughput,
+    Count,
+    Bytes,
+}
+
+impl Table {
+    fn parse_header(line: &str) -> Option<Self> {
+        let (root, columns) = line.split_once("fastest")?;
+        let mut columns = columns.split(SEPARATOR).skip(1).map(str::trim);
+        for expected in ["slowest", "median", "mean", "samples", "iters"] {
+            if columns.next()? != expected {
+                return None;
+            }
+        }
+        Some(Self {
+            root: root.trim().to_owned(),
+            offset: root.chars().count(),
+            path: Vec::new(),
+            section: None,
+            benchmark: None,
+            benchmarks: Vec::new(),
+        })
+    }
+
+    // Returns `None` once the line is no longer a part of the table
+    fn parse_row(&mut self, line: &str, average: JsonAverage) -> Option<()> {
+        let name_area = line.chars().take(self.offset).collect::<String>();
+        let value_area = line.chars().skip(self.offset).collect::<String>();
+        let cells = value_area
+            .split(SEPARATOR)
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        if cells.len() != COLUMNS {
+            return None;
+        }
+
+        let (depth, name) = parse_tree(&name_area)?;
+        if !name.is_empty() {
+            self.push_benchmark();
+            self.path.truncate(depth.saturating_sub(1));
+            self.path.push(name.to_owned());
+            self.section = None;
+            // Group rows do not have any values
+            if let Some(json_metric) = parse_cells(&cells, average, Column::Latency) {
+                let benchmark_name = self.benchmark_name()?;
+                self.benchmark = Some((benchmark_name, vec![DivanMeasure::Latency(json_metric)]));
+            }
+            return Some(());
+        }
+
+        // Any remaining rows are counters for the current benchmark
+        let Some((_, measures)) = self.benchmark.as_mut() else {
+            return Some(());
+        };
+        let first_cell = cells.first().copied().unwrap_or_default();
+        if let Some(section) = parse_alloc_section(first_cell) {
+            self.section = Some(section);
+        } else if let Some(json_metric) = parse_cells(&cells, average, Column::Throughput) {
+            measures.push(DivanMeasure::Throughput(json_metric));
+        } else if let Some(section) = self.section {
+            if let Some(json_metric) = parse_cells(&cells, average, Column::Bytes) {
+                measures.push(section.bytes(json_metric));
+            } else if let Some(json_metric) = parse_cells(&cells, average, Column::Count) {
+                measures.push(section.count(json_metric));
+            }
+        }
+        Some(())
+    }
+
+    fn benchmark_name(&self) -> Option<BenchmarkName> {
+        let mut name = self.root.clone();
+        for segment in &self.path {
+            if !name.is_empty() {
+                name.push_str("::");
+            }
+            name.push_str(segment);
+        }
+        name.parse().ok()
+    }
+
+    fn push_benchmark(&mut self) {
+        if let Some(benchmark) = self.benchmark.take() {
+            self.benchmarks.push(benchmark);
+        }
+    }
+
+    fn finish(mut self, benchmark_metrics: &mut Vec<(BenchmarkName, Vec<DivanMeasure>)>) {
+        self.push_benchmark();
+        benchmark_metrics.extend(self.benchmarks);
+    }
+}
+
+impl AllocSection {
+    fn count(self, json_metric: JsonNewMetric) -> DivanMeasure {
+        match self {
+            Self::MaxAlloc => DivanMeasure::MaxAllocCount(json_metric),
+            Self::Alloc => DivanMeasure::AllocCount(json_metric),
+            Self::Dealloc => DivanMeasure::DeallocCount(json_metric),
+            Self::Grow => DivanMeasure::GrowCount(json_metric),
+            Self::Shrink => DivanMeasure::ShrinkCount(json_metric),
+        }
+    }
+
+    fn bytes(self, json_metric: JsonNewMetric) -> DivanMeasure {
+        match self {
+            Self::MaxAlloc => DivanMeasure::MaxAllocBytes(json_metric),
+            Self::Alloc => DivanMeasure::AllocBytes(json_metric),
+            Self::Dealloc => DivanMeasure::DeallocBytes(json_metric),
+            Self::Grow => DivanMeasure::GrowBytes(json_metric),
+            Self::Shrink => DivanMeasure::ShrinkBytes(json_metric),
+        }
+    }
+}
+
+// Parse the tree prefix of the name column, returning the depth and the name.
+// Counter rows only have a prefix, so their name is empty.
+fn parse_tree(name_area: &str) -> Option<(usize, &str)> {
+    let mut remainder = name_area;
+    let mut depth = 0;
+    while let Some(rest) = remainder
+        .strip_prefix("│  ")
+        .or_else(|| remainder.strip_prefix("   "))
+    {
+        remainder = rest;
+        depth += 1;
+    }
+    if let Some(rest) = remainder
+        .strip_prefix("├─ ")
+        .or_else(|| remainder.strip_prefix("╰─ "))
+    {
+        Some((depth + 1, rest.trim()))
+    } else {
+        let name = remainder.trim_matches(|c: char| c == SEPARATOR || c.is_whitespace());
+        name.is_empty().then_some((depth, name))
+    }
+}
+
+fn parse_alloc_section(cell: &str) -> Option<AllocSection> {
+    match cell {
+        "max alloc:" => Some(AllocSection::MaxAlloc),
+        "alloc:" => Some(AllocSection::Alloc),
+        "dealloc:" => Some(AllocSection::Dealloc),
+        "grow:" => Some(AllocSection::Grow),
+        "shrink:" => Some(AllocSection::Shrink),
+        _ => None,
+    }
+}
+
+// Map the fastest, slowest, median, and mean columns to a metric
+// with the lower and upper values being the extremes of all four
+fn parse_cells(cells: &[&str], average: JsonAverage, column: Column) -> Option<JsonNewMetric> {
+    let parse_cell = |cell: &str| -> Option<OrderedFloat<f64>> {
+        let parser = match column {
+            Column::Latency => parse_divan_latency,
+            Column::Throughput => parse_divan_throughput,
+            Column::Count => parse_divan_count,
+            Column::Bytes => parse_divan_bytes,
+        };
+        terminated(parser, eof)(cell).ok().map(|(_, value)| value)
+    };
+    let fastest = parse_cell(cells.first()?)?;
+    let slowest = parse_cell(cells.get(1)?)?;
+    let median = parse_cell(cells.get(2)?)?;
+    let mean = parse_cell(cells.get(3)?)?;
+
+    let value = match average {
+        JsonAverage::Mean => mean,
+        JsonAverage::Median => median,
+    };
+    let all = [fastest, slowest, median, mean];
+    Some(JsonNewMetric {
+        value,
+        lower_value: all.iter().min().copied(),
+        upper_value: all.iter().max().copied(),
+    })
+}
+
+fn parse_divan_latency(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((parse_f64, space1, parse_units)),
+        |(duration, _, units)| latency_as_nanos(duration, units),
+    )(input)
+}
+
+// Only item counters are mapped to throughput,
+// byte and char counters are ignored.
+fn parse_divan_throughput(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((parse_f64, space1, opt(parse_scale), tag("item/s"))),
+        |(items, _, scale, _)| (items * scale.unwrap_or(1.0)).into(),
+    )(input)
+}
+
+fn parse_divan_count(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((parse_f64, opt(preceded(space1, parse_scale)))),
+        |(count, scale)| (count * scale.unwrap_or(1.0)).into(),
+    )(input)
+}
+
+fn parse_divan_bytes(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((
+            parse_f64,
+            space1,
+            alt((
+                terminated(parse_binary_scale, tag("B")),
+                terminated(parse_scale, tag("B")),
+                map(tag("B"), |_| 1.0),
+            )),
+        )),
+        |(bytes, _, scale)| (bytes * scale).into(),
+    )(input)
+}
+
+fn parse_scale(input: &str) -> IResult<&str, f64> {
+    alt((
+        map(tag("K"), |_| 1e3),
+        map(tag("M"), |_| 1e6),
+        map(tag("G"), |_| 1e9),
+        map(tag("T"), |_| 1e12),
+    ))(input)
+}
+
+fn parse_binary_scale(input: &str) -> IResult<&str, f64> {
+    alt((
+        map(tag("Ki"), |_| 1024.0),
+        map(tag("Mi"), |_| 1024.0 * 1024.0),
+        map(tag("Gi"), |_| 1024.0 * 1024.0 * 1024.0),
+        map(tag("Ti"), |_| 1024.0 * 1024.0 * 1024.0 * 1024.0),
+    ))(input)
+}
+
+#[cfg(test)]
+pub(crate) mod test_rust_divan {
+    use bencher_json::project::measure::built_in::{divan, BuiltInMeasure};
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, validate_latency, validate_metric,
+        },
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults,
+    };
+
+    use super::{parse_divan_bytes, parse_divan_count, parse_tree, AdapterRustDivan};
+
+    fn convert_rust_divan(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/rust/divan/{suffix}.txt");
+        convert_file_path::<AdapterRustDivan>(&file_path)
+    }
+
+    fn validate_divan_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: f64,
+        upper_value: f64,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(metric.lower_value, Some(lower_value.into()), "{key}");
+        assert_eq!(metric.upper_value, Some(upper_value.into()), "{key}");
+    }
+
+    #[test]
+    fn test_parse_tree() {
+        for (index, (expected, input)) in [
+            (Some((1, "add")), "├─ add            "),
+            (Some((1, "fibonacci")), "╰─ fibonacci      "),
+            (Some((2, "16")), "│  ╰─ 16          "),
+            (Some((3, "vec")), "   │  ├─ vec       "),
+            (None, "example          "),
+            (Some((6, "")), "│                 "),
+            (None, "│  some text      "),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(expected, parse_tree(input), "#{index}: {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_divan_counters() {
+        assert_eq!(parse_divan_count("4").unwrap().1, OrderedFloat::from(4.0));
+        assert_eq!(
+            parse_divan_count("1.5 K").unwrap().1,
+            OrderedFloat::from(1_500.0)
+        );
+        assert_eq!(
+            parse_divan_bytes("120 B").unwrap().1,
+            OrderedFloat::from(120.0)
+        );
+        assert_eq!(
+            parse_divan_bytes("1.5 KB").unwrap().1,
+            OrderedFloat::from(1_500.0)
+        );
+        assert_eq!(
+            parse_divan_bytes("2 KiB").unwrap().1,
+            OrderedFloat::from(2_048.0)
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_rust_divan("many");
+        validate_adapter_rust_divan(&results);
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_median() {
+        let results =
+            convert_file_path_median::<AdapterRustDivan>("./tool_output/rust/divan/many.txt");
+        assert_eq!(results.inner.len(), 5);
+
+        let metrics = results.get("example::fibonacci::16").unwrap();
+        validate_latency(metrics, 2_620.0, Some(2_580.0), Some(4_500.0));
+    }
+
+    pub fn validate_adapter_rust_divan(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 5);
+
+        let metrics = results.get("example::add").unwrap();
+        validate_latency(metrics, 0.18, Some(0.166), Some(0.5));
+
+        let metrics = results.get("example::fibonacci::1").unwrap();
+        validate_latency(metrics, 0.178, Some(0.166), Some(0.229));
+
+        let metrics = results.get("example::fibonacci::2").unwrap();
+        validate_latency(metrics, 1.6, Some(1.5), Some(2.1));
+
+        let metrics = results.get("example::fibonacci::16").unwrap();
+        validate_latency(metrics, 2_700.0, Some(2_580.0), Some(4_500.0));
+
+        let metrics = results.get("example::sort::vec").unwrap();
+        validate_latency(metrics, 13_400.0, Some(12_500.0), Some(20_100.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_alloc() {
+        let results = convert_rust_divan("alloc");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("alloc::vec_push").unwrap();
+        assert_eq!(metrics.inner.len(), 9);
+        validate_divan_metric(metrics, "latency", 90.47, 82.49, 290.3);
+        validate_divan_metric(metrics, divan::MaxAllocCount::SLUG_STR, 1.0, 1.0, 1.0);
+        validate_divan_metric(metrics, divan::MaxAllocBytes::SLUG_STR, 16.0, 16.0, 16.0);
+        validate_divan_metric(metrics, divan::AllocCount::SLUG_STR, 4.0, 4.0, 4.0);
+        validate_divan_metric(metrics, divan::AllocBytes::SLUG_STR, 120.0, 120.0, 120.0);
+        validate_divan_metric(metrics, divan::DeallocCount::SLUG_STR, 3.0, 3.0, 3.0);
+        validate_divan_metric(metrics, divan::DeallocBytes::SLUG_STR, 56.0, 56.0, 56.0);
+        validate_divan_metric(metrics, divan::GrowCount::SLUG_STR, 2.0, 2.0, 2.0);
+        validate_divan_metric(metrics, divan::GrowBytes::SLUG_STR, 48.0, 48.0, 48.0);
+
+        let metrics = results.get("alloc::collect::1000").unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_divan_metric(metrics, "latency", 1_035.0, 980.1, 1_400.0);
+        validate_divan_metric(
+            metrics,
+            "throughput",
+            968_750_000.0,
+            750_000_000.0,
+            1_250_000_000.0,
+        );
+        validate_divan_metric(metrics, divan::MaxAllocCount::SLUG_STR, 1.0, 1.0, 1.0);
+        validate_divan_metric(
+            metrics,
+            divan::MaxAllocBytes::SLUG_STR,
+            4_000.0,
+            4_000.0,
+            4_000.0,
+        );
+        validate_divan_metric(metrics, divan::AllocCount::SLUG_STR, 1.0, 1.0, 1.0);
+        validate_divan_metric(
+            metrics,
+            divan::AllocBytes::SLUG_STR,
+            4_000.0,
+            4_000.0,
+            4_000.0,
+        );
+
+        // Byte throughput is ignored
+        let metrics = results.get("alloc::checksum").unwrap();
+        validate_latency(metrics, 7.5, Some(7.3), Some(9.5));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_average() {
+        let results =
+            convert_file_path_median::<AdapterRustDivan>("./tool_output/rust/divan/alloc.txt");
+        let metrics = results.get("alloc::checksum").unwrap();
+        validate_metric(metrics, "latency", 7.4, Some(7.3), Some(9.5));
+    }
+}
@@ -1,12 +1,21 @@
 pub mod bench;
 pub mod criterion;
+pub mod criterion_dir;
+pub mod divan;
 pub mod iai;
 pub mod iai_callgrind;
 
 use self::{
-    criterion::AdapterRustCriterion, iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind,
+    criterion::AdapterRustCriterion, divan::AdapterRustDivan, iai::AdapterRustIai,
+    iai_callgrind::AdapterRustIaiCallgrind,
+};
+use std::io::BufRead;
+
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    adapters::magic::parse_reader_prefix, Adaptable, AdapterError, AdapterResults, Settings,
 };
-use crate::{Adaptable, AdapterResults, Settings};
 use bench::AdapterRustBench;
 
 pub struct AdapterRust;
@@ -17,6 +26,14 @@ impl Adaptable for AdapterRust {
             .or_else(|| AdapterRustCriterion::parse(input, settings))
             .or_else(|| AdapterRustIai::parse(input, settings))
             .or_else(|| AdapterRustIaiCallgrind::parse(input, settings))
+            .or_else(|| AdapterRustDivan::parse(input, settings))
+    }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        parse_reader_prefix::<Self, R>(reader, settings, &[Adapter::RustBench])
     }
 }
 
@@ -25,8 +42,8 @@ mod test_rust {
     use super::AdapterRust;
     use crate::adapters::{
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+            bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+            iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
         },
         test_util::convert_file_path,
     };
@@ -60,4 +77,10 @@ mod test_rust {
             &test_rust_iai_callgrind::OptionalMetrics::default(),
         );
     }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_file_path::<AdapterRust>("./tool_output/rust/divan/many.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
 }
@@ -122,9 +122,7 @@ pub(crate) mod test_shell_hyperfine {
 
         let results = opt_convert_file_path::<AdapterShellHyperfine>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_shell_hyperfine(&results);
@@ -0,0 +1,181 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::{AdapterResults, LoadMeasure},
+    Adaptable, Settings,
+};
+
+const K6_BENCHMARK_NAME: &str = "k6";
+
+pub struct AdapterShellK6;
+
+impl Adaptable for AdapterShellK6 {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<K6>(input).ok()?.convert(settings)
+    }
+}
+
+// https://grafana.com/docs/k6/latest/results-output/end-of-test/#summary-export
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6 {
+    pub metrics: K6Metrics,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Metrics {
+    pub http_req_duration: K6Trend,
+    pub http_reqs: K6Counter,
+    pub http_req_failed: Option<K6Rate>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct K6Trend {
+    pub avg: OrderedFloat<f64>,
+    pub min: OrderedFloat<f64>,
+    pub med: OrderedFloat<f64>,
+    pub max: OrderedFloat<f64>,
+    #[serde(rename = "p(90)")]
+    pub p90: Option<OrderedFloat<f64>>,
+    #[serde(rename = "p(95)")]
+    pub p95: Option<OrderedFloat<f64>>,
+    #[serde(rename = "p(99)")]
+    pub p99: Option<OrderedFloat<f64>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct K6Counter {
+    pub count: u64,
+    pub rate: OrderedFloat<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct K6Rate {
+    pub value: OrderedFloat<f64>,
+}
+
+impl K6 {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let K6Metrics {
+            http_req_duration,
+            http_reqs,
+            http_req_failed,
+        } = self.metrics;
+
+        let mut metrics = vec![
+            LoadMeasure::Latency(http_req_duration.latency_metric(settings.average)),
+            LoadMeasure::Throughput(JsonNewMetric {
+                value: http_reqs.rate,
+                lower_value: None,
+                upper_value: None,
+            }),
+        ];
+        // The `http_req_failed` metric was added in k6 v0.31.0
+        if let Some(http_req_failed) = http_req_failed {
+            metrics.push(LoadMeasure::ErrorRate(JsonNewMetric {
+                value: http_req_failed.value * 100.0,
+                lower_value: None,
+                upper_value: None,
+            }));
+        }
+
+        let benchmark_name: BenchmarkName = K6_BENCHMARK_NAME.parse().ok()?;
+        AdapterResults::new_load(vec![(benchmark_name, metrics)])
+    }
+}
+
+impl K6Trend {
+    // Trend durations are always in milliseconds.
+    // The median is the lower bound for the mean,
+    // and the minimum is the lower bound for the median.
+    // The highest tail percentile that was exported is always the upper bound.
+    fn latency_metric(self, average: Option<JsonAverage>) -> JsonNewMetric {
+        let units = Units::Milli;
+        let (value, lower) = match average.unwrap_or_default() {
+            JsonAverage::Mean => (self.avg, self.med),
+            JsonAverage::Median => (self.med, self.min),
+        };
+        let upper = self.p99.or(self.p95).or(self.p90).unwrap_or(self.max);
+        JsonNewMetric {
+            value: latency_as_nanos(value, units),
+            lower_value: Some(latency_as_nanos(lower, units)),
+            upper_value: Some(latency_as_nanos(upper, units)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_k6 {
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median},
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults,
+    };
+
+    use super::AdapterShellK6;
+
+    const K6_SUMMARY: &str = "./tool_output/shell/k6/summary.json";
+
+    fn validate_load_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(
+            metric.lower_value,
+            lower_value.map(OrderedFloat::from),
+            "{key}"
+        );
+        assert_eq!(
+            metric.upper_value,
+            upper_value.map(OrderedFloat::from),
+            "{key}"
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_k6() {
+        let results = convert_file_path::<AdapterShellK6>(K6_SUMMARY);
+        validate_adapter_shell_k6(&results);
+    }
+
+    pub fn validate_adapter_shell_k6(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("k6").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_load_metric(
+            metrics,
+            "latency",
+            18_250_000.0,
+            Some(16_500_000.0),
+            Some(31_250_000.0),
+        );
+        validate_load_metric(metrics, "throughput", 99.9625, None, None);
+        validate_load_metric(metrics, "error-rate", 0.2, None, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_k6_median() {
+        let results = convert_file_path_median::<AdapterShellK6>(K6_SUMMARY);
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("k6").unwrap();
+        validate_load_metric(
+            metrics,
+            "latency",
+            16_500_000.0,
+            Some(9_500_000.0),
+            Some(31_250_000.0),
+        );
+    }
+}
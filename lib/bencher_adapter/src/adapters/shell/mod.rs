@@ -1,24 +1,57 @@
 pub mod hyperfine;
+pub mod k6;
+pub mod vegeta;
+pub mod wrk;
 
 use crate::{Adaptable, AdapterResults, Settings};
 use hyperfine::AdapterShellHyperfine;
+use k6::AdapterShellK6;
+use vegeta::AdapterShellVegeta;
+use wrk::AdapterShellWrk;
 
 pub struct AdapterShell;
 
 impl Adaptable for AdapterShell {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterShellHyperfine::parse(input, settings)
+            .or_else(|| AdapterShellK6::parse(input, settings))
+            .or_else(|| AdapterShellWrk::parse(input, settings))
+            .or_else(|| AdapterShellVegeta::parse(input, settings))
     }
 }
 
 #[cfg(test)]
 mod test_shell {
     use super::AdapterShell;
-    use crate::adapters::{shell::hyperfine::test_shell_hyperfine, test_util::convert_file_path};
+    use crate::adapters::{
+        shell::{
+            hyperfine::test_shell_hyperfine, k6::test_shell_k6, vegeta::test_shell_vegeta,
+            wrk::test_shell_wrk,
+        },
+        test_util::convert_file_path,
+    };
 
     #[test]
     fn test_adapter_shell_hyperfine() {
         let results = convert_file_path::<AdapterShell>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_shell_k6() {
+        let results = convert_file_path::<AdapterShell>("./tool_output/shell/k6/summary.json");
+        test_shell_k6::validate_adapter_shell_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_shell_wrk() {
+        let results = convert_file_path::<AdapterShell>("./tool_output/shell/wrk/latency.txt");
+        test_shell_wrk::validate_adapter_shell_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_shell_vegeta() {
+        let results = convert_file_path::<AdapterShell>("./tool_output/shell/vegeta/report.json");
+        test_shell_vegeta::validate_adapter_shell_vegeta(&results);
+    }
 }
@@ -0,0 +1,162 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::{AdapterResults, LoadMeasure},
+    Adaptable, Settings,
+};
+
+const VEGETA_BENCHMARK_NAME: &str = "vegeta";
+
+pub struct AdapterShellVegeta;
+
+impl Adaptable for AdapterShellVegeta {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<Vegeta>(input)
+            .ok()?
+            .convert(settings)
+    }
+}
+
+// https://github.com/tsenart/vegeta/blob/v12.12.0/lib/metrics.go#L14
+#[derive(Debug, Clone, Deserialize)]
+pub struct Vegeta {
+    pub latencies: VegetaLatencies,
+    pub requests: u64,
+    pub rate: OrderedFloat<f64>,
+    pub throughput: OrderedFloat<f64>,
+    pub success: OrderedFloat<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct VegetaLatencies {
+    pub mean: u64,
+    #[serde(rename = "50th")]
+    pub p50: u64,
+    #[serde(rename = "90th")]
+    pub p90: u64,
+    #[serde(rename = "95th")]
+    pub p95: u64,
+    #[serde(rename = "99th")]
+    pub p99: u64,
+    pub max: u64,
+    pub min: u64,
+}
+
+impl Vegeta {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let metrics = vec![
+            LoadMeasure::Latency(self.latencies.latency_metric(settings.average)),
+            // Vegeta throughput only counts successful requests,
+            // unlike the rate which counts all attempted requests.
+            LoadMeasure::Throughput(JsonNewMetric {
+                value: self.throughput,
+                lower_value: None,
+                upper_value: None,
+            }),
+            LoadMeasure::ErrorRate(JsonNewMetric {
+                value: (OrderedFloat::from(1.0) - self.success) * 100.0,
+                lower_value: None,
+                upper_value: None,
+            }),
+        ];
+
+        let benchmark_name: BenchmarkName = VEGETA_BENCHMARK_NAME.parse().ok()?;
+        AdapterResults::new_load(vec![(benchmark_name, metrics)])
+    }
+}
+
+impl VegetaLatencies {
+    // Latencies are always in nanoseconds.
+    // The median is the lower bound for the mean,
+    // and the minimum is the lower bound for the median.
+    // The 99th percentile is always the upper bound.
+    fn latency_metric(self, average: Option<JsonAverage>) -> JsonNewMetric {
+        let units = Units::Nano;
+        let (value, lower) = match average.unwrap_or_default() {
+            JsonAverage::Mean => (self.mean, self.p50),
+            JsonAverage::Median => (self.p50, self.min),
+        };
+        JsonNewMetric {
+            value: latency_as_nanos(value, units),
+            lower_value: Some(latency_as_nanos(lower, units)),
+            upper_value: Some(latency_as_nanos(self.p99, units)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_vegeta {
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median},
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults,
+    };
+
+    use super::AdapterShellVegeta;
+
+    const VEGETA_REPORT: &str = "./tool_output/shell/vegeta/report.json";
+
+    fn validate_load_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(
+            metric.lower_value,
+            lower_value.map(OrderedFloat::from),
+            "{key}"
+        );
+        assert_eq!(
+            metric.upper_value,
+            upper_value.map(OrderedFloat::from),
+            "{key}"
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_vegeta() {
+        let results = convert_file_path::<AdapterShellVegeta>(VEGETA_REPORT);
+        validate_adapter_shell_vegeta(&results);
+    }
+
+    pub fn validate_adapter_shell_vegeta(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("vegeta").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_load_metric(
+            metrics,
+            "latency",
+            4_756_875.0,
+            Some(4_100_000.0),
+            Some(12_500_000.0),
+        );
+        validate_load_metric(metrics, "throughput", 49.875, None, None);
+        validate_load_metric(metrics, "error-rate", 1.000_000_000_000_000_9, None, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_vegeta_median() {
+        let results = convert_file_path_median::<AdapterShellVegeta>(VEGETA_REPORT);
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("vegeta").unwrap();
+        validate_load_metric(
+            metrics,
+            "latency",
+            4_100_000.0,
+            Some(2_050_000.0),
+            Some(12_500_000.0),
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::{AdapterResults, LoadMeasure},
+    Adaptable, Settings,
+};
+
+pub struct AdapterShellWrk;
+
+impl Adaptable for AdapterShellWrk {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Wrk::parse(input)?.convert(settings)
+    }
+}
+
+// https://github.com/wg/wrk/blob/a211dd5a7050b1f9e8a9870b95513060e72ac4a0/src/wrk.c#L187
+#[derive(Debug, Default)]
+struct Wrk {
+    url: Option<String>,
+    latency_avg: Option<f64>,
+    latency_max: Option<f64>,
+    // Only available with the `--latency` flag
+    distribution: HashMap<u8, f64>,
+    requests: Option<u64>,
+    errors: u64,
+    requests_per_sec: Option<f64>,
+}
+
+impl Wrk {
+    fn parse(input: &str) -> Option<Self> {
+        let mut wrk = Self::default();
+        for line in input.lines() {
+            let line = line.trim();
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            match tokens.as_slice() {
+                ["Running", _, "test", "@", url] => wrk.url = Some((*url).to_owned()),
+                ["Latency", "Distribution"] => {},
+                ["Latency", avg, _stdev, max, _within_stdev] => {
+                    wrk.latency_avg = Some(parse_duration(avg)?);
+                    wrk.latency_max = Some(parse_duration(max)?);
+                },
+                [percentile, duration] if percentile.ends_with('%') => {
+                    let percentile = percentile.trim_end_matches('%').parse().ok()?;
+                    wrk.distribution
+                        .insert(percentile, parse_duration(duration)?);
+                },
+                [requests, "requests", "in", ..] => wrk.requests = Some(requests.parse().ok()?),
+                ["Socket", "errors:", errors @ ..] => {
+                    // connect 0, read 0, write 0, timeout 0
+                    for count in errors.iter().skip(1).step_by(2) {
+                        wrk.errors += count.trim_end_matches(',').parse::<u64>().ok()?;
+                    }
+                },
+                ["Non-2xx", "or", "3xx", "responses:", count] => {
+                    wrk.errors += count.parse::<u64>().ok()?;
+                },
+                ["Requests/sec:", requests_per_sec] => {
+                    wrk.requests_per_sec = Some(requests_per_sec.parse().ok()?);
+                },
+                _ => {},
+            }
+        }
+        Some(wrk)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let benchmark_name: BenchmarkName = self.url?.parse().ok()?;
+        let requests = self.requests?;
+
+        // Without the `--latency` flag, there is no median to use
+        let p50 = self.distribution.get(&50).copied();
+        let p99 = self.distribution.get(&99).copied();
+        let (value, lower) = match settings.average.unwrap_or_default() {
+            JsonAverage::Mean => (self.latency_avg?, p50),
+            JsonAverage::Median => (p50?, None),
+        };
+        let upper = p99.or(self.latency_max);
+        let latency = JsonNewMetric {
+            value: value.into(),
+            lower_value: lower.map(Into::into),
+            upper_value: upper.map(Into::into),
+        };
+
+        let throughput = JsonNewMetric {
+            value: self.requests_per_sec?.into(),
+            lower_value: None,
+            upper_value: None,
+        };
+
+        let error_rate = if requests == 0 {
+            0.0
+        } else {
+            self.errors as f64 / requests as f64 * 100.0
+        };
+        let error_rate = JsonNewMetric {
+            value: error_rate.into(),
+            lower_value: None,
+            upper_value: None,
+        };
+
+        AdapterResults::new_load(vec![(
+            benchmark_name,
+            vec![
+                LoadMeasure::Latency(latency),
+                LoadMeasure::Throughput(throughput),
+                LoadMeasure::ErrorRate(error_rate),
+            ],
+        )])
+    }
+}
+
+// Durations are formatted as `635.91us`, `0.89ms`, or `1.02s`
+fn parse_duration(duration: &str) -> Option<f64> {
+    let index = duration.find(|c: char| c.is_ascii_alphabetic())?;
+    let (time, units) = duration.split_at(index);
+    let time: f64 = time.parse().ok()?;
+    let units: Units = units.parse().ok()?;
+    Some(latency_as_nanos(time, units).into_inner())
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_wrk {
+    use bencher_json::project::report::JsonAverage;
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, opt_convert_file_path},
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults, Settings,
+    };
+
+    use super::AdapterShellWrk;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/shell/wrk/{suffix}.txt")
+    }
+
+    fn validate_load_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(
+            metric.lower_value,
+            lower_value.map(OrderedFloat::from),
+            "{key}"
+        );
+        assert_eq!(
+            metric.upper_value,
+            upper_value.map(OrderedFloat::from),
+            "{key}"
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_wrk_latency() {
+        let results = convert_file_path::<AdapterShellWrk>(&file_path("latency"));
+        validate_adapter_shell_wrk(&results);
+    }
+
+    pub fn validate_adapter_shell_wrk(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("http://127.0.0.1:8080/index.html").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_load_metric(
+            metrics,
+            "latency",
+            635_910.0,
+            Some(250_000.0),
+            Some(5_800_000.0),
+        );
+        validate_load_metric(metrics, "throughput", 748_868.53, None, None);
+        // 1,000 read errors, 1,000 timeouts, and 2,000 non-2xx or 3xx responses
+        validate_load_metric(metrics, "error-rate", 0.017_805_747_045_236_433, None, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_wrk_latency_median() {
+        let results = convert_file_path_median::<AdapterShellWrk>(&file_path("latency"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("http://127.0.0.1:8080/index.html").unwrap();
+        validate_load_metric(metrics, "latency", 250_000.0, None, Some(5_800_000.0));
+    }
+
+    #[test]
+    fn test_adapter_shell_wrk_default() {
+        let results = convert_file_path::<AdapterShellWrk>(&file_path("default"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results
+            .get("http://localhost:3000/api/v0/server/version")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_load_metric(metrics, "latency", 1_250_000.0, None, Some(14_500_000.0));
+        validate_load_metric(metrics, "throughput", 8_063.77, None, None);
+        validate_load_metric(metrics, "error-rate", 0.0, None, None);
+
+        // There is no median without the latency distribution
+        let results = opt_convert_file_path::<AdapterShellWrk>(
+            &file_path("default"),
+            Settings::new(Some(JsonAverage::Median)),
+        );
+        assert_eq!(results, None);
+    }
+}
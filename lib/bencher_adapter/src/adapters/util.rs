@@ -1,6 +1,11 @@
-use std::{fmt, str::FromStr};
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{self, BufReader, Read},
+    str::FromStr,
+};
 
-use bencher_json::BenchmarkName;
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -11,10 +16,11 @@ use nom::{
     IResult,
 };
 use ordered_float::OrderedFloat;
+use quick_xml::events::BytesStart;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{
-    de::{self, Visitor},
+    de::{self, DeserializeOwned, Visitor},
     Deserialize, Deserializer,
 };
 
@@ -22,6 +28,47 @@ use crate::AdapterError;
 
 pub type NomError = nom::Err<nom::error::Error<String>>;
 
+// Invalid JSON is not an error, it just means that the output is for a different adapter.
+// However, failing to read the output at all is an error.
+pub fn from_json_reader<R, T>(reader: R) -> Result<Option<T>, AdapterError>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    // `serde_json` reads one byte at a time, so make sure those reads are cheap.
+    match serde_json::from_reader(BufReader::new(reader)) {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.is_io() => Err(AdapterError::Read(e.into())),
+        Err(_) => Ok(None),
+    }
+}
+
+// Malformed XML is not an error, it just means that the output is for a different adapter.
+// However, failing to read the output at all is an error.
+pub fn from_xml_result<T>(
+    result: Result<Option<T>, quick_xml::Error>,
+) -> Result<Option<T>, AdapterError> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(quick_xml::Error::Io(e)) => Err(AdapterError::Read(io::Error::new(e.kind(), e))),
+        Err(
+            quick_xml::Error::Syntax(_)
+            | quick_xml::Error::IllFormed(_)
+            | quick_xml::Error::InvalidAttr(_)
+            | quick_xml::Error::Encoding(_)
+            | quick_xml::Error::Escape(_)
+            | quick_xml::Error::Namespace(_),
+        ) => Ok(None),
+    }
+}
+
+pub fn xml_attribute(start: &BytesStart, name: &str) -> Result<Option<String>, quick_xml::Error> {
+    start
+        .try_get_attribute(name)?
+        .map(|attribute| attribute.unescape_value().map(Cow::into_owned))
+        .transpose()
+}
+
 pub fn nom_error<T>(input: T) -> NomError
 where
     T: Into<String>,
@@ -43,6 +90,92 @@ where
     (time.into().as_f64() / units.as_secs()).into()
 }
 
+/// Summary statistics for the per-run values of a benchmark
+#[derive(Debug, Clone, Copy)]
+pub struct SampleStats {
+    pub mean: f64,
+    pub stddev: f64,
+    pub median: f64,
+    pub iqr: f64,
+}
+
+impl SampleStats {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let len = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / len;
+        let stddev = if sorted.len() > 1 {
+            let variance = sorted
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (len - 1.0);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+        let median = quantile_of_sorted(&sorted, 0.5);
+        let iqr = quantile_of_sorted(&sorted, 0.75) - quantile_of_sorted(&sorted, 0.25);
+
+        Some(Self {
+            mean,
+            stddev,
+            median,
+            iqr,
+        })
+    }
+
+    // The mean is bounded by one standard deviation
+    // and the median is bounded by the interquartile range.
+    fn average(self, average: Option<JsonAverage>) -> (f64, f64) {
+        match average.unwrap_or_default() {
+            JsonAverage::Mean => (self.mean, self.stddev),
+            JsonAverage::Median => (self.median, self.iqr),
+        }
+    }
+
+    pub fn latency_metric(self, average: Option<JsonAverage>, units: Units) -> JsonNewMetric {
+        let (average, spread) = self.average(average);
+        let value = latency_as_nanos(average, units);
+        let spread = latency_as_nanos(spread, units);
+        JsonNewMetric {
+            value,
+            lower_value: Some(value - spread),
+            upper_value: Some(value + spread),
+        }
+    }
+
+    pub fn metric(self, average: Option<JsonAverage>) -> JsonNewMetric {
+        let (average, spread) = self.average(average);
+        JsonNewMetric {
+            value: average.into(),
+            lower_value: Some((average - spread).into()),
+            upper_value: Some((average + spread).into()),
+        }
+    }
+}
+
+// Linear interpolation between the closest ranks
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::indexing_slicing
+)]
+fn quantile_of_sorted(sorted: &[f64], quantile: f64) -> f64 {
+    let rank = quantile * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - rank.floor();
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
 #[derive(Clone, Copy)]
 pub enum Time {
     UInt64(u64),
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,4 +10,16 @@ pub enum AdapterError {
     BenchmarkUnits(String),
     #[error("Failed to convert results: {0}")]
     Convert(String),
+    #[error("Failed to read results: {0}")]
+    Read(#[from] std::io::Error),
+    #[error("Failed to read Criterion results ({path}): {error}", path = path.display())]
+    CriterionDir {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to parse Criterion results ({path}): {error}", path = path.display())]
+    CriterionJson {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
 }
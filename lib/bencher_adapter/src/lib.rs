@@ -2,6 +2,8 @@ pub mod adapters;
 pub mod error;
 pub mod results;
 
+use std::io::BufRead;
+
 use adapters::{
     c_sharp::{dot_net::AdapterCSharpDotNet, AdapterCSharp},
     cpp::{catch2::AdapterCppCatch2, google::AdapterCppGoogle, AdapterCpp},
@@ -9,16 +11,25 @@ use adapters::{
     java::{jmh::AdapterJavaJmh, AdapterJava},
     js::{benchmark::AdapterJsBenchmark, time::AdapterJsTime, AdapterJs},
     json::AdapterJson,
+    julia::{benchmark_tools::AdapterJuliaBenchmarkTools, AdapterJulia},
+    junit::AdapterJunit,
     magic::AdapterMagic,
-    python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
+    open_metrics::AdapterOpenMetrics,
+    python::{
+        asv::AdapterPythonAsv, pyperf::AdapterPythonPyperf, pytest::AdapterPythonPytest,
+        AdapterPython,
+    },
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
     rust::{
-        bench::AdapterRustBench, criterion::AdapterRustCriterion, iai::AdapterRustIai,
-        iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
+        bench::AdapterRustBench, criterion::AdapterRustCriterion, divan::AdapterRustDivan,
+        iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
+    },
+    shell::{
+        hyperfine::AdapterShellHyperfine, k6::AdapterShellK6, vegeta::AdapterShellVegeta,
+        wrk::AdapterShellWrk, AdapterShell,
     },
-    shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
 };
-use bencher_json::project::report::{Adapter, JsonAverage};
+use bencher_json::project::report::{Adapter, JsonAdapterMeasure, JsonAverage};
 pub use bencher_json::{BenchmarkName, JsonNewMetric};
 pub use error::AdapterError;
 pub use results::{adapter_results::AdapterResults, AdapterResultsArray};
@@ -28,7 +39,45 @@ pub trait Adaptable {
         Self::parse(input, settings)
     }
 
+    fn convert_iterations(&self, input: &str, settings: Settings) -> Option<Vec<AdapterResults>> {
+        Self::parse_iterations(input, settings)
+    }
+
+    fn convert_reader<R: BufRead>(
+        &self,
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        Self::parse_reader(reader, settings)
+    }
+
+    fn detect(&self, prefix: &str, settings: Settings) -> bool {
+        Self::sniff(prefix, settings)
+    }
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults>;
+
+    /// Parse benchmark harness output that may contain multiple iterations (ie Go `-count`)
+    fn parse_iterations(input: &str, settings: Settings) -> Option<Vec<AdapterResults>> {
+        Self::parse(input, settings).map(|results| vec![results])
+    }
+
+    /// Incrementally parse benchmark harness output that may contain multiple iterations
+    /// from a buffered reader.
+    /// Adapters that can not parse their output incrementally read all of it into memory.
+    fn parse_reader<R: BufRead>(
+        mut reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::parse_iterations(&input, settings))
+    }
+
+    /// Detect whether the start of some benchmark harness output is for this adapter
+    fn sniff(prefix: &str, settings: Settings) -> bool {
+        Self::parse(prefix, settings).is_some()
+    }
 }
 
 impl Adaptable for Adapter {
@@ -36,6 +85,8 @@ impl Adaptable for Adapter {
         match self {
             Adapter::Magic => AdapterMagic::parse(input, settings),
             Adapter::Json => AdapterJson::parse(input, settings),
+            Adapter::OpenMetrics => AdapterOpenMetrics::parse(input, settings),
+            Adapter::Junit => AdapterJunit::parse(input, settings),
             Adapter::CSharp => AdapterCSharp::parse(input, settings),
             Adapter::CSharpDotNet => AdapterCSharpDotNet::parse(input, settings),
             Adapter::Cpp => AdapterCpp::parse(input, settings),
@@ -51,6 +102,7 @@ impl Adaptable for Adapter {
             Adapter::Python => AdapterPython::parse(input, settings),
             Adapter::PythonAsv => AdapterPythonAsv::parse(input, settings),
             Adapter::PythonPytest => AdapterPythonPytest::parse(input, settings),
+            Adapter::PythonPyperf => AdapterPythonPyperf::parse(input, settings),
             Adapter::Ruby => AdapterRuby::parse(input, settings),
             Adapter::RubyBenchmark => AdapterRubyBenchmark::parse(input, settings),
             Adapter::Rust => AdapterRust::parse(input, settings),
@@ -58,23 +110,202 @@ impl Adaptable for Adapter {
             Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
             Adapter::RustIai => AdapterRustIai::parse(input, settings),
             Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse(input, settings),
+            Adapter::RustDivan => AdapterRustDivan::parse(input, settings),
             Adapter::Shell => AdapterShell::parse(input, settings),
             Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
+            Adapter::ShellK6 => AdapterShellK6::parse(input, settings),
+            Adapter::ShellWrk => AdapterShellWrk::parse(input, settings),
+            Adapter::ShellVegeta => AdapterShellVegeta::parse(input, settings),
+            Adapter::Julia => AdapterJulia::parse(input, settings),
+            Adapter::JuliaBenchmarkTools => AdapterJuliaBenchmarkTools::parse(input, settings),
+        }
+    }
+
+    fn convert_iterations(&self, input: &str, settings: Settings) -> Option<Vec<AdapterResults>> {
+        match self {
+            Adapter::Go => AdapterGo::parse_iterations(input, settings),
+            Adapter::GoBench => AdapterGoBench::parse_iterations(input, settings),
+            Adapter::Magic
+            | Adapter::Json
+            | Adapter::OpenMetrics
+            | Adapter::Junit
+            | Adapter::CSharp
+            | Adapter::CSharpDotNet
+            | Adapter::Cpp
+            | Adapter::CppCatch2
+            | Adapter::CppGoogle
+            | Adapter::Java
+            | Adapter::JavaJmh
+            | Adapter::Js
+            | Adapter::JsBenchmark
+            | Adapter::JsTime
+            | Adapter::Python
+            | Adapter::PythonAsv
+            | Adapter::PythonPytest
+            | Adapter::PythonPyperf
+            | Adapter::Ruby
+            | Adapter::RubyBenchmark
+            | Adapter::Rust
+            | Adapter::RustBench
+            | Adapter::RustCriterion
+            | Adapter::RustIai
+            | Adapter::RustIaiCallgrind
+            | Adapter::RustDivan
+            | Adapter::Shell
+            | Adapter::ShellHyperfine
+            | Adapter::ShellK6
+            | Adapter::ShellWrk
+            | Adapter::ShellVegeta
+            | Adapter::Julia
+            | Adapter::JuliaBenchmarkTools => {
+                self.convert(input, settings).map(|results| vec![results])
+            },
+        }
+    }
+
+    fn convert_reader<R: BufRead>(
+        &self,
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        match self {
+            Adapter::Magic => AdapterMagic::parse_reader(reader, settings),
+            Adapter::Json => AdapterJson::parse_reader(reader, settings),
+            Adapter::OpenMetrics => AdapterOpenMetrics::parse_reader(reader, settings),
+            Adapter::Junit => AdapterJunit::parse_reader(reader, settings),
+            Adapter::CSharp => AdapterCSharp::parse_reader(reader, settings),
+            Adapter::CSharpDotNet => AdapterCSharpDotNet::parse_reader(reader, settings),
+            Adapter::Cpp => AdapterCpp::parse_reader(reader, settings),
+            Adapter::CppCatch2 => AdapterCppCatch2::parse_reader(reader, settings),
+            Adapter::CppGoogle => AdapterCppGoogle::parse_reader(reader, settings),
+            Adapter::Go => AdapterGo::parse_reader(reader, settings),
+            Adapter::GoBench => AdapterGoBench::parse_reader(reader, settings),
+            Adapter::Java => AdapterJava::parse_reader(reader, settings),
+            Adapter::JavaJmh => AdapterJavaJmh::parse_reader(reader, settings),
+            Adapter::Js => AdapterJs::parse_reader(reader, settings),
+            Adapter::JsBenchmark => AdapterJsBenchmark::parse_reader(reader, settings),
+            Adapter::JsTime => AdapterJsTime::parse_reader(reader, settings),
+            Adapter::Python => AdapterPython::parse_reader(reader, settings),
+            Adapter::PythonAsv => AdapterPythonAsv::parse_reader(reader, settings),
+            Adapter::PythonPytest => AdapterPythonPytest::parse_reader(reader, settings),
+            Adapter::PythonPyperf => AdapterPythonPyperf::parse_reader(reader, settings),
+            Adapter::Ruby => AdapterRuby::parse_reader(reader, settings),
+            Adapter::RubyBenchmark => AdapterRubyBenchmark::parse_reader(reader, settings),
+            Adapter::Rust => AdapterRust::parse_reader(reader, settings),
+            Adapter::RustBench => AdapterRustBench::parse_reader(reader, settings),
+            Adapter::RustCriterion => AdapterRustCriterion::parse_reader(reader, settings),
+            Adapter::RustIai => AdapterRustIai::parse_reader(reader, settings),
+            Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse_reader(reader, settings),
+            Adapter::RustDivan => AdapterRustDivan::parse_reader(reader, settings),
+            Adapter::Shell => AdapterShell::parse_reader(reader, settings),
+            Adapter::ShellHyperfine => AdapterShellHyperfine::parse_reader(reader, settings),
+            Adapter::ShellK6 => AdapterShellK6::parse_reader(reader, settings),
+            Adapter::ShellWrk => AdapterShellWrk::parse_reader(reader, settings),
+            Adapter::ShellVegeta => AdapterShellVegeta::parse_reader(reader, settings),
+            Adapter::Julia => AdapterJulia::parse_reader(reader, settings),
+            Adapter::JuliaBenchmarkTools => {
+                AdapterJuliaBenchmarkTools::parse_reader(reader, settings)
+            },
+        }
+    }
+
+    fn detect(&self, prefix: &str, settings: Settings) -> bool {
+        match self {
+            Adapter::CppCatch2 => AdapterCppCatch2::sniff(prefix, settings),
+            Adapter::CppGoogle => AdapterCppGoogle::sniff(prefix, settings),
+            Adapter::Json => AdapterJson::sniff(prefix, settings),
+            Adapter::Junit => AdapterJunit::sniff(prefix, settings),
+            Adapter::JavaJmh => AdapterJavaJmh::sniff(prefix, settings),
+            Adapter::Go
+            | Adapter::GoBench
+            | Adapter::Magic
+            | Adapter::OpenMetrics
+            | Adapter::CSharp
+            | Adapter::CSharpDotNet
+            | Adapter::Cpp
+            | Adapter::Java
+            | Adapter::Js
+            | Adapter::JsBenchmark
+            | Adapter::JsTime
+            | Adapter::Python
+            | Adapter::PythonAsv
+            | Adapter::PythonPytest
+            | Adapter::PythonPyperf
+            | Adapter::Ruby
+            | Adapter::RubyBenchmark
+            | Adapter::Rust
+            | Adapter::RustBench
+            | Adapter::RustCriterion
+            | Adapter::RustIai
+            | Adapter::RustIaiCallgrind
+            | Adapter::RustDivan
+            | Adapter::Shell
+            | Adapter::ShellHyperfine
+            | Adapter::ShellK6
+            | Adapter::ShellWrk
+            | Adapter::ShellVegeta
+            | Adapter::Julia
+            | Adapter::JuliaBenchmarkTools => self.convert(prefix, settings).is_some(),
         }
     }
 
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterMagic::parse(input, settings)
     }
+
+    fn parse_reader<R: BufRead>(
+        reader: R,
+        settings: Settings,
+    ) -> Result<Option<Vec<AdapterResults>>, AdapterError> {
+        AdapterMagic::parse_reader(reader, settings)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
-pub struct Settings {
+pub struct Settings<'s> {
     pub average: Option<JsonAverage>,
+    /// The labels to include in the benchmark name for labeled metrics (ie `OpenMetrics`).
+    /// If not set, then all labels are included.
+    pub labels: Option<&'s [String]>,
+    /// Rename, scale, or set the units for the measures output by the adapter.
+    pub measures: Option<&'s [JsonAdapterMeasure]>,
+    /// A prefix to add to the start of every benchmark name.
+    pub benchmark_prefix: Option<&'s str>,
+    /// A suffix to add to the end of every benchmark name.
+    pub benchmark_suffix: Option<&'s str>,
 }
 
-impl Settings {
+impl<'s> Settings<'s> {
     pub fn new(average: Option<JsonAverage>) -> Self {
-        Self { average }
+        Self {
+            average,
+            labels: None,
+            measures: None,
+            benchmark_prefix: None,
+            benchmark_suffix: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_labels(mut self, labels: Option<&'s [String]>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    #[must_use]
+    pub fn with_measures(mut self, measures: Option<&'s [JsonAdapterMeasure]>) -> Self {
+        self.measures = measures;
+        self
+    }
+
+    #[must_use]
+    pub fn with_benchmark_affixes(
+        mut self,
+        benchmark_prefix: Option<&'s str>,
+        benchmark_suffix: Option<&'s str>,
+    ) -> Self {
+        self.benchmark_prefix = benchmark_prefix;
+        self.benchmark_suffix = benchmark_suffix;
+        self
     }
 }
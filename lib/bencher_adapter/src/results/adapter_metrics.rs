@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
-use bencher_json::{JsonNewMetric, MeasureNameId};
+use bencher_json::{project::report::JsonAdapterMeasure, JsonNewMetric, MeasureNameId};
 use serde::{Deserialize, Serialize};
 
 use super::{CombinedKind, OrdKind};
@@ -41,6 +41,27 @@ impl AdapterMetrics {
         metric_map.into()
     }
 
+    // Any measures without settings are left as is.
+    // If a measure is renamed to an existing measure, the renamed metric is kept.
+    pub(crate) fn remap(self, measures: &[JsonAdapterMeasure]) -> Self {
+        let mut metric_map = HashMap::with_capacity(self.inner.len());
+        let mut renamed_map = HashMap::new();
+        for (measure, metric) in self.inner {
+            if let Some(setting) = measures.iter().find(|setting| setting.measure == measure) {
+                let metric = if let Some(scale) = setting.scale {
+                    metric * scale
+                } else {
+                    metric
+                };
+                renamed_map.insert(setting.renamed().clone(), metric);
+            } else {
+                metric_map.insert(measure, metric);
+            }
+        }
+        metric_map.extend(renamed_map);
+        metric_map.into()
+    }
+
     pub fn get(&self, key: &str) -> Option<&JsonNewMetric> {
         self.inner.get(&MeasureNameId::from_str(key).ok()?)
     }
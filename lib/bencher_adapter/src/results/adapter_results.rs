@@ -3,13 +3,15 @@ use std::{collections::HashMap, str::FromStr};
 use bencher_json::{
     project::{
         measure::built_in::{self, BuiltInMeasure},
-        metric::Mean,
+        metric::{Mean, MeasureNameId},
     },
     BenchmarkName, JsonNewMetric,
 };
 use literally::hmap;
 use serde::{Deserialize, Serialize};
 
+use crate::{AdapterError, Settings};
+
 use super::{adapter_metrics::AdapterMetrics, CombinedKind};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -67,6 +69,63 @@ pub enum IaiCallgrindMeasure {
     WritesBytes(JsonNewMetric),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DivanMeasure {
+    Latency(JsonNewMetric),
+    Throughput(JsonNewMetric),
+
+    /*
+     * AllocProfiler:
+     */
+    MaxAllocCount(JsonNewMetric),
+    MaxAllocBytes(JsonNewMetric),
+    AllocCount(JsonNewMetric),
+    AllocBytes(JsonNewMetric),
+    DeallocCount(JsonNewMetric),
+    DeallocBytes(JsonNewMetric),
+    GrowCount(JsonNewMetric),
+    GrowBytes(JsonNewMetric),
+    ShrinkCount(JsonNewMetric),
+    ShrinkBytes(JsonNewMetric),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoMeasure {
+    Latency(JsonNewMetric),
+
+    /*
+     * `-benchmem`:
+     */
+    AllocBytes(JsonNewMetric),
+    AllocCount(JsonNewMetric),
+
+    /*
+     * `b.SetBytes`:
+     */
+    ByteThroughput(JsonNewMetric),
+
+    /*
+     * `b.ReportMetric`:
+     */
+    Custom(MeasureNameId, JsonNewMetric),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoogleMeasure {
+    Latency(JsonNewMetric),
+    /*
+     * User counters:
+     */
+    Counter(MeasureNameId, JsonNewMetric),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadMeasure {
+    Latency(JsonNewMetric),
+    Throughput(JsonNewMetric),
+    ErrorRate(JsonNewMetric),
+}
+
 impl AdapterResults {
     pub fn new(benchmark_metrics: Vec<(BenchmarkName, AdapterMeasure)>) -> Option<Self> {
         if benchmark_metrics.is_empty() {
@@ -233,6 +292,152 @@ impl AdapterResults {
         Some(results_map.into())
     }
 
+    pub fn new_divan(benchmark_metrics: Vec<(BenchmarkName, Vec<DivanMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    DivanMeasure::Latency(json_metric) => {
+                        (built_in::default::Latency::name_id(), json_metric)
+                    },
+                    DivanMeasure::Throughput(json_metric) => {
+                        (built_in::default::Throughput::name_id(), json_metric)
+                    },
+
+                    /*
+                     * AllocProfiler:
+                     */
+                    DivanMeasure::MaxAllocCount(json_metric) => {
+                        (built_in::divan::MaxAllocCount::name_id(), json_metric)
+                    },
+                    DivanMeasure::MaxAllocBytes(json_metric) => {
+                        (built_in::divan::MaxAllocBytes::name_id(), json_metric)
+                    },
+                    DivanMeasure::AllocCount(json_metric) => {
+                        (built_in::divan::AllocCount::name_id(), json_metric)
+                    },
+                    DivanMeasure::AllocBytes(json_metric) => {
+                        (built_in::divan::AllocBytes::name_id(), json_metric)
+                    },
+                    DivanMeasure::DeallocCount(json_metric) => {
+                        (built_in::divan::DeallocCount::name_id(), json_metric)
+                    },
+                    DivanMeasure::DeallocBytes(json_metric) => {
+                        (built_in::divan::DeallocBytes::name_id(), json_metric)
+                    },
+                    DivanMeasure::GrowCount(json_metric) => {
+                        (built_in::divan::GrowCount::name_id(), json_metric)
+                    },
+                    DivanMeasure::GrowBytes(json_metric) => {
+                        (built_in::divan::GrowBytes::name_id(), json_metric)
+                    },
+                    DivanMeasure::ShrinkCount(json_metric) => {
+                        (built_in::divan::ShrinkCount::name_id(), json_metric)
+                    },
+                    DivanMeasure::ShrinkBytes(json_metric) => {
+                        (built_in::divan::ShrinkBytes::name_id(), json_metric)
+                    },
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
+    pub fn new_go(benchmark_metrics: Vec<(BenchmarkName, Vec<GoMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    GoMeasure::Latency(json_metric) => {
+                        (built_in::default::Latency::name_id(), json_metric)
+                    },
+                    GoMeasure::AllocBytes(json_metric) => {
+                        (built_in::divan::AllocBytes::name_id(), json_metric)
+                    },
+                    GoMeasure::AllocCount(json_metric) => {
+                        (built_in::divan::AllocCount::name_id(), json_metric)
+                    },
+                    GoMeasure::ByteThroughput(json_metric) => {
+                        (built_in::go::ByteThroughput::name_id(), json_metric)
+                    },
+                    GoMeasure::Custom(resource_id, json_metric) => (resource_id, json_metric),
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
+    pub fn new_google(benchmark_metrics: Vec<(BenchmarkName, Vec<GoogleMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    GoogleMeasure::Latency(json_metric) => {
+                        (built_in::default::Latency::name_id(), json_metric)
+                    },
+                    GoogleMeasure::Counter(resource_id, json_metric) => (resource_id, json_metric),
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
+    pub fn new_load(benchmark_metrics: Vec<(BenchmarkName, Vec<LoadMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    LoadMeasure::Latency(json_metric) => {
+                        (built_in::default::Latency::name_id(), json_metric)
+                    },
+                    LoadMeasure::Throughput(json_metric) => {
+                        (built_in::default::Throughput::name_id(), json_metric)
+                    },
+                    LoadMeasure::ErrorRate(json_metric) => {
+                        (built_in::load::ErrorRate::name_id(), json_metric)
+                    },
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
     pub(crate) fn combined(self, mut other: Self, kind: CombinedKind) -> Self {
         let mut results_map = HashMap::new();
         for (benchmark_name, metrics) in self.inner {
@@ -248,6 +453,40 @@ impl AdapterResults {
         results_map.into()
     }
 
+    /// Rewrite the benchmark names and measures according to the adapter settings.
+    pub(crate) fn remap(self, settings: Settings) -> Result<Self, AdapterError> {
+        let Settings {
+            measures,
+            benchmark_prefix,
+            benchmark_suffix,
+            ..
+        } = settings;
+        if measures.is_none() && benchmark_prefix.is_none() && benchmark_suffix.is_none() {
+            return Ok(self);
+        }
+
+        let mut results_map = HashMap::with_capacity(self.inner.len());
+        for (benchmark_name, metrics) in self.inner {
+            let benchmark_name = if benchmark_prefix.is_some() || benchmark_suffix.is_some() {
+                format!(
+                    "{prefix}{benchmark_name}{suffix}",
+                    prefix = benchmark_prefix.unwrap_or_default(),
+                    suffix = benchmark_suffix.unwrap_or_default()
+                )
+                .parse()?
+            } else {
+                benchmark_name
+            };
+            let metrics = if let Some(measures) = measures {
+                metrics.remap(measures)
+            } else {
+                metrics
+            };
+            results_map.insert(benchmark_name, metrics);
+        }
+        Ok(results_map.into())
+    }
+
     pub fn get(&self, key: &str) -> Option<&AdapterMetrics> {
         self.inner.get(&BenchmarkName::from_str(key).ok()?)
     }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io::BufRead};
 
 use bencher_json::project::{
     metric::Mean,
@@ -35,14 +35,29 @@ impl AdapterResultsArray {
     ) -> Result<Self, AdapterError> {
         let mut parsed_results_array = Vec::new();
         for &results in results_array {
-            let parsed_results = adapter
-                .convert(results, settings)
+            let parsed_results = Self::from_reader(results.as_bytes(), adapter, settings)?
                 .ok_or_else(|| AdapterError::Convert((results).to_owned()))?;
-            parsed_results_array.push(parsed_results);
+            parsed_results_array.extend(parsed_results.inner);
         }
         Ok(parsed_results_array.into())
     }
 
+    /// Incrementally parse the iterations of benchmark harness output from a buffered reader.
+    pub fn from_reader<R: BufRead>(
+        reader: R,
+        adapter: Adapter,
+        settings: Settings,
+    ) -> Result<Option<Self>, AdapterError> {
+        let Some(parsed_results) = adapter.convert_reader(reader, settings)? else {
+            return Ok(None);
+        };
+        parsed_results
+            .into_iter()
+            .map(|parsed_results| parsed_results.remap(settings))
+            .collect::<Result<ResultsArray, _>>()
+            .map(|parsed_results_array| Some(parsed_results_array.into()))
+    }
+
     pub fn min(self) -> AdapterResults {
         self.ord(OrdKind::Min)
     }
@@ -98,3 +113,86 @@ pub(crate) enum CombinedKind {
     Ord(OrdKind),
     Add,
 }
+
+#[cfg(test)]
+mod test_remap {
+    use bencher_json::project::report::{Adapter, JsonAdapterMeasure};
+    use pretty_assertions::assert_eq;
+
+    use crate::{adapters::test_util::validate_metric, Settings};
+
+    use super::AdapterResultsArray;
+
+    fn remap_results(settings: Settings) -> AdapterResultsArray {
+        let contents = std::fs::read_to_string("./tool_output/json/report_latency.json").unwrap();
+        AdapterResultsArray::new(&[contents.as_str()], Adapter::Json, settings).unwrap()
+    }
+
+    #[test]
+    fn test_remap_measures() {
+        let measures = [JsonAdapterMeasure {
+            measure: "latency".parse().unwrap(),
+            rename: Some("latency-us".parse().unwrap()),
+            scale: Some(0.001),
+            units: Some("microseconds (µs)".parse().unwrap()),
+        }];
+        let results_array = remap_results(Settings::default().with_measures(Some(&measures)));
+        assert_eq!(results_array.inner.len(), 1);
+
+        let results = results_array.inner.first().unwrap();
+        assert_eq!(results.inner.len(), 3);
+        let metrics = results.get("tests::benchmark_a").unwrap();
+        validate_metric(metrics, "latency-us", 3.247, Some(1.044), Some(1.044));
+    }
+
+    #[test]
+    fn test_remap_measures_reader() {
+        let measures = [JsonAdapterMeasure {
+            measure: "latency".parse().unwrap(),
+            rename: Some("latency-us".parse().unwrap()),
+            scale: Some(0.001),
+            units: None,
+        }];
+        let file = std::fs::File::open("./tool_output/json/report_latency.json").unwrap();
+        let results_array = AdapterResultsArray::from_reader(
+            std::io::BufReader::new(file),
+            Adapter::Magic,
+            Settings::default().with_measures(Some(&measures)),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(results_array.inner.len(), 1);
+
+        let results = results_array.inner.first().unwrap();
+        let metrics = results.get("tests::benchmark_a").unwrap();
+        validate_metric(metrics, "latency-us", 3.247, Some(1.044), Some(1.044));
+        assert_eq!(metrics.get("latency"), None);
+    }
+
+    #[test]
+    fn test_remap_measures_unmatched() {
+        let measures = [JsonAdapterMeasure {
+            measure: "throughput".parse().unwrap(),
+            rename: Some("byte-throughput".parse().unwrap()),
+            scale: None,
+            units: None,
+        }];
+        let results_array = remap_results(Settings::default().with_measures(Some(&measures)));
+
+        let results = results_array.inner.first().unwrap();
+        let metrics = results.get("tests::benchmark_b").unwrap();
+        validate_metric(metrics, "latency", 3443.0, Some(2275.0), Some(2275.0));
+    }
+
+    #[test]
+    fn test_remap_benchmark_affixes() {
+        let results_array =
+            remap_results(Settings::default().with_benchmark_affixes(Some("rust::"), Some("::v2")));
+
+        let results = results_array.inner.first().unwrap();
+        assert_eq!(results.inner.len(), 3);
+        assert_eq!(results.get("tests::benchmark_a"), None);
+        let metrics = results.get("rust::tests::benchmark_c::v2").unwrap();
+        validate_metric(metrics, "latency", 3361.0, Some(1093.0), Some(1093.0));
+    }
+}
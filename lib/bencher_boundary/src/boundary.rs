@@ -1,8 +1,13 @@
-use bencher_json::{project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize};
+use bencher_json::{
+    project::boundary::BoundaryLimit, Boundary, CdfBoundary, ModelTest, SampleSize,
+};
 use slog::Logger;
+use statrs::distribution::{ContinuousCDF, Normal};
 
+use crate::change_point::ChangePoint;
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
+use crate::mann_whitney::MannWhitneyU;
 use crate::mean::Mean;
 use crate::quartiles::Quartiles;
 use crate::{BoundaryError, MetricsData};
@@ -11,6 +16,9 @@ use crate::{BoundaryError, MetricsData};
 pub struct MetricsBoundary {
     pub limits: MetricsLimits,
     pub outlier: Option<BoundaryLimit>,
+    /// The index of the most recent change point, if any.
+    /// Index `0` is the datum under test and index `i` is the historical data at index `i - 1`.
+    pub change_point: Option<usize>,
 }
 
 impl MetricsBoundary {
@@ -44,30 +52,17 @@ impl MetricsBoundary {
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
     ) -> Result<Option<Self>, BoundaryError> {
-        // If there is no boundary, then simply return.
-        if lower_boundary.is_none() && upper_boundary.is_none() {
-            slog::debug!(
-                log,
-                "No lower or upper boundary for threshold model test {model_test:?}",
-            );
+        if !Self::is_testable(
+            log,
+            metrics_data,
+            model_test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+        ) {
             return Ok(None);
         }
         let data = &metrics_data.data;
-        let data_len = data.len();
-        // If there is a min sample size, then check to see if it is met.
-        // Otherwise, simply return.
-        if let Some(min_sample_size) = min_sample_size {
-            if data_len < min_sample_size.into() {
-                slog::debug!(
-                    log,
-                    "Data length ({data_len}) is less than min sample size ({min_sample_size})",
-                );
-                return Ok(None);
-            }
-        } else if data_len == 0 {
-            slog::debug!(log, "No data for threshold model test {model_test:?}");
-            return Ok(None);
-        }
 
         match model_test {
             ModelTest::Static => Ok(Some(Self::new_static(
@@ -106,7 +101,95 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::ChangePoint => {
+                Self::new_change_point(log, datum, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::MannWhitneyU => {
+                Self::new_mann_whitney_u(log, &[datum], data, lower_boundary, upper_boundary)
+            },
+        }
+    }
+
+    /// Create a boundary for a sample of multiple new metrics, such as all of the iterations in a report.
+    /// For the Mann-Whitney U test, the entire sample is tested against the historical data.
+    /// For all other model tests, the median of the sample is tested.
+    pub fn new_sample(
+        log: &Logger,
+        sample: &[f64],
+        metrics_data: &MetricsData,
+        model_test: ModelTest,
+        min_sample_size: Option<SampleSize>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Self, BoundaryError> {
+        if model_test != ModelTest::MannWhitneyU {
+            let Some(datum) = Quartiles::percentile(sample, 0.5) else {
+                return Ok(Self::default());
+            };
+            return Self::new(
+                log,
+                datum,
+                metrics_data,
+                model_test,
+                min_sample_size,
+                lower_boundary,
+                upper_boundary,
+            );
+        }
+
+        if !Self::is_testable(
+            log,
+            metrics_data,
+            model_test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+        ) {
+            return Ok(Self::default());
+        }
+        Self::new_mann_whitney_u(
+            log,
+            sample,
+            &metrics_data.data,
+            lower_boundary,
+            upper_boundary,
+        )
+        .map(Option::unwrap_or_default)
+    }
+
+    fn is_testable(
+        log: &Logger,
+        metrics_data: &MetricsData,
+        model_test: ModelTest,
+        min_sample_size: Option<SampleSize>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> bool {
+        // If there is no boundary, then simply return.
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            slog::debug!(
+                log,
+                "No lower or upper boundary for threshold model test {model_test:?}",
+            );
+            return false;
+        }
+        let data_len = metrics_data.data.len();
+        // If there is a min sample size, then check to see if it is met.
+        // Otherwise, simply return.
+        if let Some(min_sample_size) = min_sample_size {
+            if data_len < min_sample_size.into() {
+                slog::debug!(
+                    log,
+                    "Data length ({data_len}) is less than min sample size ({min_sample_size})",
+                );
+                return false;
+            }
+        } else if data_len == 0 {
+            slog::debug!(log, "No data for threshold model test {model_test:?}");
+            return false;
         }
+
+        true
     }
 
     fn new_static(
@@ -117,7 +200,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_static(lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Self { limits, outlier }
+        Self {
+            limits,
+            outlier,
+            change_point: None,
+        }
     }
 
     fn new_percentage(
@@ -144,7 +231,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_percentage(log, mean, lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: None,
+        }))
     }
 
     fn new_normal(
@@ -183,7 +274,11 @@ impl MetricsBoundary {
         )?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: None,
+        }))
     }
 
     fn new_log_normal(
@@ -209,7 +304,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_log_normal(log, ln, lower_boundary, upper_boundary)?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: None,
+        }))
     }
 
     fn new_iqr(
@@ -251,6 +350,120 @@ impl MetricsBoundary {
         );
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: None,
+        }))
+    }
+
+    fn new_change_point(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // Split the historical data into regimes and only keep the current regime.
+        let Some(ChangePoint {
+            regime,
+            index,
+            is_newest,
+        }) = ChangePoint::new(datum, data)
+        else {
+            return Ok(None);
+        };
+        slog::debug!(
+            log,
+            "Change point: index={index:?}, is_newest={is_newest}, regime={}",
+            regime.len()
+        );
+
+        // Get the mean and standard deviation of the current regime.
+        let Some(mean) = Mean::new(&regime) else {
+            return Ok(None);
+        };
+        let Some(std_dev) = mean.std_deviation(&regime) else {
+            return Ok(None);
+        };
+        let Mean { mean } = mean;
+
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_dev,
+            #[allow(clippy::cast_precision_loss)]
+            NormalTestKind::T {
+                freedom: (regime.len() - 1) as f64,
+            },
+            lower_boundary,
+            upper_boundary,
+        )?;
+        // Only alert if the regime shift lands on the newest version.
+        // A one-off outlier that is not a statistically significant change point is ignored.
+        let outlier = is_newest.then(|| limits.outlier(datum)).flatten();
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: index,
+        }))
+    }
+
+    fn new_mann_whitney_u(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary: Option<CdfBoundary> = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary: Option<CdfBoundary> = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(mann_whitney_u) = MannWhitneyU::new(data, sample) else {
+            return Ok(None);
+        };
+
+        // The U statistic is approximately normally distributed,
+        // so the critical values come from the standard normal distribution.
+        let (mean, std_dev) = (0.0, 1.0);
+        let normal = Normal::new(mean, std_dev).map_err(|error| BoundaryError::Normal {
+            mean,
+            std_dev,
+            error,
+        })?;
+        let lower_z = lower_boundary.map(|limit| normal.inverse_cdf(limit.into()));
+        let upper_z = upper_boundary.map(|limit| normal.inverse_cdf(limit.into()));
+
+        let limits = MetricsLimits::new_mann_whitney_u(log, data, mann_whitney_u, lower_z, upper_z);
+        let z_score = mann_whitney_u.z_score();
+        let outlier = if lower_z.is_some_and(|z| z_score < -z) {
+            Some(BoundaryLimit::Lower)
+        } else if upper_z.is_some_and(|z| z_score > z) {
+            Some(BoundaryLimit::Upper)
+        } else {
+            None
+        };
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            change_point: None,
+        }))
     }
 }
@@ -0,0 +1,281 @@
+use std::{f64::consts::SQRT_2, iter};
+
+use crate::{
+    mean::{mean, std_deviation},
+    quartiles::Quartiles,
+};
+
+// The minimum number of samples required in the current regime in order to calculate its spread.
+const MIN_REGIME_LEN: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangePoint {
+    /// The samples in the regime that the newest sample is tested against,
+    /// ordered from oldest to newest.
+    pub regime: Vec<f64>,
+    /// The index (in newest first order) of the sample that starts the most recent regime,
+    /// if there has been a change point.
+    /// Index `0` is the datum under test and index `i` is the historical sample at index `i - 1`.
+    pub index: Option<usize>,
+    /// Whether the most recent change point lands on the newest sample.
+    pub is_newest: bool,
+}
+
+impl ChangePoint {
+    // The historical data is expected to be ordered from newest to oldest,
+    // and it must not include the datum under test.
+    // The datum is treated as the newest sample in the series.
+    pub fn new(datum: f64, data: &[f64]) -> Option<Self> {
+        let series = data
+            .iter()
+            .rev()
+            .copied()
+            .chain(iter::once(datum))
+            .collect::<Vec<_>>();
+        let len = series.len();
+        // There must be at least one historical sample before the newest sample.
+        if len < MIN_REGIME_LEN + 1 {
+            return None;
+        }
+
+        let change_points = pelt(&series);
+        let last = change_points.last().copied();
+        let newest = len - 1;
+        let is_newest = last == Some(newest);
+
+        // The regime is the segment that the newest sample is compared against.
+        // If the newest sample starts a new segment, then that is the segment right before it.
+        // Otherwise, it is the current segment, excluding the newest sample itself.
+        let regime_end = newest;
+        let regime_start = if is_newest {
+            change_points
+                .iter()
+                .rev()
+                .nth(1)
+                .copied()
+                .unwrap_or_default()
+        } else {
+            last.unwrap_or_default()
+        };
+        let regime = series.get(regime_start..regime_end)?.to_vec();
+        if regime.len() < MIN_REGIME_LEN {
+            return None;
+        }
+
+        Some(Self {
+            regime,
+            index: last.map(|change_point| newest - change_point),
+            is_newest,
+        })
+    }
+}
+
+// Pruned Exact Linear Time (PELT) change point detection for changes in the mean.
+// https://arxiv.org/abs/1101.1438
+// Returns the (oldest first) indices at which each new segment starts.
+fn pelt(series: &[f64]) -> Vec<usize> {
+    let len = series.len();
+    let Some(sigma) = noise(series) else {
+        return Vec::new();
+    };
+    // Normalize the data by the noise so that the cost is in units of variance.
+    let normalized = series.iter().map(|x| x / sigma).collect::<Vec<_>>();
+    let cost = SegmentCost::new(&normalized);
+    // Use the Bayesian Information Criterion (BIC) as the penalty for adding a change point.
+    #[allow(clippy::cast_precision_loss)]
+    let penalty = 2.0 * (len as f64).ln();
+
+    // The optimal cost for the first `t` samples
+    let mut optimal = Vec::with_capacity(len + 1);
+    optimal.push(-penalty);
+    // The last change point for the optimal segmentation of the first `t` samples
+    let mut last_change = vec![0; len + 1];
+    let mut candidates = vec![0];
+
+    for end in 1..=len {
+        let mut best_cost = f64::INFINITY;
+        let mut best_start = 0;
+        let mut costs = Vec::with_capacity(candidates.len());
+        for &start in &candidates {
+            let candidate_cost =
+                optimal.get(start).copied().unwrap_or(f64::INFINITY) + cost.cost(start, end);
+            costs.push(candidate_cost);
+            if candidate_cost + penalty < best_cost {
+                best_cost = candidate_cost + penalty;
+                best_start = start;
+            }
+        }
+        optimal.push(best_cost);
+        if let Some(last) = last_change.get_mut(end) {
+            *last = best_start;
+        }
+        // Prune any candidates that can never be optimal.
+        candidates = candidates
+            .into_iter()
+            .zip(costs)
+            .filter_map(|(start, candidate_cost)| (candidate_cost <= best_cost).then_some(start))
+            .collect();
+        candidates.push(end);
+    }
+
+    let mut change_points = Vec::new();
+    let mut end = len;
+    while let Some(&start) = last_change.get(end) {
+        if start == 0 {
+            break;
+        }
+        change_points.push(start);
+        end = start;
+    }
+    change_points.reverse();
+    change_points
+}
+
+// Estimate the noise level using the median absolute deviation of the first differences.
+// This is robust to the level shifts that we are trying to detect.
+fn noise(series: &[f64]) -> Option<f64> {
+    // Scale factor to convert MAD into a standard deviation estimate for normally distributed data.
+    // The differences of two independent samples have twice the variance.
+    const MAD_SCALE: f64 = 0.674_489_750_196_081_7 * SQRT_2;
+
+    let diffs = series
+        .windows(2)
+        .filter_map(|window| match window {
+            [a, b] => Some((b - a).abs()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let sigma = Quartiles::new(&diffs).map(|Quartiles { q2, .. }| q2 / MAD_SCALE);
+    match sigma {
+        Some(sigma) if sigma > 0.0 && sigma.is_finite() => Some(sigma),
+        // If most of the differences are zero, fall back to the standard deviation.
+        _ => mean(series).and_then(|mean| std_deviation(mean, series)),
+    }
+}
+
+// The cost of a segment is the sum of the squared deviations from the segment mean.
+struct SegmentCost {
+    sum: Vec<f64>,
+    sum_sq: Vec<f64>,
+}
+
+impl SegmentCost {
+    fn new(data: &[f64]) -> Self {
+        let mut sum = Vec::with_capacity(data.len() + 1);
+        let mut sum_sq = Vec::with_capacity(data.len() + 1);
+        sum.push(0.0);
+        sum_sq.push(0.0);
+        let (mut total, mut total_sq) = (0.0, 0.0);
+        for x in data {
+            total += x;
+            total_sq += x * x;
+            sum.push(total);
+            sum_sq.push(total_sq);
+        }
+        Self { sum, sum_sq }
+    }
+
+    fn cost(&self, start: usize, end: usize) -> f64 {
+        let (Some(sum_start), Some(sum_end), Some(sq_start), Some(sq_end)) = (
+            self.sum.get(start),
+            self.sum.get(end),
+            self.sum_sq.get(start),
+            self.sum_sq.get(end),
+        ) else {
+            return f64::INFINITY;
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let len = (end - start) as f64;
+        let sum = sum_end - sum_start;
+        (sq_end - sq_start) - sum * sum / len
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{pelt, ChangePoint};
+
+    const NOISE: &[f64] = &[0.2, -0.1, 0.0, 0.3, -0.2, 0.1, -0.3, 0.2, 0.0, -0.1];
+
+    fn regime(level: f64) -> Vec<f64> {
+        NOISE.iter().map(|n| level + n).collect()
+    }
+
+    fn newest_first(series: Vec<f64>) -> Vec<f64> {
+        series.into_iter().rev().collect()
+    }
+
+    #[test]
+    fn test_pelt_none() {
+        let series = [regime(10.0), regime(10.0)].concat();
+        assert_eq!(pelt(&series), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_pelt_one() {
+        let series = [regime(10.0), regime(20.0)].concat();
+        assert_eq!(pelt(&series), vec![10]);
+    }
+
+    #[test]
+    fn test_pelt_two() {
+        let series = [regime(10.0), regime(20.0), regime(5.0)].concat();
+        assert_eq!(pelt(&series), vec![10, 20]);
+    }
+
+    #[test]
+    fn test_pelt_constant() {
+        let series = [1.0; 10];
+        assert_eq!(pelt(&series), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_change_point_too_short() {
+        assert_eq!(ChangePoint::new(1.0, &[]), None);
+        assert_eq!(ChangePoint::new(1.0, &[2.0]), None);
+    }
+
+    #[test]
+    fn test_change_point_stable() {
+        let data = newest_first(regime(10.0));
+        let change_point = ChangePoint::new(10.1, &data).unwrap();
+        assert_eq!(change_point.index, None);
+        assert!(!change_point.is_newest, "No change point");
+        assert_eq!(change_point.regime, regime(10.0));
+    }
+
+    #[test]
+    fn test_change_point_newest() {
+        let data = newest_first([regime(10.0), regime(10.0)].concat());
+        let change_point = ChangePoint::new(20.0, &data).unwrap();
+        assert_eq!(change_point.index, Some(0));
+        assert!(change_point.is_newest, "Change point at newest");
+        assert_eq!(change_point.regime, [regime(10.0), regime(10.0)].concat());
+    }
+
+    #[test]
+    fn test_change_point_previous() {
+        let data = newest_first([regime(10.0), regime(20.0)].concat());
+        let change_point = ChangePoint::new(20.1, &data).unwrap();
+        assert_eq!(change_point.index, Some(10));
+        assert!(!change_point.is_newest, "Change point before newest");
+        assert_eq!(change_point.regime, regime(20.0));
+    }
+
+    #[test]
+    fn test_change_point_datum_not_in_data() {
+        // The datum is never read from the historical data,
+        // such as for a start point baseline where it is on a different branch.
+        let data = newest_first([regime(10.0), regime(10.0)].concat());
+        let change_point = ChangePoint::new(20.0, &data).unwrap();
+        assert_eq!(change_point.index, Some(0));
+        assert!(change_point.is_newest, "Change point at the datum");
+        let change_point = ChangePoint::new(10.0, &data).unwrap();
+        assert_eq!(change_point.index, None);
+        assert!(!change_point.is_newest, "Datum is in the current regime");
+        assert_eq!(change_point.regime.len(), 20);
+    }
+}
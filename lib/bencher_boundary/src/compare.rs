@@ -0,0 +1,166 @@
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::{
+    mann_whitney::MannWhitneyU,
+    mean::{mean, variance},
+};
+
+/// The significance level for a difference between the baseline and the candidate.
+const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// A comparison of the data for a baseline metric and a candidate metric.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsComparison {
+    pub baseline: MetricsSample,
+    pub candidate: MetricsSample,
+    pub difference: f64,
+    pub change: Option<f64>,
+    pub p_value: Option<f64>,
+    pub significant: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsSample {
+    pub count: usize,
+    pub mean: f64,
+    pub std_dev: Option<f64>,
+}
+
+impl MetricsComparison {
+    pub fn new(baseline: &[f64], candidate: &[f64]) -> Option<Self> {
+        let baseline_sample = MetricsSample::new(baseline)?;
+        let candidate_sample = MetricsSample::new(candidate)?;
+        let difference = candidate_sample.mean - baseline_sample.mean;
+        let change = (difference / baseline_sample.mean) * 100.0;
+        let p_value = p_value(baseline, candidate);
+        Some(Self {
+            baseline: baseline_sample,
+            candidate: candidate_sample,
+            difference,
+            change: change.is_finite().then_some(change),
+            p_value,
+            significant: p_value.is_some_and(|p_value| p_value < SIGNIFICANCE_LEVEL),
+        })
+    }
+}
+
+impl MetricsSample {
+    fn new(data: &[f64]) -> Option<Self> {
+        let mean = mean(data)?;
+        let std_dev = variance(mean, data)
+            .map(f64::sqrt)
+            .and_then(|std_dev| std_dev.is_finite().then_some(std_dev));
+        Some(Self {
+            count: data.len(),
+            mean,
+            std_dev,
+        })
+    }
+}
+
+// The two-tailed p-value of the Mann-Whitney U test,
+// using the normal approximation of the U statistic.
+fn p_value(baseline: &[f64], candidate: &[f64]) -> Option<f64> {
+    let mann_whitney_u = MannWhitneyU::new(baseline, candidate)?;
+    let normal = Normal::new(0.0, 1.0).ok()?;
+    let p_value = 2.0 * (1.0 - normal.cdf(mann_whitney_u.z_score().abs()));
+    p_value.is_finite().then_some(p_value)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use std::f64::consts::SQRT_2;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{MetricsComparison, MetricsSample};
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_GREATER: &[f64] = &[6.0, 7.0, 8.0, 9.0, 10.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_FIVE_CONST_DOUBLE: &[f64] = &[2.0, 2.0, 2.0, 2.0, 2.0];
+
+    #[test]
+    fn test_comparison_zero() {
+        assert_eq!(MetricsComparison::new(DATA_ZERO, DATA_FIVE), None);
+        assert_eq!(MetricsComparison::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_comparison_one() {
+        let comparison = MetricsComparison::new(DATA_ONE, DATA_ONE).unwrap();
+        assert_eq!(
+            comparison,
+            MetricsComparison {
+                baseline: MetricsSample {
+                    count: 1,
+                    mean: 1.0,
+                    std_dev: None,
+                },
+                candidate: MetricsSample {
+                    count: 1,
+                    mean: 1.0,
+                    std_dev: None,
+                },
+                difference: 0.0,
+                change: Some(0.0),
+                p_value: None,
+                significant: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_comparison_five_same() {
+        let comparison = MetricsComparison::new(DATA_FIVE, DATA_FIVE).unwrap();
+        assert_eq!(
+            comparison,
+            MetricsComparison {
+                baseline: MetricsSample {
+                    count: 5,
+                    mean: 3.0,
+                    std_dev: Some(SQRT_2),
+                },
+                candidate: MetricsSample {
+                    count: 5,
+                    mean: 3.0,
+                    std_dev: Some(SQRT_2),
+                },
+                difference: 0.0,
+                change: Some(0.0),
+                p_value: Some(1.0),
+                significant: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_comparison_five_greater() {
+        let comparison = MetricsComparison::new(DATA_FIVE, DATA_FIVE_GREATER).unwrap();
+        assert_eq!(comparison.difference, 5.0);
+        assert_eq!(comparison.change, Some(166.66666666666669));
+        assert!(comparison.p_value.unwrap() < 0.05);
+        assert!(comparison.significant);
+
+        let comparison = MetricsComparison::new(DATA_FIVE_GREATER, DATA_FIVE).unwrap();
+        assert_eq!(comparison.difference, -5.0);
+        assert_eq!(comparison.change, Some(-62.5));
+        assert!(comparison.p_value.unwrap() < 0.05);
+        assert!(comparison.significant);
+    }
+
+    #[test]
+    fn test_comparison_five_const() {
+        let comparison = MetricsComparison::new(DATA_FIVE_CONST, DATA_FIVE_CONST_DOUBLE).unwrap();
+        assert_eq!(comparison.difference, 1.0);
+        assert_eq!(comparison.change, Some(100.0));
+        assert!(comparison.significant);
+
+        let comparison = MetricsComparison::new(DATA_FIVE_CONST, DATA_FIVE_CONST).unwrap();
+        assert_eq!(comparison.p_value, None);
+        assert!(!comparison.significant);
+    }
+}
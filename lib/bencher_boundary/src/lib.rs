@@ -1,13 +1,20 @@
 pub mod boundary;
+mod change_point;
+mod compare;
 mod error;
 pub mod limits;
 mod ln;
+mod mann_whitney;
 mod mean;
 mod quartiles;
+mod summary;
 
 pub use boundary::MetricsBoundary;
+pub use compare::{MetricsComparison, MetricsSample};
 pub use error::BoundaryError;
+pub use summary::MetricsSummary;
 
+/// The historical data for a metric, ordered from the most recent to the least recent.
 #[derive(Debug, Clone)]
 pub struct MetricsData {
     pub data: Vec<f64>,
@@ -78,6 +78,12 @@ impl From<MetricsLimit> for f64 {
     }
 }
 
+impl From<f64> for MetricsLimit {
+    fn from(value: f64) -> Self {
+        Self { value }
+    }
+}
+
 impl From<Boundary> for MetricsLimit {
     fn from(boundary: Boundary) -> Self {
         Self {
@@ -4,7 +4,7 @@ use bencher_json::{
 use slog::{debug, Logger};
 use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
 
-use crate::{ln::Ln, quartiles::Quartiles, BoundaryError};
+use crate::{ln::Ln, mann_whitney::MannWhitneyU, quartiles::Quartiles, BoundaryError};
 
 mod limit;
 
@@ -197,6 +197,31 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_mann_whitney_u(
+        log: &Logger,
+        data: &[f64],
+        mann_whitney_u: MannWhitneyU,
+        lower_z: Option<f64>,
+        upper_z: Option<f64>,
+    ) -> Self {
+        debug!(log, "Mann-Whitney U: {mann_whitney_u:?}");
+        // The boundary limits are the historical percentiles that the entire new sample
+        // would have to be beyond in order for the difference to be statistically significant.
+        let lower = lower_z.and_then(|z| {
+            Quartiles::percentile(data, mann_whitney_u.critical_fraction(-z))
+                .map(MetricsLimit::from)
+        });
+        let upper = upper_z.and_then(|z| {
+            Quartiles::percentile(data, mann_whitney_u.critical_fraction(z)).map(MetricsLimit::from)
+        });
+
+        Self {
+            baseline: Quartiles::percentile(data, 0.5),
+            lower,
+            upper,
+        }
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
@@ -0,0 +1,150 @@
+use std::cmp;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MannWhitneyU {
+    /// The size of the historical sample.
+    pub n: usize,
+    /// The size of the new sample.
+    pub m: usize,
+    /// The U statistic for the new sample.
+    pub u: f64,
+    /// The standard deviation of the U statistic, corrected for ties.
+    pub sigma: f64,
+}
+
+impl MannWhitneyU {
+    // https://en.wikipedia.org/wiki/Mann%E2%80%93Whitney_U_test
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let n = data.len();
+        let m = sample.len();
+        if n == 0 || m == 0 {
+            return None;
+        }
+
+        // Rank the combined samples, keeping track of which ones are from the new sample.
+        let mut combined = data
+            .iter()
+            .map(|&x| (x, false))
+            .chain(sample.iter().map(|&y| (y, true)))
+            .collect::<Vec<_>>();
+        combined
+            .sort_unstable_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(cmp::Ordering::Equal));
+
+        let mut rank_sum = 0.0;
+        let mut tie_correction = 0.0;
+        let mut start = 0;
+        while let Some(&(value, _)) = combined.get(start) {
+            let tie_group = combined
+                .get(start..)?
+                .iter()
+                .take_while(|(x, _)| x.total_cmp(&value).is_eq())
+                .collect::<Vec<_>>();
+            let ties = tie_group.len();
+            // Tied values are given the average of the ranks they span (ranks start at 1).
+            #[allow(clippy::cast_precision_loss)]
+            let rank = (start + 1 + start + ties) as f64 / 2.0;
+            #[allow(clippy::cast_precision_loss)]
+            let sample_ties = tie_group.iter().filter(|(_, is_sample)| *is_sample).count() as f64;
+            rank_sum += rank * sample_ties;
+            #[allow(clippy::cast_precision_loss)]
+            let t = ties as f64;
+            tie_correction += t.powi(3) - t;
+            start += ties;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let (n_f, m_f) = (n as f64, m as f64);
+        let total = n_f + m_f;
+        let u = rank_sum - m_f * (m_f + 1.0) / 2.0;
+        let variance =
+            m_f * n_f / 12.0 * ((total + 1.0) - tie_correction / (total * (total - 1.0)));
+        let sigma = variance.sqrt();
+        (sigma.is_finite() && sigma > 0.0).then_some(Self { n, m, u, sigma })
+    }
+
+    pub fn mean(self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let mean = (self.n * self.m) as f64 / 2.0;
+        mean
+    }
+
+    // The normal approximation of the U statistic, with a continuity correction.
+    pub fn z_score(self) -> f64 {
+        let diff = self.u - self.mean();
+        let corrected = if diff > 0.0 {
+            diff - 0.5
+        } else if diff < 0.0 {
+            diff + 0.5
+        } else {
+            diff
+        };
+        corrected / self.sigma
+    }
+
+    // The fraction of the historical sample that all of the new sample must exceed
+    // in order for the test to reach the given critical z-score.
+    pub fn critical_fraction(self, z: f64) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let max_u = (self.n * self.m) as f64;
+        ((self.mean() + z * self.sigma) / max_u).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::MannWhitneyU;
+
+    const DATA: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+
+    #[test]
+    fn test_mann_whitney_u_empty() {
+        assert_eq!(MannWhitneyU::new(&[], DATA), None);
+        assert_eq!(MannWhitneyU::new(DATA, &[]), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_constant() {
+        assert_eq!(MannWhitneyU::new(&[1.0, 1.0], &[1.0, 1.0]), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_same() {
+        let mwu = MannWhitneyU::new(DATA, DATA).unwrap();
+        assert_eq!(mwu.u, 12.5);
+        assert_eq!(mwu.mean(), 12.5);
+        assert_eq!(mwu.z_score(), 0.0);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_greater() {
+        let mwu = MannWhitneyU::new(DATA, &[6.0, 7.0, 8.0, 9.0, 10.0]).unwrap();
+        assert_eq!(mwu.u, 25.0);
+        assert_eq!(mwu.sigma, 4.7871355387816905);
+        assert_eq!(mwu.z_score(), 2.5067182457620487);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_less() {
+        let mwu = MannWhitneyU::new(DATA, &[-1.0, -2.0, -3.0, -4.0, -5.0]).unwrap();
+        assert_eq!(mwu.u, 0.0);
+        assert_eq!(mwu.z_score(), -2.5067182457620487);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_ties() {
+        let mwu = MannWhitneyU::new(&[1.0, 2.0, 2.0, 3.0], &[2.0, 3.0]).unwrap();
+        assert_eq!(mwu.u, 5.5);
+        assert_eq!(mwu.sigma, 2.0);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_critical_fraction() {
+        let mwu = MannWhitneyU::new(DATA, DATA).unwrap();
+        assert_eq!(mwu.critical_fraction(0.0), 0.5);
+        assert_eq!(mwu.critical_fraction(100.0), 1.0);
+        assert_eq!(mwu.critical_fraction(-100.0), 0.0);
+    }
+}
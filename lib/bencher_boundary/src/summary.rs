@@ -0,0 +1,199 @@
+use crate::{
+    mean::{mean, variance},
+    quartiles::Quartiles,
+};
+
+/// A statistical summary of the data for a metric, ordered from the least recent to the most recent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsSummary {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: Option<f64>,
+    pub p5: f64,
+    pub p95: f64,
+    pub trend: Option<f64>,
+    pub change: Option<f64>,
+}
+
+impl MetricsSummary {
+    pub fn new(data: &[f64]) -> Option<Self> {
+        let mean = mean(data)?;
+        let Quartiles { q2: median, .. } = Quartiles::new(data)?;
+        // Unlike a threshold model, a constant series has a valid standard deviation of zero
+        let std_dev = variance(mean, data)
+            .map(f64::sqrt)
+            .and_then(|std_dev| std_dev.is_finite().then_some(std_dev));
+        Some(Self {
+            count: data.len(),
+            mean,
+            median,
+            std_dev,
+            p5: Quartiles::percentile(data, 0.05)?,
+            p95: Quartiles::percentile(data, 0.95)?,
+            trend: trend(mean, data),
+            change: change(data),
+        })
+    }
+}
+
+// The slope of the least squares linear regression line,
+// with each datum evenly spaced along the x-axis
+#[allow(clippy::cast_precision_loss)]
+fn trend(mean: f64, data: &[f64]) -> Option<f64> {
+    // Do not calculate a trend if there are less than 2 data points
+    if data.len() < 2 {
+        return None;
+    }
+    let x_mean = (data.len() - 1) as f64 / 2.0;
+    let (covariance, x_variance) =
+        data.iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(covariance, x_variance), (index, &value)| {
+                let x_delta = index as f64 - x_mean;
+                (
+                    covariance + x_delta * (value - mean),
+                    x_variance + x_delta.powi(2),
+                )
+            });
+    let slope = covariance / x_variance;
+    slope.is_finite().then_some(slope)
+}
+
+// The percent change between the mean of the first quartile and the mean of the last quartile of the data
+fn change(data: &[f64]) -> Option<f64> {
+    // Do not calculate a change if there are less than 2 data points
+    if data.len() < 2 {
+        return None;
+    }
+    let quartile_len = data.len().div_ceil(4);
+    let first = mean(data.get(..quartile_len)?)?;
+    let last = mean(data.get(data.len() - quartile_len..)?)?;
+    let change = ((last - first) / first) * 100.0;
+    change.is_finite().then_some(change)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use std::f64::consts::SQRT_2;
+
+    use pretty_assertions::assert_eq;
+
+    use super::MetricsSummary;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_TWO: &[f64] = &[1.0, 2.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_DESC: &[f64] = &[5.0, 4.0, 3.0, 2.0, 1.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_EIGHT: &[f64] = &[10.0, 12.0, 11.0, 13.0, 12.0, 14.0, 15.0, 13.0];
+
+    #[test]
+    fn test_summary_zero() {
+        let summary = MetricsSummary::new(DATA_ZERO);
+        assert_eq!(summary, None);
+    }
+
+    #[test]
+    fn test_summary_one() {
+        let summary = MetricsSummary::new(DATA_ONE).unwrap();
+        assert_eq!(
+            summary,
+            MetricsSummary {
+                count: 1,
+                mean: 1.0,
+                median: 1.0,
+                std_dev: None,
+                p5: 1.0,
+                p95: 1.0,
+                trend: None,
+                change: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_two() {
+        let summary = MetricsSummary::new(DATA_TWO).unwrap();
+        assert_eq!(
+            summary,
+            MetricsSummary {
+                count: 2,
+                mean: 1.5,
+                median: 1.5,
+                std_dev: Some(0.5),
+                p5: 1.05,
+                p95: 1.95,
+                trend: Some(1.0),
+                change: Some(100.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_five() {
+        let summary = MetricsSummary::new(DATA_FIVE).unwrap();
+        assert_eq!(
+            summary,
+            MetricsSummary {
+                count: 5,
+                mean: 3.0,
+                median: 3.0,
+                std_dev: Some(SQRT_2),
+                p5: 1.2,
+                p95: 4.8,
+                trend: Some(1.0),
+                change: Some(200.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_five_desc() {
+        let summary = MetricsSummary::new(DATA_FIVE_DESC).unwrap();
+        assert_eq!(
+            summary,
+            MetricsSummary {
+                count: 5,
+                mean: 3.0,
+                median: 3.0,
+                std_dev: Some(SQRT_2),
+                p5: 1.2,
+                p95: 4.8,
+                trend: Some(-1.0),
+                change: Some(-66.66666666666666),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_five_const() {
+        let summary = MetricsSummary::new(DATA_FIVE_CONST).unwrap();
+        assert_eq!(
+            summary,
+            MetricsSummary {
+                count: 5,
+                mean: 1.0,
+                median: 1.0,
+                std_dev: Some(0.0),
+                p5: 1.0,
+                p95: 1.0,
+                trend: Some(0.0),
+                change: Some(0.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_eight() {
+        let summary = MetricsSummary::new(DATA_EIGHT).unwrap();
+        assert_eq!(summary.count, 8);
+        assert_eq!(summary.mean, 12.5);
+        assert_eq!(summary.median, 12.5);
+        assert_eq!(summary.trend, Some(0.5238095238095238));
+        // The first two (11.0) and the last two (14.0) data points
+        assert_eq!(summary.change, Some(27.27272727272727));
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::absolute_paths)]
 
 use bencher_json::{Jwt, BENCHER_API_URL};
+use futures_util::TryStreamExt;
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::time::{sleep, Duration};
 
@@ -161,19 +162,7 @@ impl BencherClient {
         Json: DeserializeOwned + Serialize + TryFrom<T, Error = E>,
         E: std::error::Error + Send + Sync + 'static,
     {
-        let timeout = Duration::from_secs(15);
-        let mut client_builder = reqwest::ClientBuilder::new().connect_timeout(timeout);
-
-        if let Some(token) = &self.token {
-            let mut headers = reqwest::header::HeaderMap::new();
-            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
-                .map_err(ClientError::HeaderValue)?;
-            headers.insert("Authorization", bearer_token);
-            client_builder = client_builder.default_headers(headers);
-        }
-
-        let reqwest_client = client_builder.build().map_err(ClientError::BuildClient)?;
-        let client = crate::codegen::Client::new_with_client(self.host.as_ref(), reqwest_client);
+        let client = self.codegen_client()?;
 
         let attempts = self.attempts;
         let max_attempts = attempts.checked_sub(1).unwrap_or_default();
@@ -265,6 +254,121 @@ impl BencherClient {
         Err(ClientError::SendTimeout(attempts))
     }
 
+    /// Send a request to the Bencher API that responds with a stream of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `sender`: A function that takes a `codegen::Client` and returns a `Future` that resolves
+    ///   to a `Result` containing a `ResponseValue` of a `ByteStream` or an `Error`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response `ByteStream` or an `Error`
+    pub async fn send_stream<F, R>(
+        &self,
+        sender: F,
+    ) -> Result<progenitor_client::ByteStream, ClientError>
+    where
+        F: Fn(crate::codegen::Client) -> R,
+        R: std::future::Future<
+            Output = Result<
+                progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                crate::codegen::Error<progenitor_client::ByteStream>,
+            >,
+        >,
+    {
+        let client = self.codegen_client()?;
+
+        let attempts = self.attempts;
+        let max_attempts = attempts.checked_sub(1).unwrap_or_default();
+        let mut retry_after = self.retry_after;
+
+        for attempt in 0..attempts {
+            match sender(client.clone()).await {
+                Ok(response_value) => return Ok(response_value.into_inner()),
+                #[allow(clippy::print_stderr)]
+                Err(crate::codegen::Error::CommunicationError(e)) => {
+                    if self.log {
+                        eprintln!("\nSend attempt #{}/{attempts}: {e}", attempt + 1);
+                    }
+                    if attempt != max_attempts {
+                        if self.log {
+                            eprintln!("Will retry after {retry_after} second(s).");
+                        }
+                        sleep(Duration::from_secs(retry_after)).await;
+                        retry_after *= 2;
+                    }
+                },
+                Err(crate::codegen::Error::InvalidRequest(e)) => {
+                    return Err(ClientError::InvalidRequest(e))
+                },
+                Err(crate::codegen::Error::PreHookError(e)) => {
+                    return Err(ClientError::PreHookError(e))
+                },
+                Err(crate::codegen::Error::PostHookError(e)) => {
+                    return Err(ClientError::PostHookError(e))
+                },
+                Err(crate::codegen::Error::ErrorResponse(e)) => {
+                    let status = e.status();
+                    let headers = e.headers().clone();
+                    // The error response body is still JSON, even though the success response is not
+                    let mut stream = e.into_inner();
+                    let mut body = Vec::new();
+                    while let Some(bytes) = stream
+                        .try_next()
+                        .await
+                        .map_err(ClientError::ResponseBodyError)?
+                    {
+                        body.extend_from_slice(&bytes);
+                    }
+                    let http_error: crate::codegen::types::Error = serde_json::from_slice(&body)
+                        .map_err(|e| ClientError::InvalidResponsePayloadStrict(body.into(), e))?;
+                    return Err(ClientError::ErrorResponse(ErrorResponse {
+                        status,
+                        headers,
+                        request_id: http_error.request_id,
+                        error_code: http_error.error_code,
+                        message: http_error.message,
+                    }));
+                },
+                Err(crate::codegen::Error::InvalidUpgrade(e)) => {
+                    return Err(ClientError::InvalidUpgrade(e))
+                },
+                Err(crate::codegen::Error::ResponseBodyError(e)) => {
+                    return Err(ClientError::ResponseBodyError(e))
+                },
+                Err(crate::codegen::Error::InvalidResponsePayload(bytes, e)) => {
+                    return Err(ClientError::InvalidResponsePayloadStrict(bytes, e))
+                },
+                Err(crate::codegen::Error::UnexpectedResponse(response)) => {
+                    return Err(ClientError::UnexpectedResponseErr(response))
+                },
+            }
+        }
+
+        Err(ClientError::SendTimeout(attempts))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn codegen_client(&self) -> Result<crate::codegen::Client, ClientError> {
+        let timeout = Duration::from_secs(15);
+        let mut client_builder = reqwest::ClientBuilder::new().connect_timeout(timeout);
+
+        if let Some(token) = &self.token {
+            let mut headers = reqwest::header::HeaderMap::new();
+            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
+                .map_err(ClientError::HeaderValue)?;
+            headers.insert("Authorization", bearer_token);
+            client_builder = client_builder.default_headers(headers);
+        }
+
+        let reqwest_client = client_builder.build().map_err(ClientError::BuildClient)?;
+        Ok(crate::codegen::Client::new_with_client(
+            self.host.as_ref(),
+            reqwest_client,
+        ))
+    }
+
     #[allow(clippy::result_large_err)]
     fn log<T>(&self, response: &T) -> Result<(), ClientError>
     where
@@ -39,7 +39,9 @@ from_client!(
     NonEmpty,
     ResourceId,
     ResourceName,
+    ResolveVersions,
     SampleSize,
+    Secret,
     Slug,
     Url,
     UserName,
@@ -60,6 +62,8 @@ from_client!(
     ThresholdUuid,
     ModelUuid,
     AlertUuid,
+    AlertRuleUuid,
+    WebhookUuid,
     UserUuid,
     TokenUuid
 );
@@ -115,6 +119,8 @@ try_from_client!(
     JsonReports,
     JsonReport,
     JsonPerf,
+    JsonPerfSummary,
+    JsonPerfCompare,
     JsonPlots,
     JsonPlot,
     JsonBranches,
@@ -131,6 +137,10 @@ try_from_client!(
     JsonModel,
     JsonAlerts,
     JsonAlert,
+    JsonAlertRules,
+    JsonAlertRule,
+    JsonWebhooks,
+    JsonWebhook,
     JsonUsers,
     JsonUser,
     JsonPubUser,
@@ -196,7 +206,9 @@ into_uuids!(
     JsonBenchmarks[JsonBenchmark],
     JsonMeasures[JsonMeasure],
     JsonThresholds[JsonThreshold],
-    JsonAlerts[JsonAlert]
+    JsonAlerts[JsonAlert],
+    JsonAlertRules[JsonAlertRule],
+    JsonWebhooks[JsonWebhook]
 );
 
 macro_rules! into_uuid {
@@ -228,5 +240,7 @@ into_uuid!(
     JsonMeasure,
     JsonThreshold,
     JsonModel,
-    JsonAlert
+    JsonAlert,
+    JsonAlertRule,
+    JsonWebhook
 );
@@ -11,8 +11,8 @@ use bencher_json::{
         plot::{LOWER_BOUNDARY, UPPER_BOUNDARY},
         report::{JsonReportIteration, JsonReportMeasure, JsonReportResult},
     },
-    AlertUuid, JsonAlert, JsonBenchmark, JsonBoundary, JsonMeasure, JsonPerfQuery, JsonReport,
-    ReportUuid, ResourceName, Slug, ThresholdUuid, Units,
+    AlertUuid, JsonAlert, JsonBenchmark, JsonBoundary, JsonMeasure, JsonModel, JsonPerfQuery,
+    JsonReport, ModelBaseline, ReportUuid, ResourceName, Slug, ThresholdUuid, Units,
 };
 use ordered_float::OrderedFloat;
 use url::Url;
@@ -39,6 +39,12 @@ pub struct SubAdapter {
     pub file_size: bool,
 }
 
+pub struct CheckAlert {
+    pub active: bool,
+    pub title: String,
+    pub message: String,
+}
+
 impl ReportComment {
     pub fn new(
         console_url: Url,
@@ -261,17 +267,7 @@ impl ReportComment {
         html.push_str("<tbody>");
 
         for alert in &self.json_report.alerts {
-            let (factor, units) = {
-                let mut min = alert.metric.value;
-                if let Some(lower_limit) = alert.boundary.lower_limit {
-                    min = min.min(lower_limit);
-                }
-                if let Some(upper_limit) = alert.boundary.upper_limit {
-                    min = min.min(upper_limit);
-                }
-                let units = Units::new(min.into(), alert.threshold.measure.units.clone());
-                (units.scale_factor(), units.scale_units())
-            };
+            let (factor, units) = alert_units(alert);
 
             html.push_str("<tr>");
             if self.multiple_iterations {
@@ -292,6 +288,7 @@ impl ReportComment {
                 html,
                 alert.metric.value,
                 alert.boundary.baseline,
+                is_start_point_baseline(alert.threshold.model.as_ref()),
                 factor,
                 true,
             );
@@ -463,6 +460,9 @@ impl ReportComment {
                         html,
                         report_measure.metric.value,
                         report_measure.boundary.and_then(|b| b.baseline),
+                        is_start_point_baseline(
+                            report_measure.threshold.as_ref().map(|t| &t.model),
+                        ),
                         factor,
                         alert.is_some(),
                     );
@@ -596,6 +596,107 @@ impl ReportComment {
         !self.json_report.alerts.is_empty()
     }
 
+    pub fn has_active_alert(&self) -> bool {
+        self.json_report
+            .alerts
+            .iter()
+            .any(|alert| alert.status == AlertStatus::Active)
+    }
+
+    // Warnings should be looked at but do not need to block a merge:
+    // no benchmarks, missing thresholds, or alerts that are no longer active
+    pub fn has_warning(&self, require_threshold: bool) -> bool {
+        self.benchmark_count == 0
+            || (!require_threshold && !self.missing_threshold.is_empty())
+            || self
+                .json_report
+                .alerts
+                .iter()
+                .any(|alert| alert.status != AlertStatus::Active)
+    }
+
+    pub fn check_alerts(&self) -> Vec<CheckAlert> {
+        self.json_report
+            .alerts
+            .iter()
+            .map(|alert| {
+                let (factor, units) = alert_units(alert);
+                let limit = match alert.limit {
+                    BoundaryLimit::Lower => alert.boundary.lower_limit,
+                    BoundaryLimit::Upper => alert.boundary.upper_limit,
+                };
+                let title = format!(
+                    "{benchmark} ({measure}){iter}",
+                    benchmark = alert.benchmark.name,
+                    measure = alert.threshold.measure.name,
+                    iter = if self.multiple_iterations {
+                        format!(" (Iteration {iteration})", iteration = alert.iteration)
+                    } else {
+                        String::new()
+                    },
+                );
+                let message = format!(
+                    "{value} {units} exceeded the {boundary} Boundary Limit{limit}\n{url}",
+                    value = Units::format_float((alert.metric.value / factor).into()),
+                    boundary = alert.limit,
+                    limit = limit
+                        .map(|limit| format!(
+                            " of {limit} {units}",
+                            limit = Units::format_float((limit / factor).into())
+                        ))
+                        .unwrap_or_default(),
+                    url = self.alert_perf_url(alert),
+                );
+                CheckAlert {
+                    active: alert.status == AlertStatus::Active,
+                    title,
+                    message,
+                }
+            })
+            .collect()
+    }
+
+    pub fn markdown_alerts_table(&self) -> Option<String> {
+        if self.json_report.alerts.is_empty() {
+            return None;
+        }
+
+        let mut markdown = String::new();
+        if self.multiple_iterations {
+            markdown.push_str("| Iteration ");
+        }
+        markdown.push_str(
+            "| Benchmark | Measure (Units) | Benchmark Result | Boundary Limit | Status | Plot |\n",
+        );
+        if self.multiple_iterations {
+            markdown.push_str("| --- ");
+        }
+        markdown.push_str("| --- | --- | --- | --- | --- | --- |\n");
+        for alert in &self.json_report.alerts {
+            let (factor, units) = alert_units(alert);
+            let limit = match alert.limit {
+                BoundaryLimit::Lower => alert.boundary.lower_limit,
+                BoundaryLimit::Upper => alert.boundary.upper_limit,
+            };
+            if self.multiple_iterations {
+                markdown.push_str(&format!("| {} ", alert.iteration));
+            }
+            markdown.push_str(&format!(
+                "| {benchmark} | {measure} ({units}) | {value} | {boundary}: {limit} | {status} | [plot]({url}) |\n",
+                benchmark = alert.benchmark.name,
+                measure = alert.threshold.measure.name,
+                value = Units::format_float((alert.metric.value / factor).into()),
+                boundary = alert.limit,
+                limit = limit
+                    .map(|limit| Units::format_float((limit / factor).into()))
+                    .unwrap_or_default(),
+                status = alert_status(alert),
+                url = self.alert_perf_url(alert),
+            ));
+        }
+        Some(markdown)
+    }
+
     pub fn find_alert(&self, result: &JsonReportResult, measure: &Measure) -> Option<&JsonAlert> {
         self.json_report.alerts.iter().find(|alert| {
             alert.benchmark.slug == result.benchmark.slug
@@ -693,6 +794,7 @@ impl ReportComment {
                 (self.json_report.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into(),
             ),
             end_time: Some(self.json_report.end_time),
+            x_axis: None,
         };
         let mut query_string = vec![("report", Some(self.json_report.uuid.to_string()))];
         if boundary_limits.is_some_and(|bl| bl.lower) {
@@ -782,23 +884,44 @@ impl Measure {
     }
 }
 
+// Scale the alert units to fit the smallest of the value and its boundary limits
+fn alert_units(alert: &JsonAlert) -> (OrderedFloat<f64>, String) {
+    let mut min = alert.metric.value;
+    if let Some(lower_limit) = alert.boundary.lower_limit {
+        min = min.min(lower_limit);
+    }
+    if let Some(upper_limit) = alert.boundary.upper_limit {
+        min = min.min(upper_limit);
+    }
+    let units = Units::new(min.into(), alert.threshold.measure.units.clone());
+    (units.scale_factor(), units.scale_units())
+}
+
 fn alert_status(alert: &JsonAlert) -> &str {
     match alert.status {
         AlertStatus::Active => "🔔",
         AlertStatus::Dismissed | AlertStatus::Silenced => "🔕",
+        AlertStatus::Resolved => "✅",
     }
 }
 
+// A start point baseline is a direct comparison against the base branch.
+fn is_start_point_baseline(model: Option<&JsonModel>) -> bool {
+    model.is_some_and(|model| model.baseline == Some(ModelBaseline::StartPoint))
+}
+
 fn value_cell(
     html: &mut String,
     value: OrderedFloat<f64>,
     baseline: Option<OrderedFloat<f64>>,
+    vs_base: bool,
     factor: OrderedFloat<f64>,
     bold: bool,
 ) {
     fn value_cell_inner(
         value: OrderedFloat<f64>,
         baseline: Option<OrderedFloat<f64>>,
+        vs_base: bool,
         factor: OrderedFloat<f64>,
     ) -> String {
         let mut cell = Units::format_float((value / factor).into());
@@ -815,9 +938,17 @@ fn value_cell(
             cell.push_str("<br />");
             cell.push_str("<details>");
             cell.push_str("<summary>");
-            cell.push_str(&format!("({plus}{percent}%)"));
+            if vs_base {
+                cell.push_str(&format!("({plus}{percent}% vs. base)"));
+            } else {
+                cell.push_str(&format!("({plus}{percent}%)"));
+            }
             cell.push_str("</summary>");
-            cell.push_str(&format!("Baseline: {baseline}"));
+            if vs_base {
+                cell.push_str(&format!("Base: {baseline}"));
+            } else {
+                cell.push_str(&format!("Baseline: {baseline}"));
+            }
             cell.push_str("</details>");
         }
 
@@ -828,10 +959,10 @@ fn value_cell(
     if bold {
         html.push_str(&format!(
             "<b>{}</b>",
-            value_cell_inner(value, baseline, factor)
+            value_cell_inner(value, baseline, vs_base, factor)
         ));
     } else {
-        html.push_str(&value_cell_inner(value, baseline, factor));
+        html.push_str(&value_cell_inner(value, baseline, vs_base, factor));
     }
     html.push_str("</td>");
 }
@@ -1002,3 +1133,84 @@ fn boundary_limits_map(
     }
     map
 }
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{project::model::JsonModel, DateTime, ModelBaseline, ModelTest};
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use super::{is_start_point_baseline, value_cell};
+
+    fn json_model(baseline: Option<ModelBaseline>) -> JsonModel {
+        JsonModel {
+            uuid: "5d7fb5f2-e0c4-4b56-b4df-0ac4b5e1e3e6".parse().unwrap(),
+            test: ModelTest::TTest,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: None,
+            upper_boundary: None,
+            baseline,
+            max_versions: None,
+            resolve_versions: None,
+            created: DateTime::now(),
+            replaced: None,
+        }
+    }
+
+    fn cell(value: f64, baseline: Option<f64>, vs_base: bool) -> String {
+        let mut html = String::new();
+        value_cell(
+            &mut html,
+            value.into(),
+            baseline.map(OrderedFloat::from),
+            vs_base,
+            1.0.into(),
+            false,
+        );
+        html
+    }
+
+    #[test]
+    fn test_is_start_point_baseline() {
+        assert!(!is_start_point_baseline(None), "No model");
+        assert!(
+            !is_start_point_baseline(Some(&json_model(None))),
+            "Default baseline"
+        );
+        assert!(
+            !is_start_point_baseline(Some(&json_model(Some(ModelBaseline::Head)))),
+            "Head baseline"
+        );
+        assert!(
+            is_start_point_baseline(Some(&json_model(Some(ModelBaseline::StartPoint)))),
+            "Start point baseline"
+        );
+    }
+
+    #[test]
+    fn test_value_cell_vs_base() {
+        assert_eq!(
+            cell(110.0, Some(100.0), true),
+            "<td>110.00<br /><details><summary>(+10.00% vs. base)</summary>Base: 100.00</details></td>"
+        );
+        assert_eq!(
+            cell(75.0, Some(100.0), true),
+            "<td>75.00<br /><details><summary>(-25.00% vs. base)</summary>Base: 100.00</details></td>"
+        );
+    }
+
+    #[test]
+    fn test_value_cell_baseline() {
+        assert_eq!(
+            cell(110.0, Some(100.0), false),
+            "<td>110.00<br /><details><summary>(+10.00%)</summary>Baseline: 100.00</details></td>"
+        );
+    }
+
+    #[test]
+    fn test_value_cell_no_baseline() {
+        assert_eq!(cell(110.0, None, true), "<td>110.00</td>");
+    }
+}
@@ -2,9 +2,9 @@ use std::sync::LazyLock;
 
 pub use bencher_valid::{
     BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
-    Index, IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary,
-    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, Units, Url,
-    UserName, ValidError, Window,
+    Index, IqrBoundary, Jwt, Model, ModelBaseline, ModelTest, NameId, NameIdKind, NonEmpty,
+    PercentageBoundary, ResolveVersions, ResourceId, ResourceIdKind, ResourceName, SampleSize,
+    Sanitize, Secret, Slug, Units, Url, UserName, ValidError, Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -35,6 +35,7 @@ pub use organization::{
 pub use pagination::{JsonDirection, JsonPagination};
 pub use project::{
     alert::{AlertUuid, JsonAlert, JsonAlerts},
+    alert_rule::{AlertRuleUuid, JsonAlertRule, JsonAlertRules, JsonNewAlertRule},
     benchmark::{BenchmarkUuid, JsonBenchmark, JsonBenchmarks},
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{BranchUuid, JsonBranch, JsonBranches, JsonNewBranch, JsonNewStartPoint},
@@ -45,11 +46,15 @@ pub use project::{
         MetricUuid,
     },
     model::{JsonModel, ModelUuid},
-    perf::{JsonPerf, JsonPerfQuery, ReportBenchmarkUuid},
+    perf::{JsonPerf, JsonPerfCompare, JsonPerfQuery, JsonPerfSummary, ReportBenchmarkUuid},
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
     report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
     testbed::{JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedUuid},
     threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    webhook::{
+        JsonNewWebhook, JsonWebhook, JsonWebhookDeliveries, JsonWebhookDelivery,
+        JsonWebhookPayload, JsonWebhooks, WebhookDeliveryUuid, WebhookEvent, WebhookUuid,
+    },
     JsonNewProject, JsonProject, JsonProjects, ProjectUuid,
 };
 #[cfg(feature = "plus")]
@@ -68,6 +73,7 @@ pub use system::{
     version::JsonApiVersion,
 };
 pub use user::{
+    notification::{AlertDigest, JsonNotifications},
     token::{JsonNewToken, JsonToken, JsonTokens, TokenUuid},
     JsonPubUser, JsonUpdateUser, JsonUser, JsonUsers, UserUuid,
 };
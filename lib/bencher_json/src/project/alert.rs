@@ -27,6 +27,8 @@ pub struct JsonAlert {
     pub boundary: JsonBoundary,
     pub limit: BoundaryLimit,
     pub status: AlertStatus,
+    /// The report that automatically resolved the alert, if any.
+    pub resolved_report: Option<ReportUuid>,
     pub created: DateTime,
     pub modified: DateTime,
 }
@@ -34,9 +36,12 @@ pub struct JsonAlert {
 const ACTIVE_INT: i32 = 0;
 const DISMISSED_INT: i32 = 1;
 const SILENCED_INT: i32 = 10;
+const RESOLVED_INT: i32 = 11;
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, Copy, Default, derive_more::Display, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
 #[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
@@ -50,6 +55,9 @@ pub enum AlertStatus {
     Dismissed = DISMISSED_INT,
     /// The alert has been silenced by the system.
     Silenced = SILENCED_INT,
+    /// The alert has been resolved by the system,
+    /// as the benchmark has recovered to within its boundary limits.
+    Resolved = RESOLVED_INT,
 }
 
 impl From<UpdateAlertStatus> for AlertStatus {
@@ -63,7 +71,7 @@ impl From<UpdateAlertStatus> for AlertStatus {
 
 #[cfg(feature = "db")]
 mod alert_status {
-    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT, SILENCED_INT};
+    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT, RESOLVED_INT, SILENCED_INT};
 
     #[derive(Debug, thiserror::Error)]
     pub enum AlertStatusError {
@@ -84,6 +92,7 @@ mod alert_status {
                 Self::Active => ACTIVE_INT.to_sql(out),
                 Self::Dismissed => DISMISSED_INT.to_sql(out),
                 Self::Silenced => SILENCED_INT.to_sql(out),
+                Self::Resolved => RESOLVED_INT.to_sql(out),
             }
         }
     }
@@ -98,6 +107,7 @@ mod alert_status {
                 ACTIVE_INT => Ok(Self::Active),
                 DISMISSED_INT => Ok(Self::Dismissed),
                 SILENCED_INT => Ok(Self::Silenced),
+                RESOLVED_INT => Ok(Self::Resolved),
                 value => Err(Box::new(AlertStatusError::Invalid(value))),
             }
         }
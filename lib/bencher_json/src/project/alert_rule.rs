@@ -0,0 +1,39 @@
+use bencher_valid::{DateTime, NameId, ResourceName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonMeasure, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(AlertRuleUuid);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewAlertRule {
+    /// The name of the alert rule.
+    /// Maximum length is 64 characters.
+    pub name: ResourceName,
+    /// The UUIDs, slugs, or names of the alert rule measures.
+    /// An alert is only generated when all of these measures
+    /// exceed their threshold boundaries for the same benchmark in the same report.
+    /// At least two measures must be specified.
+    pub measures: Vec<NameId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertRules(pub Vec<JsonAlertRule>);
+
+crate::from_vec!(JsonAlertRules[JsonAlertRule]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertRule {
+    pub uuid: AlertRuleUuid,
+    pub project: ProjectUuid,
+    pub name: ResourceName,
+    pub measures: Vec<JsonMeasure>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
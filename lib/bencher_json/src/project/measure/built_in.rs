@@ -114,3 +114,45 @@ pub mod iai_callgrind {
     create_measure!(ReadsBytes, "Reads bytes", "reads-bytes", BYTES);
     create_measure!(WritesBytes, "Writes bytes", "writes-bytes", BYTES);
 }
+
+pub mod divan {
+    use bencher_valid::BYTES;
+
+    // AllocProfiler
+    create_measure!(
+        MaxAllocCount,
+        "Max Alloc Count",
+        "max-alloc-count",
+        "allocations"
+    );
+    create_measure!(MaxAllocBytes, "Max Alloc Bytes", "max-alloc-bytes", BYTES);
+    create_measure!(AllocCount, "Alloc Count", "alloc-count", "allocations");
+    create_measure!(AllocBytes, "Alloc Bytes", "alloc-bytes", BYTES);
+    create_measure!(
+        DeallocCount,
+        "Dealloc Count",
+        "dealloc-count",
+        "deallocations"
+    );
+    create_measure!(DeallocBytes, "Dealloc Bytes", "dealloc-bytes", BYTES);
+    create_measure!(GrowCount, "Grow Count", "grow-count", "reallocations");
+    create_measure!(GrowBytes, "Grow Bytes", "grow-bytes", BYTES);
+    create_measure!(ShrinkCount, "Shrink Count", "shrink-count", "reallocations");
+    create_measure!(ShrinkBytes, "Shrink Bytes", "shrink-bytes", BYTES);
+}
+
+// `-benchmem` uses the `divan` `AllocBytes` and `AllocCount` Measures
+pub mod go {
+    // `b.SetBytes`
+    create_measure!(
+        ByteThroughput,
+        "Byte Throughput",
+        "byte-throughput",
+        "megabytes / second (MB/s)"
+    );
+}
+
+pub mod load {
+    // HTTP load testing
+    create_measure!(ErrorRate, "Error Rate", "error-rate", "percent (%)");
+}
@@ -151,6 +151,26 @@ impl std::ops::Div<usize> for JsonNewMetric {
     }
 }
 
+impl std::ops::Mul<f64> for JsonNewMetric {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        let lower_value = self.lower_value.map(|b| b * rhs);
+        let upper_value = self.upper_value.map(|b| b * rhs);
+        // A negative factor flips the boundaries
+        let (lower_value, upper_value) = if rhs < 0.0 {
+            (upper_value, lower_value)
+        } else {
+            (lower_value, upper_value)
+        };
+        Self {
+            value: self.value * rhs,
+            lower_value,
+            upper_value,
+        }
+    }
+}
+
 impl Mean for JsonNewMetric {}
 
 impl Median for JsonNewMetric {}
@@ -14,6 +14,7 @@ use serde::{
 use crate::OrganizationUuid;
 
 pub mod alert;
+pub mod alert_rule;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
@@ -26,6 +27,7 @@ pub mod plot;
 pub mod report;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 
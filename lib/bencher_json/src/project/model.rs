@@ -1,4 +1,6 @@
-use bencher_valid::{Boundary, DateTime, ModelTest, SampleSize, Window};
+use bencher_valid::{
+    Boundary, DateTime, ModelBaseline, ModelTest, ResolveVersions, SampleSize, Window,
+};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,9 @@ pub struct JsonModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub baseline: Option<ModelBaseline>,
+    pub max_versions: Option<SampleSize>,
+    pub resolve_versions: Option<ResolveVersions>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -1,3 +1,4 @@
+use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::ser::{self, SerializeStruct};
@@ -5,18 +6,19 @@ use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 
 use crate::urlencoded::{
-    from_urlencoded_list, from_urlencoded_nullable_list, to_urlencoded, to_urlencoded_list,
-    to_urlencoded_optional_list, UrlEncodedError,
+    from_urlencoded_list, from_urlencoded_nullable_list, from_urlencoded_optional_list,
+    to_urlencoded, to_urlencoded_list, to_urlencoded_optional_list, UrlEncodedError,
 };
 use crate::{
-    BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, HeadUuid, JsonBenchmark, JsonBranch,
-    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
+    BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, GitHash, HeadUuid, JsonBenchmark,
+    JsonBranch, JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
 };
 
 use super::alert::JsonPerfAlert;
 use super::boundary::JsonBoundary;
 use super::head::JsonVersion;
 use super::metric::JsonMetric;
+use super::plot::{XAxis, X_AXIS};
 use super::report::Iteration;
 use super::threshold::JsonThresholdModel;
 
@@ -45,6 +47,9 @@ pub struct JsonPerfQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// The x-axis to order metrics by.
+    /// If not provided, metrics will be ordered by version number.
+    pub x_axis: Option<XAxis>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -68,6 +73,46 @@ pub struct JsonPerfImgQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// The x-axis for the perf plot.
+    /// If not provided, the date and time of each report will be used.
+    pub x_axis: Option<XAxis>,
+    /// The image format for the perf plot.
+    /// If not provided, a JPEG image will be generated.
+    pub format: Option<PerfImgFormat>,
+    /// The width of the perf plot in pixels.
+    /// Must be between 512 and 2048.
+    /// If not provided, the width will be 1024 pixels.
+    pub width: Option<u32>,
+    /// The height of the perf plot in pixels.
+    /// Must be between 384 and 2048.
+    /// If not provided, the height will be 768 pixels.
+    pub height: Option<u32>,
+    /// The color theme for the perf plot.
+    /// If not provided, the light theme will be used.
+    pub theme: Option<PerfImgTheme>,
+    /// Display lower boundary limits as a shaded band.
+    pub lower_boundary: Option<bool>,
+    /// Display upper boundary limits as a shaded band.
+    pub upper_boundary: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgFormat {
+    #[default]
+    Jpeg,
+    Png,
+    Svg,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgTheme {
+    #[default]
+    Light,
+    Dark,
 }
 
 impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
@@ -81,6 +126,13 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             measures,
             start_time,
             end_time,
+            x_axis,
+            format: _,
+            width: _,
+            height: _,
+            theme: _,
+            lower_boundary: _,
+            upper_boundary: _,
         } = query;
         Self {
             branches,
@@ -90,6 +142,7 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             measures,
             start_time,
             end_time,
+            x_axis,
         }
     }
 }
@@ -106,6 +159,7 @@ pub struct JsonPerfQuery {
     pub measures: Vec<MeasureUuid>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub x_axis: Option<XAxis>,
 }
 
 impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
@@ -120,6 +174,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            x_axis,
         } = query_params;
 
         if branches.is_empty() {
@@ -152,6 +207,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            x_axis,
         })
     }
 }
@@ -210,7 +266,7 @@ impl JsonPerfQuery {
         serde_urlencoded::to_string(query).map_err(Into::into)
     }
 
-    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 7], UrlEncodedError> {
+    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 8], UrlEncodedError> {
         QUERY_KEYS
             .into_iter()
             .zip([
@@ -221,6 +277,7 @@ impl JsonPerfQuery {
                 Some(self.measures()),
                 self.start_time_str(),
                 self.end_time_str(),
+                self.x_axis_str(),
             ])
             .collect::<Vec<_>>()
             .try_into()
@@ -266,6 +323,16 @@ impl JsonPerfQuery {
     fn end_time_str(&self) -> Option<String> {
         self.end_time().as_ref().map(to_urlencoded)
     }
+
+    fn x_axis_str(&self) -> Option<String> {
+        self.x_axis.map(|x_axis| {
+            match x_axis {
+                XAxis::DateTime => "date_time",
+                XAxis::Version => "version",
+            }
+            .to_owned()
+        })
+    }
 }
 
 #[typeshare::typeshare]
@@ -280,6 +347,7 @@ pub enum PerfQueryKey {
     Measures,
     StartTime,
     EndTime,
+    XAxis,
 }
 
 pub const BRANCHES: &str = "branches";
@@ -289,8 +357,8 @@ pub const BENCHMARKS: &str = "benchmarks";
 pub const MEASURES: &str = "measures";
 pub const START_TIME: &str = "start_time";
 pub const END_TIME: &str = "end_time";
-const QUERY_KEYS: [&str; 7] = [
-    BRANCHES, HEADS, TESTBEDS, BENCHMARKS, MEASURES, START_TIME, END_TIME,
+const QUERY_KEYS: [&str; 8] = [
+    BRANCHES, HEADS, TESTBEDS, BENCHMARKS, MEASURES, START_TIME, END_TIME, X_AXIS,
 ];
 
 #[typeshare::typeshare]
@@ -330,6 +398,330 @@ pub struct JsonPerfMetric {
     pub alert: Option<JsonPerfAlert>,
 }
 
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfSummary {
+    pub project: JsonProject,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub results: Vec<JsonPerfSummaryMetrics>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfSummaryMetrics {
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    pub summary: JsonMetricsSummary,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricsSummary {
+    /// The number of metrics.
+    pub count: u32,
+    pub mean: OrderedFloat<f64>,
+    pub median: OrderedFloat<f64>,
+    /// The standard deviation, if there are at least two metrics.
+    pub std_dev: Option<OrderedFloat<f64>>,
+    /// The 5th percentile.
+    pub p5: OrderedFloat<f64>,
+    /// The 95th percentile.
+    pub p95: OrderedFloat<f64>,
+    /// The slope of the linear trend, in measure units per metric.
+    pub trend: Option<OrderedFloat<f64>>,
+    /// The percent change between the mean of the first and last quartile of metrics.
+    pub change: Option<OrderedFloat<f64>>,
+}
+
+/// `JsonPerfCompareQueryParams` is the actual query parameters accepted by the server.
+/// All query parameter values are therefore scalar values.
+/// Arrays are represented as comma separated lists.
+/// `JsonPerfCompareQueryParams` should always be converted into `JsonPerfCompareQuery` for full type level validation.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfCompareQueryParams {
+    /// The branch UUID for the baseline.
+    pub baseline_branch: BranchUuid,
+    /// The branch head UUID for the baseline.
+    /// If not provided, the current branch head will be used.
+    pub baseline_head: Option<HeadUuid>,
+    /// The testbed UUID for the baseline.
+    pub baseline_testbed: TestbedUuid,
+    /// The git hash for the baseline.
+    /// If not provided, the most recent version with metrics for the baseline branch head and testbed will be used.
+    pub baseline_hash: Option<GitHash>,
+    /// The branch UUID for the candidate.
+    pub candidate_branch: BranchUuid,
+    /// The branch head UUID for the candidate.
+    /// If not provided, the current branch head will be used.
+    pub candidate_head: Option<HeadUuid>,
+    /// The testbed UUID for the candidate.
+    pub candidate_testbed: TestbedUuid,
+    /// The git hash for the candidate.
+    /// If not provided, the most recent version with metrics for the candidate branch head and testbed will be used.
+    pub candidate_hash: Option<GitHash>,
+    /// A comma separated list of measure UUIDs to compare.
+    pub measures: String,
+}
+
+/// `JsonPerfCompareQuery` is the full, strongly typed version of `JsonPerfCompareQueryParams`.
+/// It should always be used to validate `JsonPerfCompareQueryParams`.
+#[derive(Debug, Clone)]
+pub struct JsonPerfCompareQuery {
+    pub baseline: JsonPerfCompareSelector,
+    pub candidate: JsonPerfCompareSelector,
+    pub measures: Vec<MeasureUuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonPerfCompareSelector {
+    pub branch: BranchUuid,
+    pub head: Option<HeadUuid>,
+    pub testbed: TestbedUuid,
+    pub hash: Option<GitHash>,
+}
+
+impl TryFrom<JsonPerfCompareQueryParams> for JsonPerfCompareQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonPerfCompareQueryParams) -> Result<Self, Self::Error> {
+        let JsonPerfCompareQueryParams {
+            baseline_branch,
+            baseline_head,
+            baseline_testbed,
+            baseline_hash,
+            candidate_branch,
+            candidate_head,
+            candidate_testbed,
+            candidate_hash,
+            measures,
+        } = query_params;
+
+        if measures.is_empty() {
+            return Err(UrlEncodedError::EmptyMeasures);
+        }
+        let measures = from_urlencoded_list(&measures)?;
+
+        Ok(Self {
+            baseline: JsonPerfCompareSelector {
+                branch: baseline_branch,
+                head: baseline_head,
+                testbed: baseline_testbed,
+                hash: baseline_hash,
+            },
+            candidate: JsonPerfCompareSelector {
+                branch: candidate_branch,
+                head: candidate_head,
+                testbed: candidate_testbed,
+                hash: candidate_hash,
+            },
+            measures,
+        })
+    }
+}
+
+impl JsonPerfCompareQuery {
+    pub fn measures(&self) -> String {
+        to_urlencoded_list(&self.measures)
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfCompare {
+    pub project: JsonProject,
+    /// The baseline branch, including the branch head and version that was compared.
+    pub baseline_branch: JsonBranch,
+    pub baseline_testbed: JsonTestbed,
+    /// The candidate branch, including the branch head and version that was compared.
+    pub candidate_branch: JsonBranch,
+    pub candidate_testbed: JsonTestbed,
+    pub results: Vec<JsonPerfCompareMetrics>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfCompareMetrics {
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    pub baseline: JsonMetricsSample,
+    pub candidate: JsonMetricsSample,
+    /// The candidate mean minus the baseline mean.
+    pub difference: OrderedFloat<f64>,
+    /// The percent change from the baseline mean to the candidate mean.
+    pub change: Option<OrderedFloat<f64>>,
+    /// The two-tailed p-value from the Mann-Whitney U test.
+    /// It is not calculated if all of the baseline and candidate metrics are equal.
+    pub p_value: Option<OrderedFloat<f64>>,
+    /// Whether the p-value is less than the 0.05 significance level.
+    pub significant: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMetricsSample {
+    /// The number of metrics.
+    pub count: u32,
+    pub mean: OrderedFloat<f64>,
+    /// The standard deviation, if there are at least two metrics.
+    pub std_dev: Option<OrderedFloat<f64>>,
+}
+
+/// `JsonPerfExportQueryParams` is the actual query parameters accepted by the server.
+/// All query parameter values are therefore scalar values.
+/// Arrays are represented as comma separated lists.
+/// Optional date times are simply stored as their millisecond representation.
+/// `JsonPerfExportQueryParams` should always be converted into `JsonPerfExportQuery` for full type level validation.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfExportQueryParams {
+    /// The file format for the export.
+    /// If not provided, a CSV file will be generated.
+    pub format: Option<PerfExportFormat>,
+    /// An optional comma separated list of branch UUIDs to export.
+    /// If not provided, metrics for all branches will be exported.
+    pub branches: Option<String>,
+    /// An optional comma separated list of branch head UUIDs.
+    /// To not specify a particular branch head leave an empty entry in the list.
+    pub heads: Option<String>,
+    /// An optional comma separated list of testbed UUIDs to export.
+    /// If not provided, metrics for all testbeds will be exported.
+    pub testbeds: Option<String>,
+    /// An optional comma separated list of benchmark UUIDs to export.
+    /// If not provided, metrics for all benchmarks will be exported.
+    pub benchmarks: Option<String>,
+    /// An optional comma separated list of measure UUIDs to export.
+    /// If not provided, metrics for all measures will be exported.
+    pub measures: Option<String>,
+    /// Export metrics after the given date time in milliseconds.
+    pub start_time: Option<DateTimeMillis>,
+    /// Export metrics before the given date time in milliseconds.
+    pub end_time: Option<DateTimeMillis>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfExportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+/// `JsonPerfExportQuery` is the full, strongly typed version of `JsonPerfExportQueryParams`.
+/// It should always be used to validate `JsonPerfExportQueryParams`.
+/// An empty list of UUIDs means that the dimension is not filtered.
+#[derive(Debug, Clone, Default)]
+pub struct JsonPerfExportQuery {
+    pub format: Option<PerfExportFormat>,
+    pub branches: Vec<BranchUuid>,
+    pub heads: Vec<Option<HeadUuid>>,
+    pub testbeds: Vec<TestbedUuid>,
+    pub benchmarks: Vec<BenchmarkUuid>,
+    pub measures: Vec<MeasureUuid>,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+}
+
+impl TryFrom<JsonPerfExportQueryParams> for JsonPerfExportQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonPerfExportQueryParams) -> Result<Self, Self::Error> {
+        let JsonPerfExportQueryParams {
+            format,
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+        } = query_params;
+
+        let branches = from_urlencoded_optional_list(branches.as_deref())?;
+        let heads = from_urlencoded_nullable_list(heads.as_deref())?;
+        let testbeds = from_urlencoded_optional_list(testbeds.as_deref())?;
+        let benchmarks = from_urlencoded_optional_list(benchmarks.as_deref())?;
+        let measures = from_urlencoded_optional_list(measures.as_deref())?;
+
+        // Guarantee that the `heads` array is the same length as the `branches` array.
+        let heads = size_heads_to_branches(&branches, &heads);
+
+        Ok(Self {
+            format,
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            measures,
+            start_time: start_time.map(Into::into),
+            end_time: end_time.map(Into::into),
+        })
+    }
+}
+
+impl JsonPerfExportQuery {
+    pub fn format(&self) -> PerfExportFormat {
+        self.format.unwrap_or_default()
+    }
+
+    pub fn branches(&self) -> Option<String> {
+        (!self.branches.is_empty()).then(|| to_urlencoded_list(&self.branches))
+    }
+
+    pub fn heads(&self) -> Option<String> {
+        self.heads
+            .iter()
+            .any(Option::is_some)
+            .then(|| to_urlencoded_optional_list(&self.heads))
+    }
+
+    pub fn testbeds(&self) -> Option<String> {
+        (!self.testbeds.is_empty()).then(|| to_urlencoded_list(&self.testbeds))
+    }
+
+    pub fn benchmarks(&self) -> Option<String> {
+        (!self.benchmarks.is_empty()).then(|| to_urlencoded_list(&self.benchmarks))
+    }
+
+    pub fn measures(&self) -> Option<String> {
+        (!self.measures.is_empty()).then(|| to_urlencoded_list(&self.measures))
+    }
+
+    pub fn start_time(&self) -> Option<DateTimeMillis> {
+        self.start_time.map(Into::into)
+    }
+
+    pub fn end_time(&self) -> Option<DateTimeMillis> {
+        self.end_time.map(Into::into)
+    }
+}
+
+impl PerfExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
 #[cfg(feature = "table")]
 pub mod table {
     use std::fmt;
@@ -340,8 +732,8 @@ pub mod table {
 
     use crate::{
         project::{head::VersionNumber, report::Iteration},
-        DateTime, JsonBenchmark, JsonBranch, JsonMeasure, JsonMetric, JsonPerf, JsonProject,
-        JsonTestbed,
+        DateTime, JsonBenchmark, JsonBranch, JsonMeasure, JsonMetric, JsonPerf, JsonPerfCompare,
+        JsonPerfSummary, JsonProject, JsonTestbed,
     };
 
     impl From<JsonPerf> for Table {
@@ -417,6 +809,110 @@ pub mod table {
         pub upper_limit: DisplayOption<OrderedFloat<f64>>,
     }
 
+    impl From<JsonPerfSummary> for Table {
+        fn from(json_perf_summary: JsonPerfSummary) -> Self {
+            let perf_summary_table = json_perf_summary
+                .results
+                .into_iter()
+                .map(|result| {
+                    let summary = result.summary;
+                    PerfSummaryTable {
+                        project: json_perf_summary.project.clone(),
+                        branch: result.branch,
+                        testbed: result.testbed,
+                        benchmark: result.benchmark,
+                        measure: result.measure,
+                        count: summary.count,
+                        mean: summary.mean,
+                        median: summary.median,
+                        std_dev: DisplayOption(summary.std_dev),
+                        p5: summary.p5,
+                        p95: summary.p95,
+                        trend: DisplayOption(summary.trend),
+                        change: DisplayOption(summary.change),
+                    }
+                })
+                .collect::<Vec<_>>();
+            Self::new(perf_summary_table)
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct PerfSummaryTable {
+        #[tabled(rename = "Project")]
+        pub project: JsonProject,
+        #[tabled(rename = "Branch")]
+        pub branch: JsonBranch,
+        #[tabled(rename = "Testbed")]
+        pub testbed: JsonTestbed,
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Measure")]
+        pub measure: JsonMeasure,
+        #[tabled(rename = "Count")]
+        pub count: u32,
+        #[tabled(rename = "Mean")]
+        pub mean: OrderedFloat<f64>,
+        #[tabled(rename = "Median")]
+        pub median: OrderedFloat<f64>,
+        #[tabled(rename = "Standard Deviation")]
+        pub std_dev: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "5th Percentile")]
+        pub p5: OrderedFloat<f64>,
+        #[tabled(rename = "95th Percentile")]
+        pub p95: OrderedFloat<f64>,
+        #[tabled(rename = "Trend")]
+        pub trend: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "Change (%)")]
+        pub change: DisplayOption<OrderedFloat<f64>>,
+    }
+
+    impl From<JsonPerfCompare> for Table {
+        fn from(json_perf_compare: JsonPerfCompare) -> Self {
+            let perf_compare_table = json_perf_compare
+                .results
+                .into_iter()
+                .map(|result| PerfCompareTable {
+                    benchmark: result.benchmark,
+                    measure: result.measure,
+                    baseline_count: result.baseline.count,
+                    baseline_mean: result.baseline.mean,
+                    candidate_count: result.candidate.count,
+                    candidate_mean: result.candidate.mean,
+                    difference: result.difference,
+                    change: DisplayOption(result.change),
+                    p_value: DisplayOption(result.p_value),
+                    significant: result.significant,
+                })
+                .collect::<Vec<_>>();
+            Self::new(perf_compare_table)
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct PerfCompareTable {
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Measure")]
+        pub measure: JsonMeasure,
+        #[tabled(rename = "Baseline Count")]
+        pub baseline_count: u32,
+        #[tabled(rename = "Baseline Mean")]
+        pub baseline_mean: OrderedFloat<f64>,
+        #[tabled(rename = "Candidate Count")]
+        pub candidate_count: u32,
+        #[tabled(rename = "Candidate Mean")]
+        pub candidate_mean: OrderedFloat<f64>,
+        #[tabled(rename = "Difference")]
+        pub difference: OrderedFloat<f64>,
+        #[tabled(rename = "Change (%)")]
+        pub change: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "p-value")]
+        pub p_value: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "Significant")]
+        pub significant: bool,
+    }
+
     #[derive(Default)]
     pub struct DisplayOption<T>(Option<T>);
 
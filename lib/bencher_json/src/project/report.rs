@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt};
 
-use bencher_valid::{DateTime, DateTimeMillis, GitHash, Model};
+use bencher_valid::{DateTime, DateTimeMillis, GitHash, Model, ResourceName};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     urlencoded::{from_urlencoded, to_urlencoded, UrlEncodedError},
     JsonAlert, JsonBenchmark, JsonBoundary, JsonBranch, JsonMeasure, JsonMetric, JsonProject,
-    JsonPubUser, JsonTestbed, NameId,
+    JsonPubUser, JsonTestbed, MeasureNameId, NameId,
 };
 
 use super::{branch::JsonUpdateStartPoint, threshold::JsonThresholdModel};
@@ -66,7 +66,7 @@ pub struct JsonReportThresholds {
     pub reset: Option<bool>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReportSettings {
     /// The benchmark harness adapter for parsing the benchmark results.
@@ -78,15 +78,50 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// The labels to include in the benchmark name for adapters with labeled metrics (ie `OpenMetrics`).
+    /// If no labels are specified, then all labels will be included.
+    pub labels: Option<Vec<String>>,
+    /// Rename, scale, or set the units for the measures output by the adapter.
+    pub measures: Option<Vec<JsonAdapterMeasure>>,
+    /// A prefix to add to the start of every benchmark name.
+    /// This can be useful for keeping the results from different harnesses in the same namespace.
+    pub benchmark_prefix: Option<String>,
+    /// A suffix to add to the end of every benchmark name.
+    pub benchmark_suffix: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterMeasure {
+    /// The measure UUID, slug, or name output by the adapter.
+    pub measure: MeasureNameId,
+    /// Rename the measure to this UUID, slug, or name.
+    /// If a measure name or slug is provided, the measure will be created if it does not exist.
+    pub rename: Option<MeasureNameId>,
+    /// Multiply the value, lower value, and upper value of every metric by this factor.
+    pub scale: Option<f64>,
+    /// The units to use if the measure needs to be created.
+    /// The units of an existing measure are never changed.
+    pub units: Option<ResourceName>,
+}
+
+impl JsonAdapterMeasure {
+    /// The measure after it has been renamed, if at all.
+    pub fn renamed(&self) -> &MeasureNameId {
+        self.rename.as_ref().unwrap_or(&self.measure)
+    }
 }
 
 const MAGIC_INT: i32 = 0;
 const JSON_INT: i32 = 10;
+const OPEN_METRICS_INT: i32 = 11;
+const JUNIT_INT: i32 = 12;
 const RUST_INT: i32 = 20;
 const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
 const RUST_IAI_INT: i32 = 23;
 const RUST_IAI_CALLGRIND_INT: i32 = 24;
+const RUST_DIVAN_INT: i32 = 25;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
@@ -102,10 +137,16 @@ const JS_TIME_INT: i32 = 72;
 const PYTHON_INT: i32 = 80;
 const PYTHON_ASV_INT: i32 = 81;
 const PYTHON_PYTEST_INT: i32 = 82;
+const PYTHON_PYPERF_INT: i32 = 83;
 const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
 const SHELL_INT: i32 = 100;
 const SHELL_HYPERFINE_INT: i32 = 101;
+const SHELL_K6_INT: i32 = 102;
+const SHELL_WRK_INT: i32 = 103;
+const SHELL_VEGETA_INT: i32 = 104;
+const JULIA_INT: i32 = 110;
+const JULIA_BENCHMARK_TOOLS_INT: i32 = 111;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -118,11 +159,14 @@ pub enum Adapter {
     #[default]
     Magic = MAGIC_INT,
     Json = JSON_INT,
+    OpenMetrics = OPEN_METRICS_INT,
+    Junit = JUNIT_INT,
     Rust = RUST_INT,
     RustBench = RUST_BENCH_INT,
     RustCriterion = RUST_CRITERION_INT,
     RustIai = RUST_IAI_INT,
     RustIaiCallgrind = RUST_IAI_CALLGRIND_INT,
+    RustDivan = RUST_DIVAN_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
@@ -138,10 +182,16 @@ pub enum Adapter {
     Python = PYTHON_INT,
     PythonAsv = PYTHON_ASV_INT,
     PythonPytest = PYTHON_PYTEST_INT,
+    PythonPyperf = PYTHON_PYPERF_INT,
     Ruby = RUBY_INT,
     RubyBenchmark = RUBY_BENCHMARK_INT,
     Shell = SHELL_INT,
     ShellHyperfine = SHELL_HYPERFINE_INT,
+    ShellK6 = SHELL_K6_INT,
+    ShellWrk = SHELL_WRK_INT,
+    ShellVegeta = SHELL_VEGETA_INT,
+    Julia = JULIA_INT,
+    JuliaBenchmarkTools = JULIA_BENCHMARK_TOOLS_INT,
 }
 
 impl fmt::Display for Adapter {
@@ -149,11 +199,14 @@ impl fmt::Display for Adapter {
         match self {
             Self::Magic => write!(f, "magic"),
             Self::Json => write!(f, "json"),
+            Self::OpenMetrics => write!(f, "open_metrics"),
+            Self::Junit => write!(f, "junit"),
             Self::Rust => write!(f, "rust"),
             Self::RustBench => write!(f, "rust_bench"),
             Self::RustCriterion => write!(f, "rust_criterion"),
             Self::RustIai => write!(f, "rust_iai"),
             Self::RustIaiCallgrind => write!(f, "rust_iai_callgrind"),
+            Self::RustDivan => write!(f, "rust_divan"),
             Self::Cpp => write!(f, "cpp"),
             Self::CppGoogle => write!(f, "cpp_google"),
             Self::CppCatch2 => write!(f, "cpp_catch2"),
@@ -169,10 +222,16 @@ impl fmt::Display for Adapter {
             Self::Python => write!(f, "python"),
             Self::PythonAsv => write!(f, "python_asv"),
             Self::PythonPytest => write!(f, "python_pytest"),
+            Self::PythonPyperf => write!(f, "python_pyperf"),
             Self::Ruby => write!(f, "ruby"),
             Self::RubyBenchmark => write!(f, "ruby_benchmark"),
             Self::Shell => write!(f, "shell"),
             Self::ShellHyperfine => write!(f, "shell_hyperfine"),
+            Self::ShellK6 => write!(f, "shell_k6"),
+            Self::ShellWrk => write!(f, "shell_wrk"),
+            Self::ShellVegeta => write!(f, "shell_vegeta"),
+            Self::Julia => write!(f, "julia"),
+            Self::JuliaBenchmarkTools => write!(f, "julia_benchmark_tools"),
         }
     }
 }
@@ -182,9 +241,11 @@ mod adapter {
     use super::{
         Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT, C_SHARP_DOT_NET_INT, C_SHARP_INT,
         GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT,
-        JS_TIME_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT, RUST_IAI_INT,
-        RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        JS_TIME_INT, JULIA_BENCHMARK_TOOLS_INT, JULIA_INT, JUNIT_INT, MAGIC_INT, OPEN_METRICS_INT,
+        PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYPERF_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
+        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_DIVAN_INT, RUST_IAI_CALLGRIND_INT,
+        RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT, SHELL_K6_INT, SHELL_VEGETA_INT,
+        SHELL_WRK_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -205,11 +266,14 @@ mod adapter {
             match self {
                 Self::Magic => MAGIC_INT.to_sql(out),
                 Self::Json => JSON_INT.to_sql(out),
+                Self::OpenMetrics => OPEN_METRICS_INT.to_sql(out),
+                Self::Junit => JUNIT_INT.to_sql(out),
                 Self::Rust => RUST_INT.to_sql(out),
                 Self::RustBench => RUST_BENCH_INT.to_sql(out),
                 Self::RustCriterion => RUST_CRITERION_INT.to_sql(out),
                 Self::RustIai => RUST_IAI_INT.to_sql(out),
                 Self::RustIaiCallgrind => RUST_IAI_CALLGRIND_INT.to_sql(out),
+                Self::RustDivan => RUST_DIVAN_INT.to_sql(out),
                 Self::Cpp => CPP_INT.to_sql(out),
                 Self::CppGoogle => CPP_GOOGLE_INT.to_sql(out),
                 Self::CppCatch2 => CPP_CATCH2_INT.to_sql(out),
@@ -225,10 +289,16 @@ mod adapter {
                 Self::Python => PYTHON_INT.to_sql(out),
                 Self::PythonAsv => PYTHON_ASV_INT.to_sql(out),
                 Self::PythonPytest => PYTHON_PYTEST_INT.to_sql(out),
+                Self::PythonPyperf => PYTHON_PYPERF_INT.to_sql(out),
                 Self::Ruby => RUBY_INT.to_sql(out),
                 Self::RubyBenchmark => RUBY_BENCHMARK_INT.to_sql(out),
                 Self::Shell => SHELL_INT.to_sql(out),
                 Self::ShellHyperfine => SHELL_HYPERFINE_INT.to_sql(out),
+                Self::ShellK6 => SHELL_K6_INT.to_sql(out),
+                Self::ShellWrk => SHELL_WRK_INT.to_sql(out),
+                Self::ShellVegeta => SHELL_VEGETA_INT.to_sql(out),
+                Self::Julia => JULIA_INT.to_sql(out),
+                Self::JuliaBenchmarkTools => JULIA_BENCHMARK_TOOLS_INT.to_sql(out),
             }
         }
     }
@@ -242,11 +312,14 @@ mod adapter {
             match i32::from_sql(bytes)? {
                 MAGIC_INT => Ok(Self::Magic),
                 JSON_INT => Ok(Self::Json),
+                OPEN_METRICS_INT => Ok(Self::OpenMetrics),
+                JUNIT_INT => Ok(Self::Junit),
                 RUST_INT => Ok(Self::Rust),
                 RUST_BENCH_INT => Ok(Self::RustBench),
                 RUST_CRITERION_INT => Ok(Self::RustCriterion),
                 RUST_IAI_INT => Ok(Self::RustIai),
                 RUST_IAI_CALLGRIND_INT => Ok(Self::RustIaiCallgrind),
+                RUST_DIVAN_INT => Ok(Self::RustDivan),
                 CPP_INT => Ok(Self::Cpp),
                 CPP_GOOGLE_INT => Ok(Self::CppGoogle),
                 CPP_CATCH2_INT => Ok(Self::CppCatch2),
@@ -262,10 +335,16 @@ mod adapter {
                 PYTHON_INT => Ok(Self::Python),
                 PYTHON_ASV_INT => Ok(Self::PythonAsv),
                 PYTHON_PYTEST_INT => Ok(Self::PythonPytest),
+                PYTHON_PYPERF_INT => Ok(Self::PythonPyperf),
                 RUBY_INT => Ok(Self::Ruby),
                 RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
                 SHELL_INT => Ok(Self::Shell),
                 SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
+                SHELL_K6_INT => Ok(Self::ShellK6),
+                SHELL_WRK_INT => Ok(Self::ShellWrk),
+                SHELL_VEGETA_INT => Ok(Self::ShellVegeta),
+                JULIA_INT => Ok(Self::Julia),
+                JULIA_BENCHMARK_TOOLS_INT => Ok(Self::JuliaBenchmarkTools),
                 value => Err(Box::new(AdapterError::Invalid(value))),
             }
         }
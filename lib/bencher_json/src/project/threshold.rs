@@ -152,6 +152,7 @@ pub struct JsonRemoveModel {
 }
 
 impl<'de> Deserialize<'de> for JsonUpdateThreshold {
+    #[allow(clippy::too_many_lines)]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -162,6 +163,9 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
         const WINDOW_FIELD: &str = "window";
         const LOWER_BOUNDARY_FIELD: &str = "lower_boundary";
         const UPPER_BOUNDARY_FIELD: &str = "upper_boundary";
+        const BASELINE_FIELD: &str = "baseline";
+        const MAX_VERSIONS_FIELD: &str = "max_versions";
+        const RESOLVE_VERSIONS_FIELD: &str = "resolve_versions";
 
         const FIELDS: &[&str] = &[
             TEST_FIELD,
@@ -170,6 +174,9 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             WINDOW_FIELD,
             LOWER_BOUNDARY_FIELD,
             UPPER_BOUNDARY_FIELD,
+            BASELINE_FIELD,
+            MAX_VERSIONS_FIELD,
+            RESOLVE_VERSIONS_FIELD,
         ];
 
         #[derive(Deserialize)]
@@ -181,6 +188,9 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             Window,
             LowerBoundary,
             UpperBoundary,
+            Baseline,
+            MaxVersions,
+            ResolveVersions,
         }
 
         struct UpdateThresholdVisitor;
@@ -202,6 +212,9 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                 let mut window = None;
                 let mut lower_boundary = None;
                 let mut upper_boundary = None;
+                let mut baseline = None;
+                let mut max_versions = None;
+                let mut resolve_versions = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -241,6 +254,24 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             }
                             upper_boundary = Some(map.next_value()?);
                         },
+                        Field::Baseline => {
+                            if baseline.is_some() {
+                                return Err(de::Error::duplicate_field(BASELINE_FIELD));
+                            }
+                            baseline = Some(map.next_value()?);
+                        },
+                        Field::MaxVersions => {
+                            if max_versions.is_some() {
+                                return Err(de::Error::duplicate_field(MAX_VERSIONS_FIELD));
+                            }
+                            max_versions = Some(map.next_value()?);
+                        },
+                        Field::ResolveVersions => {
+                            if resolve_versions.is_some() {
+                                return Err(de::Error::duplicate_field(RESOLVE_VERSIONS_FIELD));
+                            }
+                            resolve_versions = Some(map.next_value()?);
+                        },
                     }
                 }
 
@@ -253,6 +284,9 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             window,
                             lower_boundary,
                             upper_boundary,
+                            baseline,
+                            max_versions,
+                            resolve_versions,
                         },
                     })),
                     Some(None) => Ok(Self::Value::Remove(JsonRemoveModel { test: () })),
@@ -0,0 +1,175 @@
+use bencher_valid::{DateTime, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonAlert, JsonReport, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+crate::typed_uuid::typed_uuid!(WebhookDeliveryUuid);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL that the webhook payloads are sent to.
+    pub url: Url,
+    /// The events that the webhook is subscribed to.
+    /// If not provided, the webhook is subscribed to all events.
+    pub events: Option<Vec<WebhookEvent>>,
+    /// The secret used to sign the webhook payloads.
+    /// If not provided, a random secret will be generated.
+    pub secret: Option<Secret>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub events: Vec<WebhookEvent>,
+    pub secret: Secret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    /// The new URL that the webhook payloads are sent to.
+    pub url: Option<Url>,
+    /// The new events that the webhook is subscribed to.
+    pub events: Option<Vec<WebhookEvent>>,
+    /// The new secret used to sign the webhook payloads.
+    pub secret: Option<Secret>,
+}
+
+const REPORT_CREATED_INT: i32 = 0;
+const ALERT_CREATED_INT: i32 = 1;
+const ALERT_STATUS_CHANGED_INT: i32 = 2;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum WebhookEvent {
+    /// A report was created.
+    #[display("report_created")]
+    ReportCreated = REPORT_CREATED_INT,
+    /// An alert was created.
+    #[display("alert_created")]
+    AlertCreated = ALERT_CREATED_INT,
+    /// The status of an alert was changed.
+    #[display("alert_status_changed")]
+    AlertStatusChanged = ALERT_STATUS_CHANGED_INT,
+}
+
+impl WebhookEvent {
+    pub const ALL: [Self; 3] = [
+        Self::ReportCreated,
+        Self::AlertCreated,
+        Self::AlertStatusChanged,
+    ];
+}
+
+#[cfg(feature = "db")]
+mod webhook_event {
+    use super::{WebhookEvent, ALERT_CREATED_INT, ALERT_STATUS_CHANGED_INT, REPORT_CREATED_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum WebhookEventError {
+        #[error("Invalid webhook event value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::ReportCreated => REPORT_CREATED_INT.to_sql(out),
+                Self::AlertCreated => ALERT_CREATED_INT.to_sql(out),
+                Self::AlertStatusChanged => ALERT_STATUS_CHANGED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                REPORT_CREATED_INT => Ok(Self::ReportCreated),
+                ALERT_CREATED_INT => Ok(Self::AlertCreated),
+                ALERT_STATUS_CHANGED_INT => Ok(Self::AlertStatusChanged),
+                value => Err(Box::new(WebhookEventError::Invalid(value))),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDeliveries(pub Vec<JsonWebhookDelivery>);
+
+crate::from_vec!(JsonWebhookDeliveries[JsonWebhookDelivery]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook: WebhookUuid,
+    pub event: WebhookEvent,
+    /// The number of attempts made to deliver the payload.
+    pub attempts: u32,
+    /// The HTTP status code of the last attempt, if a response was received.
+    pub status_code: Option<u16>,
+    /// The error from the last attempt, if the delivery failed.
+    pub error: Option<String>,
+    pub created: DateTime,
+}
+
+/// The payload sent to a webhook URL.
+///
+/// The payload is signed with the webhook secret using HMAC-SHA256,
+/// and the hex encoded signature is sent in the `X-Bencher-Signature-256` header
+/// prefixed with `sha256=`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookPayload {
+    pub uuid: WebhookDeliveryUuid,
+    pub event: WebhookEvent,
+    pub project: ProjectUuid,
+    pub data: JsonWebhookData,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum JsonWebhookData {
+    /// The report for a `report_created` event.
+    Report(Box<JsonReport>),
+    /// The alert for an `alert_created` or `alert_status_changed` event.
+    Alert(Box<JsonAlert>),
+}
@@ -75,6 +75,16 @@ where
     Ok(values)
 }
 
+pub fn from_urlencoded_optional_list<T>(list: Option<&str>) -> Result<Vec<T>, UrlEncodedError>
+where
+    T: FromStr,
+{
+    match list {
+        Some(list) if !list.is_empty() => from_urlencoded_list(list),
+        _ => Ok(Vec::new()),
+    }
+}
+
 pub fn from_urlencoded<T>(input: &str) -> Result<T, UrlEncodedError>
 where
     T: FromStr,
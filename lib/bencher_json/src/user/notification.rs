@@ -0,0 +1,106 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::UserUuid;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNotifications {
+    pub user: UserUuid,
+    /// How often to receive an email digest of new active alerts
+    /// for all of the projects that the user is a member of.
+    pub alert_digest: AlertDigest,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateNotifications {
+    /// How often to receive an email digest of new active alerts.
+    /// Set to `never` to opt out of the alert digest.
+    pub alert_digest: Option<AlertDigest>,
+}
+
+const NEVER_INT: i32 = 0;
+const DAILY_INT: i32 = 1;
+const WEEKLY_INT: i32 = 2;
+
+const DAY_SECONDS: i64 = 24 * 60 * 60;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AlertDigest {
+    /// Never send an alert digest.
+    #[default]
+    #[display("never")]
+    Never = NEVER_INT,
+    /// Send an alert digest once a day.
+    #[display("daily")]
+    Daily = DAILY_INT,
+    /// Send an alert digest once a week.
+    #[display("weekly")]
+    Weekly = WEEKLY_INT,
+}
+
+impl AlertDigest {
+    /// The number of seconds between each digest, if any.
+    pub fn period_seconds(self) -> Option<i64> {
+        match self {
+            Self::Never => None,
+            Self::Daily => Some(DAY_SECONDS),
+            Self::Weekly => Some(7 * DAY_SECONDS),
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod alert_digest {
+    use super::{AlertDigest, DAILY_INT, NEVER_INT, WEEKLY_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum AlertDigestError {
+        #[error("Invalid alert digest value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AlertDigest
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Never => NEVER_INT.to_sql(out),
+                Self::Daily => DAILY_INT.to_sql(out),
+                Self::Weekly => WEEKLY_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AlertDigest
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                NEVER_INT => Ok(Self::Never),
+                DAILY_INT => Ok(Self::Daily),
+                WEEKLY_INT => Ok(Self::Weekly),
+                value => Err(Box::new(AlertDigestError::Invalid(value))),
+            }
+        }
+    }
+}
@@ -2,16 +2,28 @@ use plotters::{prelude::DrawingAreaErrorKind, style::FontError};
 use plotters_bitmap::BitMapBackendError;
 use thiserror::Error;
 
+use crate::line::{MAX_IMG_HEIGHT, MAX_IMG_WIDTH, MIN_IMG_HEIGHT, MIN_IMG_WIDTH};
+
 #[derive(Debug, Error)]
 pub enum PlotError {
     #[error("Failed to cast integer: {0}")]
     IntError(#[from] std::num::TryFromIntError),
     #[error("Failed to draw plot: {0}")]
     BitMap(#[from] DrawingAreaErrorKind<BitMapBackendError>),
+    #[error("Failed to draw SVG plot: {0}")]
+    Svg(#[from] DrawingAreaErrorKind<std::io::Error>),
     #[error("Failed to generate image buffer")]
     ImageBuffer,
     #[error("Failed to generate image: {0}")]
     Image(#[from] image::error::ImageError),
     #[error("Failed to generate font: {0}")]
     Font(FontError),
+    #[error(
+        "Invalid image width ({0}). Must be between {MIN_IMG_WIDTH} and {MAX_IMG_WIDTH} pixels."
+    )]
+    Width(u32),
+    #[error(
+        "Invalid image height ({0}). Must be between {MIN_IMG_HEIGHT} and {MAX_IMG_HEIGHT} pixels."
+    )]
+    Height(u32),
 }
@@ -1,25 +1,38 @@
 use std::sync::LazyLock;
-use std::{io::Cursor, ops::Range};
+use std::{collections::HashMap, fmt::Debug, io::Cursor, ops::Range};
 
-use bencher_json::{project::perf::JsonPerfMetrics, JsonPerf};
-use bencher_json::{JsonMeasure, Units};
+use bencher_json::project::{
+    perf::{JsonPerfMetric, JsonPerfMetrics, PerfImgFormat, PerfImgTheme},
+    plot::XAxis,
+};
+use bencher_json::{project::alert::AlertStatus, JsonMeasure, JsonPerf, Units};
 use chrono::{DateTime, Duration, Utc};
 use image::{GenericImageView, ImageBuffer};
 use ordered_float::OrderedFloat;
 use plotters::{
-    coord::{types::RangedCoordf64, Shift},
+    coord::{
+        ranged1d::{KeyPointHint, NoDefaultFormatting, ValueFormatter},
+        types::RangedCoordf64,
+        Shift,
+    },
+    element::{Drawable, PointCollection},
     prelude::{
-        BitMapBackend, BitMapElement, ChartBuilder, DrawingArea, IntoDrawingArea, MultiLineText,
-        Ranged, Rectangle,
+        BitMapBackend, BitMapElement, ChartBuilder, Circle, DrawingArea, DrawingAreaErrorKind,
+        DrawingBackend, IntoDrawingArea, MultiLineText, Polygon, Ranged, RangedDateTime, Rectangle,
+        SVGBackend,
     },
     series::LineSeries,
-    style::{Color, FontFamily, RGBColor, ShapeStyle, WHITE},
+    style::{Color, FontFamily, IntoFont, RGBColor, ShapeStyle, BLACK, WHITE},
 };
 
 use crate::PlotError;
 
 const IMG_WIDTH: u32 = 1024;
 const IMG_HEIGHT: u32 = 768;
+pub const MIN_IMG_WIDTH: u32 = 512;
+pub const MAX_IMG_WIDTH: u32 = 2048;
+pub const MIN_IMG_HEIGHT: u32 = 384;
+pub const MAX_IMG_HEIGHT: u32 = 2048;
 const TITLE_HEIGHT: u32 = 48;
 const PLOT_HEIGHT: u32 = 600;
 const KEY_HEIGHT: u32 = IMG_HEIGHT - PLOT_HEIGHT;
@@ -28,27 +41,34 @@ const MAX_TITLE_LEN: usize = 28;
 const X_LABELS: i64 = 5;
 const Y_LABELS: usize = 5;
 const DATE_TIME_FMT: &str = "%d %b %Y %H:%M:%S";
+const SHORT_HASH_LEN: usize = 7;
 
 // RGB is three units in size
 // https://docs.rs/image/latest/image/struct.Rgb.html
-const BUFFER_SIZE: usize = IMG_WIDTH as usize * IMG_HEIGHT as usize * 3;
+const RGB_SIZE: usize = 3;
 
 const MAX_LINES: usize = 10;
 
+const SVG_BITMAP_HREF: &str = "href=\"data:png;base64,";
+
 pub const BENCHER_WORDMARK: &[u8; 4406] = include_bytes!("../wordmark.png");
-#[allow(clippy::expect_used)]
-static WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> = LazyLock::new(|| {
-    let wordmark_cursor = Cursor::new(BENCHER_WORDMARK);
-    let wordmark_image =
-        image::load(wordmark_cursor, image::ImageFormat::Png).expect("Failed to load wordmark");
-    let size = wordmark_image.dimensions();
-    let buf = wordmark_image.to_rgb8().into_raw();
-    BitMapElement::with_owned_buffer((0, 5), size, buf).expect("Failed to create wordmark")
-});
+static WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> =
+    LazyLock::new(|| Palette::LIGHT.wordmark());
+static WORDMARK_DARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> =
+    LazyLock::new(|| Palette::DARK.wordmark());
+
+// Bulma danger red, which is used for alerts in the console
+const ALERT_COLOR: RGBColor = RGBColor(255, 56, 96);
+const BOUNDARY_OPACITY: f64 = 0.16;
 
 pub struct LinePlot {
     width: u32,
     height: u32,
+    format: PerfImgFormat,
+    theme: PerfImgTheme,
+    x_axis: XAxis,
+    lower_boundary: bool,
+    upper_boundary: bool,
 }
 
 impl Default for LinePlot {
@@ -56,6 +76,11 @@ impl Default for LinePlot {
         Self {
             width: IMG_WIDTH,
             height: IMG_HEIGHT,
+            format: PerfImgFormat::default(),
+            theme: PerfImgTheme::default(),
+            x_axis: XAxis::default(),
+            lower_boundary: false,
+            upper_boundary: false,
         }
     }
 }
@@ -65,56 +90,147 @@ impl LinePlot {
         Self::default()
     }
 
+    pub fn with_size(mut self, width: Option<u32>, height: Option<u32>) -> Result<Self, PlotError> {
+        if let Some(width) = width {
+            if !(MIN_IMG_WIDTH..=MAX_IMG_WIDTH).contains(&width) {
+                return Err(PlotError::Width(width));
+            }
+            self.width = width;
+        }
+        if let Some(height) = height {
+            if !(MIN_IMG_HEIGHT..=MAX_IMG_HEIGHT).contains(&height) {
+                return Err(PlotError::Height(height));
+            }
+            self.height = height;
+        }
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn with_format(mut self, format: PerfImgFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub fn with_theme(mut self, theme: PerfImgTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    #[must_use]
+    pub fn with_x_axis(mut self, x_axis: XAxis) -> Self {
+        self.x_axis = x_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn with_boundaries(mut self, lower_boundary: bool, upper_boundary: bool) -> Self {
+        self.lower_boundary = lower_boundary;
+        self.upper_boundary = upper_boundary;
+        self
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self.format {
+            PerfImgFormat::Jpeg => "image/jpeg",
+            PerfImgFormat::Png => "image/png",
+            PerfImgFormat::Svg => "image/svg+xml",
+        }
+    }
+
     pub fn draw(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
-        let mut plot_buffer = vec![0; BUFFER_SIZE];
-        self.draw_inner(title, json_perf, &mut plot_buffer)?;
+        match self.format {
+            PerfImgFormat::Jpeg => self.draw_bitmap(title, json_perf, image::ImageFormat::Jpeg),
+            PerfImgFormat::Png => self.draw_bitmap(title, json_perf, image::ImageFormat::Png),
+            PerfImgFormat::Svg => self.draw_svg(title, json_perf),
+        }
+    }
+
+    fn draw_bitmap(
+        &self,
+        title: Option<&str>,
+        json_perf: &JsonPerf,
+        image_format: image::ImageFormat,
+    ) -> Result<Vec<u8>, PlotError> {
+        let buffer_size = usize::try_from(self.width)? * usize::try_from(self.height)? * RGB_SIZE;
+        let mut plot_buffer = vec![0; buffer_size];
+        {
+            let root_area = BitMapBackend::with_buffer(&mut plot_buffer, (self.width, self.height))
+                .into_drawing_area();
+            self.draw_inner(title, json_perf, &root_area)?;
+        }
 
         let image_buffer: ImageBuffer<image::Rgb<u8>, Vec<u8>> =
             ImageBuffer::from_vec(self.width, self.height, plot_buffer)
                 .ok_or(PlotError::ImageBuffer)?;
-        let mut image_cursor = Cursor::new(Vec::with_capacity(BUFFER_SIZE));
-        image_buffer.write_to(&mut image_cursor, image::ImageFormat::Jpeg)?;
+        let mut image_cursor = Cursor::new(Vec::with_capacity(buffer_size));
+        image_buffer.write_to(&mut image_cursor, image_format)?;
 
         Ok(image_cursor.into_inner())
     }
 
-    fn draw_inner(
+    fn draw_svg(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
+        let mut svg = String::new();
+        {
+            let root_area =
+                SVGBackend::with_string(&mut svg, (self.width, self.height)).into_drawing_area();
+            self.draw_inner(title, json_perf, &root_area)?;
+        }
+        // The SVG backend embeds bitmaps without a valid media type
+        Ok(svg
+            .replace(SVG_BITMAP_HREF, "href=\"data:image/png;base64,")
+            .into_bytes())
+    }
+
+    fn draw_inner<DB: DrawingBackend>(
         &self,
         title: Option<&str>,
         json_perf: &JsonPerf,
-        plot_buffer: &mut [u8],
-    ) -> Result<(), PlotError> {
-        let root_area =
-            BitMapBackend::with_buffer(plot_buffer, (self.width, self.height)).into_drawing_area();
-
-        let (header_area, plot_area) = Self::split_header(&root_area)?;
-        Self::header(title, json_perf, &header_area)?;
-
-        // Marshal the perf data into a plot-able form
-        let perf_data = PerfData::new(json_perf);
-        let Some(perf_data) = perf_data else {
-            return Self::no_data_found(&root_area, &plot_area);
-        };
-
-        Self::plot(perf_data, &plot_area)?;
+        root_area: &Area<DB>,
+    ) -> Result<(), PlotError>
+    where
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let palette = Palette::from(self.theme);
+        let (header_area, plot_area) = self.split_header(root_area, palette)?;
+        Self::header(title, json_perf, &header_area, palette)?;
+
+        match self.x_axis {
+            XAxis::DateTime => self.plot::<DB, DateTime<Utc>>(json_perf, &plot_area, palette)?,
+            XAxis::Version => self.plot::<DB, Version>(json_perf, &plot_area, palette)?,
+        }
 
         root_area.present().map_err(Into::into)
     }
 
-    fn split_header<'b>(
-        root_area: &DrawingArea<BitMapBackend<'b>, Shift>,
-    ) -> Result<(Area<'b>, Area<'b>), PlotError> {
-        root_area.fill(&WHITE)?;
+    fn split_header<DB: DrawingBackend>(
+        &self,
+        root_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(Area<DB>, Area<DB>), PlotError>
+    where
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        root_area.fill(&palette.background)?;
         // Bencher Wordmark
-        root_area.draw(&*WORDMARK_ELEMENT)?;
+        let wordmark = match self.theme {
+            PerfImgTheme::Light => &*WORDMARK_ELEMENT,
+            PerfImgTheme::Dark => &*WORDMARK_DARK_ELEMENT,
+        };
+        root_area.draw(wordmark)?;
         Ok(root_area.split_vertically(TITLE_HEIGHT))
     }
 
-    fn header(
+    fn header<DB: DrawingBackend>(
         title: Option<&str>,
         json_perf: &JsonPerf,
-        header_area: &Area<'_>,
-    ) -> Result<(), PlotError> {
+        header_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(), PlotError>
+    where
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
         // Adaptive title sizing
         let title = title.unwrap_or(json_perf.project.name.as_ref());
         let title_len = title.len();
@@ -124,68 +240,149 @@ impl LinePlot {
         } else {
             TITLE_HEIGHT
         };
-        header_area.titled(title, (FontFamily::Monospace, size))?;
+        header_area.titled(
+            title,
+            (FontFamily::Monospace, size)
+                .into_font()
+                .color(&palette.foreground),
+        )?;
         Ok(())
     }
 
-    fn no_data_found(root_area: &Area<'_>, plot_area: &Area<'_>) -> Result<(), PlotError> {
+    fn no_data_found<DB: DrawingBackend, X: XValue>(
+        plot_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(), PlotError>
+    where
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
         // Return an informative message if there is no perf data found
         let _chart_context = ChartBuilder::on(plot_area)
             .margin_top(TITLE_HEIGHT)
             .caption(
                 format!("No Data Found: {}", Utc::now().format(DATE_TIME_FMT)),
-                (FontFamily::Monospace, 32),
+                (FontFamily::Monospace, 32, &palette.foreground),
             )
-            .build_cartesian_2d(PerfData::default_x_range(), PerfData::default_y_range())?;
+            .build_cartesian_2d(X::default_range(), PerfData::<X>::default_y_range())?;
 
-        root_area.present().map_err(Into::into)
+        Ok(())
     }
 
-    fn plot(
-        perf_data: PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'_>, Shift>,
-    ) -> Result<(), PlotError> {
-        let (plot_area, key_area) = plot_area.split_vertically(PLOT_HEIGHT);
+    fn plot<DB: DrawingBackend, X: XValue>(
+        &self,
+        json_perf: &JsonPerf,
+        plot_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(), PlotError>
+    where
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        // Marshal the perf data into a plot-able form
+        let perf_data = PerfData::<X>::new(json_perf, self.lower_boundary, self.upper_boundary);
+        let Some(perf_data) = perf_data else {
+            return Self::no_data_found::<DB, X>(plot_area, palette);
+        };
+
+        // The key area is always the same height, so the plot takes up any extra height
+        let (plot_area, key_area) = plot_area.split_vertically(self.height - KEY_HEIGHT);
         let mut chart = Chart::new(&perf_data, &plot_area)?;
-        chart.layout_plot(&perf_data)?;
-        chart.plot_lines(perf_data, &key_area)
+        chart.layout_plot(&perf_data, palette)?;
+        chart.plot_lines(perf_data, &key_area, self.width, palette)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Palette {
+    background: RGBColor,
+    foreground: RGBColor,
+}
+
+impl From<PerfImgTheme> for Palette {
+    fn from(theme: PerfImgTheme) -> Self {
+        match theme {
+            PerfImgTheme::Light => Self::LIGHT,
+            PerfImgTheme::Dark => Self::DARK,
+        }
+    }
+}
+
+impl Palette {
+    const LIGHT: Self = Self {
+        background: WHITE,
+        foreground: BLACK,
+    };
+    // GitHub dark mode colors, as these images are often embedded in READMEs and pull requests
+    const DARK: Self = Self {
+        background: RGBColor(13, 17, 23),
+        foreground: RGBColor(230, 237, 243),
+    };
+
+    // The wordmark is dark text on a white background,
+    // so recolor its grayscale pixels to match the palette.
+    // For the light palette, this leaves the wordmark unchanged.
+    #[allow(clippy::expect_used)]
+    fn wordmark(self) -> BitMapElement<'static, (i32, i32)> {
+        let wordmark_cursor = Cursor::new(BENCHER_WORDMARK);
+        let wordmark_image =
+            image::load(wordmark_cursor, image::ImageFormat::Png).expect("Failed to load wordmark");
+        let size = wordmark_image.dimensions();
+        let mut buf = wordmark_image.to_rgb8().into_raw();
+        for pixel in buf.chunks_exact_mut(RGB_SIZE) {
+            self.recolor(pixel);
+        }
+        BitMapElement::with_owned_buffer((0, 5), size, buf).expect("Failed to create wordmark")
+    }
+
+    #[allow(clippy::integer_division)]
+    fn recolor(self, pixel: &mut [u8]) {
+        const GRAYSCALE_TOLERANCE: u8 = 24;
+        let (Some(min), Some(max)) = (pixel.iter().min(), pixel.iter().max()) else {
+            return;
+        };
+        if max - min > GRAYSCALE_TOLERANCE {
+            return;
+        }
+        let RGBColor(bg_r, bg_g, bg_b) = self.background;
+        let RGBColor(fg_r, fg_g, fg_b) = self.foreground;
+        for (channel, (background, foreground)) in
+            pixel
+                .iter_mut()
+                .zip([(bg_r, fg_r), (bg_g, fg_g), (bg_b, fg_b)])
+        {
+            let (background, foreground) = (i32::from(background), i32::from(foreground));
+            // How far the channel is from white, i.e. the original background
+            let darkness = 255 - i32::from(*channel);
+            let value = background + (foreground - background) * darkness / 255;
+            *channel = u8::try_from(value.clamp(0, 255)).unwrap_or_default();
+        }
     }
 }
 
 // https://github.com/plotters-rs/plotters/blob/v0.3.7/plotters/examples/two-scales.rs
 #[allow(clippy::large_enum_variant, clippy::type_complexity)]
-enum Chart<'b> {
+enum Chart<'b, DB: DrawingBackend, X: XValue> {
     Single(
         plotters::chart::ChartContext<
             'b,
-            BitMapBackend<'b>,
-            plotters::prelude::Cartesian2d<
-                plotters::prelude::RangedDateTime<DateTime<Utc>>,
-                RangedCoordf64,
-            >,
+            DB,
+            plotters::prelude::Cartesian2d<X::Range, RangedCoordf64>,
         >,
     ),
     Dual(
         plotters::chart::DualCoordChartContext<
             'b,
-            BitMapBackend<'b>,
-            plotters::prelude::Cartesian2d<
-                plotters::prelude::RangedDateTime<DateTime<Utc>>,
-                RangedCoordf64,
-            >,
-            plotters::prelude::Cartesian2d<
-                plotters::prelude::RangedDateTime<DateTime<Utc>>,
-                RangedCoordf64,
-            >,
+            DB,
+            plotters::prelude::Cartesian2d<X::Range, RangedCoordf64>,
+            plotters::prelude::Cartesian2d<X::Range, RangedCoordf64>,
         >,
     ),
 }
 
-impl<'b> Chart<'b> {
-    fn new(
-        perf_data: &PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'b>, Shift>,
-    ) -> Result<Self, PlotError> {
+impl<'b, DB: DrawingBackend, X: XValue> Chart<'b, DB, X>
+where
+    PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+{
+    fn new(perf_data: &PerfData<X>, plot_area: &'b Area<DB>) -> Result<Self, PlotError> {
         let chart_context = ChartBuilder::on(plot_area)
             .x_label_area_size(40)
             .y_label_area_size(perf_data.left_y_label_area_size()?)
@@ -193,42 +390,52 @@ impl<'b> Chart<'b> {
             .margin_left(8)
             .margin_right(32)
             .margin_bottom(8)
-            .build_cartesian_2d(perf_data.x_range(), perf_data.left_y_range())?;
+            .build_cartesian_2d(X::range(perf_data), perf_data.left_y_range())?;
 
         Ok(if let Some(right_y_range) = perf_data.right_y_range() {
-            Self::Dual(chart_context.set_secondary_coord(perf_data.x_range(), right_y_range))
+            Self::Dual(chart_context.set_secondary_coord(X::range(perf_data), right_y_range))
         } else {
             Self::Single(chart_context)
         })
     }
 
     #[allow(clippy::items_after_statements)]
-    fn layout_plot(&mut self, perf_data: &PerfData) -> Result<(), PlotError> {
-        const AXIS_DESC_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 20);
-        const X_DESC: &str = "Benchmark Date and Time";
+    fn layout_plot(&mut self, perf_data: &PerfData<X>, palette: Palette) -> Result<(), PlotError> {
+        const AXIS_DESC_SIZE: u32 = 20;
         let x_labels = usize::try_from(X_LABELS)?;
-        const X_LABEL_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 16);
+        const X_LABEL_SIZE: u32 = 16;
         #[allow(clippy::type_complexity)]
-        let x_label_formatter: Box<dyn Fn(&DateTime<Utc>) -> String> =
-            Box::new(move |x| perf_data.x_label_fmt(x));
-        const Y_LABEL_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 12);
+        let x_label_formatter: Box<dyn Fn(&X) -> String> =
+            Box::new(move |x| X::label(perf_data, x));
+        const Y_LABEL_SIZE: u32 = 12;
         fn y_label_formatter(trim_decimal: bool) -> Box<dyn Fn(&f64) -> String> {
             Box::new(move |&y| Units::format_number(y, trim_decimal))
         }
         const MAX_LIGHT_LINES: usize = 4;
 
+        let foreground = palette.foreground;
+        let axis_desc_style = (FontFamily::Monospace, AXIS_DESC_SIZE, &foreground);
+        let x_label_style = (FontFamily::Monospace, X_LABEL_SIZE, &foreground);
+        let y_label_style = (FontFamily::Monospace, Y_LABEL_SIZE, &foreground);
+        // These match the default mesh colors, relative to the foreground color
+        let bold_line_style = foreground.mix(0.2);
+        let light_line_style = foreground.mix(0.1);
+
         match self {
             Chart::Single(chart_context) => {
                 chart_context
                     .configure_mesh()
-                    .axis_desc_style(AXIS_DESC_STYLE)
-                    .x_desc(X_DESC)
+                    .axis_style(foreground)
+                    .bold_line_style(bold_line_style)
+                    .light_line_style(light_line_style)
+                    .axis_desc_style(axis_desc_style)
+                    .x_desc(X::DESC)
                     .x_labels(x_labels)
-                    .x_label_style(X_LABEL_STYLE)
+                    .x_label_style(x_label_style)
                     .x_label_formatter(&x_label_formatter)
                     .y_desc(&perf_data.left_y_desc)
                     .y_labels(Y_LABELS)
-                    .y_label_style(Y_LABEL_STYLE)
+                    .y_label_style(y_label_style)
                     .y_label_formatter(&y_label_formatter(perf_data.trim_left_key_point_decimal()))
                     .max_light_lines(MAX_LIGHT_LINES)
                     .draw()?;
@@ -236,14 +443,17 @@ impl<'b> Chart<'b> {
             Chart::Dual(chart_context) => {
                 chart_context
                     .configure_mesh()
-                    .axis_desc_style(AXIS_DESC_STYLE)
-                    .x_desc(X_DESC)
+                    .axis_style(foreground)
+                    .bold_line_style(bold_line_style)
+                    .light_line_style(light_line_style)
+                    .axis_desc_style(axis_desc_style)
+                    .x_desc(X::DESC)
                     .x_labels(x_labels)
-                    .x_label_style(X_LABEL_STYLE)
+                    .x_label_style(x_label_style)
                     .x_label_formatter(&x_label_formatter)
                     .y_desc(&perf_data.left_y_desc)
                     .y_labels(Y_LABELS)
-                    .y_label_style(Y_LABEL_STYLE)
+                    .y_label_style(y_label_style)
                     .y_label_formatter(&y_label_formatter(perf_data.trim_left_key_point_decimal()))
                     .max_light_lines(MAX_LIGHT_LINES)
                     .draw()?;
@@ -254,10 +464,11 @@ impl<'b> Chart<'b> {
                 ) {
                     chart_context
                         .configure_secondary_axes()
-                        .axis_desc_style(AXIS_DESC_STYLE)
+                        .axis_style(foreground)
+                        .axis_desc_style(axis_desc_style)
                         .y_desc(right_y_desc)
                         .y_labels(Y_LABELS)
-                        .label_style(Y_LABEL_STYLE)
+                        .label_style(y_label_style)
                         .y_label_formatter(&y_label_formatter(right_trim_decimal))
                         .draw()?;
                 }
@@ -268,10 +479,12 @@ impl<'b> Chart<'b> {
 
     fn plot_lines(
         &mut self,
-        perf_data: PerfData,
-        key_area: &DrawingArea<BitMapBackend<'_>, Shift>,
+        perf_data: PerfData<X>,
+        key_area: &Area<DB>,
+        width: u32,
+        palette: Palette,
     ) -> Result<(), PlotError> {
-        let plot_box = perf_data.plot_box()?;
+        let plot_box = perf_data.plot_box(width)?;
         let mut box_x_left = plot_box.x_left;
         for LineData {
             data,
@@ -280,7 +493,7 @@ impl<'b> Chart<'b> {
             dimensions,
         } in perf_data.lines
         {
-            self.plot_line(data, anchor, color)?;
+            self.plot_line(&data, anchor, color)?;
 
             // Draw key for plot line
             let box_x_right = box_x_left + plot_box.width;
@@ -295,7 +508,9 @@ impl<'b> Chart<'b> {
                 let text = MultiLineText::from_str(
                     dimensions.as_str(),
                     (box_x_left, plot_box.text_start),
-                    (FontFamily::Monospace, font),
+                    (FontFamily::Monospace, font)
+                        .into_font()
+                        .color(&palette.foreground),
                     plot_box.text_width,
                 );
                 let (_, text_height) = text.estimate_dimension().map_err(PlotError::Font)?;
@@ -314,24 +529,49 @@ impl<'b> Chart<'b> {
 
     fn plot_line(
         &mut self,
-        data: Vec<(DateTime<Utc>, OrderedFloat<f64>)>,
+        data: &[LinePoint<X>],
         anchor: Anchor,
         color: RGBColor,
     ) -> Result<(), PlotError> {
-        let line_series =
-            LineSeries::new(data.into_iter().map(|(x, y)| (x, y.into())), color.filled())
-                .point_size(2);
+        // Draw the boundary bands first, so they are underneath the line
+        let bands = LinePoint::boundary_bands(data)
+            .into_iter()
+            .map(|band| Polygon::new(band, color.mix(BOUNDARY_OPACITY).filled()));
+        self.draw_series(anchor, bands)?;
 
+        let line_series = LineSeries::new(
+            data.iter().map(|point| (point.x, point.y.into())),
+            color.filled(),
+        )
+        .point_size(2);
+        self.draw_series(anchor, line_series)?;
+
+        let alerts = data.iter().filter(|point| point.alert).map(|point| {
+            Circle::new(
+                (point.x, point.y.into()),
+                5,
+                ShapeStyle::from(ALERT_COLOR).stroke_width(2),
+            )
+        });
+        self.draw_series(anchor, alerts)
+    }
+
+    fn draw_series<E, S>(&mut self, anchor: Anchor, series: S) -> Result<(), PlotError>
+    where
+        S: IntoIterator<Item = E>,
+        E: Drawable<DB>,
+        for<'e> &'e E: PointCollection<'e, (X, f64)>,
+    {
         match self {
             Chart::Single(chart_context) => {
-                let _series = chart_context.draw_series(line_series)?;
+                let _series = chart_context.draw_series(series)?;
             },
             Chart::Dual(chart_context) => match anchor {
                 Anchor::Left => {
-                    let _series = chart_context.draw_series(line_series)?;
+                    let _series = chart_context.draw_series(series)?;
                 },
                 Anchor::Right => {
-                    let _series = chart_context.draw_secondary_series(line_series)?;
+                    let _series = chart_context.draw_secondary_series(series)?;
                 },
             },
         }
@@ -340,25 +580,171 @@ impl<'b> Chart<'b> {
     }
 }
 
-type Area<'b> = DrawingArea<BitMapBackend<'b>, Shift>;
+type Area<DB> = DrawingArea<DB, Shift>;
+
+// The value type for the x-axis of the plot
+trait XValue: Debug + Copy + Ord + 'static {
+    type Range: Ranged<ValueType = Self> + ValueFormatter<Self>;
+    const DESC: &'static str;
+
+    fn from_metric(metric: &JsonPerfMetric) -> Self;
+
+    fn range(perf_data: &PerfData<Self>) -> Self::Range;
+
+    fn default_range() -> Self::Range;
+
+    fn label(perf_data: &PerfData<Self>, x: &Self) -> String;
+}
+
+impl XValue for DateTime<Utc> {
+    type Range = RangedDateTime<DateTime<Utc>>;
+    const DESC: &'static str = "Benchmark Date and Time";
+
+    fn from_metric(metric: &JsonPerfMetric) -> Self {
+        metric.start_time.into_inner()
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn range(perf_data: &PerfData<Self>) -> Self::Range {
+        let (min_x, max_x) = perf_data.x;
+        let diff = Duration::seconds(((max_x - min_x).num_seconds() as f64 * 0.04) as i64);
+        (min_x..(max_x + diff)).into()
+    }
+
+    fn default_range() -> Self::Range {
+        let epoch = DateTime::default();
+        (epoch..epoch).into()
+    }
+
+    fn label(perf_data: &PerfData<Self>, x: &Self) -> String {
+        let (min_x, max_x) = perf_data.x;
+        let fmt = if max_x - min_x < Duration::days(X_LABELS) {
+            DATE_TIME_FMT
+        } else {
+            "%d %b %Y"
+        };
+        format!("{}", x.format(fmt))
+    }
+}
+
+// The version number, which orders metrics by commit instead of by report time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u32);
+
+impl XValue for Version {
+    type Range = VersionRange;
+    const DESC: &'static str = "Benchmark Version";
+
+    fn from_metric(metric: &JsonPerfMetric) -> Self {
+        Self(metric.version.number.0)
+    }
 
-struct PerfData {
-    lines: Vec<LineData>,
-    x: (DateTime<Utc>, DateTime<Utc>),
-    x_time: bool,
+    fn range(perf_data: &PerfData<Self>) -> Self::Range {
+        let mut versions = perf_data
+            .lines
+            .iter()
+            .flat_map(|line| line.data.iter().map(|point| point.x))
+            .collect::<Vec<_>>();
+        versions.sort_unstable();
+        versions.dedup();
+        let (Version(min_x), Version(max_x)) = perf_data.x;
+        let diff = std::cmp::max((max_x - min_x).div_ceil(25), 1);
+        VersionRange {
+            versions,
+            range: Version(min_x)..Version(max_x.saturating_add(diff)),
+        }
+    }
+
+    fn default_range() -> Self::Range {
+        VersionRange {
+            versions: Vec::new(),
+            range: Version(0)..Version(0),
+        }
+    }
+
+    fn label(perf_data: &PerfData<Self>, x: &Self) -> String {
+        let Version(number) = x;
+        perf_data
+            .hashes
+            .get(number)
+            .cloned()
+            .unwrap_or_else(|| format!("v{number}"))
+    }
+}
+
+// A version range only has key points at the versions that are actually present,
+// so that each x-axis label can be the git hash for that version.
+struct VersionRange {
+    versions: Vec<Version>,
+    range: Range<Version>,
+}
+
+impl Ranged for VersionRange {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Version;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn map(&self, value: &Version, limit: (i32, i32)) -> i32 {
+        let (Version(start), Version(end)) = (self.range.start, self.range.end);
+        let pixels = f64::from(limit.1 - limit.0);
+        if start == end {
+            return limit.0 + (pixels * 0.5) as i32;
+        }
+        let position =
+            (f64::from(value.0) - f64::from(start)) / (f64::from(end) - f64::from(start));
+        limit.0 + (pixels * position) as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Version> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return Vec::new();
+        }
+        let step = self.versions.len().div_ceil(max_points);
+        self.versions
+            .iter()
+            .step_by(std::cmp::max(step, 1))
+            .copied()
+            .collect()
+    }
+
+    fn range(&self) -> Range<Version> {
+        self.range.clone()
+    }
+}
+
+impl ValueFormatter<Version> for VersionRange {
+    fn format(value: &Version) -> String {
+        format!("v{}", value.0)
+    }
+}
+
+struct PerfData<X> {
+    lines: Vec<LineData<X>>,
+    x: (X, X),
+    hashes: HashMap<u32, String>,
     left_y: (OrderedFloat<f64>, OrderedFloat<f64>),
     left_y_desc: String,
     right_y: Option<(OrderedFloat<f64>, OrderedFloat<f64>)>,
     right_y_desc: Option<String>,
 }
 
-struct LineData {
-    data: Vec<(DateTime<Utc>, OrderedFloat<f64>)>,
+struct LineData<X> {
+    data: Vec<LinePoint<X>>,
     anchor: Anchor,
     color: RGBColor,
     dimensions: String,
 }
 
+#[derive(Clone, Copy)]
+struct LinePoint<X> {
+    x: X,
+    y: OrderedFloat<f64>,
+    lower_limit: Option<OrderedFloat<f64>>,
+    upper_limit: Option<OrderedFloat<f64>>,
+    alert: bool,
+}
+
 #[derive(Clone, Copy, Default)]
 enum Anchor {
     #[default]
@@ -366,8 +752,8 @@ enum Anchor {
     Right,
 }
 
-impl PerfData {
-    fn new(json_perf: &JsonPerf) -> Option<PerfData> {
+impl<X: XValue> PerfData<X> {
+    fn new(json_perf: &JsonPerf, lower_boundary: bool, upper_boundary: bool) -> Option<Self> {
         let Extent {
             left_measure,
             right_measure,
@@ -378,7 +764,7 @@ impl PerfData {
             right_min_y,
             right_max_y,
             lines,
-        } = Extent::new(json_perf)?;
+        } = Extent::new(json_perf, lower_boundary, upper_boundary)?;
 
         let (Some(min_x), Some(max_x), Some(left_min_y), Some(left_max_y)) =
             (min_x, max_x, left_min_y, left_max_y)
@@ -387,7 +773,7 @@ impl PerfData {
         };
 
         let x = (min_x, max_x);
-        let x_time = max_x - min_x < Duration::days(X_LABELS);
+        let hashes = Self::hashes(json_perf);
 
         let (left_factor, left_y_desc) = Self::measure_units(&left_measure, left_min_y);
         let left_min_y = left_min_y / left_factor;
@@ -413,15 +799,10 @@ impl PerfData {
                 data: line
                     .data
                     .into_iter()
-                    .map(|(x, y)| {
-                        (
-                            x,
-                            match line.anchor {
-                                Anchor::Left if left_factor.is_normal() => y / left_factor,
-                                Anchor::Right if right_factor.is_normal() => y / right_factor,
-                                Anchor::Left | Anchor::Right => y,
-                            },
-                        )
+                    .map(|point| match line.anchor {
+                        Anchor::Left if left_factor.is_normal() => point.scale(left_factor),
+                        Anchor::Right if right_factor.is_normal() => point.scale(right_factor),
+                        Anchor::Left | Anchor::Right => point,
                     })
                     .collect(),
                 ..line
@@ -431,7 +812,7 @@ impl PerfData {
         Some(PerfData {
             lines,
             x,
-            x_time,
+            hashes,
             left_y,
             left_y_desc,
             right_y,
@@ -449,24 +830,18 @@ impl PerfData {
         (factor, y_desc)
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
-    fn x_range(&self) -> Range<DateTime<Utc>> {
-        let diff = Duration::seconds(((self.x.1 - self.x.0).num_seconds() as f64 * 0.04) as i64);
-        self.x.0..(self.x.1 + diff)
-    }
-
-    fn default_x_range() -> Range<DateTime<Utc>> {
-        let epoch = DateTime::default();
-        epoch..epoch
-    }
-
-    fn x_label_fmt(&self, x: &DateTime<Utc>) -> String {
-        let fmt = if self.x_time {
-            DATE_TIME_FMT
-        } else {
-            "%d %b %Y"
-        };
-        format!("{}", x.format(fmt))
+    // Short git hashes by version number, used to label the version x-axis
+    fn hashes(json_perf: &JsonPerf) -> HashMap<u32, String> {
+        json_perf
+            .results
+            .iter()
+            .flat_map(|result| &result.metrics)
+            .filter_map(|metric| {
+                let hash = metric.version.hash.as_ref()?;
+                let short_hash = hash.as_ref().chars().take(SHORT_HASH_LEN).collect();
+                Some((metric.version.number.0, short_hash))
+            })
+            .collect()
     }
 
     fn left_y_range(&self) -> Range<f64> {
@@ -536,7 +911,7 @@ impl PerfData {
         u32::try_from(y_len).map_err(Into::into)
     }
 
-    fn plot_box(&self) -> Result<PlotBox, PlotError> {
+    fn plot_box(&self, img_width: u32) -> Result<PlotBox, PlotError> {
         const KEY_LEFT_MARGIN: usize = 48;
         const BOX_GAP: usize = 12;
         const BOX_HEIGHT: i32 = 24;
@@ -550,10 +925,20 @@ impl PerfData {
             let box_gap = std::cmp::max(MIN_GAP, BOX_GAP - extra_lines);
             let box_gaps = lines_len * box_gap;
             #[allow(clippy::integer_division)]
-            let width = (usize::try_from(IMG_WIDTH)? - box_x_left - box_gaps) / lines_len;
+            let width = (usize::try_from(img_width)? - box_x_left - box_gaps) / lines_len;
             (box_x_left, width, box_gap)
         } else {
-            (KEY_LEFT_MARGIN, 256, BOX_GAP)
+            // Narrower images may not have room for full width boxes
+            const MAX_BOX_WIDTH: usize = 256;
+            let box_gaps = lines_len * BOX_GAP;
+            #[allow(clippy::integer_division)]
+            let width = (usize::try_from(img_width)? - KEY_LEFT_MARGIN - box_gaps)
+                / std::cmp::max(lines_len, 1);
+            (
+                KEY_LEFT_MARGIN,
+                std::cmp::min(MAX_BOX_WIDTH, width),
+                BOX_GAP,
+            )
         };
 
         let text_end = i32::try_from(KEY_HEIGHT)? - TEXT_START - 48;
@@ -571,20 +956,20 @@ impl PerfData {
     }
 }
 
-struct Extent {
+struct Extent<X> {
     left_measure: JsonMeasure,
     right_measure: Option<JsonMeasure>,
-    min_x: Option<DateTime<Utc>>,
-    max_x: Option<DateTime<Utc>>,
+    min_x: Option<X>,
+    max_x: Option<X>,
     left_min_y: Option<OrderedFloat<f64>>,
     left_max_y: Option<OrderedFloat<f64>>,
     right_min_y: Option<OrderedFloat<f64>>,
     right_max_y: Option<OrderedFloat<f64>>,
-    lines: Vec<LineData>,
+    lines: Vec<LineData<X>>,
 }
 
-impl Extent {
-    fn new(json_perf: &JsonPerf) -> Option<Self> {
+impl<X: XValue> Extent<X> {
+    fn new(json_perf: &JsonPerf, lower_boundary: bool, upper_boundary: bool) -> Option<Self> {
         let (left_measure, right_measure) = Self::measures(json_perf)?;
 
         let find_anchor = |measure: &JsonMeasure| -> Option<Anchor> {
@@ -613,41 +998,46 @@ impl Extent {
             .enumerate()
             .map(|(index, result)| {
                 let anchor = find_anchor(&result.measure).unwrap_or_default();
-                let data = result
+                let mut data: Vec<LinePoint<X>> = result
                     .metrics
                     .iter()
                     .map(|metric| {
-                        let x_value = metric.start_time.into_inner();
+                        let point = LinePoint::new(metric, lower_boundary, upper_boundary);
+                        let x_value = point.x;
                         min_x = min_x
                             .map(|min| std::cmp::min(min, x_value))
                             .or(Some(x_value));
                         max_x = max_x
                             .map(|max| std::cmp::max(max, x_value))
                             .or(Some(x_value));
-                        let y_value = metric.metric.value;
-                        match anchor {
-                            Anchor::Left => {
-                                left_min_y = left_min_y
-                                    .map(|min| std::cmp::min(min, y_value))
-                                    .or(Some(y_value));
-                                left_max_y = left_max_y
-                                    .map(|max| std::cmp::max(max, y_value))
-                                    .or(Some(y_value));
-                            },
-                            Anchor::Right => {
-                                right_min_y = right_min_y
-                                    .map(|min| std::cmp::min(min, y_value))
-                                    .or(Some(y_value));
-                                right_max_y = right_max_y
-                                    .map(|max| std::cmp::max(max, y_value))
-                                    .or(Some(y_value));
-                            },
+                        // Any displayed boundary limits must also fit within the plot
+                        for y_value in point.y_values() {
+                            match anchor {
+                                Anchor::Left => {
+                                    left_min_y = left_min_y
+                                        .map(|min| std::cmp::min(min, y_value))
+                                        .or(Some(y_value));
+                                    left_max_y = left_max_y
+                                        .map(|max| std::cmp::max(max, y_value))
+                                        .or(Some(y_value));
+                                },
+                                Anchor::Right => {
+                                    right_min_y = right_min_y
+                                        .map(|min| std::cmp::min(min, y_value))
+                                        .or(Some(y_value));
+                                    right_max_y = right_max_y
+                                        .map(|max| std::cmp::max(max, y_value))
+                                        .or(Some(y_value));
+                                },
+                            }
                         }
-                        (x_value, y_value)
+                        point
                     })
                     .collect();
-                let color = LineData::color(index);
-                let dimensions = LineData::dimensions(result);
+                // Plot the points in x-axis order, keeping the query order for any ties
+                data.sort_by_key(|point| point.x);
+                let color = LineData::<X>::color(index);
+                let dimensions = LineData::<X>::dimensions(result);
                 LineData {
                     data,
                     anchor,
@@ -655,7 +1045,7 @@ impl Extent {
                     dimensions,
                 }
             })
-            .collect::<Vec<LineData>>();
+            .collect::<Vec<LineData<X>>>();
 
         Some(Self {
             left_measure,
@@ -734,7 +1124,60 @@ static TABLEAU_10_RGB: LazyLock<[RGBColor; 10]> = LazyLock::new(|| {
         .expect("Failed to map Tableau 10 RGB values")
 });
 
-impl LineData {
+impl<X: XValue> LinePoint<X> {
+    fn new(metric: &JsonPerfMetric, lower_boundary: bool, upper_boundary: bool) -> Self {
+        let boundary = metric.boundary.as_ref();
+        Self {
+            x: X::from_metric(metric),
+            y: metric.metric.value,
+            lower_limit: boundary
+                .and_then(|boundary| boundary.lower_limit)
+                .filter(|_| lower_boundary),
+            upper_limit: boundary
+                .and_then(|boundary| boundary.upper_limit)
+                .filter(|_| upper_boundary),
+            alert: metric
+                .alert
+                .as_ref()
+                .is_some_and(|alert| alert.status == AlertStatus::Active),
+        }
+    }
+
+    fn scale(self, factor: OrderedFloat<f64>) -> Self {
+        Self {
+            y: self.y / factor,
+            lower_limit: self.lower_limit.map(|limit| limit / factor),
+            upper_limit: self.upper_limit.map(|limit| limit / factor),
+            ..self
+        }
+    }
+
+    fn y_values(&self) -> impl Iterator<Item = OrderedFloat<f64>> {
+        [Some(self.y), self.lower_limit, self.upper_limit]
+            .into_iter()
+            .flatten()
+    }
+
+    // Each run of consecutive points with a displayed boundary limit is a separate band.
+    // If only one boundary limit is displayed, then the metric value is used for the other side.
+    fn boundary_bands(data: &[Self]) -> Vec<Vec<(X, f64)>> {
+        data.split(|point| point.lower_limit.is_none() && point.upper_limit.is_none())
+            .filter(|band| !band.is_empty())
+            .map(|band| {
+                let upper = band
+                    .iter()
+                    .map(|point| (point.x, point.upper_limit.unwrap_or(point.y).into()));
+                let lower = band
+                    .iter()
+                    .rev()
+                    .map(|point| (point.x, point.lower_limit.unwrap_or(point.y).into()));
+                upper.chain(lower).collect()
+            })
+            .collect()
+    }
+}
+
+impl<X> LineData<X> {
     #[allow(clippy::indexing_slicing)]
     fn color(index: usize) -> RGBColor {
         TABLEAU_10_RGB[index % 10]
@@ -753,9 +1196,15 @@ impl LineData {
 mod test {
     use std::{fs::File, io::Write, sync::LazyLock};
 
-    use bencher_json::JsonPerf;
+    use bencher_json::{
+        project::{
+            perf::{PerfImgFormat, PerfImgTheme},
+            plot::XAxis,
+        },
+        JsonPerf,
+    };
 
-    use crate::LinePlot;
+    use crate::{LinePlot, PlotError};
 
     pub const PERF_DOT_JSON: &str = include_str!("../perf.json");
     static JSON_PERF: LazyLock<JsonPerf> = LazyLock::new(|| {
@@ -768,8 +1217,37 @@ mod test {
     });
 
     fn save_jpeg(jpeg: &[u8], name: &str) {
-        let mut file = File::create(format!("{name}.jpeg")).unwrap();
-        file.write_all(jpeg).unwrap();
+        save_img(jpeg, name, "jpeg");
+    }
+
+    fn save_img(img: &[u8], name: &str, extension: &str) {
+        let mut file = File::create(format!("{name}.{extension}")).unwrap();
+        file.write_all(img).unwrap();
+    }
+
+    // Add boundary limits to the first line,
+    // and mark its last metric as an active upper boundary alert
+    fn json_perf_boundary() -> JsonPerf {
+        let mut json_perf = JSON_PERF.clone();
+        let result = json_perf.results.first_mut().unwrap();
+        for metric in &mut result.metrics {
+            let boundary = metric.boundary.as_mut().unwrap();
+            boundary.lower_limit = Some(metric.metric.value * 0.8);
+            boundary.upper_limit = Some(metric.metric.value * 1.2);
+        }
+        let metric = result.metrics.last_mut().unwrap();
+        metric.alert = Some(
+            serde_json::from_str(
+                r#"{
+                    "uuid": "26b2e0a5-e9a9-4a4b-9a3b-2f5a0a4a8c7e",
+                    "limit": "upper",
+                    "status": "active",
+                    "modified": "2023-07-02T12:53:33Z"
+                }"#,
+            )
+            .unwrap(),
+        );
+        json_perf
     }
 
     #[test]
@@ -798,4 +1276,69 @@ mod test {
         let plot_buffer = plot.draw(None, &json_perf).unwrap();
         save_jpeg(&plot_buffer, "empty");
     }
+
+    #[test]
+    fn test_plot_png() {
+        let plot = LinePlot::new()
+            .with_format(PerfImgFormat::Png)
+            .with_boundaries(true, true);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &json_perf_boundary())
+            .unwrap();
+        assert!(plot_buffer.starts_with(b"\x89PNG"));
+        save_img(&plot_buffer, "boundary", "png");
+    }
+
+    #[test]
+    fn test_plot_svg() {
+        let plot = LinePlot::new()
+            .with_size(Some(1280), Some(640))
+            .unwrap()
+            .with_format(PerfImgFormat::Svg)
+            .with_theme(PerfImgTheme::Dark)
+            .with_boundaries(false, true);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &json_perf_boundary())
+            .unwrap();
+        let svg = String::from_utf8(plot_buffer).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r#"width="1280" height="640""#));
+        assert!(svg.contains(r#"href="data:image/png;base64,"#));
+        save_img(svg.as_bytes(), "boundary", "svg");
+    }
+
+    #[test]
+    fn test_plot_version() {
+        let mut json_perf = JSON_PERF.clone();
+        for metric in json_perf
+            .results
+            .iter_mut()
+            .flat_map(|result| &mut result.metrics)
+        {
+            let hash = format!("{:07}{}", metric.version.number.0, "a".repeat(33));
+            metric.version.hash = Some(hash.parse().unwrap());
+        }
+        let plot = LinePlot::new()
+            .with_format(PerfImgFormat::Svg)
+            .with_x_axis(XAxis::Version);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &json_perf)
+            .unwrap();
+        let svg = String::from_utf8(plot_buffer).unwrap();
+        assert!(svg.contains("Benchmark Version"));
+        assert!(svg.contains("0000002"));
+        save_img(svg.as_bytes(), "version", "svg");
+    }
+
+    #[test]
+    fn test_plot_size() {
+        assert!(matches!(
+            LinePlot::new().with_size(Some(64), None),
+            Err(PlotError::Width(64))
+        ));
+        assert!(matches!(
+            LinePlot::new().with_size(None, Some(4096)),
+            Err(PlotError::Height(4096))
+        ));
+    }
 }
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::{Boundary, SampleSize, Window};
+use crate::{Boundary, ModelBaseline, SampleSize, Window};
 
 pub(crate) const REGEX_ERROR: &str = "Failed to compile regex.";
 
@@ -52,6 +52,10 @@ pub enum ValidError {
     Window(u32),
     #[error("Failed to parse model window: {0}")]
     WindowStr(std::num::ParseIntError),
+    #[error("Invalid model resolve versions: {0}")]
+    ResolveVersions(u32),
+    #[error("Failed to parse model resolve versions: {0}")]
+    ResolveVersionsStr(std::num::ParseIntError),
     #[error("Invalid plot index: {0}")]
     Index(u8),
     #[error("Failed to parse plot index: {0}")]
@@ -114,6 +118,10 @@ pub enum ValidError {
     StaticMaxSampleSize(SampleSize),
     #[error("Invalid static model, includes a sampling window: {0}")]
     StaticWindow(Window),
+    #[error("Invalid static model, includes a baseline: {0}")]
+    StaticBaseline(ModelBaseline),
+    #[error("Invalid static model, includes a maximum number of versions: {0}")]
+    StaticMaxVersions(SampleSize),
     #[error("Invalid percentage boundary: {0}")]
     PercentageBoundary(f64),
     #[error("Invalid statistical boundary: {0}")]
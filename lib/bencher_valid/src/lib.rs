@@ -36,7 +36,9 @@ pub use index::Index;
 pub use jwt::Jwt;
 pub use model::{
     boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary},
+    model_baseline::ModelBaseline,
     model_test::ModelTest,
+    resolve_versions::ResolveVersions,
     sample_size::SampleSize,
     window::Window,
     Model,
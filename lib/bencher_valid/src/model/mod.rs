@@ -8,12 +8,16 @@ use serde::{Deserialize, Serialize};
 use crate::ValidError;
 
 pub mod boundary;
+pub mod model_baseline;
 pub mod model_test;
+pub mod resolve_versions;
 pub mod sample_size;
 pub mod window;
 
 use boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
+use model_baseline::ModelBaseline;
 use model_test::ModelTest;
+use resolve_versions::ResolveVersions;
 use sample_size::SampleSize;
 use window::Window;
 
@@ -37,6 +41,16 @@ pub struct Model {
     /// The upper boundary used to calculate the upper boundary limit.
     /// The requirements for this field depend on which `test` is selected.
     pub upper_boundary: Option<Boundary>,
+    /// The historical metrics used to perform the test.
+    /// If not set, the current branch head is used.
+    pub baseline: Option<ModelBaseline>,
+    /// The maximum number of versions used to perform the test.
+    /// Only the samples from the most recent versions will be used if there are more.
+    pub max_versions: Option<SampleSize>,
+    /// The number of consecutive versions that must be within the boundary limits
+    /// before an active alert is automatically resolved.
+    /// If not set, alerts are never automatically resolved.
+    pub resolve_versions: Option<ResolveVersions>,
 }
 
 impl Model {
@@ -48,6 +62,9 @@ impl Model {
             window: None,
             lower_boundary: Some(Boundary::NINETY_NINE),
             upper_boundary: None,
+            baseline: None,
+            max_versions: None,
+            resolve_versions: None,
         }
     }
 
@@ -59,6 +76,9 @@ impl Model {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_NINE),
+            baseline: None,
+            max_versions: None,
+            resolve_versions: None,
         }
     }
 
@@ -75,6 +95,9 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         window,
         lower_boundary,
         upper_boundary,
+        baseline,
+        max_versions,
+        resolve_versions: _,
     } = model;
     match test {
         ModelTest::Static => {
@@ -84,6 +107,10 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
                 return Err(ValidError::StaticMaxSampleSize(max_sample_size));
             } else if let Some(&window) = window.as_ref() {
                 return Err(ValidError::StaticWindow(window));
+            } else if let Some(&baseline) = baseline.as_ref() {
+                return Err(ValidError::StaticBaseline(baseline));
+            } else if let Some(&max_versions) = max_versions.as_ref() {
+                return Err(ValidError::StaticMaxVersions(max_versions));
             }
 
             match (lower_boundary.as_ref(), upper_boundary.as_ref()) {
@@ -102,7 +129,11 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::LogNormal
+        | ModelTest::ChangePoint
+        | ModelTest::MannWhitneyU => {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
@@ -0,0 +1,67 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use serde::{Deserialize, Serialize};
+
+const HEAD_INT: i32 = 0;
+const START_POINT_INT: i32 = 10;
+
+/// The historical metrics that a threshold model compares against.
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum ModelBaseline {
+    /// Compare against the history of the current branch head.
+    #[default]
+    Head = HEAD_INT,
+    /// Compare against the start point branch head, up to and including the start point version.
+    /// If the branch does not have a start point, then the current branch head is used.
+    StartPoint = START_POINT_INT,
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::{ModelBaseline, HEAD_INT, START_POINT_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum ModelBaselineError {
+        #[error("Invalid model baseline value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for ModelBaseline
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Head => HEAD_INT.to_sql(out),
+                Self::StartPoint => START_POINT_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for ModelBaseline
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                HEAD_INT => Ok(Self::Head),
+                START_POINT_INT => Ok(Self::StartPoint),
+                value => Err(Box::new(ModelBaselineError::Invalid(value))),
+            }
+        }
+    }
+}
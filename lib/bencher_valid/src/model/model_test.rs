@@ -10,6 +10,8 @@ const T_TEST_INT: i32 = 1;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const CHANGE_POINT_INT: i32 = 50;
+const MANN_WHITNEY_U_INT: i32 = 60;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,13 +30,15 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    ChangePoint = CHANGE_POINT_INT,
+    MannWhitneyU = MANN_WHITNEY_U_INT,
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        ModelTest, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
-        Z_SCORE_INT,
+        ModelTest, CHANGE_POINT_INT, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, MANN_WHITNEY_U_INT,
+        PERCENTAGE_INT, STATIC_INT, T_TEST_INT, Z_SCORE_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -60,6 +64,8 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::ChangePoint => CHANGE_POINT_INT.to_sql(out),
+                Self::MannWhitneyU => MANN_WHITNEY_U_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +84,8 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                CHANGE_POINT_INT => Ok(Self::ChangePoint),
+                MANN_WHITNEY_U_INT => Ok(Self::MannWhitneyU),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
@@ -0,0 +1,150 @@
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::BigInt))]
+pub struct ResolveVersions(u32);
+
+impl TryFrom<u32> for ResolveVersions {
+    type Error = ValidError;
+
+    fn try_from(resolve_versions: u32) -> Result<Self, Self::Error> {
+        is_valid_resolve_versions(resolve_versions)
+            .then_some(Self(resolve_versions))
+            .ok_or(ValidError::ResolveVersions(resolve_versions))
+    }
+}
+
+impl From<ResolveVersions> for i64 {
+    fn from(resolve_versions: ResolveVersions) -> Self {
+        i64::from(resolve_versions.0)
+    }
+}
+
+impl From<ResolveVersions> for u32 {
+    fn from(resolve_versions: ResolveVersions) -> Self {
+        resolve_versions.0
+    }
+}
+
+impl From<ResolveVersions> for usize {
+    fn from(resolve_versions: ResolveVersions) -> Self {
+        resolve_versions.0 as usize
+    }
+}
+
+impl ResolveVersions {
+    pub const MIN: Self = Self(1);
+    pub const MAX: Self = Self(u32::MAX);
+}
+
+impl FromStr for ResolveVersions {
+    type Err = ValidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(u32::from_str(s).map_err(ValidError::ResolveVersionsStr)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResolveVersions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u32(ResolveVersionsVisitor)
+    }
+}
+
+struct ResolveVersionsVisitor;
+
+impl Visitor<'_> for ResolveVersionsVisitor {
+    type Value = ResolveVersions;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a model resolve versions greater than or equal to 1")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_u32(u32::try_from(v).map_err(E::custom)?)
+    }
+
+    fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.try_into().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::ResolveVersions;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::BigInt, DB> for ResolveVersions
+    where
+        DB: diesel::backend::Backend,
+        for<'a> i64: diesel::serialize::ToSql<diesel::sql_types::BigInt, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(i64::from(*self));
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB> for ResolveVersions
+    where
+        DB: diesel::backend::Backend,
+        i64: diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            u32::try_from(i64::from_sql(bytes)?)?
+                .try_into()
+                .map_err(Into::into)
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_resolve_versions(resolve_versions: u32) -> bool {
+    resolve_versions > 0
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_resolve_versions, ResolveVersions};
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn test_resolve_versions() {
+        assert_eq!(true, is_valid_resolve_versions(ResolveVersions::MIN.into()));
+        assert_eq!(true, is_valid_resolve_versions(1));
+        assert_eq!(true, is_valid_resolve_versions(2));
+        assert_eq!(true, is_valid_resolve_versions(3));
+        assert_eq!(true, is_valid_resolve_versions(ResolveVersions::MAX.into()));
+
+        assert_eq!(false, is_valid_resolve_versions(0));
+    }
+}
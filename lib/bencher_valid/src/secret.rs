@@ -15,6 +15,8 @@ pub const SANITIZED_SECRET: &str = "************";
 #[typeshare::typeshare]
 #[derive(Clone, Eq, PartialEq, Hash, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
 pub struct Secret(String);
 
 impl fmt::Debug for Secret {
@@ -33,6 +35,36 @@ impl fmt::Display for Secret {
     }
 }
 
+// The `Display` implementation sanitizes the secret in release builds,
+// so the raw value is used when storing it in the database.
+#[cfg(feature = "db")]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for Secret
+where
+    DB: diesel::backend::Backend,
+    String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+#[cfg(feature = "db")]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for Secret
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        String::from_sql(bytes)?
+            .as_str()
+            .parse()
+            .map_err(Into::into)
+    }
+}
+
 impl Sanitize for Secret {
     fn sanitize(&mut self) {
         self.0 = SANITIZED_SECRET.into();
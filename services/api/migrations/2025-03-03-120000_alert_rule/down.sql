@@ -0,0 +1,4 @@
+PRAGMA foreign_keys = off;
+DROP TABLE alert_rule_measure;
+DROP TABLE alert_rule;
+PRAGMA foreign_keys = on;
\ No newline at end of file
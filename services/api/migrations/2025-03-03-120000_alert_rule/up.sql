@@ -0,0 +1,19 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE alert_rule (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name)
+);
+CREATE TABLE alert_rule_measure (
+    alert_rule_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    FOREIGN KEY (alert_rule_id) REFERENCES alert_rule (id) ON DELETE CASCADE,
+    FOREIGN KEY (measure_id) REFERENCES measure (id) ON DELETE CASCADE,
+    PRIMARY KEY (alert_rule_id, measure_id)
+);
+PRAGMA foreign_keys = on;
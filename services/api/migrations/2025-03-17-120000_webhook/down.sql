@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = off;
+DROP INDEX IF EXISTS index_webhook_delivery_webhook;
+DROP TABLE webhook_delivery;
+DROP TABLE webhook;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,27 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE webhook (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    report_created BOOLEAN NOT NULL,
+    alert_created BOOLEAN NOT NULL,
+    alert_status_changed BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE webhook_delivery (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    event INTEGER NOT NULL,
+    attempts INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
+CREATE INDEX index_webhook_delivery_webhook ON webhook_delivery(webhook_id, created);
+PRAGMA foreign_keys = on;
@@ -0,0 +1,3 @@
+PRAGMA foreign_keys = off;
+DROP TABLE notification;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,11 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE notification (
+    id INTEGER PRIMARY KEY NOT NULL,
+    user_id INTEGER NOT NULL UNIQUE,
+    alert_digest INTEGER NOT NULL,
+    last_digest BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE CASCADE
+);
+PRAGMA foreign_keys = on;
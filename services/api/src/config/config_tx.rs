@@ -25,6 +25,7 @@ use crate::model::server::QueryServer;
 use crate::{
     context::{ApiContext, Database, DbConnection},
     endpoints::Api,
+    model::user::notification::digest::spawn_alert_digest,
 };
 
 use super::Config;
@@ -133,6 +134,13 @@ impl ConfigTx {
             query_server.spawn_stats(log.clone(), conn, context.stats, licensor, messenger);
         }
 
+        spawn_alert_digest(
+            log.clone(),
+            context.database.connection.clone(),
+            context.messenger.clone(),
+            context.console_url.clone(),
+        );
+
         let mut api = ApiDescription::new();
         debug!(log, "Registering server APIs");
         Api::register(
@@ -246,7 +254,7 @@ fn diesel_database_url(log: &Logger, database_path: &str) {
     std::env::set_var(DATABASE_URL, database_path);
 }
 
-fn run_migrations(database: &mut DbConnection) -> Result<(), ConfigTxError> {
+pub(crate) fn run_migrations(database: &mut DbConnection) -> Result<(), ConfigTxError> {
     // It is not possible to enable or disable foreign key constraints in the middle of a multi-statement transaction
     // (when SQLite is not in autocommit mode).
     // Attempting to do so does not return an error; it simply has no effect.
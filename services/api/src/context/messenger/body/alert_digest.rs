@@ -0,0 +1,291 @@
+use std::fmt::Write as _;
+
+use bencher_json::{project::boundary::BoundaryLimit, AlertDigest};
+use slog::Logger;
+
+use super::FmtBody;
+
+#[derive(Debug)]
+pub struct AlertDigestBody {
+    pub name: String,
+    pub alert_digest: AlertDigest,
+    pub projects: Vec<AlertDigestProject>,
+    pub settings_url: String,
+}
+
+#[derive(Debug)]
+pub struct AlertDigestProject {
+    pub name: String,
+    pub alerts_url: String,
+    pub alerts: Vec<AlertDigestAlert>,
+}
+
+#[derive(Debug)]
+pub struct AlertDigestAlert {
+    pub benchmark: String,
+    pub measure: String,
+    pub branch: String,
+    pub testbed: String,
+    pub limit: BoundaryLimit,
+    pub perf_url: String,
+}
+
+impl AlertDigestBody {
+    pub fn subject(&self) -> String {
+        format!(
+            "🐰 Bencher {period} Alert Digest: {count} new alert{s}",
+            period = period_title(self.alert_digest),
+            count = self.alert_count(),
+            s = if self.alert_count() == 1 { "" } else { "s" }
+        )
+    }
+
+    fn alert_count(&self) -> usize {
+        self.projects
+            .iter()
+            .map(|project| project.alerts.len())
+            .sum()
+    }
+}
+
+impl FmtBody for AlertDigestBody {
+    fn text(&self) -> String {
+        let Self {
+            name,
+            alert_digest,
+            projects,
+            settings_url,
+        } = self;
+
+        let mut text = format!(
+            "\nAhoy {name},\nHere are the new active alerts from the past {period}:\n",
+            period = period_noun(*alert_digest)
+        );
+        for project in projects {
+            let AlertDigestProject {
+                name,
+                alerts_url,
+                alerts,
+            } = project;
+            _ = write!(text, "\n{name} ({alerts_url})\n");
+            for alert in alerts {
+                let AlertDigestAlert {
+                    benchmark,
+                    measure,
+                    branch,
+                    testbed,
+                    limit,
+                    perf_url,
+                } = alert;
+                _ = writeln!(
+                    text,
+                    "- {benchmark} | {measure} | {branch} | {testbed} | {limit} Boundary: {perf_url}"
+                );
+            }
+        }
+        _ = write!(
+            text,
+            "\n🐰 Bencher\nBencher - Continuous Benchmarking\nManage email settings ({settings_url})"
+        );
+        text
+    }
+
+    fn html(&self, _log: &Logger) -> String {
+        let Self {
+            name,
+            alert_digest,
+            projects,
+            settings_url,
+        } = self;
+
+        let mut projects_html = String::new();
+        for project in projects {
+            let AlertDigestProject {
+                name,
+                alerts_url,
+                alerts,
+            } = project;
+            _ = write!(
+                projects_html,
+                "<h3><a href=\"{alerts_url}\">{name}</a></h3>
+        <table>
+            <tr><th>Benchmark</th><th>Measure</th><th>Branch</th><th>Testbed</th><th>Boundary</th><th></th></tr>",
+                name = escape_html(name),
+            );
+            for alert in alerts {
+                let AlertDigestAlert {
+                    benchmark,
+                    measure,
+                    branch,
+                    testbed,
+                    limit,
+                    perf_url,
+                } = alert;
+                _ = write!(
+                    projects_html,
+                    "
+            <tr><td>{benchmark}</td><td>{measure}</td><td>{branch}</td><td>{testbed}</td><td>{limit}</td><td><a href=\"{perf_url}\">View</a></td></tr>",
+                    benchmark = escape_html(benchmark),
+                    measure = escape_html(measure),
+                    branch = escape_html(branch),
+                    testbed = escape_html(testbed),
+                );
+            }
+            projects_html.push_str("\n        </table>\n        ");
+        }
+
+        format!(
+            "<!doctype html>
+<html>
+    <head>
+        <meta charset=\"utf-8\" />
+        <meta name=\"viewport\" content=\"width=device-width, initial-scale=1, shrink-to-fit=no\" />
+        <meta name=\"theme-color\" content=\"#ffffff\" />
+        <title>Bencher {period_title} Alert Digest</title>
+    </head>
+    <body>
+        <p>Ahoy {name},</p>
+        <p>Here are the new active alerts from the past {period_noun}:</p>
+        {projects_html}
+        <br />
+        <p>🐰 Bencher</p>
+        <hr />
+        <p>Bencher - Continuous Benchmarking</p>
+        <a href=\"{settings_url}\">Manage email settings</a>
+    </body>
+</html>",
+            name = escape_html(name),
+            period_title = period_title(*alert_digest),
+            period_noun = period_noun(*alert_digest),
+        )
+    }
+}
+
+fn period_title(alert_digest: AlertDigest) -> &'static str {
+    match alert_digest {
+        AlertDigest::Never => "",
+        AlertDigest::Daily => "Daily",
+        AlertDigest::Weekly => "Weekly",
+    }
+}
+
+fn period_noun(alert_digest: AlertDigest) -> &'static str {
+    match alert_digest {
+        AlertDigest::Never => "",
+        AlertDigest::Daily => "day",
+        AlertDigest::Weekly => "week",
+    }
+}
+
+// Benchmark and other resource names are user provided.
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, AlertDigest};
+    use pretty_assertions::assert_eq;
+
+    use super::{AlertDigestAlert, AlertDigestBody, AlertDigestProject, FmtBody};
+    use crate::util::test_db::test_log;
+
+    fn alert(benchmark: &str, limit: BoundaryLimit) -> AlertDigestAlert {
+        AlertDigestAlert {
+            benchmark: benchmark.to_owned(),
+            measure: "Latency".to_owned(),
+            branch: "main".to_owned(),
+            testbed: "localhost".to_owned(),
+            limit,
+            perf_url: "https://bencher.dev/perf".to_owned(),
+        }
+    }
+
+    fn body(alert_digest: AlertDigest, alerts: Vec<AlertDigestAlert>) -> AlertDigestBody {
+        AlertDigestBody {
+            name: "Muriel".to_owned(),
+            alert_digest,
+            projects: vec![AlertDigestProject {
+                name: "Project".to_owned(),
+                alerts_url: "https://bencher.dev/alerts".to_owned(),
+                alerts,
+            }],
+            settings_url: "https://bencher.dev/settings".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_alert_digest_subject() {
+        let one = body(AlertDigest::Daily, vec![alert("a", BoundaryLimit::Upper)]);
+        assert_eq!(one.subject(), "🐰 Bencher Daily Alert Digest: 1 new alert");
+
+        let two = body(
+            AlertDigest::Weekly,
+            vec![
+                alert("a", BoundaryLimit::Upper),
+                alert("b", BoundaryLimit::Lower),
+            ],
+        );
+        assert_eq!(
+            two.subject(),
+            "🐰 Bencher Weekly Alert Digest: 2 new alerts"
+        );
+    }
+
+    #[test]
+    fn test_alert_digest_text() {
+        let body = body(
+            AlertDigest::Weekly,
+            vec![
+                alert("a", BoundaryLimit::Upper),
+                alert("b", BoundaryLimit::Lower),
+            ],
+        );
+        assert_eq!(
+            body.text(),
+            "
+Ahoy Muriel,
+Here are the new active alerts from the past week:
+
+Project (https://bencher.dev/alerts)
+- a | Latency | main | localhost | Upper Boundary: https://bencher.dev/perf
+- b | Latency | main | localhost | Lower Boundary: https://bencher.dev/perf
+
+🐰 Bencher
+Bencher - Continuous Benchmarking
+Manage email settings (https://bencher.dev/settings)"
+        );
+    }
+
+    #[test]
+    fn test_alert_digest_html() {
+        let body = body(
+            AlertDigest::Daily,
+            vec![alert("<script>\"a\" & b</script>", BoundaryLimit::Upper)],
+        );
+        let html = body.html(&test_log());
+        assert!(
+            html.contains("<title>Bencher Daily Alert Digest</title>"),
+            "{html}"
+        );
+        assert!(
+            html.contains("<p>Here are the new active alerts from the past day:</p>"),
+            "{html}"
+        );
+        assert!(
+            html.contains("<h3><a href=\"https://bencher.dev/alerts\">Project</a></h3>"),
+            "{html}"
+        );
+        assert!(
+            html.contains(
+                "<tr><td>&lt;script&gt;&quot;a&quot; &amp; b&lt;/script&gt;</td><td>Latency</td><td>main</td><td>localhost</td><td>Upper</td><td><a href=\"https://bencher.dev/perf\">View</a></td></tr>"
+            ),
+            "{html}"
+        );
+        assert!(!html.contains("<script>"), "{html}");
+    }
+}
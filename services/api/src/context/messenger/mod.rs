@@ -5,7 +5,9 @@ mod message;
 use bencher_json::system::config::JsonSmtp;
 #[cfg(feature = "plus")]
 pub use body::ServerStatsBody;
-pub use body::{Body, ButtonBody, NewUserBody};
+pub use body::{
+    AlertDigestAlert, AlertDigestBody, AlertDigestProject, Body, ButtonBody, NewUserBody,
+};
 pub use email::Email;
 pub use message::Message;
 use slog::{info, Logger};
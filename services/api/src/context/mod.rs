@@ -23,7 +23,10 @@ pub use database::{DataStoreError, Database, DbConnection};
 pub use indexer::Indexer;
 #[cfg(feature = "plus")]
 pub use messenger::ServerStatsBody;
-pub use messenger::{Body, ButtonBody, Email, Message, Messenger, NewUserBody};
+pub use messenger::{
+    AlertDigestAlert, AlertDigestBody, AlertDigestProject, Body, ButtonBody, Email, Message,
+    Messenger, NewUserBody,
+};
 pub use rbac::{Rbac, RbacError};
 
 pub struct ApiContext {
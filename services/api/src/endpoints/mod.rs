@@ -149,6 +149,23 @@ impl Api {
         }
         api.register(project::perf::img::proj_perf_img_get)?;
 
+        // Perf Summary
+        if http_options {
+            api.register(project::perf::summary::proj_perf_summary_options)?;
+        }
+        api.register(project::perf::summary::proj_perf_summary_get)?;
+
+        // Perf Compare
+        if http_options {
+            api.register(project::perf::compare::proj_perf_compare_options)?;
+        }
+        api.register(project::perf::compare::proj_perf_compare_get)?;
+        // Perf Export
+        if http_options {
+            api.register(project::perf::export::proj_perf_export_options)?;
+        }
+        api.register(project::perf::export::proj_perf_export_get)?;
+
         // Plots
         if http_options {
             api.register(project::plots::proj_plots_options)?;
@@ -230,6 +247,29 @@ impl Api {
         api.register(project::alerts::proj_alert_get)?;
         api.register(project::alerts::proj_alert_patch)?;
 
+        // Alert Rules
+        if http_options {
+            api.register(project::alert_rules::proj_alert_rules_options)?;
+            api.register(project::alert_rules::proj_alert_rule_options)?;
+        }
+        api.register(project::alert_rules::proj_alert_rules_get)?;
+        api.register(project::alert_rules::proj_alert_rule_post)?;
+        api.register(project::alert_rules::proj_alert_rule_get)?;
+        api.register(project::alert_rules::proj_alert_rule_delete)?;
+
+        // Webhooks
+        if http_options {
+            api.register(project::webhooks::proj_webhooks_options)?;
+            api.register(project::webhooks::proj_webhook_options)?;
+            api.register(project::webhooks::proj_webhook_deliveries_options)?;
+        }
+        api.register(project::webhooks::proj_webhooks_get)?;
+        api.register(project::webhooks::proj_webhook_post)?;
+        api.register(project::webhooks::proj_webhook_get)?;
+        api.register(project::webhooks::proj_webhook_patch)?;
+        api.register(project::webhooks::proj_webhook_delete)?;
+        api.register(project::webhooks::proj_webhook_deliveries_get)?;
+
         // Users
         if http_options {
             api.register(user::users::users_options)?;
@@ -239,6 +279,13 @@ impl Api {
         api.register(user::users::user_get)?;
         api.register(user::users::user_patch)?;
 
+        // Notifications
+        if http_options {
+            api.register(user::notifications::user_notifications_options)?;
+        }
+        api.register(user::notifications::user_notifications_get)?;
+        api.register(user::notifications::user_notifications_patch)?;
+
         // Tokens
         if http_options {
             api.register(user::tokens::user_tokens_options)?;
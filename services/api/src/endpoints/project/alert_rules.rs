@@ -0,0 +1,346 @@
+use bencher_json::{
+    AlertRuleUuid, JsonAlertRule, JsonAlertRules, JsonDirection, JsonNewAlertRule, JsonPagination,
+    ResourceId, ResourceName,
+};
+use bencher_rbac::project::Permission;
+use diesel::{
+    BelongingToDsl, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    TextExpressionMethods,
+};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk},
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            alert_rule::{InsertAlertRule, QueryAlertRule},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken, PubBearerToken},
+    },
+    schema,
+    util::{headers::TotalCount, search::Search},
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjAlertRulesParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjAlertRulesPagination = JsonPagination<ProjAlertRulesSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjAlertRulesSort {
+    /// Sort by alert rule name.
+    #[default]
+    Name,
+    /// Sort by alert rule creation date time.
+    Created,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjAlertRulesQuery {
+    /// Filter by alert rule name, exact match.
+    pub name: Option<ResourceName>,
+    /// Search by alert rule name or UUID.
+    pub search: Option<Search>,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/alert-rules",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rules_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjAlertRulesParams>,
+    _pagination_params: Query<ProjAlertRulesPagination>,
+    _query_params: Query<ProjAlertRulesQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List alert rules for a project
+///
+/// List all composite alert rules for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// By default, the alert rules are sorted in alphabetical order by name.
+/// The HTTP response header `X-Total-Count` contains the total number of alert rules.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/alert-rules",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rules_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjAlertRulesParams>,
+    pagination_params: Query<ProjAlertRulesPagination>,
+    query_params: Query<ProjAlertRulesQuery>,
+) -> Result<ResponseOk<JsonAlertRules>, HttpError> {
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok_with_total_count(
+        json,
+        auth_user.is_some(),
+        total_count,
+    ))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjAlertRulesParams,
+    pagination_params: ProjAlertRulesPagination,
+    query_params: ProjAlertRulesQuery,
+) -> Result<(JsonAlertRules, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let alert_rules = get_ls_query(&query_project, &pagination_params, &query_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryAlertRule>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            AlertRule,
+            (&query_project, &pagination_params, &query_params)
+        ))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_alert_rules = Vec::with_capacity(alert_rules.len());
+    for alert_rule in alert_rules {
+        match alert_rule.into_json_for_project(conn_lock!(context), &query_project) {
+            Ok(alert_rule) => json_alert_rules.push(alert_rule),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            AlertRule,
+            (&query_project, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_alert_rules.into(), total_count))
+}
+
+fn get_ls_query<'q>(
+    query_project: &'q QueryProject,
+    pagination_params: &ProjAlertRulesPagination,
+    query_params: &'q ProjAlertRulesQuery,
+) -> schema::alert_rule::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+    let mut query = QueryAlertRule::belonging_to(&query_project).into_boxed();
+
+    if let Some(name) = query_params.name.as_ref() {
+        query = query.filter(schema::alert_rule::name.eq(name));
+    }
+    if let Some(search) = query_params.search.as_ref() {
+        query = query.filter(
+            schema::alert_rule::name
+                .like(search)
+                .or(schema::alert_rule::uuid.like(search)),
+        );
+    }
+
+    match pagination_params.order() {
+        ProjAlertRulesSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::alert_rule::name.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::alert_rule::name.desc()),
+        },
+        ProjAlertRulesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::alert_rule::created.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::alert_rule::created.desc()),
+        },
+    }
+}
+
+/// Create an alert rule
+///
+/// Create a composite alert rule for a project.
+/// An alert rule only generates alerts when all of its measures
+/// exceed their threshold boundaries for the same benchmark in the same report.
+/// The user must have `create` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/alert-rules",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rule_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjAlertRulesParams>,
+    body: TypedBody<JsonNewAlertRule>,
+) -> Result<ResponseCreated<JsonAlertRule>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjAlertRulesParams,
+    json_alert_rule: JsonNewAlertRule,
+    auth_user: &AuthUser,
+) -> Result<JsonAlertRule, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    let query_alert_rule =
+        InsertAlertRule::from_json(context, &query_project, json_alert_rule).await?;
+
+    query_alert_rule.into_json_for_project(conn_lock!(context), &query_project)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjAlertRuleParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for an alert rule.
+    pub alert_rule: AlertRuleUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/alert-rules/{alert_rule}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rule_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjAlertRuleParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Delete.into()]))
+}
+
+/// View an alert rule
+///
+/// View a composite alert rule for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/alert-rules/{alert_rule}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rule_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjAlertRuleParams>,
+) -> Result<ResponseOk<JsonAlertRule>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjAlertRuleParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonAlertRule, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    conn_lock!(context, |conn| QueryAlertRule::get_with_uuid(
+        conn,
+        &query_project,
+        path_params.alert_rule
+    )
+    .and_then(
+        |alert_rule| alert_rule.into_json_for_project(conn, &query_project)
+    ))
+}
+
+/// Delete an alert rule
+///
+/// Delete a composite alert rule for a project.
+/// The user must have `delete` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/alert-rules/{alert_rule}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_rule_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjAlertRuleParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjAlertRuleParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_alert_rule =
+        QueryAlertRule::get_with_uuid(conn_lock!(context), &query_project, path_params.alert_rule)?;
+
+    diesel::delete(
+        schema::alert_rule::table.filter(schema::alert_rule::id.eq(query_alert_rule.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(AlertRule, query_alert_rule))?;
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl, Se
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 use crate::{
     conn_lock,
@@ -19,6 +20,7 @@ use crate::{
     model::{
         project::{
             threshold::alert::{QueryAlert, UpdateAlert},
+            webhook::Webhooks,
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
@@ -364,6 +366,7 @@ pub async fn proj_alert_patch(
 ) -> Result<ResponseOk<JsonAlert>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = patch_inner(
+        &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
@@ -374,6 +377,7 @@ pub async fn proj_alert_patch(
 }
 
 async fn patch_inner(
+    log: &Logger,
     context: &ApiContext,
     path_params: ProjAlertParams,
     json_alert: JsonUpdateAlert,
@@ -397,7 +401,14 @@ async fn patch_inner(
         .map_err(resource_conflict_err!(Alert, (&query_alert, &json_alert)))?;
 
     let alert = QueryAlert::get(conn_lock!(context), query_alert.id)?;
+    let status_changed = alert.status != query_alert.status;
 
     // Separate out this query to prevent a deadlock when getting the conn_lock
-    alert.into_json(context).await
+    let json_alert = alert.into_json(context).await?;
+
+    if status_changed {
+        Webhooks::alert_status_changed(log, context, &query_project, &json_alert).await;
+    }
+
+    Ok(json_alert)
 }
@@ -154,6 +154,9 @@ async fn get_one_inner(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::baseline,
+                    schema::model::max_versions,
+                    schema::model::resolve_versions,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -163,6 +166,7 @@ async fn get_one_inner(
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
                     schema::alert::status,
+                    schema::alert::resolved_report_id,
                     schema::alert::modified,
                 ).nullable(),
             ).nullable(),
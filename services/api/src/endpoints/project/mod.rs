@@ -1,3 +1,4 @@
+pub mod alert_rules;
 pub mod alerts;
 pub mod allowed;
 pub mod benchmarks;
@@ -10,3 +11,4 @@ pub mod projects;
 pub mod reports;
 pub mod testbeds;
 pub mod thresholds;
+pub mod webhooks;
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use bencher_boundary::{MetricsComparison, MetricsSample};
+use bencher_json::{
+    project::perf::{
+        JsonMetricsSample, JsonPerfCompareMetrics, JsonPerfCompareQuery,
+        JsonPerfCompareQueryParams, JsonPerfCompareSelector,
+    },
+    BenchmarkUuid, JsonBranch, JsonPerfCompare, JsonTestbed, MeasureUuid,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    error::{bad_request_error, resource_not_found_err},
+    model::{
+        project::{
+            benchmark::QueryBenchmark,
+            branch::{head::QueryHead, version::QueryVersion, QueryBranch},
+            measure::QueryMeasure,
+            testbed::QueryTestbed,
+            QueryProject,
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+};
+
+use super::ProjPerfParams;
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_compare_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfCompareQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Compare project performance metrics
+///
+/// Compare the performance metrics for a baseline and a candidate in a project.
+/// The baseline and the candidate are each selected by a branch, an optional branch head, a testbed, and an optional git hash.
+/// If a git hash is not provided, then the most recent version with metrics for that branch head and testbed is used.
+/// Benchmarks are matched by UUID, and only benchmarks with metrics for both the baseline and the candidate are compared.
+/// Each comparison includes the difference and percent change in the means,
+/// along with the p-value and statistical significance from the Mann-Whitney U test.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_compare_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfCompareQueryParams>,
+) -> Result<ResponseOk<JsonPerfCompare>, HttpError> {
+    // Second round of marshaling
+    let json_perf_compare_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_perf_compare_query,
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjPerfParams,
+    json_perf_compare_query: JsonPerfCompareQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonPerfCompare, HttpError> {
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let JsonPerfCompareQuery {
+        baseline,
+        candidate,
+        measures,
+    } = json_perf_compare_query;
+
+    let baseline = CompareSide::new(conn_lock!(context), &project, &baseline)?;
+    let candidate = CompareSide::new(conn_lock!(context), &project, &candidate)?;
+
+    let baseline_metrics = baseline.metrics(conn_lock!(context), &project, &measures)?;
+    let mut candidate_metrics = candidate
+        .metrics(conn_lock!(context), &project, &measures)?
+        .into_iter()
+        .map(|(benchmark, measure, data)| ((benchmark.uuid, measure.uuid), data))
+        .collect::<HashMap<(BenchmarkUuid, MeasureUuid), Vec<f64>>>();
+
+    let results = baseline_metrics
+        .into_iter()
+        .filter_map(|(benchmark, measure, baseline_data)| {
+            let candidate_data = candidate_metrics.remove(&(benchmark.uuid, measure.uuid))?;
+            let MetricsComparison {
+                baseline,
+                candidate,
+                difference,
+                change,
+                p_value,
+                significant,
+            } = MetricsComparison::new(&baseline_data, &candidate_data)?;
+            Some(JsonPerfCompareMetrics {
+                benchmark: benchmark.into_json_for_project(&project),
+                measure: measure.into_json_for_project(&project),
+                baseline: into_json_sample(baseline),
+                candidate: into_json_sample(candidate),
+                difference: difference.into(),
+                change: change.map(Into::into),
+                p_value: p_value.map(Into::into),
+                significant,
+            })
+        })
+        .collect();
+
+    let (baseline_branch, baseline_testbed) = baseline.into_json(conn_lock!(context), &project)?;
+    let (candidate_branch, candidate_testbed) =
+        candidate.into_json(conn_lock!(context), &project)?;
+    Ok(JsonPerfCompare {
+        project: project.into_json(conn_lock!(context))?,
+        baseline_branch,
+        baseline_testbed,
+        candidate_branch,
+        candidate_testbed,
+        results,
+    })
+}
+
+struct CompareSide {
+    branch: QueryBranch,
+    head: QueryHead,
+    testbed: QueryTestbed,
+    version: QueryVersion,
+}
+
+impl CompareSide {
+    fn new(
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        selector: &JsonPerfCompareSelector,
+    ) -> Result<Self, HttpError> {
+        let branch = QueryBranch::from_uuid(conn, project.id, selector.branch)?;
+        let head = if let Some(head_uuid) = selector.head {
+            let head = QueryHead::from_uuid(conn, project.id, head_uuid)?;
+            if head.branch_id != branch.id {
+                return Err(bad_request_error(format!(
+                    "Branch head ({head_uuid}) does not belong to branch ({})",
+                    branch.uuid
+                )));
+            }
+            head
+        } else {
+            branch.head(conn)?
+        };
+        let testbed = QueryTestbed::from_uuid(conn, project.id, selector.testbed)?;
+
+        // It is important to find the version through the `head_version` table
+        // so that versions from the start point of the branch head are also included.
+        let mut query = schema::version::table
+            .inner_join(schema::head_version::table)
+            .inner_join(schema::report::table)
+            .filter(schema::head_version::head_id.eq(head.id))
+            .filter(schema::report::testbed_id.eq(testbed.id))
+            .filter(schema::version::project_id.eq(project.id))
+            .into_boxed();
+        if let Some(hash) = &selector.hash {
+            query = query.filter(schema::version::hash.eq(hash.as_ref()));
+        }
+        let version = query
+            .order(schema::version::number.desc())
+            .select(QueryVersion::as_select())
+            .first::<QueryVersion>(conn)
+            .map_err(resource_not_found_err!(Version, (project, selector)))?;
+
+        Ok(Self {
+            branch,
+            head,
+            testbed,
+            version,
+        })
+    }
+
+    // Every metric for the version on the testbed, grouped by benchmark and measure
+    fn metrics(
+        &self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        measures: &[MeasureUuid],
+    ) -> Result<Vec<(QueryBenchmark, QueryMeasure, Vec<f64>)>, HttpError> {
+        let metrics = schema::metric::table
+            .inner_join(
+                schema::report_benchmark::table
+                    .inner_join(schema::report::table)
+                    .inner_join(schema::benchmark::table),
+            )
+            .inner_join(schema::measure::table)
+            .filter(schema::report::version_id.eq(self.version.id))
+            .filter(schema::report::testbed_id.eq(self.testbed.id))
+            .filter(schema::measure::uuid.eq_any(measures))
+            // Make sure that the project is the same for all dimensions
+            .filter(schema::benchmark::project_id.eq(project.id))
+            .filter(schema::measure::project_id.eq(project.id))
+            .order((
+                schema::benchmark::name,
+                schema::benchmark::id,
+                schema::measure::name,
+                schema::measure::id,
+                schema::report::start_time,
+                schema::report_benchmark::iteration,
+            ))
+            .select((
+                QueryBenchmark::as_select(),
+                QueryMeasure::as_select(),
+                schema::metric::value,
+            ))
+            .load::<(QueryBenchmark, QueryMeasure, f64)>(conn)
+            .map_err(resource_not_found_err!(
+                Metric,
+                (project, &self.version, &self.testbed)
+            ))?;
+
+        let mut grouped: Vec<(QueryBenchmark, QueryMeasure, Vec<f64>)> = Vec::new();
+        for (benchmark, measure, value) in metrics {
+            match grouped.last_mut() {
+                Some((last_benchmark, last_measure, data))
+                    if last_benchmark.id == benchmark.id && last_measure.id == measure.id =>
+                {
+                    data.push(value);
+                },
+                _ => grouped.push((benchmark, measure, vec![value])),
+            }
+        }
+        Ok(grouped)
+    }
+
+    fn into_json(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<(JsonBranch, JsonTestbed), HttpError> {
+        let Self {
+            branch,
+            head,
+            testbed,
+            version,
+        } = self;
+        Ok((
+            branch.into_json_for_head(conn, project, &head, Some(version))?,
+            testbed.into_json_for_project(project),
+        ))
+    }
+}
+
+fn into_json_sample(sample: MetricsSample) -> JsonMetricsSample {
+    let MetricsSample {
+        count,
+        mean,
+        std_dev,
+    } = sample;
+    JsonMetricsSample {
+        count: u32::try_from(count).unwrap_or(u32::MAX),
+        mean: mean.into(),
+        std_dev: std_dev.map(Into::into),
+    }
+}
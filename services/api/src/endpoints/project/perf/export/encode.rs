@@ -0,0 +1,487 @@
+use std::{fmt, io::Write, sync::Arc};
+
+use bencher_json::{
+    project::{
+        alert::AlertStatus, boundary::BoundaryLimit, head::VersionNumber, report::Iteration,
+    },
+    AlertUuid, BenchmarkName, BenchmarkUuid, BoundaryUuid, BranchName, BranchUuid, DateTime,
+    GitHash, HeadUuid, MeasureUuid, MetricUuid, ModelTest, ReportUuid, ResourceName, TestbedUuid,
+    ThresholdUuid,
+};
+use bytes::Bytes;
+use parquet::{
+    data_type::{ByteArray, ByteArrayType, DataType, DoubleType, Int64Type},
+    errors::ParquetError,
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedFileWriter, SerializedRowGroupWriter},
+    },
+    schema::parser::parse_message_type,
+};
+use serde::Serialize;
+
+/// The columns for an export, in order.
+pub const EXPORT_COLUMNS: [&str; 29] = [
+    "branch_uuid",
+    "branch_name",
+    "head_uuid",
+    "testbed_uuid",
+    "testbed_name",
+    "benchmark_uuid",
+    "benchmark_name",
+    "measure_uuid",
+    "measure_name",
+    "measure_units",
+    "version_number",
+    "version_hash",
+    "report_uuid",
+    "report_start_time",
+    "report_end_time",
+    "iteration",
+    "metric_uuid",
+    "value",
+    "lower_value",
+    "upper_value",
+    "threshold_uuid",
+    "threshold_model",
+    "boundary_uuid",
+    "baseline",
+    "lower_limit",
+    "upper_limit",
+    "alert_uuid",
+    "alert_limit",
+    "alert_status",
+];
+
+// The Parquet schema must have the same columns in the same order as `EXPORT_COLUMNS`.
+const PARQUET_SCHEMA: &str = "
+message perf_export {
+    required binary branch_uuid (STRING);
+    required binary branch_name (STRING);
+    required binary head_uuid (STRING);
+    required binary testbed_uuid (STRING);
+    required binary testbed_name (STRING);
+    required binary benchmark_uuid (STRING);
+    required binary benchmark_name (STRING);
+    required binary measure_uuid (STRING);
+    required binary measure_name (STRING);
+    required binary measure_units (STRING);
+    required int64 version_number;
+    optional binary version_hash (STRING);
+    required binary report_uuid (STRING);
+    required int64 report_start_time (TIMESTAMP(MILLIS, true));
+    required int64 report_end_time (TIMESTAMP(MILLIS, true));
+    required int64 iteration;
+    required binary metric_uuid (STRING);
+    required double value;
+    optional double lower_value;
+    optional double upper_value;
+    optional binary threshold_uuid (STRING);
+    optional binary threshold_model (STRING);
+    optional binary boundary_uuid (STRING);
+    optional double baseline;
+    optional double lower_limit;
+    optional double upper_limit;
+    optional binary alert_uuid (STRING);
+    optional binary alert_limit (STRING);
+    optional binary alert_status (STRING);
+}
+";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("Failed to query metrics: {0}")]
+    Query(#[from] diesel::result::Error),
+    #[error("Failed to encode CSV: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Failed to write CSV: {0}")]
+    CsvWrite(#[from] std::io::Error),
+    #[error("Failed to encode Parquet: {0}")]
+    Parquet(#[from] ParquetError),
+    #[error("Missing Parquet column: {0}")]
+    MissingColumn(&'static str),
+}
+
+/// A single exported metric, along with all of its dimensions, boundary, and alert.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportRow {
+    pub branch_uuid: BranchUuid,
+    pub branch_name: BranchName,
+    pub head_uuid: HeadUuid,
+    pub testbed_uuid: TestbedUuid,
+    pub testbed_name: ResourceName,
+    pub benchmark_uuid: BenchmarkUuid,
+    pub benchmark_name: BenchmarkName,
+    pub measure_uuid: MeasureUuid,
+    pub measure_name: ResourceName,
+    pub measure_units: ResourceName,
+    pub version_number: VersionNumber,
+    pub version_hash: Option<GitHash>,
+    pub report_uuid: ReportUuid,
+    pub report_start_time: DateTime,
+    pub report_end_time: DateTime,
+    pub iteration: Iteration,
+    pub metric_uuid: MetricUuid,
+    pub value: f64,
+    pub lower_value: Option<f64>,
+    pub upper_value: Option<f64>,
+    pub threshold_uuid: Option<ThresholdUuid>,
+    pub threshold_model: Option<ModelTest>,
+    pub boundary_uuid: Option<BoundaryUuid>,
+    pub baseline: Option<f64>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    pub alert_uuid: Option<AlertUuid>,
+    pub alert_limit: Option<BoundaryLimit>,
+    pub alert_status: Option<AlertStatus>,
+}
+
+/// Encodes pages of export rows into chunks of a CSV or Parquet file.
+pub enum ExportEncoder {
+    Csv,
+    Parquet(Box<SerializedFileWriter<Vec<u8>>>),
+}
+
+impl ExportEncoder {
+    /// Create a new CSV encoder.
+    pub fn csv() -> Self {
+        Self::Csv
+    }
+
+    /// Create a new Parquet encoder.
+    pub fn parquet() -> Result<Self, ExportError> {
+        let schema = Arc::new(parse_message_type(PARQUET_SCHEMA)?);
+        let properties = Arc::new(
+            WriterProperties::builder()
+                .set_created_by(format!("Bencher v{}", env!("CARGO_PKG_VERSION")))
+                .build(),
+        );
+        let writer = SerializedFileWriter::new(Vec::new(), schema, properties)?;
+        Ok(Self::Parquet(Box::new(writer)))
+    }
+
+    /// The first chunk of the file.
+    /// For CSV, this is the header record.
+    /// For Parquet, this is the magic number.
+    pub fn start(&mut self) -> Result<Bytes, ExportError> {
+        match self {
+            Self::Csv => {
+                let mut writer = csv_writer();
+                writer.write_record(EXPORT_COLUMNS)?;
+                csv_bytes(writer)
+            },
+            Self::Parquet(writer) => Ok(drain(writer)),
+        }
+    }
+
+    /// Encode a page of rows.
+    /// For Parquet, each page is written as its own row group.
+    pub fn encode(&mut self, rows: &[ExportRow]) -> Result<Bytes, ExportError> {
+        match self {
+            Self::Csv => {
+                let mut writer = csv_writer();
+                for row in rows {
+                    writer.serialize(row)?;
+                }
+                csv_bytes(writer)
+            },
+            Self::Parquet(writer) => {
+                let mut row_group = writer.next_row_group()?;
+                write_row_group(&mut row_group, rows)?;
+                row_group.close()?;
+                Ok(drain(writer))
+            },
+        }
+    }
+
+    /// The last chunk of the file.
+    /// For CSV, this is empty.
+    /// For Parquet, this is the file metadata footer.
+    pub fn finish(self) -> Result<Bytes, ExportError> {
+        match self {
+            Self::Csv => Ok(Bytes::new()),
+            Self::Parquet(writer) => Ok(writer.into_inner()?.into()),
+        }
+    }
+}
+
+fn csv_writer() -> csv::Writer<Vec<u8>> {
+    // The header record is only written once by `ExportEncoder::start`
+    csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new())
+}
+
+fn csv_bytes(writer: csv::Writer<Vec<u8>>) -> Result<Bytes, ExportError> {
+    writer
+        .into_inner()
+        .map(Into::into)
+        .map_err(|e| e.into_error().into())
+}
+
+// Take all of the bytes that have been flushed to the underlying buffer so far.
+// Any bytes that are still buffered by the Parquet writer will be flushed later, in order.
+fn drain(writer: &mut SerializedFileWriter<Vec<u8>>) -> Bytes {
+    std::mem::take(writer.inner_mut()).into()
+}
+
+fn write_row_group<W>(
+    row_group: &mut SerializedRowGroupWriter<'_, W>,
+    rows: &[ExportRow],
+) -> Result<(), ExportError>
+where
+    W: Write + Send,
+{
+    let mut columns = ColumnWriter {
+        row_group,
+        index: 0,
+    };
+    columns.text(rows, |row| Some(&row.branch_uuid))?;
+    columns.text(rows, |row| Some(&row.branch_name))?;
+    columns.text(rows, |row| Some(&row.head_uuid))?;
+    columns.text(rows, |row| Some(&row.testbed_uuid))?;
+    columns.text(rows, |row| Some(&row.testbed_name))?;
+    columns.text(rows, |row| Some(&row.benchmark_uuid))?;
+    columns.text(rows, |row| Some(&row.benchmark_name))?;
+    columns.text(rows, |row| Some(&row.measure_uuid))?;
+    columns.text(rows, |row| Some(&row.measure_name))?;
+    columns.text(rows, |row| Some(&row.measure_units))?;
+    columns.int(rows, |row| Some(i64::from(row.version_number.0)))?;
+    columns.text(rows, |row| row.version_hash.as_ref())?;
+    columns.text(rows, |row| Some(&row.report_uuid))?;
+    columns.int(rows, |row| Some(timestamp_millis(row.report_start_time)))?;
+    columns.int(rows, |row| Some(timestamp_millis(row.report_end_time)))?;
+    columns.int(rows, |row| Some(i64::from(row.iteration.0)))?;
+    columns.text(rows, |row| Some(&row.metric_uuid))?;
+    columns.double(rows, |row| Some(row.value))?;
+    columns.double(rows, |row| row.lower_value)?;
+    columns.double(rows, |row| row.upper_value)?;
+    columns.text(rows, |row| row.threshold_uuid.as_ref())?;
+    columns.serde(rows, |row| row.threshold_model.as_ref())?;
+    columns.text(rows, |row| row.boundary_uuid.as_ref())?;
+    columns.double(rows, |row| row.baseline)?;
+    columns.double(rows, |row| row.lower_limit)?;
+    columns.double(rows, |row| row.upper_limit)?;
+    columns.text(rows, |row| row.alert_uuid.as_ref())?;
+    columns.serde(rows, |row| row.alert_limit.as_ref())?;
+    columns.serde(rows, |row| row.alert_status.as_ref())?;
+    Ok(())
+}
+
+struct ColumnWriter<'r, 'a, W>
+where
+    W: Write + Send,
+{
+    row_group: &'r mut SerializedRowGroupWriter<'a, W>,
+    index: usize,
+}
+
+impl<W> ColumnWriter<'_, '_, W>
+where
+    W: Write + Send,
+{
+    fn text<'r, T, F>(&mut self, rows: &'r [ExportRow], f: F) -> Result<(), ExportError>
+    where
+        T: fmt::Display + 'r,
+        F: Fn(&'r ExportRow) -> Option<&'r T>,
+    {
+        self.write::<ByteArrayType, _>(
+            rows.iter()
+                .map(|row| f(row).map(|value| ByteArray::from(value.to_string().into_bytes()))),
+        )
+    }
+
+    // Use the serialized name for enums, so that it matches the CSV output
+    fn serde<'r, T, F>(&mut self, rows: &'r [ExportRow], f: F) -> Result<(), ExportError>
+    where
+        T: Serialize + 'r,
+        F: Fn(&'r ExportRow) -> Option<&'r T>,
+    {
+        self.write::<ByteArrayType, _>(rows.iter().map(|row| {
+            f(row)
+                .and_then(|value| serde_json::to_value(value).ok())
+                .and_then(|value| value.as_str().map(ByteArray::from))
+        }))
+    }
+
+    fn int<F>(&mut self, rows: &[ExportRow], f: F) -> Result<(), ExportError>
+    where
+        F: Fn(&ExportRow) -> Option<i64>,
+    {
+        self.write::<Int64Type, _>(rows.iter().map(f))
+    }
+
+    fn double<F>(&mut self, rows: &[ExportRow], f: F) -> Result<(), ExportError>
+    where
+        F: Fn(&ExportRow) -> Option<f64>,
+    {
+        self.write::<DoubleType, _>(rows.iter().map(f))
+    }
+
+    fn write<D, I>(&mut self, values: I) -> Result<(), ExportError>
+    where
+        D: DataType,
+        I: Iterator<Item = Option<D::T>>,
+    {
+        let name = EXPORT_COLUMNS.get(self.index).copied().unwrap_or_default();
+        self.index += 1;
+        let mut column = self
+            .row_group
+            .next_column()?
+            .ok_or(ExportError::MissingColumn(name))?;
+
+        let mut data = Vec::new();
+        let mut def_levels = Vec::new();
+        for value in values {
+            def_levels.push(i16::from(value.is_some()));
+            if let Some(value) = value {
+                data.push(value);
+            }
+        }
+        let writer = column.typed::<D>();
+        // Required columns do not have definition levels
+        let def_levels = (writer.get_descriptor().max_def_level() > 0).then_some(&def_levels[..]);
+        writer.write_batch(&data, def_levels, None)?;
+        column.close()?;
+        Ok(())
+    }
+}
+
+fn timestamp_millis(date_time: DateTime) -> i64 {
+    date_time.into_inner().timestamp_millis()
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::{
+            alert::AlertStatus, boundary::BoundaryLimit, head::VersionNumber, report::Iteration,
+        },
+        DateTime, ModelTest,
+    };
+    use bytes::Bytes;
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::Field,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::{ExportEncoder, ExportRow, EXPORT_COLUMNS};
+
+    const UUID: &str = "00000000-0000-0000-0000-000000000000";
+
+    fn row(value: f64) -> ExportRow {
+        ExportRow {
+            branch_uuid: UUID.parse().unwrap(),
+            branch_name: "main".parse().unwrap(),
+            head_uuid: UUID.parse().unwrap(),
+            testbed_uuid: UUID.parse().unwrap(),
+            testbed_name: "localhost".parse().unwrap(),
+            benchmark_uuid: UUID.parse().unwrap(),
+            benchmark_name: "bench, \"quoted\"".parse().unwrap(),
+            measure_uuid: UUID.parse().unwrap(),
+            measure_name: "Latency".parse().unwrap(),
+            measure_units: "nanoseconds (ns)".parse().unwrap(),
+            version_number: VersionNumber(7),
+            version_hash: None,
+            report_uuid: UUID.parse().unwrap(),
+            report_start_time: DateTime::try_from(1_700_000_000).unwrap(),
+            report_end_time: DateTime::try_from(1_700_000_060).unwrap(),
+            iteration: Iteration(0),
+            metric_uuid: UUID.parse().unwrap(),
+            value,
+            lower_value: Some(value - 1.0),
+            upper_value: None,
+            threshold_uuid: Some(UUID.parse().unwrap()),
+            threshold_model: Some(ModelTest::TTest),
+            boundary_uuid: Some(UUID.parse().unwrap()),
+            baseline: Some(value),
+            lower_limit: None,
+            upper_limit: Some(value + 1.0),
+            alert_uuid: Some(UUID.parse().unwrap()),
+            alert_limit: Some(BoundaryLimit::Upper),
+            alert_status: Some(AlertStatus::Active),
+        }
+    }
+
+    fn encode(mut encoder: ExportEncoder, pages: &[&[ExportRow]]) -> Vec<u8> {
+        let mut bytes = encoder.start().unwrap().to_vec();
+        for page in pages {
+            bytes.extend(encoder.encode(page).unwrap());
+        }
+        bytes.extend(encoder.finish().unwrap());
+        bytes
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let csv = encode(ExportEncoder::csv(), &[&[row(1.0)], &[row(2.0)]]);
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next().unwrap(), EXPORT_COLUMNS.join(","));
+        let uuid = UUID;
+        for value in [1.0, 2.0] {
+            assert_eq!(
+                lines.next().unwrap(),
+                format!(
+                    "{uuid},main,{uuid},{uuid},localhost,{uuid},\"bench, \"\"quoted\"\"\",{uuid},Latency,nanoseconds (ns),7,,{uuid},2023-11-14T22:13:20Z,2023-11-14T22:14:20Z,0,{uuid},{value:?},{lower:?},,{uuid},t_test,{uuid},{value:?},,{upper:?},{uuid},upper,active",
+                    lower = value - 1.0,
+                    upper = value + 1.0,
+                )
+            );
+        }
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_export_parquet() {
+        let parquet = encode(
+            ExportEncoder::parquet().unwrap(),
+            &[&[row(1.0), row(2.0)], &[row(3.0)]],
+        );
+        let reader = SerializedFileReader::new(Bytes::from(parquet)).unwrap();
+        // Each page is its own row group
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+
+        let rows = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+        for (row, value) in rows.iter().zip([1.0, 2.0, 3.0]) {
+            let columns = row
+                .get_column_iter()
+                .map(|(name, field)| (name.as_str(), field.clone()))
+                .collect::<Vec<_>>();
+            let names = columns.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            assert_eq!(names, EXPORT_COLUMNS);
+
+            let field = |name: &str| {
+                columns
+                    .iter()
+                    .find_map(|(column, field)| (*column == name).then(|| field.clone()))
+                    .unwrap()
+            };
+            assert_eq!(field("branch_name"), Field::Str("main".to_owned()));
+            assert_eq!(
+                field("benchmark_name"),
+                Field::Str("bench, \"quoted\"".to_owned())
+            );
+            assert_eq!(field("version_number"), Field::Long(7));
+            assert_eq!(field("version_hash"), Field::Null);
+            assert_eq!(
+                field("report_start_time"),
+                Field::TimestampMillis(1_700_000_000_000)
+            );
+            assert_eq!(field("iteration"), Field::Long(0));
+            assert_eq!(field("value"), Field::Double(value));
+            assert_eq!(field("lower_value"), Field::Double(value - 1.0));
+            assert_eq!(field("upper_value"), Field::Null);
+            assert_eq!(field("threshold_model"), Field::Str("t_test".to_owned()));
+            assert_eq!(field("alert_limit"), Field::Str("upper".to_owned()));
+            assert_eq!(field("alert_status"), Field::Str("active".to_owned()));
+        }
+    }
+}
@@ -0,0 +1,793 @@
+use std::sync::Arc;
+
+use bencher_json::{
+    project::{
+        alert::AlertStatus,
+        boundary::BoundaryLimit,
+        head::VersionNumber,
+        perf::{JsonPerfExportQuery, JsonPerfExportQueryParams, PerfExportFormat},
+        report::Iteration,
+    },
+    AlertUuid, BenchmarkName, BenchmarkUuid, BoundaryUuid, BranchName, BranchUuid, DateTime,
+    GitHash, HeadUuid, MeasureUuid, MetricUuid, ModelTest, ReportUuid, ResourceName, TestbedUuid,
+    ThresholdUuid,
+};
+use bytes::Bytes;
+use diesel::{ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, Body, HttpError, Path, Query, RequestContext};
+use http::Response;
+use http_body::Frame;
+use http_body_util::StreamBody;
+use slog::{error, Logger};
+use tokio::sync::{mpsc, Mutex};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    endpoints::{
+        endpoint::{CorsResponse, Get},
+        Endpoint,
+    },
+    error::{bad_request_error, issue_error},
+    model::{
+        project::{
+            branch::{
+                head::{HeadId, QueryHead},
+                QueryBranch,
+            },
+            metric::MetricId,
+            QueryProject,
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema, view,
+};
+
+use super::ProjPerfParams;
+
+mod encode;
+
+use encode::{ExportEncoder, ExportError, ExportRow};
+
+/// The number of metrics to query and encode at a time.
+/// For Parquet, each page is also a row group.
+const PAGE_SIZE: i64 = 1024;
+/// The number of encoded pages to buffer before waiting on the client.
+const CHANNEL_CAPACITY: usize = 4;
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/export",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_export_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfExportQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Export project performance metrics
+///
+/// Export every performance metric for a project as either a CSV or an Apache Parquet file.
+/// Each row is a single metric, along with its branch, branch head, testbed, benchmark, measure,
+/// version, report, iteration, and any boundary and alert for the metric.
+/// The metrics can optionally be filtered by branch, testbed, benchmark, measure, and date time.
+/// If branches are provided, then every metric for each branch head is exported,
+/// including those from the start point of the branch head.
+/// Otherwise, every metric in the project is exported once, for the branch head that created it.
+/// The metrics are queried one page at a time and streamed to the client.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/export",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_export_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfExportQueryParams>,
+) -> Result<Response<Body>, HttpError> {
+    // Second round of marshaling
+    let json_perf_export_query: JsonPerfExportQuery = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let context = rqctx.context();
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.into_inner().project,
+        auth_user.as_ref(),
+    )?;
+
+    let format = json_perf_export_query.format();
+    let export = Export::new(conn_lock!(context), &project, json_perf_export_query)?;
+    let encoder = match format {
+        PerfExportFormat::Csv => Ok(ExportEncoder::csv()),
+        PerfExportFormat::Parquet => ExportEncoder::parquet(),
+    }
+    .map_err(|e| {
+        issue_error(
+            "Failed to create perf export encoder",
+            &format!("Failed to create perf export encoder for project ({project:?}): {format:?}"),
+            e,
+        )
+    })?;
+    let body = export.stream(
+        rqctx.log.clone(),
+        context.database.connection.clone(),
+        encoder,
+    );
+
+    Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, format.content_type())
+        .header(
+            http::header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"{}.{}\"",
+                project.slug,
+                format.extension()
+            ),
+        )
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(body)
+        .map_err(Into::into)
+}
+
+struct Export {
+    project: QueryProject,
+    heads: Vec<ExportHead>,
+    testbeds: Vec<TestbedUuid>,
+    benchmarks: Vec<BenchmarkUuid>,
+    measures: Vec<MeasureUuid>,
+    start_time: Option<DateTime>,
+    end_time: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ExportHead {
+    // Every metric in the project, exported once for the branch head that created it
+    All,
+    // Every metric for the branch head, including those from its start point
+    Head(HeadId),
+}
+
+type ExportQuery = (
+    (
+        BranchUuid,
+        BranchName,
+        HeadUuid,
+        TestbedUuid,
+        ResourceName,
+        BenchmarkUuid,
+        BenchmarkName,
+        MeasureUuid,
+        ResourceName,
+        ResourceName,
+    ),
+    (
+        VersionNumber,
+        Option<GitHash>,
+        ReportUuid,
+        DateTime,
+        DateTime,
+        Iteration,
+    ),
+    (MetricId, MetricUuid, f64, Option<f64>, Option<f64>),
+    (
+        Option<ThresholdUuid>,
+        Option<ModelTest>,
+        Option<BoundaryUuid>,
+        Option<f64>,
+        Option<f64>,
+        Option<f64>,
+    ),
+    (
+        Option<AlertUuid>,
+        Option<BoundaryLimit>,
+        Option<AlertStatus>,
+    ),
+);
+
+impl Export {
+    fn new(
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        json_perf_export_query: JsonPerfExportQuery,
+    ) -> Result<Self, HttpError> {
+        let JsonPerfExportQuery {
+            format: _,
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+        } = json_perf_export_query;
+
+        let heads = if branches.is_empty() {
+            vec![ExportHead::All]
+        } else {
+            branches
+                .into_iter()
+                .zip(heads)
+                .map(|(branch_uuid, head_uuid)| {
+                    let branch = QueryBranch::from_uuid(conn, project.id, branch_uuid)?;
+                    let head = if let Some(head_uuid) = head_uuid {
+                        let head = QueryHead::from_uuid(conn, project.id, head_uuid)?;
+                        if head.branch_id != branch.id {
+                            return Err(bad_request_error(format!(
+                                "Branch head ({head_uuid}) does not belong to branch ({branch_uuid})"
+                            )));
+                        }
+                        head
+                    } else {
+                        branch.head(conn)?
+                    };
+                    Ok(ExportHead::Head(head.id))
+                })
+                .collect::<Result<Vec<_>, HttpError>>()?
+        };
+
+        Ok(Self {
+            project: project.clone(),
+            heads,
+            testbeds,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+        })
+    }
+
+    // Stream the export to the client one page at a time,
+    // so that the entire export is never held in memory.
+    fn stream(
+        self,
+        log: Logger,
+        connection: Arc<Mutex<DbConnection>>,
+        encoder: ExportEncoder,
+    ) -> Body {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        tokio::spawn(async move {
+            if let Err(e) = self.send(&connection, encoder, &tx).await {
+                let project = self.project.uuid;
+                error!(log, "Failed to export perf for project ({project}): {e}");
+                // Abort the response body so that the client does not receive a truncated file
+                drop(tx.send(Err(e)).await);
+            }
+        });
+        Body::wrap(StreamBody::new(ReceiverStream::new(rx)))
+    }
+
+    async fn send(
+        &self,
+        connection: &Mutex<DbConnection>,
+        mut encoder: ExportEncoder,
+        tx: &mpsc::Sender<Result<Frame<Bytes>, ExportError>>,
+    ) -> Result<(), ExportError> {
+        if !send_frame(tx, encoder.start()?).await {
+            return Ok(());
+        }
+        for &head in &self.heads {
+            let mut after = None;
+            loop {
+                // Acquire the lock on the database connection for every page.
+                // This allows other requests to use the database between pages.
+                let page = self.page(&mut *connection.lock().await, head, after)?;
+                let Some((_, _, (last_id, ..), _, _)) = page.last() else {
+                    break;
+                };
+                after = Some(*last_id);
+                let is_last_page = i64::try_from(page.len()).unwrap_or(i64::MAX) < PAGE_SIZE;
+                let rows = page.into_iter().map(into_export_row).collect::<Vec<_>>();
+                if !send_frame(tx, encoder.encode(&rows)?).await {
+                    return Ok(());
+                }
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+        send_frame(tx, encoder.finish()?).await;
+        Ok(())
+    }
+
+    // Use keyset pagination on the metric ID,
+    // so that each page is as fast to query as the first.
+    #[allow(clippy::too_many_lines)]
+    fn page(
+        &self,
+        conn: &mut DbConnection,
+        head: ExportHead,
+        after: Option<MetricId>,
+    ) -> Result<Vec<ExportQuery>, ExportError> {
+        let mut query = view::metric_boundary::table
+            .inner_join(
+                schema::report_benchmark::table
+                    .inner_join(
+                        schema::report::table
+                            .inner_join(
+                                schema::version::table.inner_join(
+                                    schema::head_version::table
+                                        .inner_join(
+                                            schema::head::table.on(schema::head_version::head_id
+                                                .eq(schema::head::id)),
+                                        )
+                                        .inner_join(
+                                            schema::branch::table
+                                                .on(schema::head::branch_id.eq(schema::branch::id)),
+                                        ),
+                                ),
+                            )
+                            .inner_join(schema::testbed::table),
+                    )
+                    .inner_join(schema::benchmark::table),
+            )
+            .inner_join(schema::measure::table)
+            // Make sure that the project is the same for all dimensions
+            .filter(schema::branch::project_id.eq(self.project.id))
+            .filter(schema::testbed::project_id.eq(self.project.id))
+            .filter(schema::benchmark::project_id.eq(self.project.id))
+            .filter(schema::measure::project_id.eq(self.project.id))
+            // There may or may not be a boundary for any given metric
+            .left_join(schema::threshold::table)
+            .left_join(schema::model::table)
+            // There may or may not be an alert for any given boundary
+            .left_join(
+                schema::alert::table.on(view::metric_boundary::boundary_id
+                    .eq(schema::alert::boundary_id.nullable())),
+            )
+            .into_boxed();
+
+        query = match head {
+            // A version may belong to multiple branch heads through the `head_version` table,
+            // so only use the branch head that created the report.
+            ExportHead::All => query.filter(schema::report::head_id.eq(schema::head::id)),
+            ExportHead::Head(head_id) => query.filter(schema::head::id.eq(head_id)),
+        };
+        if !self.testbeds.is_empty() {
+            query = query.filter(schema::testbed::uuid.eq_any(&self.testbeds));
+        }
+        if !self.benchmarks.is_empty() {
+            query = query.filter(schema::benchmark::uuid.eq_any(&self.benchmarks));
+        }
+        if !self.measures.is_empty() {
+            query = query.filter(schema::measure::uuid.eq_any(&self.measures));
+        }
+        if let Some(start_time) = self.start_time {
+            query = query.filter(schema::report::start_time.ge(start_time));
+        }
+        if let Some(end_time) = self.end_time {
+            query = query.filter(schema::report::end_time.le(end_time));
+        }
+        if let Some(after) = after {
+            query = query.filter(view::metric_boundary::metric_id.gt(after));
+        }
+
+        query
+            .order(view::metric_boundary::metric_id)
+            .limit(PAGE_SIZE)
+            .select((
+                (
+                    schema::branch::uuid,
+                    schema::branch::name,
+                    schema::head::uuid,
+                    schema::testbed::uuid,
+                    schema::testbed::name,
+                    schema::benchmark::uuid,
+                    schema::benchmark::name,
+                    schema::measure::uuid,
+                    schema::measure::name,
+                    schema::measure::units,
+                ),
+                (
+                    schema::version::number,
+                    schema::version::hash,
+                    schema::report::uuid,
+                    schema::report::start_time,
+                    schema::report::end_time,
+                    schema::report_benchmark::iteration,
+                ),
+                (
+                    view::metric_boundary::metric_id,
+                    view::metric_boundary::metric_uuid,
+                    view::metric_boundary::value,
+                    view::metric_boundary::lower_value,
+                    view::metric_boundary::upper_value,
+                ),
+                (
+                    schema::threshold::uuid.nullable(),
+                    schema::model::test.nullable(),
+                    view::metric_boundary::boundary_uuid,
+                    view::metric_boundary::baseline,
+                    view::metric_boundary::lower_limit,
+                    view::metric_boundary::upper_limit,
+                ),
+                (
+                    schema::alert::uuid.nullable(),
+                    schema::alert::boundary_limit.nullable(),
+                    schema::alert::status.nullable(),
+                ),
+            ))
+            .load::<ExportQuery>(conn)
+            .map_err(Into::into)
+    }
+}
+
+// Returns `false` if the client has disconnected.
+async fn send_frame(tx: &mpsc::Sender<Result<Frame<Bytes>, ExportError>>, bytes: Bytes) -> bool {
+    if bytes.is_empty() {
+        return true;
+    }
+    tx.send(Ok(Frame::data(bytes))).await.is_ok()
+}
+
+fn into_export_row(query: ExportQuery) -> ExportRow {
+    let (
+        (
+            branch_uuid,
+            branch_name,
+            head_uuid,
+            testbed_uuid,
+            testbed_name,
+            benchmark_uuid,
+            benchmark_name,
+            measure_uuid,
+            measure_name,
+            measure_units,
+        ),
+        (version_number, version_hash, report_uuid, report_start_time, report_end_time, iteration),
+        (_metric_id, metric_uuid, value, lower_value, upper_value),
+        (threshold_uuid, threshold_model, boundary_uuid, baseline, lower_limit, upper_limit),
+        (alert_uuid, alert_limit, alert_status),
+    ) = query;
+    ExportRow {
+        branch_uuid,
+        branch_name,
+        head_uuid,
+        testbed_uuid,
+        testbed_name,
+        benchmark_uuid,
+        benchmark_name,
+        measure_uuid,
+        measure_name,
+        measure_units,
+        version_number,
+        version_hash,
+        report_uuid,
+        report_start_time,
+        report_end_time,
+        iteration,
+        metric_uuid,
+        value,
+        lower_value,
+        upper_value,
+        threshold_uuid,
+        threshold_model,
+        boundary_uuid,
+        baseline,
+        lower_limit,
+        upper_limit,
+        alert_uuid,
+        alert_limit,
+        alert_status,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::perf::JsonPerfExportQuery, BenchmarkUuid, BranchUuid, DateTime, HeadUuid,
+        MeasureUuid, TestbedUuid,
+    };
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+    use pretty_assertions::assert_eq;
+    use tokio::sync::{mpsc, Mutex};
+
+    use crate::{
+        context::DbConnection,
+        model::project::{
+            branch::{head::HeadId, BranchId},
+            QueryProject,
+        },
+        schema,
+        util::test_db::{add_head_version, test_conn, TestProject},
+    };
+
+    use super::{
+        encode::{ExportEncoder, EXPORT_COLUMNS},
+        Export, PAGE_SIZE,
+    };
+
+    struct Branches {
+        project: TestProject,
+        main: (BranchId, HeadId),
+        feature: (BranchId, HeadId),
+    }
+
+    // Main has versions 1 to 3.
+    // Feature starts from main version 2 and then has its own version 3.
+    fn setup(conn: &mut DbConnection) -> Branches {
+        let project = TestProject::new(conn, "project");
+        let main = project.branch(conn, "main", None);
+        let mut start_point = Vec::new();
+        for number in 1..=3 {
+            let (version_id, head_version_id) = project.version(conn, main.1, number);
+            let report_id = project.report(conn, main.1, version_id, at(number));
+            project.metric(conn, report_id, f64::from(number));
+            if number <= 2 {
+                start_point.push((version_id, head_version_id));
+            }
+        }
+
+        let (_, start_point_id) = *start_point.last().unwrap();
+        let feature = project.branch(conn, "feature", Some(start_point_id));
+        // Cloning a branch from its start point shares the start point versions.
+        for &(version_id, _) in &start_point {
+            add_head_version(conn, feature.1, version_id);
+        }
+        let (version_id, _) = project.version(conn, feature.1, 3);
+        let report_id = project.report(conn, feature.1, version_id, at(4));
+        project.metric(conn, report_id, 30.0);
+
+        Branches {
+            project,
+            main,
+            feature,
+        }
+    }
+
+    fn at(minutes: u32) -> DateTime {
+        DateTime::try_from(1_700_000_000 + i64::from(minutes) * 60).unwrap()
+    }
+
+    fn query() -> JsonPerfExportQuery {
+        JsonPerfExportQuery {
+            format: None,
+            branches: Vec::new(),
+            heads: Vec::new(),
+            testbeds: Vec::new(),
+            benchmarks: Vec::new(),
+            measures: Vec::new(),
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    fn branch_uuid(conn: &mut DbConnection, branch_id: BranchId) -> BranchUuid {
+        schema::branch::table
+            .filter(schema::branch::id.eq(branch_id))
+            .select(schema::branch::uuid)
+            .first(conn)
+            .unwrap()
+    }
+
+    fn head_uuid(conn: &mut DbConnection, head_id: HeadId) -> HeadUuid {
+        schema::head::table
+            .filter(schema::head::id.eq(head_id))
+            .select(schema::head::uuid)
+            .first(conn)
+            .unwrap()
+    }
+
+    fn export(
+        conn: &mut DbConnection,
+        project: &TestProject,
+        json_perf_export_query: JsonPerfExportQuery,
+    ) -> Export {
+        let query_project = QueryProject::get(conn, project.project_id).unwrap();
+        Export::new(conn, &query_project, json_perf_export_query).unwrap()
+    }
+
+    async fn export_csv(
+        connection: &Mutex<DbConnection>,
+        project: &TestProject,
+        json_perf_export_query: JsonPerfExportQuery,
+    ) -> Vec<csv::StringRecord> {
+        let export = export(
+            &mut *connection.lock().await,
+            project,
+            json_perf_export_query,
+        );
+        // Buffer every page, so the export does not wait on the receiver.
+        let (tx, mut rx) = mpsc::channel(PAGE_SIZE.try_into().unwrap());
+        export
+            .send(connection, ExportEncoder::csv(), &tx)
+            .await
+            .unwrap();
+        drop(tx);
+
+        let mut csv = Vec::new();
+        while let Some(frame) = rx.recv().await {
+            csv.extend(frame.unwrap().into_data().unwrap());
+        }
+        let mut reader = csv::Reader::from_reader(csv.as_slice());
+        assert_eq!(reader.headers().unwrap(), EXPORT_COLUMNS.as_slice());
+        reader.records().map(Result::unwrap).collect()
+    }
+
+    fn column<'r>(records: &'r [csv::StringRecord], name: &str) -> Vec<&'r str> {
+        let index = EXPORT_COLUMNS
+            .iter()
+            .position(|column| *column == name)
+            .unwrap();
+        records
+            .iter()
+            .map(|record| record.get(index).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_export_all() {
+        let connection = Mutex::new(test_conn());
+        let Branches { project, .. } = setup(&mut *connection.lock().await);
+
+        // Every metric is exported once, for the branch head that created it.
+        let records = export_csv(&connection, &project, query()).await;
+        assert_eq!(column(&records, "value"), vec!["1.0", "2.0", "3.0", "30.0"]);
+        assert_eq!(
+            column(&records, "branch_name"),
+            vec!["main", "main", "main", "feature"]
+        );
+        assert_eq!(column(&records, "version_number"), vec!["1", "2", "3", "3"]);
+        assert_eq!(column(&records, "measure_name"), vec!["latency"; 4]);
+        assert_eq!(column(&records, "threshold_uuid"), vec![""; 4]);
+    }
+
+    #[tokio::test]
+    async fn test_export_head_start_point() {
+        let connection = Mutex::new(test_conn());
+        let Branches {
+            project,
+            main,
+            feature,
+        } = setup(&mut *connection.lock().await);
+
+        // The feature branch head includes the metrics from its start point.
+        let json_perf_export_query = JsonPerfExportQuery {
+            branches: vec![branch_uuid(&mut *connection.lock().await, feature.0)],
+            heads: vec![None],
+            ..query()
+        };
+        let records = export_csv(&connection, &project, json_perf_export_query).await;
+        assert_eq!(column(&records, "value"), vec!["1.0", "2.0", "30.0"]);
+        assert_eq!(column(&records, "branch_name"), vec!["feature"; 3]);
+        assert_eq!(column(&records, "version_number"), vec!["1", "2", "3"]);
+
+        // Each branch head is exported in order.
+        let json_perf_export_query = {
+            let conn = &mut *connection.lock().await;
+            JsonPerfExportQuery {
+                branches: vec![branch_uuid(conn, main.0), branch_uuid(conn, feature.0)],
+                heads: vec![Some(head_uuid(conn, main.1)), None],
+                ..query()
+            }
+        };
+        let records = export_csv(&connection, &project, json_perf_export_query).await;
+        assert_eq!(
+            column(&records, "value"),
+            vec!["1.0", "2.0", "3.0", "1.0", "2.0", "30.0"]
+        );
+    }
+
+    #[test]
+    fn test_export_head_wrong_branch() {
+        let mut conn = test_conn();
+        let Branches {
+            project,
+            main,
+            feature,
+        } = setup(&mut conn);
+        let query_project = QueryProject::get(&mut conn, project.project_id).unwrap();
+
+        let json_perf_export_query = JsonPerfExportQuery {
+            branches: vec![branch_uuid(&mut conn, feature.0)],
+            heads: vec![Some(head_uuid(&mut conn, main.1))],
+            ..query()
+        };
+        let result = Export::new(&mut conn, &query_project, json_perf_export_query);
+        assert!(result.is_err(), "Head does not belong to the branch");
+    }
+
+    #[tokio::test]
+    async fn test_export_filters() {
+        let connection = Mutex::new(test_conn());
+        let (project, testbed_uuid, benchmark_uuid, measure_uuid) = {
+            let conn = &mut *connection.lock().await;
+            let Branches { project, main, .. } = setup(conn);
+            // Version 4 on main has a metric for every other testbed, benchmark, and measure.
+            let mut other = project;
+            other.testbed_id = project.testbed(conn, "other-testbed");
+            other.benchmark_id = project.benchmark(conn, "other-benchmark");
+            other.measure_id = project.measure(conn, "other-measure");
+            let (version_id, _) = project.version(conn, main.1, 4);
+            let report_id = other.report(conn, main.1, version_id, at(5));
+            other.metric(conn, report_id, 40.0);
+            (
+                project,
+                schema::testbed::table
+                    .filter(schema::testbed::id.eq(other.testbed_id))
+                    .select(schema::testbed::uuid)
+                    .first::<TestbedUuid>(conn)
+                    .unwrap(),
+                schema::benchmark::table
+                    .filter(schema::benchmark::id.eq(other.benchmark_id))
+                    .select(schema::benchmark::uuid)
+                    .first::<BenchmarkUuid>(conn)
+                    .unwrap(),
+                schema::measure::table
+                    .filter(schema::measure::id.eq(other.measure_id))
+                    .select(schema::measure::uuid)
+                    .first::<MeasureUuid>(conn)
+                    .unwrap(),
+            )
+        };
+
+        let records = export_csv(&connection, &project, query()).await;
+        assert_eq!(records.len(), 5);
+
+        for json_perf_export_query in [
+            JsonPerfExportQuery {
+                testbeds: vec![testbed_uuid],
+                ..query()
+            },
+            JsonPerfExportQuery {
+                benchmarks: vec![benchmark_uuid],
+                ..query()
+            },
+            JsonPerfExportQuery {
+                measures: vec![measure_uuid],
+                ..query()
+            },
+        ] {
+            let records = export_csv(&connection, &project, json_perf_export_query).await;
+            assert_eq!(column(&records, "value"), vec!["40.0"]);
+            assert_eq!(column(&records, "testbed_name"), vec!["other-testbed"]);
+            assert_eq!(column(&records, "benchmark_name"), vec!["other-benchmark"]);
+            assert_eq!(column(&records, "measure_name"), vec!["other-measure"]);
+        }
+
+        let json_perf_export_query = JsonPerfExportQuery {
+            start_time: Some(at(2)),
+            end_time: Some(at(4)),
+            ..query()
+        };
+        let records = export_csv(&connection, &project, json_perf_export_query).await;
+        assert_eq!(column(&records, "value"), vec!["2.0", "3.0", "30.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_pages() {
+        let connection = Mutex::new(test_conn());
+        let count = u32::try_from(PAGE_SIZE).unwrap() * 2 + 1;
+        let project = {
+            let conn = &mut *connection.lock().await;
+            let project = TestProject::new(conn, "project");
+            let (_, head_id) = project.branch(conn, "main", None);
+            for number in 1..=count {
+                let (version_id, _) = project.version(conn, head_id, number);
+                let report_id = project.report(conn, head_id, version_id, at(number));
+                project.metric(conn, report_id, f64::from(number));
+            }
+            project
+        };
+
+        // Every metric is exported exactly once and in order, across all of the pages.
+        let records = export_csv(&connection, &project, query()).await;
+        let versions = (1..=count)
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(column(&records, "version_number"), versions);
+    }
+}
@@ -2,7 +2,7 @@ use bencher_json::{
     project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams},
     JsonPerfQuery,
 };
-use bencher_plot::LinePlot;
+use bencher_plot::{LinePlot, PlotError};
 use dropshot::{endpoint, Body, HttpError, Path, Query, RequestContext};
 use http::Response;
 
@@ -38,6 +38,9 @@ pub async fn proj_perf_img_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 8 permutations for a single image.
 /// Therefore, only the first 8 permutations are plotted.
+/// The x-axis can either be the date and time of each report or the version (commit) order.
+/// The image can be generated as a JPEG, PNG, or SVG with either a light or dark theme.
+/// Boundary limits can optionally be displayed as shaded bands, with markers for any active alerts.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -53,6 +56,7 @@ pub async fn proj_perf_img_get(
 ) -> Result<Response<Body>, HttpError> {
     let mut json_perf_img_query_params = query_params.into_inner();
     let title = json_perf_img_query_params.title.take();
+    let line_plot = line_plot(&json_perf_img_query_params).map_err(bad_request_error)?;
     let json_perf_query_params: JsonPerfQueryParams = json_perf_img_query_params.into();
     // Second round of marshaling
     let json_perf_query = json_perf_query_params
@@ -60,9 +64,10 @@ pub async fn proj_perf_img_get(
         .map_err(bad_request_error)?;
 
     let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
-    let jpeg = get_inner(
+    let img = get_inner(
         rqctx.context(),
         path_params.into_inner(),
+        &line_plot,
         title.as_deref(),
         json_perf_query,
         auth_user.as_ref(),
@@ -71,21 +76,44 @@ pub async fn proj_perf_img_get(
 
     Response::builder()
         .status(http::StatusCode::OK)
-        .header(http::header::CONTENT_TYPE, "image/jpeg")
+        .header(http::header::CONTENT_TYPE, line_plot.content_type())
         .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
-        .body(jpeg.into())
+        .body(img.into())
         .map_err(Into::into)
 }
 
+fn line_plot(json_perf_img_query_params: &JsonPerfImgQueryParams) -> Result<LinePlot, PlotError> {
+    let JsonPerfImgQueryParams {
+        x_axis,
+        format,
+        width,
+        height,
+        theme,
+        lower_boundary,
+        upper_boundary,
+        ..
+    } = json_perf_img_query_params;
+    Ok(LinePlot::new()
+        .with_size(*width, *height)?
+        .with_format(format.unwrap_or_default())
+        .with_theme(theme.unwrap_or_default())
+        .with_x_axis(x_axis.unwrap_or_default())
+        .with_boundaries(
+            lower_boundary.unwrap_or_default(),
+            upper_boundary.unwrap_or_default(),
+        ))
+}
+
 async fn get_inner(
     context: &ApiContext,
     path_params: ProjPerfParams,
+    line_plot: &LinePlot,
     title: Option<&str>,
     json_perf_query: JsonPerfQuery,
     auth_user: Option<&AuthUser>,
 ) -> Result<Vec<u8>, HttpError> {
     let json_perf = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
-    LinePlot::new().draw(title, &json_perf).map_err(|e| {
+    line_plot.draw(title, &json_perf).map_err(|e| {
         issue_error(
             "Failed to draw perf plot",
             &format!("Failed draw perf plot: {json_perf:?}"),
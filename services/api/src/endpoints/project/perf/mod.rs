@@ -3,6 +3,7 @@ use bencher_json::{
         alert::JsonPerfAlert,
         head::{JsonVersion, VersionNumber},
         perf::{JsonPerfMetric, JsonPerfMetrics, JsonPerfQueryParams},
+        plot::XAxis,
         report::Iteration,
         threshold::JsonThresholdModel,
     },
@@ -42,7 +43,10 @@ use crate::{
     schema, view,
 };
 
+pub mod compare;
+pub mod export;
 pub mod img;
+pub mod summary;
 
 const MAX_PERMUTATIONS: usize = 255;
 
@@ -123,6 +127,7 @@ async fn get_inner(
         measures,
         start_time,
         end_time,
+        x_axis,
     } = json_perf_query;
 
     let times = Times {
@@ -139,6 +144,7 @@ async fn get_inner(
         &benchmarks,
         &measures,
         times,
+        x_axis,
     )
     .await?;
 
@@ -166,6 +172,7 @@ async fn perf_results(
     benchmarks: &[BenchmarkUuid],
     measures: &[MeasureUuid],
     times: Times,
+    x_axis: Option<XAxis>,
 ) -> Result<Vec<JsonPerfMetrics>, HttpError> {
     let permutations = branches.len() * testbeds.len() * benchmarks.len() * measures.len();
     let gt_max_permutations = permutations > MAX_PERMUTATIONS;
@@ -194,6 +201,7 @@ async fn perf_results(
                         *benchmark_uuid,
                         *measure_uuid,
                         times,
+                        x_axis,
                     )
                     .await?;
 
@@ -233,6 +241,7 @@ async fn perf_query(
     benchmark_uuid: BenchmarkUuid,
     measure_uuid: MeasureUuid,
     times: Times,
+    x_axis: Option<XAxis>,
 ) -> Result<Vec<PerfQuery>, HttpError> {
     let mut query = view::metric_boundary::table
         .inner_join(
@@ -292,15 +301,17 @@ async fn perf_query(
         query = query.filter(schema::report::end_time.le(end_time));
     }
 
+    // Order by the version number so that the oldest version is first,
+    // unless the x-axis is the date time, in which case the oldest report is first.
+    if !matches!(x_axis, Some(XAxis::DateTime)) {
+        query = query.order(schema::version::number);
+    }
+
     let query = query
-        // Order by the version number so that the oldest version is first.
         // Because multiple reports can use the same version (via git hash), order by the start time next.
         // Then within a report order by the iteration number.
-        .order((
-            schema::version::number,
-            schema::report::start_time,
-            schema::report_benchmark::iteration,
-        ))
+        .then_order_by(schema::report::start_time)
+        .then_order_by(schema::report_benchmark::iteration)
         .select((
             QueryBranch::as_select(),
             QueryHead::as_select(),
@@ -335,6 +346,9 @@ async fn perf_query(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::baseline,
+                    schema::model::max_versions,
+                    schema::model::resolve_versions,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -344,6 +358,7 @@ async fn perf_query(
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
                     schema::alert::status,
+                    schema::alert::resolved_report_id,
                     schema::alert::modified,
                 ).nullable(),
             ).nullable(),
@@ -0,0 +1,125 @@
+use bencher_boundary::MetricsSummary;
+use bencher_json::{
+    project::perf::{
+        JsonMetricsSummary, JsonPerfMetrics, JsonPerfQueryParams, JsonPerfSummaryMetrics,
+    },
+    JsonPerf, JsonPerfSummary,
+};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    error::bad_request_error,
+    model::user::auth::{AuthUser, PubBearerToken},
+};
+
+use super::ProjPerfParams;
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/summary",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_summary_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Summarize project performance metrics
+///
+/// Summarize the performance metrics for a project.
+/// The query results are every permutation of each branch, testbed, benchmark, and measure.
+/// There is a limit of 255 permutations for a single request.
+/// Therefore, only the first 255 permutations are summarized.
+/// Each permutation includes the count, mean, median, standard deviation, and 5th and 95th percentiles of its metrics.
+/// It also includes the linear trend and the percent change between the first and last quartile of its metrics,
+/// in the same order as the `x_axis` for the query.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/summary",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_summary_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfQueryParams>,
+) -> Result<ResponseOk<JsonPerfSummary>, HttpError> {
+    // Second round of marshaling
+    let json_perf_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let JsonPerf {
+        project,
+        start_time,
+        end_time,
+        results,
+    } = super::get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_perf_query,
+        auth_user.as_ref(),
+    )
+    .await?;
+
+    let json = JsonPerfSummary {
+        project,
+        start_time,
+        end_time,
+        results: results.into_iter().filter_map(summarize).collect(),
+    };
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+fn summarize(perf_metrics: JsonPerfMetrics) -> Option<JsonPerfSummaryMetrics> {
+    let JsonPerfMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        metrics,
+    } = perf_metrics;
+    let data = metrics
+        .iter()
+        .map(|metric| metric.metric.value.into())
+        .collect::<Vec<f64>>();
+    let MetricsSummary {
+        count,
+        mean,
+        median,
+        std_dev,
+        p5,
+        p95,
+        trend,
+        change,
+    } = MetricsSummary::new(&data)?;
+    Some(JsonPerfSummaryMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        summary: JsonMetricsSummary {
+            count: u32::try_from(count).unwrap_or(u32::MAX),
+            mean: mean.into(),
+            median: median.into(),
+            std_dev: std_dev.map(Into::into),
+            p5: p5.into(),
+            p95: p95.into(),
+            trend: trend.map(Into::into),
+            change: change.map(Into::into),
+        },
+    })
+}
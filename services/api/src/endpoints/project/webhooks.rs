@@ -0,0 +1,486 @@
+use bencher_json::{
+    project::webhook::JsonUpdateWebhook, JsonDirection, JsonNewWebhook, JsonPagination,
+    JsonWebhook, JsonWebhookDeliveries, JsonWebhooks, ResourceId, WebhookEvent, WebhookUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{
+            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+        },
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            webhook::{delivery::QueryWebhookDelivery, InsertWebhook, QueryWebhook, UpdateWebhook},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+    util::headers::TotalCount,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjWebhooksPagination = JsonPagination<ProjWebhooksSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhooksSort {
+    /// Sort by webhook creation date time.
+    #[default]
+    Created,
+    /// Sort by webhook URL.
+    Url,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhooksParams>,
+    _pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// The user must have `manage` permissions for the project.
+/// By default, the webhooks are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of webhooks.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhooksParams,
+    pagination_params: ProjWebhooksPagination,
+) -> Result<(JsonWebhooks, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let webhooks = get_ls_query(&query_project, &pagination_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?;
+
+    let json_webhooks = webhooks
+        .into_iter()
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .collect::<Vec<_>>();
+
+    let total_count = get_ls_query(&query_project, &pagination_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_webhooks.into(), total_count))
+}
+
+fn get_ls_query<'q>(
+    query_project: &'q QueryProject,
+    pagination_params: &ProjWebhooksPagination,
+) -> schema::webhook::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+    let query = QueryWebhook::belonging_to(&query_project).into_boxed();
+
+    match pagination_params.order() {
+        ProjWebhooksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::webhook::created.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::webhook::created.desc()),
+        },
+        ProjWebhooksSort::Url => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::webhook::url.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::webhook::url.desc()),
+        },
+    }
+}
+
+/// Create a webhook
+///
+/// Create a webhook for a project.
+/// The webhook will be sent a signed JSON payload for each event that it is subscribed to.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let insert_webhook = InsertWebhook::from_json(query_project.id, json_webhook)?;
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, insert_webhook))?;
+
+    schema::webhook::table
+        .filter(schema::webhook::uuid.eq(&insert_webhook.uuid))
+        .first::<QueryWebhook>(conn_lock!(context))
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Webhook, insert_webhook))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a webhook
+///
+/// View a webhook for a project.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+/// Update a webhook
+///
+/// Update a webhook for a project.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    json_webhook: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+    let update_webhook = UpdateWebhook::try_from(json_webhook.clone())?;
+    diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .set(&update_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (&query_webhook, &json_webhook)
+        ))?;
+
+    QueryWebhook::get(conn_lock!(context), query_webhook.id)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+/// Delete a webhook
+///
+/// Delete a webhook for a project.
+/// All of the deliveries for the webhook will also be deleted.
+/// The user must have `manage` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook))?;
+
+    Ok(())
+}
+
+pub type ProjWebhookDeliveriesPagination = JsonPagination<ProjWebhookDeliveriesSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhookDeliveriesSort {
+    /// Sort by delivery creation date time.
+    #[default]
+    Created,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjWebhookDeliveriesQuery {
+    /// Filter by webhook event.
+    pub event: Option<WebhookEvent>,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+    _pagination_params: Query<ProjWebhookDeliveriesPagination>,
+    _query_params: Query<ProjWebhookDeliveriesQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List deliveries for a webhook
+///
+/// List the delivery log for a webhook.
+/// Each delivery records the number of attempts made,
+/// along with the status code and error from the last attempt.
+/// The user must have `manage` permissions for the project.
+/// By default, the deliveries are sorted by creation date time in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of deliveries.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjWebhookParams>,
+    pagination_params: Query<ProjWebhookDeliveriesPagination>,
+    query_params: Query<ProjWebhookDeliveriesQuery>,
+) -> Result<ResponseOk<JsonWebhookDeliveries>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let (json, total_count) = get_deliveries_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_deliveries_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhookParams,
+    pagination_params: ProjWebhookDeliveriesPagination,
+    query_params: ProjWebhookDeliveriesQuery,
+) -> Result<(JsonWebhookDeliveries, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+
+    let deliveries = get_deliveries_query(&query_webhook, &pagination_params, &query_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhookDelivery>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params, &query_params)
+        ))?;
+
+    let json_deliveries = deliveries
+        .into_iter()
+        .map(|delivery| delivery.into_json_for_webhook(&query_webhook))
+        .collect::<Vec<_>>();
+
+    let total_count = get_deliveries_query(&query_webhook, &pagination_params, &query_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_deliveries.into(), total_count))
+}
+
+fn get_deliveries_query<'q>(
+    query_webhook: &'q QueryWebhook,
+    pagination_params: &ProjWebhookDeliveriesPagination,
+    query_params: &ProjWebhookDeliveriesQuery,
+) -> schema::webhook_delivery::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+    let mut query = QueryWebhookDelivery::belonging_to(query_webhook).into_boxed();
+
+    if let Some(event) = query_params.event {
+        query = query.filter(schema::webhook_delivery::event.eq(event));
+    }
+
+    match pagination_params.order() {
+        ProjWebhookDeliveriesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::webhook_delivery::created.asc()),
+            Some(JsonDirection::Desc) | None => {
+                query.order(schema::webhook_delivery::created.desc())
+            },
+        },
+    }
+}
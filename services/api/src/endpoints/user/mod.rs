@@ -1,2 +1,3 @@
+pub mod notifications;
 pub mod tokens;
 pub mod users;
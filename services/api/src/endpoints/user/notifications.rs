@@ -0,0 +1,110 @@
+use bencher_json::{user::notification::JsonUpdateNotifications, JsonNotifications, ResourceId};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, Patch, ResponseOk},
+        Endpoint,
+    },
+    model::user::{
+        auth::{AuthUser, BearerToken},
+        notification::QueryNotification,
+        same_user, QueryUser,
+    },
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct UserNotificationsParams {
+    /// The slug or UUID for a user.
+    pub user: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/users/{user}/notifications",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notifications_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<UserNotificationsParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+}
+
+/// View notification preferences for a user
+///
+/// View the notification preferences for a user.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+/// By default, all notifications are disabled.
+#[endpoint {
+    method = GET,
+    path =  "/v0/users/{user}/notifications",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notifications_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserNotificationsParams>,
+) -> Result<ResponseOk<JsonNotifications>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: UserNotificationsParams,
+    auth_user: &AuthUser,
+) -> Result<JsonNotifications, HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    QueryNotification::json_for_user(conn_lock!(context), &query_user)
+}
+
+/// Update notification preferences for a user
+///
+/// Update the notification preferences for a user.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+/// Opting in to the alert digest sends a daily or weekly email
+/// listing the new active alerts for all of the projects that the user is a member of.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/users/{user}/notifications",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notifications_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserNotificationsParams>,
+    body: TypedBody<JsonUpdateNotifications>,
+) -> Result<ResponseOk<JsonNotifications>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: UserNotificationsParams,
+    json_notifications: JsonUpdateNotifications,
+    auth_user: &AuthUser,
+) -> Result<JsonNotifications, HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    QueryNotification::upsert(conn_lock!(context), &query_user, &json_notifications)?;
+
+    QueryNotification::json_for_user(conn_lock!(context), &query_user)
+}
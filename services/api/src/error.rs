@@ -31,8 +31,13 @@ pub enum BencherResource {
     Model,
     Boundary,
     Alert,
+    AlertRule,
+    AlertRuleMeasure,
+    Webhook,
+    WebhookDelivery,
     User,
     Token,
+    Notification,
     #[cfg(feature = "plus")]
     Plan,
     #[cfg(feature = "plus")]
@@ -68,8 +73,13 @@ impl fmt::Display for BencherResource {
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::AlertRule => "Alert Rule",
+                Self::AlertRuleMeasure => "Alert Rule Measure",
+                Self::Webhook => "Webhook",
+                Self::WebhookDelivery => "Webhook Delivery",
                 Self::User => "User",
                 Self::Token => "Token",
+                Self::Notification => "Notification",
                 #[cfg(feature = "plus")]
                 Self::Plan => "Plan",
                 #[cfg(feature = "plus")]
@@ -0,0 +1,89 @@
+use bencher_json::JsonMeasure;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::project::{
+        measure::{MeasureId, QueryMeasure},
+        ProjectId, QueryProject,
+    },
+    schema::{self, alert_rule_measure as alert_rule_measure_table},
+};
+
+use super::{AlertRuleId, QueryAlertRule};
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = alert_rule_measure_table)]
+#[diesel(primary_key(alert_rule_id, measure_id))]
+#[diesel(belongs_to(QueryAlertRule, foreign_key = alert_rule_id))]
+pub struct QueryAlertRuleMeasure {
+    pub alert_rule_id: AlertRuleId,
+    pub measure_id: MeasureId,
+}
+
+impl QueryAlertRuleMeasure {
+    pub fn all_for_project(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+    ) -> Result<Vec<Self>, HttpError> {
+        alert_rule_measure_table::table
+            .inner_join(schema::alert_rule::table)
+            .filter(schema::alert_rule::project_id.eq(project_id))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(AlertRuleMeasure, project_id))
+    }
+
+    pub fn into_json_for_alert_rule(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        query_alert_rule: &QueryAlertRule,
+    ) -> Result<Vec<JsonMeasure>, HttpError> {
+        let measure_ids = Self::belonging_to(query_alert_rule)
+            .select(alert_rule_measure_table::measure_id)
+            .load::<MeasureId>(conn)
+            .map_err(resource_not_found_err!(AlertRuleMeasure, query_alert_rule))?;
+        Ok(schema::measure::table
+            .filter(schema::measure::id.eq_any(measure_ids))
+            .order(schema::measure::name.asc())
+            .load::<QueryMeasure>(conn)
+            .map_err(resource_not_found_err!(Measure, query_alert_rule))?
+            .into_iter()
+            .map(|measure| measure.into_json_for_project(query_project))
+            .collect())
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = alert_rule_measure_table)]
+pub struct InsertAlertRuleMeasure {
+    pub alert_rule_id: AlertRuleId,
+    pub measure_id: MeasureId,
+}
+
+impl InsertAlertRuleMeasure {
+    pub fn from_measure_ids(
+        conn: &mut DbConnection,
+        alert_rule_id: AlertRuleId,
+        measure_ids: Vec<MeasureId>,
+    ) -> Result<(), HttpError> {
+        for measure_id in measure_ids {
+            let insert_alert_rule_measure = Self {
+                alert_rule_id,
+                measure_id,
+            };
+            diesel::insert_into(alert_rule_measure_table::table)
+                .values(&insert_alert_rule_measure)
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    AlertRuleMeasure,
+                    insert_alert_rule_measure
+                ))?;
+        }
+        Ok(())
+    }
+}
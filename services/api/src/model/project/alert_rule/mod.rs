@@ -0,0 +1,280 @@
+use std::collections::{HashMap, HashSet};
+
+use bencher_json::{AlertRuleUuid, DateTime, JsonAlertRule, JsonNewAlertRule, ResourceName};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use super::{
+    measure::{MeasureId, QueryMeasure},
+    ProjectId, QueryProject,
+};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{
+        assert_parentage, bad_request_error, resource_conflict_err, resource_not_found_err,
+        BencherResource,
+    },
+    schema::alert_rule as alert_rule_table,
+};
+
+mod measure;
+
+use measure::{InsertAlertRuleMeasure, QueryAlertRuleMeasure};
+
+crate::util::typed_id::typed_id!(AlertRuleId);
+
+/// The minimum number of measures for a composite alert rule.
+const MIN_MEASURES: usize = 2;
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = alert_rule_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryAlertRule {
+    pub id: AlertRuleId,
+    pub uuid: AlertRuleUuid,
+    pub project_id: ProjectId,
+    pub name: ResourceName,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryAlertRule {
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: AlertRuleUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_project)
+            .filter(alert_rule_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(AlertRule, (query_project, uuid)))
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonAlertRule, HttpError> {
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::AlertRule,
+            self.project_id,
+        );
+        let measures = QueryAlertRuleMeasure::into_json_for_alert_rule(conn, project, &self)?;
+        let Self {
+            uuid,
+            name,
+            created,
+            modified,
+            ..
+        } = self;
+        Ok(JsonAlertRule {
+            uuid,
+            project: project.uuid,
+            name,
+            measures,
+            created,
+            modified,
+        })
+    }
+}
+
+/// The composite alert rules for a project, keyed by measure.
+#[derive(Debug, Clone, Default)]
+pub struct AlertRules {
+    rules: Vec<HashSet<MeasureId>>,
+}
+
+impl AlertRules {
+    pub fn new(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        let rule_measures = QueryAlertRuleMeasure::all_for_project(conn, project_id)?;
+        let mut rules: HashMap<AlertRuleId, HashSet<MeasureId>> = HashMap::new();
+        for rule_measure in rule_measures {
+            rules
+                .entry(rule_measure.alert_rule_id)
+                .or_default()
+                .insert(rule_measure.measure_id);
+        }
+        Ok(Self {
+            rules: rules.into_values().collect(),
+        })
+    }
+
+    /// Returns `true` if an outlier for the measure should generate an alert,
+    /// given the set of all measures with outliers for the same benchmark.
+    /// Measures that are not part of any composite alert rule always generate an alert.
+    /// Otherwise, at least one rule that includes the measure must have all of its measures breached.
+    pub fn should_alert(&self, measure_id: MeasureId, breached: &HashSet<MeasureId>) -> bool {
+        let mut rules = self
+            .rules
+            .iter()
+            .filter(|rule| rule.contains(&measure_id))
+            .peekable();
+        if rules.peek().is_none() {
+            return true;
+        }
+        rules.any(|rule| rule.is_subset(breached))
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = alert_rule_table)]
+pub struct InsertAlertRule {
+    pub uuid: AlertRuleUuid,
+    pub project_id: ProjectId,
+    pub name: ResourceName,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertAlertRule {
+    pub async fn from_json(
+        context: &ApiContext,
+        query_project: &QueryProject,
+        alert_rule: JsonNewAlertRule,
+    ) -> Result<QueryAlertRule, HttpError> {
+        let JsonNewAlertRule { name, measures } = alert_rule;
+
+        let mut measure_ids = Vec::with_capacity(measures.len());
+        for measure in &measures {
+            let measure_id =
+                QueryMeasure::from_name_id(conn_lock!(context), query_project.id, measure)?.id;
+            if !measure_ids.contains(&measure_id) {
+                measure_ids.push(measure_id);
+            }
+        }
+        if measure_ids.len() < MIN_MEASURES {
+            return Err(bad_request_error(format!(
+                "An alert rule must have at least {MIN_MEASURES} distinct measures, but only {count} were provided.",
+                count = measure_ids.len()
+            )));
+        }
+
+        let timestamp = DateTime::now();
+        let insert_alert_rule = Self {
+            uuid: AlertRuleUuid::new(),
+            project_id: query_project.id,
+            name,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(alert_rule_table::table)
+            .values(&insert_alert_rule)
+            .execute(conn_lock!(context))
+            .map_err(resource_conflict_err!(AlertRule, insert_alert_rule))?;
+
+        let query_alert_rule = alert_rule_table::table
+            .filter(alert_rule_table::uuid.eq(&insert_alert_rule.uuid))
+            .first::<QueryAlertRule>(conn_lock!(context))
+            .map_err(resource_not_found_err!(AlertRule, insert_alert_rule))?;
+
+        InsertAlertRuleMeasure::from_measure_ids(
+            conn_lock!(context),
+            query_alert_rule.id,
+            measure_ids,
+        )?;
+
+        Ok(query_alert_rule)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::util::test_db::{insert_alert_rule, test_conn, TestProject};
+
+    use super::AlertRules;
+
+    #[test]
+    fn test_should_alert_no_rules() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let throughput = project.measure(conn, "throughput");
+
+        let alert_rules = AlertRules::new(conn, project.project_id).unwrap();
+        // Without any rules, every outlier generates an alert.
+        assert!(
+            alert_rules.should_alert(project.measure_id, &HashSet::from([project.measure_id])),
+            "Single measure"
+        );
+        assert!(
+            alert_rules.should_alert(throughput, &HashSet::from([project.measure_id, throughput])),
+            "Multiple measures"
+        );
+    }
+
+    #[test]
+    fn test_should_alert_all_of() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let latency = project.measure_id;
+        let throughput = project.measure(conn, "throughput");
+        let memory = project.measure(conn, "memory");
+        let file_size = project.measure(conn, "file-size");
+        insert_alert_rule(conn, project.project_id, &[latency, throughput, memory]);
+
+        let alert_rules = AlertRules::new(conn, project.project_id).unwrap();
+        // Every measure in the rule must be breached.
+        let breached = HashSet::from([latency, throughput]);
+        assert!(
+            !alert_rules.should_alert(latency, &breached),
+            "Partial breach for latency"
+        );
+        assert!(
+            !alert_rules.should_alert(throughput, &breached),
+            "Partial breach for throughput"
+        );
+        let breached = HashSet::from([latency, throughput, memory]);
+        for measure_id in [latency, throughput, memory] {
+            assert!(
+                alert_rules.should_alert(measure_id, &breached),
+                "Full breach for {measure_id}"
+            );
+        }
+        // Measures that are not part of any rule always generate an alert.
+        assert!(
+            alert_rules.should_alert(file_size, &HashSet::from([file_size])),
+            "Measure without a rule"
+        );
+    }
+
+    #[test]
+    fn test_should_alert_any_of() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let latency = project.measure_id;
+        let throughput = project.measure(conn, "throughput");
+        let memory = project.measure(conn, "memory");
+        insert_alert_rule(conn, project.project_id, &[latency, throughput]);
+        insert_alert_rule(conn, project.project_id, &[latency, memory]);
+
+        let alert_rules = AlertRules::new(conn, project.project_id).unwrap();
+        // Any one of the rules for the measure being fully breached is enough.
+        assert!(
+            !alert_rules.should_alert(latency, &HashSet::from([latency])),
+            "No rule fully breached"
+        );
+        assert!(
+            alert_rules.should_alert(latency, &HashSet::from([latency, throughput])),
+            "First rule fully breached"
+        );
+        assert!(
+            alert_rules.should_alert(latency, &HashSet::from([latency, memory])),
+            "Second rule fully breached"
+        );
+        // Only the rules that include the measure are considered.
+        assert!(
+            alert_rules.should_alert(memory, &HashSet::from([latency, memory])),
+            "Memory rule fully breached"
+        );
+        assert!(
+            !alert_rules.should_alert(memory, &HashSet::from([memory, throughput])),
+            "Memory rule not fully breached"
+        );
+    }
+}
@@ -59,8 +59,9 @@ impl QueryMeasure {
         context: &ApiContext,
         project_id: ProjectId,
         measure: &MeasureNameId,
+        units: Option<&ResourceName>,
     ) -> Result<MeasureId, HttpError> {
-        let query_measure = Self::get_or_create_inner(context, project_id, measure).await?;
+        let query_measure = Self::get_or_create_inner(context, project_id, measure, units).await?;
 
         if query_measure.archived.is_some() {
             let update_measure = UpdateMeasure::unarchive();
@@ -77,6 +78,7 @@ impl QueryMeasure {
         context: &ApiContext,
         project_id: ProjectId,
         measure: &MeasureNameId,
+        units: Option<&ResourceName>,
     ) -> Result<Self, HttpError> {
         let query_measure = Self::from_name_id(conn_lock!(context), project_id, measure);
 
@@ -113,23 +115,36 @@ impl QueryMeasure {
             .or_else(|| built_in::iai_callgrind::AtTEndBlocks::from_str(measure_str))
             .or_else(|| built_in::iai_callgrind::ReadsBytes::from_str(measure_str))
             .or_else(|| built_in::iai_callgrind::WritesBytes::from_str(measure_str))
+            .or_else(|| built_in::divan::MaxAllocCount::from_str(measure_str))
+            .or_else(|| built_in::divan::MaxAllocBytes::from_str(measure_str))
+            .or_else(|| built_in::divan::AllocCount::from_str(measure_str))
+            .or_else(|| built_in::divan::AllocBytes::from_str(measure_str))
+            .or_else(|| built_in::divan::DeallocCount::from_str(measure_str))
+            .or_else(|| built_in::divan::DeallocBytes::from_str(measure_str))
+            .or_else(|| built_in::divan::GrowCount::from_str(measure_str))
+            .or_else(|| built_in::divan::GrowBytes::from_str(measure_str))
+            .or_else(|| built_in::divan::ShrinkCount::from_str(measure_str))
+            .or_else(|| built_in::divan::ShrinkBytes::from_str(measure_str))
+            .or_else(|| built_in::go::ByteThroughput::from_str(measure_str))
+            .or_else(|| built_in::load::ErrorRate::from_str(measure_str))
         {
             measure
         } else {
             let Ok(kind) = NameIdKind::<ResourceName>::try_from(measure) else {
                 return Err(http_error);
             };
+            let units = units.cloned().unwrap_or_else(JsonNewMeasure::generic_unit);
             match kind {
                 NameIdKind::Uuid(_) => return Err(http_error),
                 NameIdKind::Slug(slug) => JsonNewMeasure {
                     name: slug.clone().into(),
                     slug: Some(slug),
-                    units: JsonNewMeasure::generic_unit(),
+                    units,
                 },
                 NameIdKind::Name(name) => JsonNewMeasure {
                     name,
                     slug: None,
-                    units: JsonNewMeasure::generic_unit(),
+                    units,
                 },
             }
         };
@@ -37,6 +37,7 @@ pub struct QueryMetricBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub change_point_id: Option<MetricId>,
 }
 
 impl QueryMetricBoundary {
@@ -56,6 +57,7 @@ impl QueryMetricBoundary {
             baseline,
             lower_limit,
             upper_limit,
+            change_point_id,
         } = self;
         let query_metric = QueryMetric {
             id: metric_id,
@@ -78,6 +80,7 @@ impl QueryMetricBoundary {
                 baseline,
                 lower_limit,
                 upper_limit,
+                change_point_id,
             })
         } else {
             None
@@ -25,6 +25,7 @@ use crate::{
 
 use super::organization::OrganizationId;
 
+pub mod alert_rule;
 pub mod benchmark;
 pub mod branch;
 pub mod measure;
@@ -35,6 +36,7 @@ pub mod project_role;
 pub mod report;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::util::typed_id::typed_id!(ProjectId);
 
@@ -25,6 +25,7 @@ use crate::{
             measure::QueryMeasure,
             testbed::{QueryTestbed, TestbedId},
             threshold::{alert::QueryAlert, model::QueryModel, QueryThreshold},
+            webhook::Webhooks,
             ProjectId, QueryProject,
         },
         user::{auth::AuthUser, QueryUser, UserId},
@@ -181,7 +182,19 @@ impl QueryReport {
         // Don't return the error from processing the report until after the metrics usage has been checked
         processed_report?;
         // If the report was processed successfully, then return the report with the results
-        query_report.into_json(log, context).await
+        let json_report = query_report.into_json(log, context).await?;
+
+        // Notify any webhooks of the new report, its alerts, and any alerts that it resolved
+        Webhooks::report_created(
+            log,
+            context,
+            query_project,
+            &json_report,
+            &report_results.resolved_alerts,
+        )
+        .await;
+
+        Ok(json_report)
     }
 
     pub async fn into_json(
@@ -282,6 +295,9 @@ async fn get_report_results(
                 schema::model::window,
                 schema::model::lower_boundary,
                 schema::model::upper_boundary,
+                schema::model::baseline,
+                schema::model::max_versions,
+                schema::model::resolve_versions,
                 schema::model::created,
                 schema::model::replaced,
             )
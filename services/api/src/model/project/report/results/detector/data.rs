@@ -1,6 +1,7 @@
 use bencher_boundary::MetricsData;
+use bencher_json::{project::head::VersionNumber, ModelBaseline};
 use chrono::offset::Utc;
-use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use diesel::{ExpressionMethods, JoinOnDsl, OptionalExtension, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 use slog::{warn, Logger};
 
@@ -8,7 +9,11 @@ use crate::{
     context::DbConnection,
     error::not_found_error,
     model::project::{
-        benchmark::BenchmarkId, branch::head::HeadId, measure::MeasureId, testbed::TestbedId,
+        benchmark::BenchmarkId,
+        branch::head::{HeadId, QueryHead},
+        measure::MeasureId,
+        metric::MetricId,
+        testbed::TestbedId,
     },
     schema,
 };
@@ -23,7 +28,9 @@ pub fn metrics_data(
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
     model: &ThresholdModel,
-) -> Result<MetricsData, HttpError> {
+) -> Result<(MetricsData, Vec<MetricId>), HttpError> {
+    let (head_id, end_number) = baseline_head(conn, head_id, model.baseline)?;
+
     let mut query = schema::metric::table
         .inner_join(
             schema::report_benchmark::table
@@ -47,6 +54,21 @@ pub fn metrics_data(
         .filter(schema::metric::measure_id.eq(measure_id))
         .into_boxed();
 
+    if let Some(end_number) = end_number {
+        query = query.filter(schema::version::number.le(end_number));
+    }
+
+    if let Some(max_versions) = model.max_versions {
+        let end_number = match end_number {
+            Some(end_number) => Some(end_number),
+            None => latest_version_number(conn, head_id)?,
+        };
+        if let Some(VersionNumber(end_number)) = end_number {
+            let start_number = end_number.saturating_sub(u32::from(max_versions).saturating_sub(1));
+            query = query.filter(schema::version::number.ge(VersionNumber(start_number)));
+        }
+    }
+
     if let Some(window) = model.window {
         let now = Utc::now().timestamp();
         if let Some(start_time) = now.checked_sub(window.into()) {
@@ -70,12 +92,188 @@ pub fn metrics_data(
         query = query.limit(max_sample_size.into());
     }
 
-    let data = query
-        .select(schema::metric::value)
-        .load::<f64>(conn)
+    let (metric_ids, data) = query
+        .select((schema::metric::id, schema::metric::value))
+        .load::<(MetricId, f64)>(conn)
         .map_err(not_found_error)?
         .into_iter()
-        .collect();
+        .unzip();
+
+    Ok((MetricsData { data }, metric_ids))
+}
+
+// Get the branch head and the latest version number to use for the historical data.
+// For a start point baseline, this is the start point branch head up to and including the start point version.
+// If there is no start point, then the current branch head is used.
+fn baseline_head(
+    conn: &mut DbConnection,
+    head_id: HeadId,
+    baseline: Option<ModelBaseline>,
+) -> Result<(HeadId, Option<VersionNumber>), HttpError> {
+    match baseline.unwrap_or_default() {
+        ModelBaseline::Head => Ok((head_id, None)),
+        ModelBaseline::StartPoint => {
+            let Some(start_point_id) = QueryHead::get(conn, head_id)?.start_point_id else {
+                return Ok((head_id, None));
+            };
+            let (start_point_head_id, number) = schema::head_version::table
+                .inner_join(schema::version::table)
+                .filter(schema::head_version::id.eq(start_point_id))
+                .select((schema::head_version::head_id, schema::version::number))
+                .first::<(HeadId, VersionNumber)>(conn)
+                .map_err(not_found_error)?;
+            Ok((start_point_head_id, Some(number)))
+        },
+    }
+}
+
+fn latest_version_number(
+    conn: &mut DbConnection,
+    head_id: HeadId,
+) -> Result<Option<VersionNumber>, HttpError> {
+    schema::version::table
+        .inner_join(schema::head_version::table)
+        .filter(schema::head_version::head_id.eq(head_id))
+        .select(schema::version::number)
+        .order(schema::version::number.desc())
+        .first::<VersionNumber>(conn)
+        .optional()
+        .map_err(not_found_error)
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{DateTime, ModelBaseline, ModelTest, SampleSize};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        context::DbConnection,
+        model::project::{
+            branch::head::HeadId, report::results::detector::threshold::ThresholdModel,
+            threshold::model::ModelId,
+        },
+        util::test_db::{add_head_version, test_conn, test_log, TestProject},
+    };
+
+    use super::metrics_data;
+
+    struct Branches {
+        project: TestProject,
+        main_head_id: HeadId,
+        feature_head_id: HeadId,
+    }
+
+    // The `main` branch has versions 1 through 5.
+    // The `feature` branch starts from version 3 of `main`, and it adds versions 4 and 5.
+    fn setup(conn: &mut DbConnection) -> Branches {
+        let project = TestProject::new(conn, "project");
+        let (_, main_head_id) = project.branch(conn, "main", None);
+        let mut start_point_id = None;
+        for number in 1..=5 {
+            let (version_id, head_version_id) = project.version(conn, main_head_id, number);
+            let report_id = project.report(conn, main_head_id, version_id, DateTime::now());
+            project.metric(conn, report_id, f64::from(number) * 10.0);
+            if number == 3 {
+                start_point_id = Some((version_id, head_version_id));
+            }
+        }
+        let (start_point_version_id, start_point_id) = start_point_id.unwrap();
 
-    Ok(MetricsData { data })
+        let (_, feature_head_id) = project.branch(conn, "feature", Some(start_point_id));
+        // Cloning a branch from its start point shares the start point versions.
+        add_head_version(conn, feature_head_id, start_point_version_id);
+        for number in 4..=5 {
+            let (version_id, _) = project.version(conn, feature_head_id, number);
+            let report_id = project.report(conn, feature_head_id, version_id, DateTime::now());
+            project.metric(conn, report_id, f64::from(number) * 100.0);
+        }
+
+        Branches {
+            project,
+            main_head_id,
+            feature_head_id,
+        }
+    }
+
+    fn threshold_model(baseline: ModelBaseline, max_versions: Option<u32>) -> ThresholdModel {
+        ThresholdModel {
+            id: ModelId::default(),
+            test: ModelTest::TTest,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary: None,
+            upper_boundary: None,
+            baseline: Some(baseline),
+            max_versions: max_versions.map(|max| SampleSize::try_from(max).unwrap()),
+            resolve_versions: None,
+        }
+    }
+
+    fn query_data(
+        conn: &mut DbConnection,
+        branches: &Branches,
+        head_id: HeadId,
+        model: &ThresholdModel,
+    ) -> Vec<f64> {
+        let (metrics_data, metric_ids) = metrics_data(
+            &test_log(),
+            conn,
+            head_id,
+            branches.project.testbed_id,
+            branches.project.benchmark_id,
+            branches.project.measure_id,
+            model,
+        )
+        .unwrap();
+        assert_eq!(metrics_data.data.len(), metric_ids.len());
+        metrics_data.data
+    }
+
+    #[test]
+    fn test_metrics_data_head() {
+        let conn = &mut test_conn();
+        let branches = setup(conn);
+
+        let model = threshold_model(ModelBaseline::Head, None);
+        let data = query_data(conn, &branches, branches.feature_head_id, &model);
+        assert_eq!(data, vec![500.0, 400.0, 30.0]);
+    }
+
+    #[test]
+    fn test_metrics_data_start_point() {
+        let conn = &mut test_conn();
+        let branches = setup(conn);
+
+        // Only the start point branch head up to and including the start point version
+        let model = threshold_model(ModelBaseline::StartPoint, None);
+        let data = query_data(conn, &branches, branches.feature_head_id, &model);
+        assert_eq!(data, vec![30.0, 20.0, 10.0]);
+    }
+
+    #[test]
+    fn test_metrics_data_start_point_max_versions() {
+        let conn = &mut test_conn();
+        let branches = setup(conn);
+
+        // The max versions are counted back from the start point version, not the latest version.
+        let model = threshold_model(ModelBaseline::StartPoint, Some(2));
+        let data = query_data(conn, &branches, branches.feature_head_id, &model);
+        assert_eq!(data, vec![30.0, 20.0]);
+
+        let model = threshold_model(ModelBaseline::Head, Some(2));
+        let data = query_data(conn, &branches, branches.feature_head_id, &model);
+        assert_eq!(data, vec![500.0, 400.0]);
+    }
+
+    #[test]
+    fn test_metrics_data_start_point_none() {
+        let conn = &mut test_conn();
+        let branches = setup(conn);
+
+        // Without a start point, the current branch head is used.
+        let model = threshold_model(ModelBaseline::StartPoint, Some(4));
+        let data = query_data(conn, &branches, branches.main_head_id, &model);
+        assert_eq!(data, vec![50.0, 40.0, 30.0, 20.0]);
+    }
 }
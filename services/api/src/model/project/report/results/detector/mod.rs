@@ -1,5 +1,5 @@
-use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
+use bencher_boundary::{MetricsBoundary, MetricsData};
+use bencher_json::{project::boundary::BoundaryLimit, BoundaryUuid, ModelTest};
 use diesel::RunQueryDsl;
 use dropshot::HttpError;
 use slog::Logger;
@@ -12,19 +12,33 @@ use crate::{
         benchmark::BenchmarkId,
         branch::{head::HeadId, BranchId},
         measure::MeasureId,
-        metric::QueryMetric,
+        metric::{MetricId, QueryMetric},
+        report::ReportId,
         testbed::TestbedId,
-        threshold::{alert::InsertAlert, boundary::InsertBoundary},
+        threshold::{alert::AlertId, boundary::InsertBoundary},
     },
     schema,
 };
 
 pub mod data;
+pub mod resolve;
 pub mod threshold;
 
 use data::metrics_data;
+use resolve::resolve_alerts;
 use threshold::Threshold;
 
+/// An `Outlier` is a boundary check that detected an outlier.
+/// Alerts are only created for outliers once all of the detectors for a report have run,
+/// so that composite alert rules can be evaluated.
+#[derive(Debug, Clone, Copy)]
+pub struct Outlier {
+    pub benchmark_id: BenchmarkId,
+    pub measure_id: MeasureId,
+    pub boundary_uuid: BoundaryUuid,
+    pub boundary_limit: BoundaryLimit,
+}
+
 #[derive(Debug, Clone)]
 pub struct Detector {
     pub head_id: HeadId,
@@ -58,9 +72,9 @@ impl Detector {
         benchmark_id: BenchmarkId,
         query_metric: &QueryMetric,
         ignore_benchmark: bool,
-    ) -> Result<(), HttpError> {
+    ) -> Result<Option<Outlier>, HttpError> {
         // Query the historical population/sample data for the benchmark
-        let metrics_data = metrics_data(
+        let (metrics_data, metric_ids) = metrics_data(
             log,
             conn_lock!(context),
             self.head_id,
@@ -69,6 +83,13 @@ impl Detector {
             self.measure_id,
             &self.threshold.model,
         )?;
+        // The change point test places the metric under test after the historical data itself,
+        // so it must not also be in the historical data.
+        let (metrics_data, metric_ids) = if self.threshold.model.test == ModelTest::ChangePoint {
+            without_metrics(metrics_data, metric_ids, &[query_metric.id])
+        } else {
+            (metrics_data, metric_ids)
+        };
 
         // Check to see if the metric has a boundary check for the given threshold model.
         let boundary = MetricsBoundary::new(
@@ -82,30 +103,157 @@ impl Detector {
         )
         .map_err(bad_request_error)?;
 
+        let boundary_uuid =
+            self.insert_boundary(conn_lock!(context), query_metric.id, &boundary, &metric_ids)?;
+
+        // If the boundary check detects an outlier then return it on the given side.
+        // As long as the benchmark is not being ignored.
+        Ok(self.outlier(benchmark_id, boundary_uuid, &boundary, ignore_benchmark))
+    }
+
+    // Detect across all of the metrics for a benchmark and measure in a report at once,
+    // such as all of the iterations for a multi-iteration report.
+    // Only a single alert is generated for the entire sample.
+    pub async fn detect_sample(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        benchmark_id: BenchmarkId,
+        query_metrics: &[QueryMetric],
+        ignore_benchmark: bool,
+    ) -> Result<Option<Outlier>, HttpError> {
+        let Some(first_metric) = query_metrics.first() else {
+            return Ok(None);
+        };
+
+        // Query the historical population/sample data for the benchmark
+        let (metrics_data, metric_ids) = metrics_data(
+            log,
+            conn_lock!(context),
+            self.head_id,
+            self.testbed_id,
+            benchmark_id,
+            self.measure_id,
+            &self.threshold.model,
+        )?;
+        // Remove the new sample from the historical data
+        let sample_ids = query_metrics
+            .iter()
+            .map(|query_metric| query_metric.id)
+            .collect::<Vec<_>>();
+        let (metrics_data, metric_ids) = without_metrics(metrics_data, metric_ids, &sample_ids);
+        let sample = query_metrics
+            .iter()
+            .map(|query_metric| query_metric.value)
+            .collect::<Vec<_>>();
+
+        // Check to see if the sample has a boundary check for the given threshold model.
+        let boundary = MetricsBoundary::new_sample(
+            log,
+            &sample,
+            &metrics_data,
+            self.threshold.model.test,
+            self.threshold.model.min_sample_size,
+            self.threshold.model.lower_boundary,
+            self.threshold.model.upper_boundary,
+        )
+        .map_err(bad_request_error)?;
+
+        // Every metric in the sample shares the same boundary.
+        let boundary_uuid =
+            self.insert_boundary(conn_lock!(context), first_metric.id, &boundary, &metric_ids)?;
+        for query_metric in query_metrics.iter().skip(1) {
+            self.insert_boundary(conn_lock!(context), query_metric.id, &boundary, &metric_ids)?;
+        }
+
+        // If the boundary check detects an outlier then return a single outlier for the entire sample.
+        // As long as the benchmark is not being ignored.
+        Ok(self.outlier(benchmark_id, boundary_uuid, &boundary, ignore_benchmark))
+    }
+
+    // Automatically resolve any active alerts for the benchmark that have recovered,
+    // if alert resolution is enabled for the threshold model.
+    pub fn resolve(
+        &self,
+        conn: &mut DbConnection,
+        benchmark_id: BenchmarkId,
+        report_id: ReportId,
+    ) -> Result<Vec<AlertId>, HttpError> {
+        let Some(resolve_versions) = self.threshold.model.resolve_versions else {
+            return Ok(Vec::new());
+        };
+        resolve_alerts(
+            conn,
+            self.head_id,
+            self.threshold.id,
+            benchmark_id,
+            report_id,
+            resolve_versions,
+        )
+    }
+
+    fn outlier(
+        &self,
+        benchmark_id: BenchmarkId,
+        boundary_uuid: BoundaryUuid,
+        boundary: &MetricsBoundary,
+        ignore_benchmark: bool,
+    ) -> Option<Outlier> {
+        if ignore_benchmark {
+            return None;
+        }
+        boundary.outlier.map(|boundary_limit| Outlier {
+            benchmark_id,
+            measure_id: self.measure_id,
+            boundary_uuid,
+            boundary_limit,
+        })
+    }
+
+    fn insert_boundary(
+        &self,
+        conn: &mut DbConnection,
+        metric_id: MetricId,
+        boundary: &MetricsBoundary,
+        metric_ids: &[MetricId],
+    ) -> Result<BoundaryUuid, HttpError> {
         let boundary_uuid = BoundaryUuid::new();
         let insert_boundary = InsertBoundary {
             uuid: boundary_uuid,
             threshold_id: self.threshold.id,
             model_id: self.threshold.model.id,
-            metric_id: query_metric.id,
+            metric_id,
             baseline: boundary.limits.baseline,
-            lower_limit: boundary.limits.lower.map(Into::into),
-            upper_limit: boundary.limits.upper.map(Into::into),
+            lower_limit: boundary.limits.lower.as_ref().map(|limit| limit.value),
+            upper_limit: boundary.limits.upper.as_ref().map(|limit| limit.value),
+            // The change point index starts with the metric under test,
+            // followed by the historical data.
+            change_point_id: boundary.change_point.and_then(|index| {
+                index
+                    .checked_sub(1)
+                    .map_or(Some(metric_id), |index| metric_ids.get(index).copied())
+            }),
         };
 
         diesel::insert_into(schema::boundary::table)
             .values(&insert_boundary)
-            .execute(conn_lock!(context))
+            .execute(conn)
             .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
 
-        // If the boundary check detects an outlier then create an alert for it on the given side.
-        // As long as the benchmark is not being ignored.
-        if ignore_benchmark {
-            Ok(())
-        } else if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
-        } else {
-            Ok(())
-        }
+        Ok(boundary_uuid)
     }
 }
+
+// Remove the given metrics from the historical data
+fn without_metrics(
+    metrics_data: MetricsData,
+    metric_ids: Vec<MetricId>,
+    exclude: &[MetricId],
+) -> (MetricsData, Vec<MetricId>) {
+    let (metric_ids, data): (Vec<MetricId>, Vec<f64>) = metric_ids
+        .into_iter()
+        .zip(metrics_data.data)
+        .filter(|(metric_id, _)| !exclude.contains(metric_id))
+        .unzip();
+    (MetricsData { data }, metric_ids)
+}
@@ -0,0 +1,315 @@
+use bencher_json::{
+    project::{alert::AlertStatus, head::VersionNumber},
+    ResolveVersions,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{not_found_error, resource_conflict_err},
+    model::project::{
+        benchmark::BenchmarkId,
+        branch::head::HeadId,
+        report::ReportId,
+        threshold::{
+            alert::{AlertId, UpdateAlert},
+            ThresholdId,
+        },
+    },
+    schema,
+};
+
+/// Resolve all of the active alerts for a threshold and benchmark on a branch head,
+/// once the benchmark has been within its boundary limits
+/// for the given number of consecutive versions since the alert.
+/// The report that resolved the alerts is recorded,
+/// and the IDs of the resolved alerts are returned.
+pub fn resolve_alerts(
+    conn: &mut DbConnection,
+    head_id: HeadId,
+    threshold_id: ThresholdId,
+    benchmark_id: BenchmarkId,
+    report_id: ReportId,
+    resolve_versions: ResolveVersions,
+) -> Result<Vec<AlertId>, HttpError> {
+    let active_alerts = schema::alert::table
+        .inner_join(
+            schema::boundary::table.inner_join(
+                schema::metric::table.inner_join(
+                    schema::report_benchmark::table
+                        .inner_join(schema::report::table.inner_join(schema::version::table)),
+                ),
+            ),
+        )
+        .filter(schema::alert::status.eq(AlertStatus::Active))
+        .filter(schema::boundary::threshold_id.eq(threshold_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::report::head_id.eq(head_id))
+        .select((schema::alert::id, schema::version::number))
+        .load::<(AlertId, VersionNumber)>(conn)
+        .map_err(not_found_error)?;
+    let Some(oldest_number) = active_alerts
+        .iter()
+        .map(|(_, VersionNumber(number))| *number)
+        .min()
+    else {
+        return Ok(Vec::new());
+    };
+
+    // All of the boundary checks since the oldest active alert, from the newest version to the oldest.
+    let boundary_checks = schema::boundary::table
+        .inner_join(
+            schema::metric::table.inner_join(
+                schema::report_benchmark::table
+                    .inner_join(schema::report::table.inner_join(schema::version::table)),
+            ),
+        )
+        .filter(schema::boundary::threshold_id.eq(threshold_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::report::head_id.eq(head_id))
+        .filter(schema::version::number.gt(VersionNumber(oldest_number)))
+        .order(schema::version::number.desc())
+        .select((
+            schema::version::number,
+            schema::metric::value,
+            schema::boundary::lower_limit,
+            schema::boundary::upper_limit,
+        ))
+        .load::<(VersionNumber, f64, Option<f64>, Option<f64>)>(conn)
+        .map_err(not_found_error)?
+        .into_iter()
+        .map(|(number, value, lower_limit, upper_limit)| {
+            let is_within = lower_limit.is_none_or(|limit| value >= limit)
+                && upper_limit.is_none_or(|limit| value <= limit);
+            (number, is_within)
+        })
+        .collect::<Vec<_>>();
+
+    let resolve_versions = usize::from(resolve_versions);
+    let mut resolved = Vec::new();
+    for (alert_id, VersionNumber(alert_number)) in active_alerts {
+        let recovered_versions = consecutive_versions(
+            boundary_checks
+                .iter()
+                .filter(|(VersionNumber(number), _)| *number > alert_number)
+                .copied(),
+        );
+        if recovered_versions < resolve_versions {
+            continue;
+        }
+
+        let resolved_alert = UpdateAlert::resolve(report_id);
+        diesel::update(schema::alert::table.filter(schema::alert::id.eq(alert_id)))
+            .set(&resolved_alert)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Alert, (alert_id, &resolved_alert)))?;
+        resolved.push(alert_id);
+    }
+
+    Ok(resolved)
+}
+
+// Count the number of consecutive versions, starting from the newest,
+// where every boundary check was within its limits.
+// The boundary checks must be sorted from the newest version to the oldest.
+fn consecutive_versions<I>(boundary_checks: I) -> usize
+where
+    I: Iterator<Item = (VersionNumber, bool)>,
+{
+    let mut count: usize = 0;
+    let mut current = None;
+    for (number, is_within) in boundary_checks {
+        if !is_within {
+            // A version that is only partially within its limits is not counted.
+            return if current == Some(number) {
+                count.saturating_sub(1)
+            } else {
+                count
+            };
+        }
+        if current != Some(number) {
+            current = Some(number);
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::{alert::AlertStatus, boundary::BoundaryLimit, head::VersionNumber},
+        DateTime, ModelTest, ResolveVersions,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        context::DbConnection,
+        model::project::{
+            branch::head::HeadId,
+            report::ReportId,
+            threshold::{
+                alert::{AlertId, QueryAlert},
+                boundary::BoundaryId,
+                model::ModelId,
+                ThresholdId,
+            },
+        },
+        util::test_db::{insert_alert, insert_boundary, test_conn, TestProject},
+    };
+
+    use super::{consecutive_versions, resolve_alerts};
+
+    const UPPER_LIMIT: f64 = 100.0;
+    const BREACH: f64 = 150.0;
+    const WITHIN: f64 = 50.0;
+
+    struct Alerting {
+        project: TestProject,
+        head_id: HeadId,
+        threshold: (ThresholdId, ModelId),
+        alert_id: AlertId,
+    }
+
+    // An alert is generated for version 1.
+    fn setup(conn: &mut DbConnection) -> Alerting {
+        let project = TestProject::new(conn, "project");
+        let (branch_id, head_id) = project.branch(conn, "main", None);
+        let threshold = project.threshold(conn, branch_id, ModelTest::TTest);
+        let mut alerting = Alerting {
+            project,
+            head_id,
+            threshold,
+            alert_id: AlertId::default(),
+        };
+        let (_, boundary_id) = alerting.boundary_check(conn, 1, BREACH);
+        alerting.alert_id = insert_alert(conn, boundary_id, BoundaryLimit::Upper);
+        alerting
+    }
+
+    impl Alerting {
+        fn boundary_check(
+            &self,
+            conn: &mut DbConnection,
+            number: u32,
+            value: f64,
+        ) -> (ReportId, BoundaryId) {
+            let (version_id, _) = self.project.version(conn, self.head_id, number);
+            let report_id = self
+                .project
+                .report(conn, self.head_id, version_id, DateTime::now());
+            let metric_id = self.project.metric(conn, report_id, value);
+            let boundary_id =
+                insert_boundary(conn, self.threshold, metric_id, None, Some(UPPER_LIMIT));
+            (report_id, boundary_id)
+        }
+
+        fn resolve(
+            &self,
+            conn: &mut DbConnection,
+            report_id: ReportId,
+            resolve_versions: u32,
+        ) -> Vec<AlertId> {
+            resolve_alerts(
+                conn,
+                self.head_id,
+                self.threshold.0,
+                self.project.benchmark_id,
+                report_id,
+                ResolveVersions::try_from(resolve_versions).unwrap(),
+            )
+            .unwrap()
+        }
+    }
+
+    fn checks(checks: &[(u32, bool)]) -> impl Iterator<Item = (VersionNumber, bool)> + '_ {
+        checks
+            .iter()
+            .map(|(number, is_within)| (VersionNumber(*number), *is_within))
+    }
+
+    #[test]
+    fn test_consecutive_versions() {
+        assert_eq!(consecutive_versions(checks(&[])), 0);
+        assert_eq!(
+            consecutive_versions(checks(&[(3, true), (2, true), (1, true)])),
+            3
+        );
+        // Multiple boundary checks for the same version only count once.
+        assert_eq!(
+            consecutive_versions(checks(&[(3, true), (3, true), (2, true)])),
+            2
+        );
+    }
+
+    #[test]
+    fn test_consecutive_versions_breach() {
+        // The streak resets at the newest breach.
+        assert_eq!(
+            consecutive_versions(checks(&[(5, true), (4, true), (3, false), (2, true)])),
+            2
+        );
+        assert_eq!(consecutive_versions(checks(&[(5, false), (4, true)])), 0);
+        // A version that is only partially within its limits is not counted.
+        assert_eq!(
+            consecutive_versions(checks(&[(5, true), (4, true), (4, false)])),
+            1
+        );
+    }
+
+    #[test]
+    fn test_resolve_alerts_exact() {
+        let conn = &mut test_conn();
+        let alerting = setup(conn);
+        alerting.boundary_check(conn, 2, WITHIN);
+        let (report_id, _) = alerting.boundary_check(conn, 3, WITHIN);
+
+        // Resolved exactly at the resolve versions
+        let resolved = alerting.resolve(conn, report_id, 2);
+        assert_eq!(resolved, vec![alerting.alert_id]);
+        let query_alert = QueryAlert::get(conn, alerting.alert_id).unwrap();
+        assert_eq!(query_alert.status, AlertStatus::Resolved);
+        assert_eq!(query_alert.resolved_report_id, Some(report_id));
+
+        // Already resolved alerts are not resolved again
+        let (report_id, _) = alerting.boundary_check(conn, 4, WITHIN);
+        assert_eq!(alerting.resolve(conn, report_id, 2), Vec::new());
+    }
+
+    #[test]
+    fn test_resolve_alerts_active() {
+        let conn = &mut test_conn();
+        let alerting = setup(conn);
+        alerting.boundary_check(conn, 2, WITHIN);
+        let (report_id, _) = alerting.boundary_check(conn, 3, WITHIN);
+
+        // One version short of the resolve versions
+        let resolved = alerting.resolve(conn, report_id, 3);
+        assert_eq!(resolved, Vec::new());
+        let query_alert = QueryAlert::get(conn, alerting.alert_id).unwrap();
+        assert_eq!(query_alert.status, AlertStatus::Active);
+        assert_eq!(query_alert.resolved_report_id, None);
+    }
+
+    #[test]
+    fn test_resolve_alerts_breach_resets() {
+        let conn = &mut test_conn();
+        let alerting = setup(conn);
+        alerting.boundary_check(conn, 2, WITHIN);
+        alerting.boundary_check(conn, 3, WITHIN);
+        alerting.boundary_check(conn, 4, BREACH);
+        let (report_id, _) = alerting.boundary_check(conn, 5, WITHIN);
+
+        // There were three versions within the limits since the alert, but not consecutively.
+        assert_eq!(alerting.resolve(conn, report_id, 2), Vec::new());
+        let query_alert = QueryAlert::get(conn, alerting.alert_id).unwrap();
+        assert_eq!(query_alert.status, AlertStatus::Active);
+
+        let (report_id, _) = alerting.boundary_check(conn, 6, WITHIN);
+        assert_eq!(
+            alerting.resolve(conn, report_id, 2),
+            vec![alerting.alert_id]
+        );
+    }
+}
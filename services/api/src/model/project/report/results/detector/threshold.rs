@@ -1,4 +1,4 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use bencher_json::{Boundary, ModelBaseline, ModelTest, ResolveVersions, SampleSize, Window};
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
@@ -33,6 +33,9 @@ pub struct ThresholdModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub baseline: Option<ModelBaseline>,
+    pub max_versions: Option<SampleSize>,
+    pub resolve_versions: Option<ResolveVersions>,
 }
 
 impl Threshold {
@@ -61,6 +64,9 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    baseline,
+                    max_versions,
+                    resolve_versions,
                     ..
                 } = query_model;
                 let model = ThresholdModel {
@@ -71,6 +77,9 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    baseline,
+                    max_versions,
+                    resolve_versions,
                 };
                 Self {
                     id: threshold_id,
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bencher_adapter::{
     results::adapter_metrics::AdapterMetrics, AdapterResults, AdapterResultsArray,
@@ -6,7 +6,7 @@ use bencher_adapter::{
 };
 use bencher_json::{
     project::report::{Adapter, Iteration, JsonReportSettings},
-    BenchmarkName, MeasureNameId,
+    BenchmarkName, MeasureNameId, ModelTest, ResourceName,
 };
 use diesel::RunQueryDsl;
 use dropshot::HttpError;
@@ -17,12 +17,14 @@ use crate::{
     context::ApiContext,
     error::{bad_request_error, issue_error, resource_conflict_err},
     model::project::{
+        alert_rule::AlertRules,
         benchmark::{BenchmarkId, QueryBenchmark},
         branch::{head::HeadId, BranchId},
         measure::{MeasureId, QueryMeasure},
         metric::{InsertMetric, QueryMetric},
         report::report_benchmark::{InsertReportBenchmark, QueryReportBenchmark},
         testbed::TestbedId,
+        threshold::alert::{AlertId, InsertAlert},
         ProjectId,
     },
     schema,
@@ -30,7 +32,7 @@ use crate::{
 
 pub mod detector;
 
-use detector::Detector;
+use detector::{Detector, Outlier};
 
 use super::ReportId;
 
@@ -43,7 +45,20 @@ pub struct ReportResults {
     pub report_id: ReportId,
     pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
     pub measure_cache: HashMap<MeasureNameId, MeasureId>,
+    pub measure_units: HashMap<MeasureNameId, ResourceName>,
     pub detector_cache: HashMap<MeasureId, Option<Detector>>,
+    pub sample_cache: HashMap<(BenchmarkId, MeasureId), DetectorSample>,
+    pub outliers: Vec<Outlier>,
+    pub resolve_cache: HashMap<(BenchmarkId, MeasureId), Detector>,
+    pub resolved_alerts: Vec<AlertId>,
+}
+
+/// `DetectorSample` holds all of the metrics for a benchmark and measure
+/// that need to be tested together, after all of the iterations have been processed.
+pub struct DetectorSample {
+    pub detector: Detector,
+    pub ignore_benchmark: bool,
+    pub query_metrics: Vec<QueryMetric>,
 }
 
 impl ReportResults {
@@ -62,7 +77,12 @@ impl ReportResults {
             report_id,
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
+            measure_units: HashMap::new(),
             detector_cache: HashMap::new(),
+            sample_cache: HashMap::new(),
+            outliers: Vec::new(),
+            resolve_cache: HashMap::new(),
+            resolved_alerts: Vec::new(),
         }
     }
 
@@ -75,7 +95,13 @@ impl ReportResults {
         settings: JsonReportSettings,
         #[cfg(feature = "plus")] usage: &mut u32,
     ) -> Result<(), HttpError> {
-        let adapter_settings = AdapterSettings::new(settings.average);
+        let adapter_settings = AdapterSettings::new(settings.average)
+            .with_labels(settings.labels.as_deref())
+            .with_measures(settings.measures.as_deref())
+            .with_benchmark_affixes(
+                settings.benchmark_prefix.as_deref(),
+                settings.benchmark_suffix.as_deref(),
+            );
         let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
             .map_err(|e| {
                 bad_request_error(format!(
@@ -83,6 +109,14 @@ impl ReportResults {
                 ))
             })?;
 
+        // The units are only used if the measure needs to be created
+        for measure in settings.measures.iter().flatten() {
+            if let Some(units) = &measure.units {
+                self.measure_units
+                    .insert(measure.renamed().clone(), units.clone());
+            }
+        }
+
         if let Some(fold) = settings.fold {
             let results = results_array.fold(fold);
             self.results(
@@ -108,6 +142,65 @@ impl ReportResults {
             }
         };
 
+        // Multi-iteration threshold model tests are only run once all of the iterations have been processed.
+        for ((benchmark_id, _), sample) in std::mem::take(&mut self.sample_cache) {
+            if let Some(outlier) = sample
+                .detector
+                .detect_sample(
+                    log,
+                    context,
+                    benchmark_id,
+                    &sample.query_metrics,
+                    sample.ignore_benchmark,
+                )
+                .await?
+            {
+                self.outliers.push(outlier);
+            }
+        }
+
+        // Alerts are only created once all of the detectors have run,
+        // so that composite alert rules can be evaluated across measures.
+        self.alerts(context).await?;
+
+        // Active alerts are only resolved once all of the boundaries for the report have been checked.
+        for ((benchmark_id, _), detector) in std::mem::take(&mut self.resolve_cache) {
+            let resolved_alerts =
+                detector.resolve(conn_lock!(context), benchmark_id, self.report_id)?;
+            self.resolved_alerts.extend(resolved_alerts);
+        }
+
+        Ok(())
+    }
+
+    async fn alerts(&mut self, context: &ApiContext) -> Result<(), HttpError> {
+        let outliers = std::mem::take(&mut self.outliers);
+        if outliers.is_empty() {
+            return Ok(());
+        }
+
+        let alert_rules = AlertRules::new(conn_lock!(context), self.project_id)?;
+        let mut breached: HashMap<BenchmarkId, HashSet<MeasureId>> = HashMap::new();
+        for outlier in &outliers {
+            breached
+                .entry(outlier.benchmark_id)
+                .or_default()
+                .insert(outlier.measure_id);
+        }
+
+        for outlier in outliers {
+            let should_alert = breached
+                .get(&outlier.benchmark_id)
+                .is_some_and(|breached| alert_rules.should_alert(outlier.measure_id, breached));
+            if should_alert {
+                InsertAlert::from_boundary(
+                    conn_lock!(context),
+                    outlier.boundary_uuid,
+                    outlier.boundary_limit,
+                )?;
+            }
+        }
+
         Ok(())
     }
 
@@ -177,6 +270,11 @@ impl ReportResults {
             let Some(detector) = self.detector(context, measure_id).await else {
                 continue;
             };
+            if detector.threshold.model.resolve_versions.is_some() {
+                self.resolve_cache
+                    .entry((benchmark_id, measure_id))
+                    .or_insert_with(|| detector.clone());
+            }
             let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
                     issue_error(
                         "Failed to find metric",
@@ -184,9 +282,26 @@ impl ReportResults {
                         e,
                     )
                 })?;
-            detector
+            // Defer the detection for threshold model tests that use the entire sample of iterations.
+            if detector.threshold.model.test == ModelTest::MannWhitneyU {
+                let sample = self
+                    .sample_cache
+                    .entry((benchmark_id, measure_id))
+                    .or_insert_with(|| DetectorSample {
+                        detector: detector.clone(),
+                        ignore_benchmark,
+                        query_metrics: Vec::new(),
+                    });
+                sample.ignore_benchmark |= ignore_benchmark;
+                sample.query_metrics.push(query_metric);
+                continue;
+            }
+            if let Some(outlier) = detector
                 .detect(log, context, benchmark_id, &query_metric, ignore_benchmark)
-                .await?;
+                .await?
+            {
+                self.outliers.push(outlier);
+            }
         }
 
         Ok(())
@@ -218,8 +333,13 @@ impl ReportResults {
         Ok(if let Some(id) = self.measure_cache.get(&measure) {
             *id
         } else {
-            let measure_id =
-                QueryMeasure::get_or_create(context, self.project_id, &measure).await?;
+            let measure_id = QueryMeasure::get_or_create(
+                context,
+                self.project_id,
+                &measure,
+                self.measure_units.get(&measure),
+            )
+            .await?;
             self.measure_cache.insert(measure, measure_id);
             measure_id
         })
@@ -21,6 +21,7 @@ use crate::{
         benchmark::QueryBenchmark,
         branch::{head::HeadId, version::VersionId},
         metric::QueryMetric,
+        report::{QueryReport, ReportId},
         ProjectId, QueryProject,
     },
     schema::{self, alert as alert_table},
@@ -37,6 +38,7 @@ pub struct QueryAlert {
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
+    pub resolved_report_id: Option<ReportId>,
     pub modified: DateTime,
 }
 
@@ -163,9 +165,15 @@ impl QueryAlert {
             uuid,
             boundary_limit,
             status,
+            resolved_report_id,
             modified,
             ..
         } = self;
+        let resolved_report = if let Some(report_id) = resolved_report_id {
+            Some(QueryReport::get_uuid(conn_lock!(context), report_id)?)
+        } else {
+            None
+        };
         let threshold = QueryThreshold::get_alert_json(
             context,
             query_boundary.threshold_id,
@@ -184,6 +192,7 @@ impl QueryAlert {
             boundary: query_boundary.into_json(),
             limit: boundary_limit,
             status,
+            resolved_report,
             created,
             modified,
         })
@@ -213,6 +222,7 @@ pub struct InsertAlert {
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
+    pub resolved_report_id: Option<ReportId>,
     pub modified: DateTime,
 }
 
@@ -227,6 +237,7 @@ impl InsertAlert {
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
             boundary_limit,
             status: AlertStatus::default(),
+            resolved_report_id: None,
             modified: DateTime::now(),
         };
 
@@ -243,14 +254,18 @@ impl InsertAlert {
 #[diesel(table_name = alert_table)]
 pub struct UpdateAlert {
     pub status: Option<AlertStatus>,
+    pub resolved_report_id: Option<Option<ReportId>>,
     pub modified: DateTime,
 }
 
 impl From<JsonUpdateAlert> for UpdateAlert {
     fn from(update: JsonUpdateAlert) -> Self {
         let JsonUpdateAlert { status } = update;
+        // Any manual status change clears the resolving report
+        let resolved_report_id = status.map(|_| None);
         Self {
             status: status.map(Into::into),
+            resolved_report_id,
             modified: DateTime::now(),
         }
     }
@@ -260,6 +275,15 @@ impl UpdateAlert {
     pub fn silence() -> Self {
         Self {
             status: Some(AlertStatus::Silenced),
+            resolved_report_id: None,
+            modified: DateTime::now(),
+        }
+    }
+
+    pub fn resolve(report_id: ReportId) -> Self {
+        Self {
+            status: Some(AlertStatus::Resolved),
+            resolved_report_id: Some(Some(report_id)),
             modified: DateTime::now(),
         }
     }
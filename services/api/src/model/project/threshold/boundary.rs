@@ -26,6 +26,7 @@ pub struct QueryBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub change_point_id: Option<MetricId>,
 }
 
 impl QueryBoundary {
@@ -59,4 +60,5 @@ pub struct InsertBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub change_point_id: Option<MetricId>,
 }
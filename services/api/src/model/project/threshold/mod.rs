@@ -516,7 +516,8 @@ impl InsertThreshold {
         // If it does exist and has changed, update it.
         if let Some(models) = json_thresholds.models {
             for (measure, model) in models {
-                let measure_id = QueryMeasure::get_or_create(context, project_id, &measure).await?;
+                let measure_id =
+                    QueryMeasure::get_or_create(context, project_id, &measure, None).await?;
                 slog::debug!(log, "Processing threshold for measure {measure_id}");
                 if let Some(current_threshold) = current_thresholds.remove(&measure_id) {
                     slog::debug!(log, "Updating threshold for measure {measure_id}");
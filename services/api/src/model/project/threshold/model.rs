@@ -1,5 +1,6 @@
 use bencher_json::{
-    Boundary, DateTime, JsonModel, Model, ModelTest, ModelUuid, SampleSize, Window,
+    Boundary, DateTime, JsonModel, Model, ModelBaseline, ModelTest, ModelUuid, ResolveVersions,
+    SampleSize, Window,
 };
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
@@ -28,6 +29,9 @@ pub struct QueryModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub baseline: Option<ModelBaseline>,
+    pub max_versions: Option<SampleSize>,
+    pub resolve_versions: Option<ResolveVersions>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -61,6 +65,9 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             ..
         } = self;
         Model {
@@ -70,6 +77,9 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         }
     }
 
@@ -83,6 +93,9 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             created,
             replaced,
             ..
@@ -101,6 +114,9 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             created,
             replaced,
         }
@@ -118,6 +134,9 @@ pub struct InsertModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub baseline: Option<ModelBaseline>,
+    pub max_versions: Option<SampleSize>,
+    pub resolve_versions: Option<ResolveVersions>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -131,6 +150,9 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         } = model;
         Self {
             uuid: ModelUuid::new(),
@@ -141,6 +163,9 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             created: DateTime::now(),
             replaced: None,
         }
@@ -154,6 +179,9 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             created,
             replaced,
             ..
@@ -167,6 +195,9 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
             created,
             replaced,
         }
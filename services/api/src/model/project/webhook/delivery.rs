@@ -0,0 +1,99 @@
+use bencher_json::{DateTime, JsonWebhookDelivery, WebhookDeliveryUuid, WebhookEvent};
+use diesel::RunQueryDsl;
+use dropshot::HttpError;
+use http::StatusCode;
+
+use super::{QueryWebhook, WebhookId};
+use crate::{
+    context::DbConnection,
+    error::{assert_parentage, resource_conflict_err, BencherResource},
+    schema::webhook_delivery as webhook_delivery_table,
+};
+
+crate::util::typed_id::typed_id!(WebhookDeliveryId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_delivery_table)]
+#[diesel(belongs_to(QueryWebhook, foreign_key = webhook_id))]
+pub struct QueryWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub created: DateTime,
+}
+
+impl QueryWebhookDelivery {
+    pub fn into_json_for_webhook(self, webhook: &QueryWebhook) -> JsonWebhookDelivery {
+        assert_parentage(
+            BencherResource::Webhook,
+            webhook.id,
+            BencherResource::WebhookDelivery,
+            self.webhook_id,
+        );
+        let Self {
+            uuid,
+            event,
+            attempts,
+            status_code,
+            error,
+            created,
+            ..
+        } = self;
+        JsonWebhookDelivery {
+            uuid,
+            webhook: webhook.uuid,
+            event,
+            attempts: u32::try_from(attempts).unwrap_or_default(),
+            status_code: status_code.and_then(|status_code| u16::try_from(status_code).ok()),
+            error,
+            created,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct InsertWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub created: DateTime,
+}
+
+impl InsertWebhookDelivery {
+    pub fn new(
+        uuid: WebhookDeliveryUuid,
+        webhook_id: WebhookId,
+        event: WebhookEvent,
+        attempts: u32,
+        status_code: Option<StatusCode>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            uuid,
+            webhook_id,
+            event,
+            attempts: i32::try_from(attempts).unwrap_or(i32::MAX),
+            status_code: status_code.map(|status_code| i32::from(status_code.as_u16())),
+            error,
+            created: DateTime::now(),
+        }
+    }
+
+    pub fn insert(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::insert_into(webhook_delivery_table::table)
+            .values(self)
+            .execute(conn)
+            .map_err(resource_conflict_err!(WebhookDelivery, self))?;
+        Ok(())
+    }
+}
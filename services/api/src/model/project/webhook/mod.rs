@@ -0,0 +1,964 @@
+use std::{
+    net::{Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+    time::Duration,
+};
+
+use bencher_json::{
+    project::webhook::{JsonUpdateWebhook, JsonWebhookData},
+    DateTime, JsonAlert, JsonNewWebhook, JsonReport, JsonWebhook, JsonWebhookPayload, Secret, Url,
+    WebhookDeliveryUuid, WebhookEvent, WebhookUuid,
+};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use hmac::{Hmac, Mac};
+use http::StatusCode;
+use sha2::Sha256;
+use slog::Logger;
+
+use super::{
+    threshold::alert::{AlertId, QueryAlert},
+    ProjectId, QueryProject,
+};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{assert_parentage, bad_request_error, resource_not_found_err, BencherResource},
+    schema::webhook as webhook_table,
+    util::fn_get::fn_get,
+};
+
+pub mod delivery;
+
+use delivery::InsertWebhookDelivery;
+
+crate::util::typed_id::typed_id!(WebhookId);
+
+/// The header for the webhook event name.
+const EVENT_HEADER: &str = "X-Bencher-Event";
+/// The header for the webhook delivery UUID.
+const DELIVERY_HEADER: &str = "X-Bencher-Delivery";
+/// The header for the HMAC-SHA256 signature of the webhook payload.
+const SIGNATURE_HEADER: &str = "X-Bencher-Signature-256";
+/// The maximum number of attempts to deliver a webhook payload.
+const MAX_ATTEMPTS: u32 = 3;
+/// The delay before the first retry, which is doubled for each subsequent retry.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+/// The timeout for each delivery attempt.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub report_created: bool,
+    pub alert_created: bool,
+    pub alert_status_changed: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    fn_get!(webhook, WebhookId);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: WebhookUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_project)
+            .filter(webhook_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Webhook, (query_project, uuid)))
+    }
+
+    pub fn is_subscribed(&self, event: WebhookEvent) -> bool {
+        match event {
+            WebhookEvent::ReportCreated => self.report_created,
+            WebhookEvent::AlertCreated => self.alert_created,
+            WebhookEvent::AlertStatusChanged => self.alert_status_changed,
+        }
+    }
+
+    pub fn into_json_for_project(self, project: &QueryProject) -> JsonWebhook {
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Webhook,
+            self.project_id,
+        );
+        let events = WebhookEvent::ALL
+            .into_iter()
+            .filter(|event| self.is_subscribed(*event))
+            .collect();
+        let Self {
+            uuid,
+            url,
+            secret,
+            created,
+            modified,
+            ..
+        } = self;
+        JsonWebhook {
+            uuid,
+            project: project.uuid,
+            url,
+            events,
+            secret,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub report_created: bool,
+    pub alert_created: bool,
+    pub alert_status_changed: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub fn from_json(project_id: ProjectId, webhook: JsonNewWebhook) -> Result<Self, HttpError> {
+        let JsonNewWebhook {
+            url,
+            events,
+            secret,
+        } = webhook;
+        validate_url(&url)?;
+        let subscriptions = events
+            .as_deref()
+            .map(Subscriptions::new)
+            .transpose()?
+            .unwrap_or_default();
+        let timestamp = DateTime::now();
+        Ok(Self {
+            uuid: WebhookUuid::new(),
+            project_id,
+            url,
+            secret: secret.unwrap_or_else(|| uuid::Uuid::new_v4().into()),
+            report_created: subscriptions.report_created,
+            alert_created: subscriptions.alert_created,
+            alert_status_changed: subscriptions.alert_status_changed,
+            created: timestamp,
+            modified: timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_table)]
+pub struct UpdateWebhook {
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub report_created: Option<bool>,
+    pub alert_created: Option<bool>,
+    pub alert_status_changed: Option<bool>,
+    pub modified: DateTime,
+}
+
+impl TryFrom<JsonUpdateWebhook> for UpdateWebhook {
+    type Error = HttpError;
+
+    fn try_from(update: JsonUpdateWebhook) -> Result<Self, Self::Error> {
+        let JsonUpdateWebhook {
+            url,
+            events,
+            secret,
+        } = update;
+        if let Some(url) = &url {
+            validate_url(url)?;
+        }
+        let subscriptions = events.as_deref().map(Subscriptions::new).transpose()?;
+        Ok(Self {
+            url,
+            secret,
+            report_created: subscriptions.map(|s| s.report_created),
+            alert_created: subscriptions.map(|s| s.alert_created),
+            alert_status_changed: subscriptions.map(|s| s.alert_status_changed),
+            modified: DateTime::now(),
+        })
+    }
+}
+
+/// Webhooks are sent from the API server,
+/// so they must not target the server itself or its private network.
+fn validate_url(url: &Url) -> Result<(), HttpError> {
+    let parsed_url = url::Url::try_from(url.clone()).map_err(bad_request_error)?;
+    let is_private = match parsed_url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        },
+        Some(url::Host::Ipv4(ip)) => is_private_ipv4(ip),
+        Some(url::Host::Ipv6(ip)) => is_private_ipv6(ip),
+        None => true,
+    };
+    if is_private {
+        Err(bad_request_error(format!(
+            "A webhook URL ({url}) cannot be a loopback or private network address."
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn is_private_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Shared address space for carrier-grade NAT (100.64.0.0/10)
+        || (first == 100 && (second & 0b1100_0000) == 64)
+}
+
+fn is_private_ipv6(ip: Ipv6Addr) -> bool {
+    let [first, ..] = ip.segments();
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // Unique local addresses (fc00::/7)
+        || (first & 0xfe00) == 0xfc00
+        // Link-local unicast addresses (fe80::/10)
+        || (first & 0xffc0) == 0xfe80
+        || ip.to_ipv4_mapped().is_some_and(is_private_ipv4)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Subscriptions {
+    report_created: bool,
+    alert_created: bool,
+    alert_status_changed: bool,
+}
+
+impl Default for Subscriptions {
+    fn default() -> Self {
+        Self {
+            report_created: true,
+            alert_created: true,
+            alert_status_changed: true,
+        }
+    }
+}
+
+impl Subscriptions {
+    fn new(events: &[WebhookEvent]) -> Result<Self, HttpError> {
+        if events.is_empty() {
+            return Err(bad_request_error(
+                "A webhook must be subscribed to at least one event.",
+            ));
+        }
+        Ok(Self {
+            report_created: events.contains(&WebhookEvent::ReportCreated),
+            alert_created: events.contains(&WebhookEvent::AlertCreated),
+            alert_status_changed: events.contains(&WebhookEvent::AlertStatusChanged),
+        })
+    }
+}
+
+/// The webhooks for a project.
+/// Sending webhooks never fails the request that triggered them.
+/// Any errors are logged, and each delivery is recorded in the delivery log.
+pub struct Webhooks {
+    project: QueryProject,
+    webhooks: Vec<QueryWebhook>,
+}
+
+impl Webhooks {
+    fn new(conn: &mut DbConnection, project: &QueryProject) -> Result<Self, HttpError> {
+        let webhooks = QueryWebhook::belonging_to(project)
+            .load::<QueryWebhook>(conn)
+            .map_err(resource_not_found_err!(Webhook, project))?;
+        Ok(Self {
+            project: project.clone(),
+            webhooks,
+        })
+    }
+
+    async fn load(log: &Logger, context: &ApiContext, project: &QueryProject) -> Option<Self> {
+        match Self::new(conn_lock!(context), project) {
+            Ok(webhooks) if webhooks.webhooks.is_empty() => None,
+            Ok(webhooks) => Some(webhooks),
+            Err(e) => {
+                slog::error!(log, "Failed to load webhooks: {e}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+                None
+            },
+        }
+    }
+
+    /// Send the webhooks for a newly created report,
+    /// the alerts that it generated, and the alerts that it resolved.
+    pub async fn report_created(
+        log: &Logger,
+        context: &ApiContext,
+        project: &QueryProject,
+        json_report: &JsonReport,
+        resolved_alerts: &[AlertId],
+    ) {
+        let Some(webhooks) = Self::load(log, context, project).await else {
+            return;
+        };
+
+        webhooks.send(
+            log,
+            context,
+            WebhookEvent::ReportCreated,
+            &JsonWebhookData::Report(Box::new(json_report.clone())),
+        );
+        for json_alert in &json_report.alerts {
+            webhooks.send(
+                log,
+                context,
+                WebhookEvent::AlertCreated,
+                &JsonWebhookData::Alert(Box::new(json_alert.clone())),
+            );
+        }
+
+        if !webhooks.is_subscribed(WebhookEvent::AlertStatusChanged) {
+            return;
+        }
+        for alert_id in resolved_alerts {
+            let json_alert = match QueryAlert::get(conn_lock!(context), *alert_id) {
+                Ok(query_alert) => query_alert.into_json(context).await,
+                Err(e) => Err(e),
+            };
+            match json_alert {
+                Ok(json_alert) => webhooks.send(
+                    log,
+                    context,
+                    WebhookEvent::AlertStatusChanged,
+                    &JsonWebhookData::Alert(Box::new(json_alert)),
+                ),
+                Err(e) => {
+                    slog::error!(log, "Failed to get resolved alert ({alert_id}): {e}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                },
+            }
+        }
+    }
+
+    /// Send the webhooks for an alert that has changed status.
+    pub async fn alert_status_changed(
+        log: &Logger,
+        context: &ApiContext,
+        project: &QueryProject,
+        json_alert: &JsonAlert,
+    ) {
+        let Some(webhooks) = Self::load(log, context, project).await else {
+            return;
+        };
+        webhooks.send(
+            log,
+            context,
+            WebhookEvent::AlertStatusChanged,
+            &JsonWebhookData::Alert(Box::new(json_alert.clone())),
+        );
+    }
+
+    fn is_subscribed(&self, event: WebhookEvent) -> bool {
+        self.webhooks
+            .iter()
+            .any(|webhook| webhook.is_subscribed(event))
+    }
+
+    fn send(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        event: WebhookEvent,
+        data: &JsonWebhookData,
+    ) {
+        let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+            Ok(client) => client,
+            Err(e) => {
+                slog::error!(log, "Failed to build webhook client: {e}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&e);
+                return;
+            },
+        };
+
+        for webhook in self
+            .webhooks
+            .iter()
+            .filter(|webhook| webhook.is_subscribed(event))
+        {
+            let payload = JsonWebhookPayload {
+                uuid: WebhookDeliveryUuid::new(),
+                event,
+                project: self.project.uuid,
+                data: data.clone(),
+                created: DateTime::now(),
+            };
+            let body = match serde_json::to_string(&payload) {
+                Ok(body) => body,
+                Err(e) => {
+                    slog::error!(log, "Failed to serialize webhook payload: {e}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                    continue;
+                },
+            };
+            let signature = match sign(&webhook.secret, &body) {
+                Ok(signature) => signature,
+                Err(e) => {
+                    slog::error!(log, "Failed to sign webhook payload: {e}");
+                    continue;
+                },
+            };
+
+            let delivery = Delivery {
+                uuid: payload.uuid,
+                webhook_id: webhook.id,
+                event,
+                url: webhook.url.clone(),
+                body,
+                signature,
+            };
+            let send_log = log.clone();
+            let send_client = client.clone();
+            let conn = Arc::clone(&context.database.connection);
+            tokio::spawn(async move {
+                let insert_delivery = delivery.deliver(&send_log, &send_client, RETRY_DELAY).await;
+                let conn = &mut *conn.lock().await;
+                if let Err(e) = insert_delivery.insert(conn) {
+                    slog::error!(send_log, "Failed to record webhook delivery: {e}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                }
+            });
+        }
+    }
+}
+
+/// The delay after the given number of failed attempts, which is doubled for each subsequent retry.
+fn backoff(retry_delay: Duration, attempts: u32) -> Duration {
+    retry_delay.saturating_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+}
+
+/// Sign a webhook payload with HMAC-SHA256, returning the hex encoded signature.
+fn sign(secret: &Secret, body: &str) -> Result<String, hmac::digest::InvalidLength> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref().as_bytes())?;
+    mac.update(body.as_bytes());
+    Ok(format!(
+        "sha256={signature}",
+        signature = hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+struct Delivery {
+    uuid: WebhookDeliveryUuid,
+    webhook_id: WebhookId,
+    event: WebhookEvent,
+    url: Url,
+    body: String,
+    signature: String,
+}
+
+impl Delivery {
+    async fn deliver(
+        self,
+        log: &Logger,
+        client: &reqwest::Client,
+        retry_delay: Duration,
+    ) -> InsertWebhookDelivery {
+        let uuid = self.uuid;
+        let url = &self.url;
+        let mut attempts = 0;
+        let (status_code, error) = loop {
+            attempts += 1;
+            let (status_code, error, retry) = match self.attempt(client).await {
+                Ok(status) if status.is_success() => (Some(status), None, false),
+                Ok(status) => (
+                    Some(status),
+                    Some(format!("Unexpected status code: {status}")),
+                    status.is_server_error()
+                        || status == StatusCode::REQUEST_TIMEOUT
+                        || status == StatusCode::TOO_MANY_REQUESTS,
+                ),
+                Err(e) => (None, Some(e.to_string()), true),
+            };
+            if !retry || attempts >= MAX_ATTEMPTS {
+                break (status_code, error);
+            }
+            let delay = backoff(retry_delay, attempts);
+            slog::debug!(
+                log,
+                "Retrying webhook delivery ({uuid}) to {url} in {delay:?}"
+            );
+            tokio::time::sleep(delay).await;
+        };
+
+        if let Some(error) = &error {
+            slog::warn!(
+                log,
+                "Failed to deliver webhook ({uuid}) to {url} after {attempts} attempt(s): {error}"
+            );
+        }
+
+        InsertWebhookDelivery::new(
+            self.uuid,
+            self.webhook_id,
+            self.event,
+            attempts,
+            status_code,
+            error,
+        )
+    }
+
+    async fn attempt(&self, client: &reqwest::Client) -> Result<StatusCode, reqwest::Error> {
+        let response = client
+            .post(self.url.as_ref())
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, self.event.to_string())
+            .header(DELIVERY_HEADER, self.uuid.to_string())
+            .header(SIGNATURE_HEADER, &self.signature)
+            .body(self.body.clone())
+            .send()
+            .await?;
+        Ok(response.status())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use bencher_json::{
+        project::webhook::JsonWebhookDelivery, JsonNewWebhook, Secret, Url, WebhookDeliveryUuid,
+        WebhookEvent,
+    };
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        sync::Mutex,
+    };
+
+    use crate::{
+        context::DbConnection,
+        model::project::ProjectId,
+        schema,
+        util::test_db::{test_conn, test_log, TestProject},
+    };
+
+    use super::{
+        backoff, delivery::QueryWebhookDelivery, sign, validate_url, Delivery, InsertWebhook,
+        QueryWebhook, WebhookId, DELIVERY_HEADER, EVENT_HEADER, MAX_ATTEMPTS, RETRY_DELAY,
+        SIGNATURE_HEADER,
+    };
+
+    const TEST_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+    #[derive(Debug, Clone)]
+    struct MockRequest {
+        head: String,
+        body: String,
+    }
+
+    impl MockRequest {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.head.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        }
+    }
+
+    /// A mock webhook receiver that responds with each of the given status codes in order,
+    /// repeating the last status code for any further requests.
+    struct MockServer {
+        url: Url,
+        requests: Arc<Mutex<Vec<MockRequest>>>,
+    }
+
+    impl MockServer {
+        async fn start(statuses: &[u16]) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap())
+                .parse()
+                .unwrap();
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let server_requests = Arc::clone(&requests);
+            let statuses = statuses.to_vec();
+            tokio::spawn(async move {
+                let mut count = 0;
+                #[allow(clippy::infinite_loop)]
+                loop {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    let request = read_request(&mut stream).await;
+                    server_requests.lock().await.push(request);
+                    let status = statuses
+                        .get(count)
+                        .or_else(|| statuses.last())
+                        .copied()
+                        .unwrap_or(200);
+                    count += 1;
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    );
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                    stream.shutdown().await.unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        async fn requests(&self) -> Vec<MockRequest> {
+            self.requests.lock().await.clone()
+        }
+    }
+
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> MockRequest {
+        let mut buffer = Vec::new();
+        let mut chunk = [0; 1024];
+        let head_end = loop {
+            let read = stream.read(&mut chunk).await.unwrap();
+            buffer.extend(chunk.iter().take(read));
+            if let Some(index) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break index + 4;
+            }
+        };
+        let mut request = MockRequest {
+            head: String::from_utf8(buffer.iter().take(head_end).copied().collect()).unwrap(),
+            body: String::new(),
+        };
+        let content_length: usize = request
+            .header("content-length")
+            .map_or(0, |length| length.parse().unwrap());
+        while buffer.len() < head_end + content_length {
+            let read = stream.read(&mut chunk).await.unwrap();
+            buffer.extend(chunk.iter().take(read));
+        }
+        request.body = String::from_utf8(buffer.into_iter().skip(head_end).collect()).unwrap();
+        request
+    }
+
+    fn secret() -> Secret {
+        "super-secret".parse().unwrap()
+    }
+
+    fn delivery(webhook_id: WebhookId, url: Url) -> Delivery {
+        let body = r#"{"event":"report_created"}"#.to_owned();
+        Delivery {
+            uuid: WebhookDeliveryUuid::new(),
+            webhook_id,
+            event: WebhookEvent::ReportCreated,
+            url,
+            signature: sign(&secret(), &body).unwrap(),
+            body,
+        }
+    }
+
+    fn json_new_webhook(url: &str, events: Option<Vec<WebhookEvent>>) -> JsonNewWebhook {
+        JsonNewWebhook {
+            url: url.parse().unwrap(),
+            events,
+            secret: Some(secret()),
+        }
+    }
+
+    fn insert_webhook(conn: &mut DbConnection, project_id: ProjectId) -> QueryWebhook {
+        let insert_webhook =
+            InsertWebhook::from_json(project_id, json_new_webhook("https://example.com", None))
+                .unwrap();
+        diesel::insert_into(schema::webhook::table)
+            .values(&insert_webhook)
+            .execute(conn)
+            .unwrap();
+        schema::webhook::table
+            .filter(schema::webhook::uuid.eq(insert_webhook.uuid))
+            .first(conn)
+            .unwrap()
+    }
+
+    fn delivery_log(conn: &mut DbConnection, webhook: &QueryWebhook) -> Vec<JsonWebhookDelivery> {
+        schema::webhook_delivery::table
+            .filter(schema::webhook_delivery::webhook_id.eq(webhook.id))
+            .order(schema::webhook_delivery::id)
+            .load::<QueryWebhookDelivery>(conn)
+            .unwrap()
+            .into_iter()
+            .map(|delivery| delivery.into_json_for_webhook(webhook))
+            .collect()
+    }
+
+    #[test]
+    fn test_sign() {
+        // https://en.wikipedia.org/wiki/HMAC#Examples
+        let secret = "key".parse().unwrap();
+        assert_eq!(
+            sign(&secret, "The quick brown fox jumps over the lazy dog").unwrap(),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn test_subscriptions() {
+        let project_id = ProjectId::default();
+
+        // All events by default
+        let webhook =
+            InsertWebhook::from_json(project_id, json_new_webhook("https://example.com", None))
+                .unwrap();
+        assert!(webhook.report_created, "Report created");
+        assert!(webhook.alert_created, "Alert created");
+        assert!(webhook.alert_status_changed, "Alert status changed");
+
+        let webhook = InsertWebhook::from_json(
+            project_id,
+            json_new_webhook(
+                "https://example.com",
+                Some(vec![WebhookEvent::AlertCreated]),
+            ),
+        )
+        .unwrap();
+        assert!(!webhook.report_created, "Report created");
+        assert!(webhook.alert_created, "Alert created");
+        assert!(!webhook.alert_status_changed, "Alert status changed");
+
+        let error = InsertWebhook::from_json(
+            project_id,
+            json_new_webhook("https://example.com", Some(Vec::new())),
+        );
+        assert!(error.is_err(), "No events");
+    }
+
+    #[test]
+    fn test_is_subscribed() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let mut webhook = insert_webhook(conn, project.project_id);
+        for event in WebhookEvent::ALL {
+            assert!(webhook.is_subscribed(event), "Subscribed to {event}");
+        }
+
+        webhook.report_created = false;
+        webhook.alert_status_changed = false;
+        assert!(
+            !webhook.is_subscribed(WebhookEvent::ReportCreated),
+            "Not subscribed to report created"
+        );
+        assert!(
+            webhook.is_subscribed(WebhookEvent::AlertCreated),
+            "Subscribed to alert created"
+        );
+        assert!(
+            !webhook.is_subscribed(WebhookEvent::AlertStatusChanged),
+            "Not subscribed to alert status changed"
+        );
+    }
+
+    #[test]
+    fn test_validate_url() {
+        for url in [
+            "https://example.com/hook",
+            "http://example.com:8080",
+            "http://8.8.8.8/hook",
+            "http://100.128.0.1",
+            "http://[2606:4700:4700::1111]/hook",
+            "http://localhost.example.com",
+        ] {
+            assert!(validate_url(&url.parse().unwrap()).is_ok(), "Public: {url}");
+        }
+
+        for url in [
+            "http://localhost:8080/hook",
+            "http://LOCALHOST.",
+            "http://api.localhost",
+            "http://127.0.0.1/hook",
+            "http://127.1.2.3",
+            "http://0.0.0.0",
+            "http://10.0.0.1",
+            "http://172.16.0.1",
+            "http://192.168.1.1",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1",
+            "http://255.255.255.255",
+            "http://[::1]/hook",
+            "http://[::]",
+            "http://[fd00::1]",
+            "http://[fe80::1]",
+            "http://[::ffff:127.0.0.1]",
+            "http://[::ffff:192.168.1.1]",
+        ] {
+            assert!(
+                validate_url(&url.parse().unwrap()).is_err(),
+                "Private: {url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_url_on_create() {
+        let error = InsertWebhook::from_json(
+            ProjectId::default(),
+            json_new_webhook("http://127.0.0.1:61016/hook", None),
+        );
+        assert!(error.is_err(), "Loopback URL on create");
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(RETRY_DELAY, 1), Duration::from_secs(2));
+        assert_eq!(backoff(RETRY_DELAY, 2), Duration::from_secs(4));
+        assert_eq!(backoff(RETRY_DELAY, 3), Duration::from_secs(8));
+    }
+
+    #[tokio::test]
+    async fn test_deliver() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let webhook = insert_webhook(conn, project.project_id);
+        let server = MockServer::start(&[200]).await;
+
+        let delivery = delivery(webhook.id, server.url.clone());
+        let uuid = delivery.uuid;
+        let body = delivery.body.clone();
+        let insert_delivery = delivery
+            .deliver(&test_log(), &reqwest::Client::new(), TEST_RETRY_DELAY)
+            .await;
+        insert_delivery.insert(conn).unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 1);
+        let request = requests.first().unwrap();
+        assert!(
+            request.head.starts_with("POST /hook HTTP/1.1"),
+            "POST request: {}",
+            request.head
+        );
+        assert_eq!(request.body, body);
+        assert_eq!(request.header(EVENT_HEADER), Some("report_created"));
+        assert_eq!(
+            request.header(DELIVERY_HEADER),
+            Some(uuid.to_string().as_str())
+        );
+        assert_eq!(
+            request.header(SIGNATURE_HEADER),
+            Some(sign(&webhook.secret, &body).unwrap().as_str())
+        );
+        assert_eq!(request.header("content-type"), Some("application/json"));
+
+        let log = delivery_log(conn, &webhook);
+        assert_eq!(log.len(), 1);
+        let json_delivery = log.first().unwrap();
+        assert_eq!(json_delivery.uuid, uuid);
+        assert_eq!(json_delivery.webhook, webhook.uuid);
+        assert_eq!(json_delivery.event, WebhookEvent::ReportCreated);
+        assert_eq!(json_delivery.attempts, 1);
+        assert_eq!(json_delivery.status_code, Some(200));
+        assert_eq!(json_delivery.error, None);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retry() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let webhook = insert_webhook(conn, project.project_id);
+        let client = reqwest::Client::new();
+
+        // Server errors are retried with exponential backoff, up to the max attempts.
+        let server = MockServer::start(&[500]).await;
+        let start = Instant::now();
+        delivery(webhook.id, server.url.clone())
+            .deliver(&test_log(), &client, TEST_RETRY_DELAY)
+            .await
+            .insert(conn)
+            .unwrap();
+        let elapsed = start.elapsed();
+        let min_elapsed = backoff(TEST_RETRY_DELAY, 1) + backoff(TEST_RETRY_DELAY, 2);
+        assert!(
+            elapsed >= min_elapsed,
+            "Backoff elapsed {elapsed:?} < {min_elapsed:?}"
+        );
+        assert_eq!(server.requests().await.len(), 3);
+
+        // Recover after a retry
+        let server = MockServer::start(&[503, 200]).await;
+        delivery(webhook.id, server.url.clone())
+            .deliver(&test_log(), &client, TEST_RETRY_DELAY)
+            .await
+            .insert(conn)
+            .unwrap();
+        assert_eq!(server.requests().await.len(), 2);
+
+        // Client errors are not retried.
+        let server = MockServer::start(&[404]).await;
+        delivery(webhook.id, server.url.clone())
+            .deliver(&test_log(), &client, TEST_RETRY_DELAY)
+            .await
+            .insert(conn)
+            .unwrap();
+        assert_eq!(server.requests().await.len(), 1);
+
+        let log = delivery_log(conn, &webhook)
+            .into_iter()
+            .map(|delivery| (delivery.attempts, delivery.status_code, delivery.error))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            log,
+            vec![
+                (
+                    MAX_ATTEMPTS,
+                    Some(500),
+                    Some("Unexpected status code: 500 Internal Server Error".to_owned())
+                ),
+                (2, Some(200), None),
+                (
+                    1,
+                    Some(404),
+                    Some("Unexpected status code: 404 Not Found".to_owned())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_unreachable() {
+        let conn = &mut test_conn();
+        let project = TestProject::new(conn, "project");
+        let webhook = insert_webhook(conn, project.project_id);
+
+        // Nothing is listening on the port once the listener is dropped.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        drop(listener);
+
+        delivery(webhook.id, url)
+            .deliver(&test_log(), &reqwest::Client::new(), TEST_RETRY_DELAY)
+            .await
+            .insert(conn)
+            .unwrap();
+
+        let log = delivery_log(conn, &webhook);
+        let json_delivery = log.first().unwrap();
+        assert_eq!(json_delivery.attempts, MAX_ATTEMPTS);
+        assert_eq!(json_delivery.status_code, None);
+        assert!(json_delivery.error.is_some(), "Connection error");
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
 
 pub mod admin;
 pub mod auth;
+pub mod notification;
 pub mod token;
 
 crate::util::typed_id::typed_id!(UserId);
@@ -0,0 +1,486 @@
+use std::{sync::Arc, time::Duration};
+
+use bencher_json::{
+    project::{
+        alert::AlertStatus,
+        boundary::BoundaryLimit,
+        plot::{LOWER_BOUNDARY, UPPER_BOUNDARY},
+    },
+    AlertDigest, BenchmarkName, BenchmarkUuid, BranchName, BranchUuid, DateTime, HeadUuid,
+    JsonPerfQuery, MeasureUuid, ResourceName, Slug, TestbedUuid,
+};
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+use slog::Logger;
+use tokio::sync::Mutex;
+use url::Url;
+
+use super::QueryNotification;
+use crate::{
+    context::{
+        AlertDigestAlert, AlertDigestBody, AlertDigestProject, Body, DbConnection, Message,
+        Messenger,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::user::{QueryUser, UserId},
+    schema,
+};
+
+// How often to check for alert digests that are due.
+const DIGEST_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// How far back to show on the perf plot for each alert.
+const DIGEST_PERF_HISTORY: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+type DigestAlertRow = (
+    ResourceName,
+    Slug,
+    BranchUuid,
+    BranchName,
+    HeadUuid,
+    TestbedUuid,
+    ResourceName,
+    BenchmarkUuid,
+    BenchmarkName,
+    MeasureUuid,
+    ResourceName,
+    BoundaryLimit,
+    DateTime,
+);
+
+pub fn spawn_alert_digest(
+    log: Logger,
+    conn: Arc<Mutex<DbConnection>>,
+    messenger: Messenger,
+    console_url: Url,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DIGEST_CHECK_INTERVAL);
+        #[allow(clippy::infinite_loop)]
+        loop {
+            interval.tick().await;
+            let conn = &mut *conn.lock().await;
+            if let Err(e) = send_alert_digests(&log, conn, &messenger, &console_url) {
+                slog::error!(log, "Failed to send alert digests: {e}");
+            }
+        }
+    });
+}
+
+pub fn send_alert_digests(
+    log: &Logger,
+    conn: &mut DbConnection,
+    messenger: &Messenger,
+    console_url: &Url,
+) -> Result<(), HttpError> {
+    let now = DateTime::now();
+    let notifications = schema::notification::table
+        .filter(schema::notification::alert_digest.ne(AlertDigest::Never))
+        .select(QueryNotification::as_select())
+        .load::<QueryNotification>(conn)
+        .map_err(resource_not_found_err!(Notification))?;
+
+    for notification in notifications {
+        let Some(period) = notification.alert_digest.period_seconds() else {
+            continue;
+        };
+        if now.timestamp() < notification.last_digest.timestamp() + period {
+            continue;
+        }
+        if let Err(e) = send_alert_digest(log, conn, messenger, console_url, &notification, now) {
+            let user_id = notification.user_id;
+            slog::error!(log, "Failed to send alert digest for user ({user_id}): {e}");
+        }
+    }
+
+    Ok(())
+}
+
+fn send_alert_digest(
+    log: &Logger,
+    conn: &mut DbConnection,
+    messenger: &Messenger,
+    console_url: &Url,
+    notification: &QueryNotification,
+    now: DateTime,
+) -> Result<(), HttpError> {
+    let query_user = QueryUser::get(conn, notification.user_id)?;
+    if !query_user.locked {
+        let projects = get_digest_projects(
+            conn,
+            console_url,
+            notification.user_id,
+            notification.last_digest,
+        )?;
+        if projects.is_empty() {
+            slog::debug!(log, "No new alerts for user {}", query_user.uuid);
+        } else {
+            let body = AlertDigestBody {
+                name: query_user.name.clone().into(),
+                alert_digest: notification.alert_digest,
+                projects,
+                settings_url: console_url
+                    .clone()
+                    .join(&format!("/console/users/{}/settings", query_user.slug))
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            };
+            let message = Message {
+                to_name: Some(query_user.name.into()),
+                to_email: query_user.email.into(),
+                subject: Some(body.subject()),
+                body: Some(Body::AlertDigest(body)),
+            };
+            messenger.send(log, message);
+        }
+    }
+
+    diesel::update(
+        schema::notification::table.filter(schema::notification::id.eq(notification.id)),
+    )
+    .set(schema::notification::last_digest.eq(now))
+    .execute(conn)
+    .map_err(resource_conflict_err!(Notification, (notification, now)))?;
+
+    Ok(())
+}
+
+// Get all of the new active alerts since the last digest
+// for the projects that the user is a member of, grouped by project.
+#[allow(clippy::too_many_lines)]
+fn get_digest_projects(
+    conn: &mut DbConnection,
+    console_url: &Url,
+    user_id: UserId,
+    last_digest: DateTime,
+) -> Result<Vec<AlertDigestProject>, HttpError> {
+    let organization_ids = schema::organization_role::table
+        .filter(schema::organization_role::user_id.eq(user_id))
+        .select(schema::organization_role::organization_id);
+    let project_ids = schema::project_role::table
+        .filter(schema::project_role::user_id.eq(user_id))
+        .select(schema::project_role::project_id);
+
+    let rows = schema::alert::table
+        .inner_join(
+            schema::boundary::table
+                .inner_join(
+                    schema::threshold::table
+                        .inner_join(schema::branch::table)
+                        .inner_join(schema::testbed::table)
+                        .inner_join(schema::measure::table),
+                )
+                .inner_join(
+                    schema::metric::table.inner_join(
+                        schema::report_benchmark::table
+                            .inner_join(schema::benchmark::table)
+                            .inner_join(
+                                schema::report::table
+                                    .inner_join(schema::project::table)
+                                    .inner_join(schema::head::table),
+                            ),
+                    ),
+                ),
+        )
+        .filter(schema::alert::status.eq(AlertStatus::Active))
+        .filter(schema::report::created.gt(last_digest))
+        .filter(
+            schema::project::organization_id
+                .eq_any(organization_ids)
+                .or(schema::project::id.eq_any(project_ids)),
+        )
+        .order((
+            schema::project::name,
+            schema::report::created,
+            schema::benchmark::name,
+        ))
+        .select((
+            schema::project::name,
+            schema::project::slug,
+            schema::branch::uuid,
+            schema::branch::name,
+            schema::head::uuid,
+            schema::testbed::uuid,
+            schema::testbed::name,
+            schema::benchmark::uuid,
+            schema::benchmark::name,
+            schema::measure::uuid,
+            schema::measure::name,
+            schema::alert::boundary_limit,
+            schema::report::created,
+        ))
+        .load::<DigestAlertRow>(conn)
+        .map_err(resource_not_found_err!(Alert, (user_id, last_digest)))?;
+
+    let mut projects: Vec<(Slug, AlertDigestProject)> = Vec::new();
+    for (
+        project_name,
+        project_slug,
+        branch_uuid,
+        branch_name,
+        head_uuid,
+        testbed_uuid,
+        testbed_name,
+        benchmark_uuid,
+        benchmark_name,
+        measure_uuid,
+        measure_name,
+        limit,
+        created,
+    ) in rows
+    {
+        let json_perf_query = JsonPerfQuery {
+            branches: vec![branch_uuid],
+            heads: vec![Some(head_uuid)],
+            testbeds: vec![testbed_uuid],
+            benchmarks: vec![benchmark_uuid],
+            measures: vec![measure_uuid],
+            start_time: Some((created.into_inner() - DIGEST_PERF_HISTORY).into()),
+            end_time: Some(created),
+            x_axis: None,
+        };
+        let boundary = match limit {
+            BoundaryLimit::Lower => LOWER_BOUNDARY,
+            BoundaryLimit::Upper => UPPER_BOUNDARY,
+        };
+        let perf_url = json_perf_query
+            .to_url(
+                console_url.as_str(),
+                &format!("/console/projects/{project_slug}/perf"),
+                &[(boundary, Some(true.to_string()))],
+            )
+            .map(Into::into)
+            .unwrap_or_default();
+        let alert = AlertDigestAlert {
+            benchmark: benchmark_name.into(),
+            measure: measure_name.into(),
+            branch: branch_name.into(),
+            testbed: testbed_name.into(),
+            limit,
+            perf_url,
+        };
+
+        if let Some((_, project)) = projects.iter_mut().find(|(slug, _)| *slug == project_slug) {
+            project.alerts.push(alert);
+        } else {
+            let alerts_url = console_url
+                .clone()
+                .join(&format!("/console/projects/{project_slug}/alerts"))
+                .map(Into::into)
+                .unwrap_or_default();
+            projects.push((
+                project_slug,
+                AlertDigestProject {
+                    name: project_name.into(),
+                    alerts_url,
+                    alerts: vec![alert],
+                },
+            ));
+        }
+    }
+
+    Ok(projects.into_iter().map(|(_, project)| project).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::{alert::AlertStatus, boundary::BoundaryLimit},
+        AlertDigest, DateTime, ModelTest,
+    };
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    use crate::{
+        context::{DbConnection, Messenger},
+        model::{
+            project::{
+                branch::head::HeadId,
+                threshold::{alert::AlertId, model::ModelId, ThresholdId},
+            },
+            user::UserId,
+        },
+        schema,
+        util::test_db::{
+            add_organization_role, add_project_role, insert_alert, insert_boundary, insert_user,
+            test_conn, test_log, TestProject,
+        },
+    };
+
+    use super::{get_digest_projects, send_alert_digests};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn console_url() -> Url {
+        "https://bencher.dev".parse().unwrap()
+    }
+
+    fn days_ago(days: i64) -> DateTime {
+        DateTime::try_from(DateTime::now().timestamp() - days * DAY).unwrap()
+    }
+
+    struct DigestProject {
+        project: TestProject,
+        head_id: HeadId,
+        threshold: (ThresholdId, ModelId),
+    }
+
+    impl DigestProject {
+        fn new(conn: &mut DbConnection, slug: &str) -> Self {
+            let project = TestProject::new(conn, slug);
+            let (branch_id, head_id) = project.branch(conn, "main", None);
+            let threshold = project.threshold(conn, branch_id, ModelTest::TTest);
+            Self {
+                project,
+                head_id,
+                threshold,
+            }
+        }
+
+        fn alert(&self, conn: &mut DbConnection, number: u32, created: DateTime) -> AlertId {
+            let (version_id, _) = self.project.version(conn, self.head_id, number);
+            let report_id = self.project.report(conn, self.head_id, version_id, created);
+            let metric_id = self.project.metric(conn, report_id, 150.0);
+            let boundary_id = insert_boundary(conn, self.threshold, metric_id, None, Some(100.0));
+            insert_alert(conn, boundary_id, BoundaryLimit::Upper)
+        }
+    }
+
+    fn insert_notification(
+        conn: &mut DbConnection,
+        user_id: UserId,
+        alert_digest: AlertDigest,
+        last_digest: DateTime,
+    ) {
+        diesel::insert_into(schema::notification::table)
+            .values((
+                schema::notification::user_id.eq(user_id),
+                schema::notification::alert_digest.eq(alert_digest),
+                schema::notification::last_digest.eq(last_digest),
+                schema::notification::created.eq(last_digest),
+                schema::notification::modified.eq(last_digest),
+            ))
+            .execute(conn)
+            .unwrap();
+    }
+
+    fn last_digest(conn: &mut DbConnection, user_id: UserId) -> DateTime {
+        schema::notification::table
+            .filter(schema::notification::user_id.eq(user_id))
+            .select(schema::notification::last_digest)
+            .first(conn)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_digest_projects_batching() {
+        let conn = &mut test_conn();
+        let user_id = insert_user(conn, "digest-user");
+        // A member of the organization
+        let beta = DigestProject::new(conn, "beta");
+        add_organization_role(conn, user_id, beta.project.organization_id);
+        // A member of the project
+        let alpha = DigestProject::new(conn, "alpha");
+        add_project_role(conn, user_id, alpha.project.project_id);
+        // Not a member
+        let gamma = DigestProject::new(conn, "gamma");
+
+        alpha.alert(conn, 1, days_ago(1));
+        beta.alert(conn, 1, days_ago(1));
+        alpha.alert(conn, 2, days_ago(0));
+        gamma.alert(conn, 1, days_ago(1));
+
+        let projects = get_digest_projects(conn, &console_url(), user_id, days_ago(2)).unwrap();
+        // The alerts are grouped by project, in order of project name.
+        let summary = projects
+            .iter()
+            .map(|project| (project.name.as_str(), project.alerts.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![("alpha", 2), ("beta", 1)]);
+
+        let alpha = projects.first().unwrap();
+        assert_eq!(
+            alpha.alerts_url,
+            "https://bencher.dev/console/projects/alpha/alerts"
+        );
+        let alert = alpha.alerts.first().unwrap();
+        assert_eq!(alert.benchmark, "bench");
+        assert_eq!(alert.measure, "latency");
+        assert_eq!(alert.branch, "main");
+        assert_eq!(alert.testbed, "localhost");
+        assert_eq!(alert.limit, BoundaryLimit::Upper);
+        assert!(
+            alert
+                .perf_url
+                .starts_with("https://bencher.dev/console/projects/alpha/perf?"),
+            "Perf URL: {}",
+            alert.perf_url
+        );
+        assert!(
+            alert.perf_url.contains("upper_boundary=true"),
+            "Perf URL: {}",
+            alert.perf_url
+        );
+    }
+
+    #[test]
+    fn test_digest_projects_window() {
+        let conn = &mut test_conn();
+        let alpha = DigestProject::new(conn, "alpha");
+        let user_id = alpha.project.user_id;
+        add_project_role(conn, user_id, alpha.project.project_id);
+
+        // Before the last digest
+        alpha.alert(conn, 1, days_ago(3));
+        // Since the last digest
+        alpha.alert(conn, 2, days_ago(1));
+        // Since the last digest, but no longer active
+        let dismissed = alpha.alert(conn, 3, days_ago(1));
+        diesel::update(schema::alert::table.filter(schema::alert::id.eq(dismissed)))
+            .set(schema::alert::status.eq(AlertStatus::Dismissed))
+            .execute(conn)
+            .unwrap();
+
+        let projects = get_digest_projects(conn, &console_url(), user_id, days_ago(2)).unwrap();
+        let counts = projects
+            .iter()
+            .map(|project| project.alerts.len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![1]);
+
+        let projects = get_digest_projects(conn, &console_url(), user_id, days_ago(0)).unwrap();
+        assert!(projects.is_empty(), "No new alerts");
+    }
+
+    #[test]
+    fn test_send_alert_digests_due() {
+        let conn = &mut test_conn();
+        let alpha = DigestProject::new(conn, "alpha");
+        alpha.alert(conn, 1, days_ago(1));
+
+        let two_days_ago = days_ago(2);
+        let thirty_days_ago = days_ago(30);
+        // A daily digest that is due
+        let daily = insert_user(conn, "daily");
+        add_project_role(conn, daily, alpha.project.project_id);
+        insert_notification(conn, daily, AlertDigest::Daily, two_days_ago);
+        // A weekly digest that is not yet due
+        let weekly = insert_user(conn, "weekly");
+        add_project_role(conn, weekly, alpha.project.project_id);
+        insert_notification(conn, weekly, AlertDigest::Weekly, two_days_ago);
+        // Opted out of the digest
+        let never = insert_user(conn, "never");
+        add_project_role(conn, never, alpha.project.project_id);
+        insert_notification(conn, never, AlertDigest::Never, thirty_days_ago);
+
+        let before = DateTime::now();
+        send_alert_digests(&test_log(), conn, &Messenger::StdOut, &console_url()).unwrap();
+
+        assert!(
+            last_digest(conn, daily).timestamp() >= before.timestamp(),
+            "Daily digest sent"
+        );
+        assert_eq!(last_digest(conn, weekly), two_days_ago);
+        assert_eq!(last_digest(conn, never), thirty_days_ago);
+    }
+}
@@ -0,0 +1,246 @@
+use bencher_json::{
+    user::notification::JsonUpdateNotifications, AlertDigest, DateTime, JsonNotifications,
+};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+
+use super::{QueryUser, UserId};
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, notification as notification_table},
+};
+
+pub mod digest;
+
+crate::util::typed_id::typed_id!(NotificationId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = notification_table)]
+pub struct QueryNotification {
+    pub id: NotificationId,
+    pub user_id: UserId,
+    pub alert_digest: AlertDigest,
+    pub last_digest: DateTime,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryNotification {
+    pub fn get_for_user(
+        conn: &mut DbConnection,
+        user_id: UserId,
+    ) -> Result<Option<Self>, HttpError> {
+        schema::notification::table
+            .filter(schema::notification::user_id.eq(user_id))
+            .select(Self::as_select())
+            .first(conn)
+            .optional()
+            .map_err(resource_not_found_err!(Notification, user_id))
+    }
+
+    pub fn json_for_user(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+    ) -> Result<JsonNotifications, HttpError> {
+        let alert_digest = Self::get_for_user(conn, query_user.id)?
+            .map(|notification| notification.alert_digest)
+            .unwrap_or_default();
+        Ok(JsonNotifications {
+            user: query_user.uuid,
+            alert_digest,
+        })
+    }
+
+    pub fn upsert(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        json_notifications: &JsonUpdateNotifications,
+    ) -> Result<(), HttpError> {
+        let JsonUpdateNotifications { alert_digest } = *json_notifications;
+        if let Some(query_notification) = Self::get_for_user(conn, query_user.id)? {
+            let update_notification = UpdateNotification::new(&query_notification, alert_digest);
+            diesel::update(
+                schema::notification::table
+                    .filter(schema::notification::id.eq(query_notification.id)),
+            )
+            .set(&update_notification)
+            .execute(conn)
+            .map_err(resource_conflict_err!(
+                Notification,
+                (&query_notification, &update_notification)
+            ))?;
+        } else {
+            let insert_notification = InsertNotification::new(query_user.id, alert_digest);
+            diesel::insert_into(schema::notification::table)
+                .values(&insert_notification)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Notification, insert_notification))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = notification_table)]
+pub struct InsertNotification {
+    pub user_id: UserId,
+    pub alert_digest: AlertDigest,
+    pub last_digest: DateTime,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertNotification {
+    fn new(user_id: UserId, alert_digest: Option<AlertDigest>) -> Self {
+        let timestamp = DateTime::now();
+        Self {
+            user_id,
+            alert_digest: alert_digest.unwrap_or_default(),
+            // The first digest only covers alerts created after opting in.
+            last_digest: timestamp,
+            created: timestamp,
+            modified: timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = notification_table)]
+pub struct UpdateNotification {
+    pub alert_digest: Option<AlertDigest>,
+    pub last_digest: Option<DateTime>,
+    pub modified: DateTime,
+}
+
+impl UpdateNotification {
+    fn new(query_notification: &QueryNotification, alert_digest: Option<AlertDigest>) -> Self {
+        let timestamp = DateTime::now();
+        // When opting back in, do not send a digest for alerts created while opted out.
+        let last_digest = (query_notification.alert_digest == AlertDigest::Never
+            && alert_digest.is_some_and(|alert_digest| alert_digest != AlertDigest::Never))
+        .then_some(timestamp);
+        Self {
+            alert_digest,
+            last_digest,
+            modified: timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{user::notification::JsonUpdateNotifications, AlertDigest, DateTime};
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+    use crate::{
+        context::DbConnection,
+        model::user::{QueryUser, UserId},
+        schema,
+        util::test_db::{insert_user, test_conn},
+    };
+
+    use super::QueryNotification;
+
+    fn upsert(conn: &mut DbConnection, user_id: UserId, alert_digest: Option<AlertDigest>) {
+        let query_user = QueryUser::get(conn, user_id).unwrap();
+        QueryNotification::upsert(conn, &query_user, &JsonUpdateNotifications { alert_digest })
+            .unwrap();
+    }
+
+    fn get(conn: &mut DbConnection, user_id: UserId) -> QueryNotification {
+        QueryNotification::get_for_user(conn, user_id)
+            .unwrap()
+            .unwrap()
+    }
+
+    fn set_last_digest(conn: &mut DbConnection, user_id: UserId, last_digest: DateTime) {
+        diesel::update(
+            schema::notification::table.filter(schema::notification::user_id.eq(user_id)),
+        )
+        .set(schema::notification::last_digest.eq(last_digest))
+        .execute(conn)
+        .unwrap();
+    }
+
+    #[test]
+    fn test_notification_default() {
+        let conn = &mut test_conn();
+        let user_id = insert_user(conn, "user");
+        let query_user = QueryUser::get(conn, user_id).unwrap();
+
+        assert!(
+            QueryNotification::get_for_user(conn, user_id)
+                .unwrap()
+                .is_none(),
+            "No notification preferences"
+        );
+        let json = QueryNotification::json_for_user(conn, &query_user).unwrap();
+        assert_eq!(json.alert_digest, AlertDigest::Never);
+
+        // Updating without an alert digest keeps the default.
+        upsert(conn, user_id, None);
+        assert_eq!(get(conn, user_id).alert_digest, AlertDigest::Never);
+    }
+
+    #[test]
+    fn test_notification_opt_in() {
+        let conn = &mut test_conn();
+        let user_id = insert_user(conn, "user");
+        let before = DateTime::now();
+
+        upsert(conn, user_id, Some(AlertDigest::Daily));
+        let notification = get(conn, user_id);
+        assert_eq!(notification.alert_digest, AlertDigest::Daily);
+        assert!(
+            notification.last_digest.timestamp() >= before.timestamp(),
+            "Only alerts after opting in"
+        );
+        let query_user = QueryUser::get(conn, user_id).unwrap();
+        let json = QueryNotification::json_for_user(conn, &query_user).unwrap();
+        assert_eq!(json.alert_digest, AlertDigest::Daily);
+    }
+
+    #[test]
+    fn test_notification_change_period() {
+        let conn = &mut test_conn();
+        let user_id = insert_user(conn, "user");
+        upsert(conn, user_id, Some(AlertDigest::Daily));
+        let last_digest = DateTime::try_from(DateTime::now().timestamp() - 60).unwrap();
+        set_last_digest(conn, user_id, last_digest);
+
+        // Changing the period keeps the digest window.
+        upsert(conn, user_id, Some(AlertDigest::Weekly));
+        let notification = get(conn, user_id);
+        assert_eq!(notification.alert_digest, AlertDigest::Weekly);
+        assert_eq!(notification.last_digest, last_digest);
+
+        // So does an update without an alert digest.
+        upsert(conn, user_id, None);
+        let notification = get(conn, user_id);
+        assert_eq!(notification.alert_digest, AlertDigest::Weekly);
+        assert_eq!(notification.last_digest, last_digest);
+    }
+
+    #[test]
+    fn test_notification_opt_out_and_back_in() {
+        let conn = &mut test_conn();
+        let user_id = insert_user(conn, "user");
+        upsert(conn, user_id, Some(AlertDigest::Weekly));
+
+        upsert(conn, user_id, Some(AlertDigest::Never));
+        assert_eq!(get(conn, user_id).alert_digest, AlertDigest::Never);
+        let last_digest = DateTime::try_from(DateTime::now().timestamp() - 60).unwrap();
+        set_last_digest(conn, user_id, last_digest);
+
+        // Opting back in skips the alerts from while opted out.
+        let before = DateTime::now();
+        upsert(conn, user_id, Some(AlertDigest::Daily));
+        let notification = get(conn, user_id);
+        assert_eq!(notification.alert_digest, AlertDigest::Daily);
+        assert!(
+            notification.last_digest.timestamp() >= before.timestamp(),
+            "Opting back in resets the digest window"
+        );
+    }
+}
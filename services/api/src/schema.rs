@@ -7,10 +7,29 @@ diesel::table! {
         boundary_id -> Integer,
         boundary_limit -> Bool,
         status -> Integer,
+        resolved_report_id -> Nullable<Integer>,
         modified -> BigInt,
     }
 }
 
+diesel::table! {
+    alert_rule (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        name -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    alert_rule_measure (alert_rule_id, measure_id) {
+        alert_rule_id -> Integer,
+        measure_id -> Integer,
+    }
+}
+
 diesel::table! {
     benchmark (id) {
         id -> Integer,
@@ -34,6 +53,7 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        change_point_id -> Nullable<Integer>,
     }
 }
 
@@ -107,11 +127,25 @@ diesel::table! {
         window -> Nullable<BigInt>,
         lower_boundary -> Nullable<Double>,
         upper_boundary -> Nullable<Double>,
+        baseline -> Nullable<Integer>,
+        max_versions -> Nullable<BigInt>,
+        resolve_versions -> Nullable<BigInt>,
         created -> BigInt,
         replaced -> Nullable<BigInt>,
     }
 }
 
+diesel::table! {
+    notification (id) {
+        id -> Integer,
+        user_id -> Integer,
+        alert_digest -> Integer,
+        last_digest -> BigInt,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     organization (id) {
         id -> Integer,
@@ -319,7 +353,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        report_created -> Bool,
+        alert_created -> Bool,
+        alert_status_changed -> Bool,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Integer,
+        uuid -> Text,
+        webhook_id -> Integer,
+        event -> Integer,
+        attempts -> Integer,
+        status_code -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        created -> BigInt,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
+diesel::joinable!(alert -> report (resolved_report_id));
+diesel::joinable!(alert_rule -> project (project_id));
+diesel::joinable!(alert_rule_measure -> alert_rule (alert_rule_id));
+diesel::joinable!(alert_rule_measure -> measure (measure_id));
 diesel::joinable!(benchmark -> project (project_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
@@ -329,6 +395,7 @@ diesel::joinable!(head_version -> version (version_id));
 diesel::joinable!(measure -> project (project_id));
 diesel::joinable!(metric -> measure (measure_id));
 diesel::joinable!(metric -> report_benchmark (report_benchmark_id));
+diesel::joinable!(notification -> user (user_id));
 diesel::joinable!(organization_role -> organization (organization_id));
 diesel::joinable!(organization_role -> user (user_id));
 diesel::joinable!(plot -> project (project_id));
@@ -357,9 +424,13 @@ diesel::joinable!(threshold -> project (project_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
+    alert_rule,
+    alert_rule_measure,
     benchmark,
     boundary,
     branch,
@@ -368,6 +439,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     measure,
     metric,
     model,
+    notification,
     organization,
     organization_role,
     plan,
@@ -386,4 +458,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     token,
     user,
     version,
+    webhook,
+    webhook_delivery,
 );
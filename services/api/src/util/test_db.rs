@@ -0,0 +1,446 @@
+//! Fixtures for testing against an in-memory database with all of the migrations applied.
+
+use bencher_json::{
+    project::{alert::AlertStatus, boundary::BoundaryLimit, head::VersionNumber, report::Adapter},
+    DateTime, ModelTest,
+};
+use diesel::{sql_types::Integer, Connection, ExpressionMethods, RunQueryDsl};
+use slog::Logger;
+
+use crate::{
+    config::config_tx::run_migrations,
+    context::DbConnection,
+    model::{
+        organization::OrganizationId,
+        project::{
+            alert_rule::AlertRuleId,
+            benchmark::BenchmarkId,
+            branch::{head::HeadId, head_version::HeadVersionId, version::VersionId, BranchId},
+            measure::MeasureId,
+            metric::MetricId,
+            report::{report_benchmark::ReportBenchmarkId, ReportId},
+            testbed::TestbedId,
+            threshold::{alert::AlertId, boundary::BoundaryId, model::ModelId, ThresholdId},
+            ProjectId,
+        },
+        user::UserId,
+    },
+    schema,
+};
+
+pub fn test_log() -> Logger {
+    Logger::root(slog::Discard, slog::o!())
+}
+
+pub fn test_conn() -> DbConnection {
+    let mut conn = DbConnection::establish(":memory:").unwrap();
+    run_migrations(&mut conn).unwrap();
+    conn
+}
+
+fn uuid() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn last_insert_id<T>(conn: &mut DbConnection) -> T
+where
+    T: diesel::deserialize::FromSqlRow<Integer, diesel::sqlite::Sqlite> + 'static,
+{
+    diesel::select(diesel::dsl::sql::<Integer>("last_insert_rowid()"))
+        .get_result::<T>(conn)
+        .unwrap()
+}
+
+pub fn insert_user(conn: &mut DbConnection, slug: &str) -> UserId {
+    diesel::insert_into(schema::user::table)
+        .values((
+            schema::user::uuid.eq(uuid()),
+            schema::user::name.eq(slug),
+            schema::user::slug.eq(slug),
+            schema::user::email.eq(format!("{slug}@bencher.dev")),
+            schema::user::admin.eq(false),
+            schema::user::locked.eq(false),
+            schema::user::created.eq(DateTime::now()),
+            schema::user::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+    last_insert_id(conn)
+}
+
+pub fn add_organization_role(
+    conn: &mut DbConnection,
+    user_id: UserId,
+    organization_id: OrganizationId,
+) {
+    diesel::insert_into(schema::organization_role::table)
+        .values((
+            schema::organization_role::user_id.eq(user_id),
+            schema::organization_role::organization_id.eq(organization_id),
+            schema::organization_role::role.eq("leader"),
+            schema::organization_role::created.eq(DateTime::now()),
+            schema::organization_role::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
+pub fn add_project_role(conn: &mut DbConnection, user_id: UserId, project_id: ProjectId) {
+    diesel::insert_into(schema::project_role::table)
+        .values((
+            schema::project_role::user_id.eq(user_id),
+            schema::project_role::project_id.eq(project_id),
+            schema::project_role::role.eq("maintainer"),
+            schema::project_role::created.eq(DateTime::now()),
+            schema::project_role::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
+pub fn insert_organization(conn: &mut DbConnection, slug: &str) -> OrganizationId {
+    diesel::insert_into(schema::organization::table)
+        .values((
+            schema::organization::uuid.eq(uuid()),
+            schema::organization::name.eq(slug),
+            schema::organization::slug.eq(slug),
+            schema::organization::created.eq(DateTime::now()),
+            schema::organization::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+    last_insert_id(conn)
+}
+
+/// A project with a single testbed, benchmark, and measure.
+#[derive(Debug, Clone, Copy)]
+pub struct TestProject {
+    pub user_id: UserId,
+    pub organization_id: OrganizationId,
+    pub project_id: ProjectId,
+    pub testbed_id: TestbedId,
+    pub benchmark_id: BenchmarkId,
+    pub measure_id: MeasureId,
+}
+
+impl TestProject {
+    pub fn new(conn: &mut DbConnection, slug: &str) -> Self {
+        let user_id = insert_user(conn, &format!("{slug}-user"));
+        let organization_id = insert_organization(conn, &format!("{slug}-organization"));
+        diesel::insert_into(schema::project::table)
+            .values((
+                schema::project::uuid.eq(uuid()),
+                schema::project::organization_id.eq(organization_id),
+                schema::project::name.eq(slug),
+                schema::project::slug.eq(slug),
+                schema::project::visibility.eq(0),
+                schema::project::created.eq(DateTime::now()),
+                schema::project::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        let project_id = last_insert_id(conn);
+
+        let mut project = Self {
+            user_id,
+            organization_id,
+            project_id,
+            testbed_id: TestbedId::default(),
+            benchmark_id: BenchmarkId::default(),
+            measure_id: MeasureId::default(),
+        };
+        project.testbed_id = project.testbed(conn, "localhost");
+        project.benchmark_id = project.benchmark(conn, "bench");
+        project.measure_id = project.measure(conn, "latency");
+        project
+    }
+
+    pub fn testbed(&self, conn: &mut DbConnection, slug: &str) -> TestbedId {
+        diesel::insert_into(schema::testbed::table)
+            .values((
+                schema::testbed::uuid.eq(uuid()),
+                schema::testbed::project_id.eq(self.project_id),
+                schema::testbed::name.eq(slug),
+                schema::testbed::slug.eq(slug),
+                schema::testbed::created.eq(DateTime::now()),
+                schema::testbed::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        last_insert_id(conn)
+    }
+
+    pub fn benchmark(&self, conn: &mut DbConnection, slug: &str) -> BenchmarkId {
+        diesel::insert_into(schema::benchmark::table)
+            .values((
+                schema::benchmark::uuid.eq(uuid()),
+                schema::benchmark::project_id.eq(self.project_id),
+                schema::benchmark::name.eq(slug),
+                schema::benchmark::slug.eq(slug),
+                schema::benchmark::created.eq(DateTime::now()),
+                schema::benchmark::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        last_insert_id(conn)
+    }
+
+    pub fn measure(&self, conn: &mut DbConnection, slug: &str) -> MeasureId {
+        diesel::insert_into(schema::measure::table)
+            .values((
+                schema::measure::uuid.eq(uuid()),
+                schema::measure::project_id.eq(self.project_id),
+                schema::measure::name.eq(slug),
+                schema::measure::slug.eq(slug),
+                schema::measure::units.eq("nanoseconds (ns)"),
+                schema::measure::created.eq(DateTime::now()),
+                schema::measure::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        last_insert_id(conn)
+    }
+
+    /// Create a branch and its head, optionally with a start point.
+    pub fn branch(
+        &self,
+        conn: &mut DbConnection,
+        slug: &str,
+        start_point_id: Option<HeadVersionId>,
+    ) -> (BranchId, HeadId) {
+        diesel::insert_into(schema::branch::table)
+            .values((
+                schema::branch::uuid.eq(uuid()),
+                schema::branch::project_id.eq(self.project_id),
+                schema::branch::name.eq(slug),
+                schema::branch::slug.eq(slug),
+                schema::branch::created.eq(DateTime::now()),
+                schema::branch::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        let branch_id: BranchId = last_insert_id(conn);
+
+        diesel::insert_into(schema::head::table)
+            .values((
+                schema::head::uuid.eq(uuid()),
+                schema::head::branch_id.eq(branch_id),
+                schema::head::start_point_id.eq(start_point_id),
+                schema::head::created.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        let head_id: HeadId = last_insert_id(conn);
+
+        diesel::update(schema::branch::table)
+            .filter(schema::branch::id.eq(branch_id))
+            .set(schema::branch::head_id.eq(head_id))
+            .execute(conn)
+            .unwrap();
+        (branch_id, head_id)
+    }
+
+    /// Create a version and add it to the branch head.
+    pub fn version(
+        &self,
+        conn: &mut DbConnection,
+        head_id: HeadId,
+        number: u32,
+    ) -> (VersionId, HeadVersionId) {
+        diesel::insert_into(schema::version::table)
+            .values((
+                schema::version::uuid.eq(uuid()),
+                schema::version::project_id.eq(self.project_id),
+                schema::version::number.eq(VersionNumber(number)),
+            ))
+            .execute(conn)
+            .unwrap();
+        let version_id: VersionId = last_insert_id(conn);
+        let head_version_id = add_head_version(conn, head_id, version_id);
+        (version_id, head_version_id)
+    }
+
+    pub fn report(
+        &self,
+        conn: &mut DbConnection,
+        head_id: HeadId,
+        version_id: VersionId,
+        start_time: DateTime,
+    ) -> ReportId {
+        diesel::insert_into(schema::report::table)
+            .values((
+                schema::report::uuid.eq(uuid()),
+                schema::report::user_id.eq(self.user_id),
+                schema::report::project_id.eq(self.project_id),
+                schema::report::head_id.eq(head_id),
+                schema::report::version_id.eq(version_id),
+                schema::report::testbed_id.eq(self.testbed_id),
+                schema::report::adapter.eq(Adapter::Json),
+                schema::report::start_time.eq(start_time),
+                schema::report::end_time.eq(start_time),
+                schema::report::created.eq(start_time),
+            ))
+            .execute(conn)
+            .unwrap();
+        last_insert_id(conn)
+    }
+
+    /// Add a metric for the default benchmark and measure to a report.
+    pub fn metric(&self, conn: &mut DbConnection, report_id: ReportId, value: f64) -> MetricId {
+        self.metric_for(conn, report_id, self.benchmark_id, self.measure_id, value)
+    }
+
+    pub fn metric_for(
+        &self,
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        benchmark_id: BenchmarkId,
+        measure_id: MeasureId,
+        value: f64,
+    ) -> MetricId {
+        diesel::insert_into(schema::report_benchmark::table)
+            .values((
+                schema::report_benchmark::uuid.eq(uuid()),
+                schema::report_benchmark::report_id.eq(report_id),
+                schema::report_benchmark::iteration.eq(0),
+                schema::report_benchmark::benchmark_id.eq(benchmark_id),
+            ))
+            .execute(conn)
+            .unwrap();
+        let report_benchmark_id: ReportBenchmarkId = last_insert_id(conn);
+
+        diesel::insert_into(schema::metric::table)
+            .values((
+                schema::metric::uuid.eq(uuid()),
+                schema::metric::report_benchmark_id.eq(report_benchmark_id),
+                schema::metric::measure_id.eq(measure_id),
+                schema::metric::value.eq(value),
+            ))
+            .execute(conn)
+            .unwrap();
+        last_insert_id(conn)
+    }
+
+    /// Create a threshold for the default testbed and measure on a branch.
+    pub fn threshold(
+        &self,
+        conn: &mut DbConnection,
+        branch_id: BranchId,
+        test: ModelTest,
+    ) -> (ThresholdId, ModelId) {
+        diesel::insert_into(schema::threshold::table)
+            .values((
+                schema::threshold::uuid.eq(uuid()),
+                schema::threshold::project_id.eq(self.project_id),
+                schema::threshold::branch_id.eq(branch_id),
+                schema::threshold::testbed_id.eq(self.testbed_id),
+                schema::threshold::measure_id.eq(self.measure_id),
+                schema::threshold::created.eq(DateTime::now()),
+                schema::threshold::modified.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        let threshold_id: ThresholdId = last_insert_id(conn);
+
+        diesel::insert_into(schema::model::table)
+            .values((
+                schema::model::uuid.eq(uuid()),
+                schema::model::threshold_id.eq(threshold_id),
+                schema::model::test.eq(test),
+                schema::model::created.eq(DateTime::now()),
+            ))
+            .execute(conn)
+            .unwrap();
+        let model_id: ModelId = last_insert_id(conn);
+
+        diesel::update(schema::threshold::table)
+            .filter(schema::threshold::id.eq(threshold_id))
+            .set(schema::threshold::model_id.eq(model_id))
+            .execute(conn)
+            .unwrap();
+        (threshold_id, model_id)
+    }
+}
+
+/// Create a composite alert rule for the project over the given measures.
+pub fn insert_alert_rule(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    measure_ids: &[MeasureId],
+) -> AlertRuleId {
+    diesel::insert_into(schema::alert_rule::table)
+        .values((
+            schema::alert_rule::uuid.eq(uuid()),
+            schema::alert_rule::project_id.eq(project_id),
+            schema::alert_rule::name.eq(uuid()),
+            schema::alert_rule::created.eq(DateTime::now()),
+            schema::alert_rule::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+    let alert_rule_id: AlertRuleId = last_insert_id(conn);
+    for measure_id in measure_ids {
+        diesel::insert_into(schema::alert_rule_measure::table)
+            .values((
+                schema::alert_rule_measure::alert_rule_id.eq(alert_rule_id),
+                schema::alert_rule_measure::measure_id.eq(measure_id),
+            ))
+            .execute(conn)
+            .unwrap();
+    }
+    alert_rule_id
+}
+
+pub fn add_head_version(
+    conn: &mut DbConnection,
+    head_id: HeadId,
+    version_id: VersionId,
+) -> HeadVersionId {
+    diesel::insert_into(schema::head_version::table)
+        .values((
+            schema::head_version::head_id.eq(head_id),
+            schema::head_version::version_id.eq(version_id),
+        ))
+        .execute(conn)
+        .unwrap();
+    last_insert_id(conn)
+}
+
+pub fn insert_boundary(
+    conn: &mut DbConnection,
+    (threshold_id, model_id): (ThresholdId, ModelId),
+    metric_id: MetricId,
+    lower_limit: Option<f64>,
+    upper_limit: Option<f64>,
+) -> BoundaryId {
+    diesel::insert_into(schema::boundary::table)
+        .values((
+            schema::boundary::uuid.eq(uuid()),
+            schema::boundary::metric_id.eq(metric_id),
+            schema::boundary::threshold_id.eq(threshold_id),
+            schema::boundary::model_id.eq(model_id),
+            schema::boundary::lower_limit.eq(lower_limit),
+            schema::boundary::upper_limit.eq(upper_limit),
+        ))
+        .execute(conn)
+        .unwrap();
+    last_insert_id(conn)
+}
+
+pub fn insert_alert(
+    conn: &mut DbConnection,
+    boundary_id: BoundaryId,
+    boundary_limit: BoundaryLimit,
+) -> AlertId {
+    diesel::insert_into(schema::alert::table)
+        .values((
+            schema::alert::uuid.eq(uuid()),
+            schema::alert::boundary_id.eq(boundary_id),
+            schema::alert::boundary_limit.eq(boundary_limit),
+            schema::alert::status.eq(AlertStatus::Active),
+            schema::alert::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .unwrap();
+    last_insert_id(conn)
+}
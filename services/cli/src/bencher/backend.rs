@@ -175,6 +175,32 @@ impl Backend {
         })
     }
 
+    pub async fn send_stream<F, R>(
+        &self,
+        sender: F,
+    ) -> Result<progenitor_client::ByteStream, BackendError>
+    where
+        F: Fn(bencher_client::Client) -> R,
+        R: std::future::Future<
+            Output = Result<
+                progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                bencher_client::Error<progenitor_client::ByteStream>,
+            >,
+        >,
+    {
+        let mismatch = self.check_version().await?;
+        self.client.send_stream(sender).await.map_err(|err| {
+            if let Some(mismatch) = mismatch {
+                BackendError::ClientMismatch {
+                    mismatch: Box::new(mismatch),
+                    err,
+                }
+            } else {
+                err.into()
+            }
+        })
+    }
+
     pub async fn check_version(&self) -> Result<Option<VersionMismatch>, BackendError> {
         let json_api_version: JsonApiVersion = self
             .client
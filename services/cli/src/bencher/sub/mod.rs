@@ -15,9 +15,12 @@ pub use mock::MockError;
 use organization::{member::Member, organization::Organization};
 use project::{
     alert::Alert,
+    alert_rule::AlertRule,
     archive::{Archive, ArchiveAction},
     benchmark::Benchmark,
     branch::Branch,
+    compare::Compare,
+    export::Export,
     measure::Measure,
     metric::Metric,
     perf::Perf,
@@ -27,9 +30,11 @@ use project::{
     run::Run,
     testbed::Testbed,
     threshold::Threshold,
+    webhook::Webhook,
 };
 pub use project::{
     archive::ArchiveError,
+    export::ExportError,
     run::{runner::output::Output, thresholds::ThresholdsError, RunError},
     threshold::ThresholdError,
 };
@@ -52,6 +57,8 @@ pub enum Sub {
     Project(Project),
     Report(Report),
     Perf(Perf),
+    Compare(Compare),
+    Export(Export),
     Plot(Plot),
     Branch(Branch),
     Testbed(Testbed),
@@ -60,6 +67,8 @@ pub enum Sub {
     Metric(Metric),
     Threshold(Threshold),
     Alert(Alert),
+    AlertRule(AlertRule),
+    Webhook(Webhook),
     User(User),
     Token(Token),
     Server(Server),
@@ -89,6 +98,8 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Project(project) => Self::Project(project.try_into()?),
             CliSub::Report(report) => Self::Report(report.try_into()?),
             CliSub::Perf(perf) => Self::Perf(perf.try_into()?),
+            CliSub::Compare(compare) => Self::Compare(compare.try_into()?),
+            CliSub::Export(export) => Self::Export(export.try_into()?),
             CliSub::Plot(plot) => Self::Plot(plot.try_into()?),
             CliSub::Branch(branch) => Self::Branch(branch.try_into()?),
             CliSub::Testbed(testbed) => Self::Testbed(testbed.try_into()?),
@@ -97,6 +108,8 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Metric(metric) => Self::Metric(metric.try_into()?),
             CliSub::Threshold(threshold) => Self::Threshold(threshold.try_into()?),
             CliSub::Alert(alert) => Self::Alert(alert.try_into()?),
+            CliSub::AlertRule(alert_rule) => Self::AlertRule(alert_rule.try_into()?),
+            CliSub::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
@@ -121,6 +134,8 @@ impl SubCmd for Sub {
             Self::Project(project) => project.exec().await,
             Self::Report(report) => report.exec().await,
             Self::Perf(perf) => perf.exec().await,
+            Self::Compare(compare) => compare.exec().await,
+            Self::Export(export) => export.exec().await,
             Self::Plot(plot) => plot.exec().await,
             Self::Branch(branch) => branch.exec().await,
             Self::Testbed(testbed) => testbed.exec().await,
@@ -129,6 +144,8 @@ impl SubCmd for Sub {
             Self::Metric(metric) => metric.exec().await,
             Self::Threshold(threshold) => threshold.exec().await,
             Self::Alert(alert) => alert.exec().await,
+            Self::AlertRule(alert_rule) => alert_rule.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
             Self::Server(server) => server.exec().await,
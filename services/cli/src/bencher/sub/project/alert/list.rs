@@ -74,6 +74,7 @@ impl From<CliAlertStatus> for AlertStatus {
             CliAlertStatus::Active => Self::Active,
             CliAlertStatus::Dismissed => Self::Dismissed,
             CliAlertStatus::Silenced => Self::Silenced,
+            CliAlertStatus::Resolved => Self::Resolved,
         }
     }
 }
@@ -0,0 +1,62 @@
+use bencher_client::types::JsonNewAlertRule;
+use bencher_json::{NameId, ResourceId, ResourceName};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::alert_rule::CliAlertRuleCreate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub name: ResourceName,
+    pub measures: Vec<NameId>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliAlertRuleCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliAlertRuleCreate) -> Result<Self, Self::Error> {
+        let CliAlertRuleCreate {
+            project,
+            name,
+            measures,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            name,
+            measures,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewAlertRule {
+    fn from(create: Create) -> Self {
+        let Create { name, measures, .. } = create;
+        Self {
+            name: name.into(),
+            measures: measures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_alert_rule_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{AlertRuleUuid, ResourceId};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::alert_rule::CliAlertRuleDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub alert_rule: AlertRuleUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliAlertRuleDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliAlertRuleDelete) -> Result<Self, Self::Error> {
+        let CliAlertRuleDelete {
+            project,
+            alert_rule,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            alert_rule,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_alert_rule_delete()
+                    .project(self.project.clone())
+                    .alert_rule(self.alert_rule)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
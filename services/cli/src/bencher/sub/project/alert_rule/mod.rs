@@ -0,0 +1,38 @@
+use crate::{bencher::sub::SubCmd, parser::project::alert_rule::CliAlertRule, CliError};
+
+mod create;
+mod delete;
+mod list;
+mod view;
+
+#[derive(Debug)]
+pub enum AlertRule {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliAlertRule> for AlertRule {
+    type Error = CliError;
+
+    fn try_from(alert_rule: CliAlertRule) -> Result<Self, Self::Error> {
+        Ok(match alert_rule {
+            CliAlertRule::List(list) => Self::List(list.try_into()?),
+            CliAlertRule::Create(create) => Self::Create(create.try_into()?),
+            CliAlertRule::View(view) => Self::View(view.try_into()?),
+            CliAlertRule::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for AlertRule {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
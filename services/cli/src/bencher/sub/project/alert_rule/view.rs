@@ -0,0 +1,48 @@
+use bencher_json::{AlertRuleUuid, ResourceId};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::alert_rule::CliAlertRuleView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ResourceId,
+    pub alert_rule: AlertRuleUuid,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliAlertRuleView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliAlertRuleView) -> Result<Self, Self::Error> {
+        let CliAlertRuleView {
+            project,
+            alert_rule,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            alert_rule,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_alert_rule_get()
+                    .project(self.project.clone())
+                    .alert_rule(self.alert_rule)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use bencher_json::{
+    project::perf::{JsonPerfCompareQuery, JsonPerfCompareSelector},
+    JsonPerfCompare, ResourceId,
+};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::project::perf::print_table},
+    parser::project::compare::CliCompare,
+    CliError,
+};
+
+use crate::bencher::SubCmd;
+
+use super::perf::table_style::TableStyle;
+
+#[derive(Debug, Clone)]
+#[allow(clippy::option_option)]
+pub struct Compare {
+    project: ResourceId,
+    query: JsonPerfCompareQuery,
+    table: Option<Option<TableStyle>>,
+    backend: PubBackend,
+}
+
+impl TryFrom<CliCompare> for Compare {
+    type Error = CliError;
+
+    fn try_from(compare: CliCompare) -> Result<Self, Self::Error> {
+        let CliCompare {
+            project,
+            baseline_branch,
+            baseline_head,
+            baseline_testbed,
+            baseline_hash,
+            candidate_branch,
+            candidate_head,
+            candidate_testbed,
+            candidate_hash,
+            measures,
+            table,
+            backend,
+        } = compare;
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
+        Ok(Self {
+            project,
+            query: JsonPerfCompareQuery {
+                baseline: JsonPerfCompareSelector {
+                    branch: baseline_branch,
+                    head: baseline_head,
+                    testbed: baseline_testbed,
+                    hash: baseline_hash,
+                },
+                candidate: JsonPerfCompareSelector {
+                    branch: candidate_branch,
+                    head: candidate_head,
+                    testbed: candidate_testbed,
+                    hash: candidate_hash,
+                },
+                measures,
+            },
+            table: table.map(|t| t.map(Into::into)),
+            backend,
+        })
+    }
+}
+
+impl SubCmd for Compare {
+    async fn exec(&self) -> Result<(), CliError> {
+        let sender = |client: bencher_client::Client| async move {
+            let JsonPerfCompareQuery {
+                baseline,
+                candidate,
+                ..
+            } = &self.query;
+            let mut client = client
+                .proj_perf_compare_get()
+                .project(self.project.clone())
+                .baseline_branch(baseline.branch)
+                .baseline_testbed(baseline.testbed)
+                .candidate_branch(candidate.branch)
+                .candidate_testbed(candidate.testbed)
+                .measures(self.query.measures());
+            if let Some(head) = baseline.head {
+                client = client.baseline_head(head);
+            }
+            if let Some(hash) = baseline.hash.clone() {
+                client = client.baseline_hash(hash);
+            }
+            if let Some(head) = candidate.head {
+                client = client.candidate_head(head);
+            }
+            if let Some(hash) = candidate.hash.clone() {
+                client = client.candidate_hash(hash);
+            }
+            client.send().await
+        };
+        if let Some(table_style) = self.table {
+            let json_perf_compare: JsonPerfCompare = self.backend.send_with(sender).await?;
+            print_table(json_perf_compare.into(), table_style);
+        } else {
+            self.backend.send(sender).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use bencher_json::{
+    project::perf::{JsonPerfExportQuery, PerfExportFormat},
+    ResourceId,
+};
+use camino::Utf8PathBuf;
+use futures_util::TryStreamExt;
+
+use crate::{
+    bencher::backend::PubBackend,
+    parser::{
+        project::export::{CliExport, CliExportFormat},
+        ElidedOption,
+    },
+    CliError,
+};
+
+use crate::bencher::SubCmd;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error("Failed to create export file ({path}): {err}")]
+    CreateFile { path: Utf8PathBuf, err: io::Error },
+    #[error("Failed to read export response: {0}")]
+    ReadResponse(reqwest::Error),
+    #[error("Failed to write export: {0}")]
+    Write(io::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct Export {
+    project: ResourceId,
+    query: JsonPerfExportQuery,
+    output: Option<Utf8PathBuf>,
+    backend: PubBackend,
+}
+
+impl TryFrom<CliExport> for Export {
+    type Error = CliError;
+
+    fn try_from(export: CliExport) -> Result<Self, Self::Error> {
+        let CliExport {
+            project,
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+            format,
+            output,
+            backend,
+        } = export;
+        let backend = PubBackend::try_from(backend)?.log(false);
+        Ok(Self {
+            project,
+            query: JsonPerfExportQuery {
+                format: Some(match format {
+                    CliExportFormat::Csv => PerfExportFormat::Csv,
+                    CliExportFormat::Parquet => PerfExportFormat::Parquet,
+                }),
+                branches,
+                heads: heads.into_iter().map(ElidedOption::into).collect(),
+                testbeds,
+                benchmarks,
+                measures,
+                start_time,
+                end_time,
+            },
+            output,
+            backend,
+        })
+    }
+}
+
+impl SubCmd for Export {
+    async fn exec(&self) -> Result<(), CliError> {
+        let sender = |client: bencher_client::Client| async move {
+            let query = &self.query;
+            let mut client = client
+                .proj_perf_export_get()
+                .project(self.project.clone())
+                .format(match query.format() {
+                    PerfExportFormat::Csv => bencher_client::types::PerfExportFormat::Csv,
+                    PerfExportFormat::Parquet => bencher_client::types::PerfExportFormat::Parquet,
+                });
+            if let Some(branches) = query.branches() {
+                client = client.branches(branches);
+            }
+            if let Some(heads) = query.heads() {
+                client = client.heads(heads);
+            }
+            if let Some(testbeds) = query.testbeds() {
+                client = client.testbeds(testbeds);
+            }
+            if let Some(benchmarks) = query.benchmarks() {
+                client = client.benchmarks(benchmarks);
+            }
+            if let Some(measures) = query.measures() {
+                client = client.measures(measures);
+            }
+            if let Some(start_time) = query.start_time() {
+                client = client.start_time(start_time);
+            }
+            if let Some(end_time) = query.end_time() {
+                client = client.end_time(end_time);
+            }
+            client.send().await
+        };
+        let mut stream = self.backend.send_stream(sender).await?.into_inner();
+
+        let mut writer: Box<dyn Write + Send> = if let Some(path) = &self.output {
+            let file = File::create(path).map_err(|err| ExportError::CreateFile {
+                path: path.clone(),
+                err,
+            })?;
+            Box::new(BufWriter::new(file))
+        } else {
+            Box::new(BufWriter::new(io::stdout()))
+        };
+        // Write each chunk as it is received, so the entire export is never held in memory
+        while let Some(bytes) = stream.try_next().await.map_err(ExportError::ReadResponse)? {
+            writer.write_all(&bytes).map_err(ExportError::Write)?;
+        }
+        writer.flush().map_err(ExportError::Write)?;
+        Ok(())
+    }
+}
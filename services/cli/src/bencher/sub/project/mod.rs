@@ -1,7 +1,10 @@
 pub mod alert;
+pub mod alert_rule;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
+pub mod export;
 pub mod measure;
 pub mod metric;
 pub mod perf;
@@ -12,3 +15,4 @@ pub mod report;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
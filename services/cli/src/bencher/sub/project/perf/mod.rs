@@ -2,17 +2,17 @@ use std::future::Future;
 use std::pin::Pin;
 
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ResourceId, TestbedUuid,
+    project::plot::XAxis, BenchmarkUuid, BranchUuid, DateTime, HeadUuid, JsonPerf, JsonPerfQuery,
+    JsonPerfSummary, MeasureUuid, ResourceId, TestbedUuid,
 };
 use tabled::Table;
 
-use crate::parser::ElidedOption;
+use crate::parser::{project::plot::CliXAxis, ElidedOption};
 use crate::{bencher::backend::PubBackend, cli_println, parser::project::perf::CliPerf, CliError};
 
 use crate::bencher::SubCmd;
 
-mod table_style;
+pub(crate) mod table_style;
 
 use table_style::TableStyle;
 
@@ -27,6 +27,8 @@ pub struct Perf {
     measures: Vec<MeasureUuid>,
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    x_axis: Option<XAxis>,
+    summary: bool,
     table: Option<Option<TableStyle>>,
     backend: PubBackend,
 }
@@ -44,6 +46,8 @@ impl TryFrom<CliPerf> for Perf {
             measures,
             start_time,
             end_time,
+            x_axis,
+            summary,
             table,
             backend,
         } = perf;
@@ -57,6 +61,11 @@ impl TryFrom<CliPerf> for Perf {
             measures,
             start_time,
             end_time,
+            x_axis: x_axis.map(|x_axis| match x_axis {
+                CliXAxis::DateTime => XAxis::DateTime,
+                CliXAxis::Version => XAxis::Version,
+            }),
+            summary,
             table: table.map(|t| t.map(Into::into)),
             backend,
         })
@@ -73,6 +82,7 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            x_axis,
             ..
         } = perf;
         Self {
@@ -83,66 +93,100 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            x_axis,
         }
     }
 }
 
 impl SubCmd for Perf {
     async fn exec(&self) -> Result<(), CliError> {
-        let sender = perf_sender(self.project.clone(), self.clone());
-        if let Some(table_style) = self.table {
-            let json_perf: JsonPerf = self.backend.send_with(sender).await?;
-            let mut perf_table: Table = json_perf.into();
-            if let Some(table_style) = table_style {
-                table_style.stylize(&mut perf_table);
+        if self.summary {
+            let sender = perf_summary_sender(self.project.clone(), self.clone());
+            if let Some(table_style) = self.table {
+                let json_perf_summary: JsonPerfSummary = self.backend.send_with(sender).await?;
+                print_table(json_perf_summary.into(), table_style);
+            } else {
+                self.backend.send(sender).await?;
             }
-            cli_println!("{perf_table}");
         } else {
-            self.backend.send(sender).await?;
+            let sender = perf_sender(self.project.clone(), self.clone());
+            if let Some(table_style) = self.table {
+                let json_perf: JsonPerf = self.backend.send_with(sender).await?;
+                print_table(json_perf.into(), table_style);
+            } else {
+                self.backend.send(sender).await?;
+            }
         }
         Ok(())
     }
 }
 
-type PerfQueryResult = Pin<
-    Box<
-        dyn Future<
-                Output = Result<
-                    progenitor_client::ResponseValue<bencher_client::types::JsonPerf>,
-                    bencher_client::Error<bencher_client::types::Error>,
-                >,
-            > + Send,
-    >,
->;
-fn perf_sender(
-    project: ResourceId,
-    json_perf_query: impl Into<JsonPerfQuery>,
-) -> Box<dyn Fn(bencher_client::Client) -> PerfQueryResult + Send> {
-    let json_perf_query: JsonPerfQuery = json_perf_query.into();
-    Box::new(move |client: bencher_client::Client| {
-        let project = project.clone();
-        let json_perf_query = json_perf_query.clone();
-        Box::pin(async move {
-            let mut client = client
-                .proj_perf_get()
-                .project(project.clone())
-                .branches(json_perf_query.branches())
-                .testbeds(json_perf_query.testbeds())
-                .benchmarks(json_perf_query.benchmarks())
-                .measures(json_perf_query.measures());
-
-            if let Some(heads) = json_perf_query.heads() {
-                client = client.heads(heads);
-            }
+pub(crate) fn print_table(mut perf_table: Table, table_style: Option<TableStyle>) {
+    if let Some(table_style) = table_style {
+        table_style.stylize(&mut perf_table);
+    }
+    cli_println!("{perf_table}");
+}
 
-            if let Some(start_time) = json_perf_query.start_time() {
-                client = client.start_time(start_time);
-            }
-            if let Some(end_time) = json_perf_query.end_time() {
-                client = client.end_time(end_time);
-            }
+// The perf and perf summary endpoints take the same query parameters
+macro_rules! perf_sender {
+    ($sender:ident, $result:ident, $json:ident, $endpoint:ident) => {
+        type $result = Pin<
+            Box<
+                dyn Future<
+                        Output = Result<
+                            progenitor_client::ResponseValue<bencher_client::types::$json>,
+                            bencher_client::Error<bencher_client::types::Error>,
+                        >,
+                    > + Send,
+            >,
+        >;
+        fn $sender(
+            project: ResourceId,
+            json_perf_query: impl Into<JsonPerfQuery>,
+        ) -> Box<dyn Fn(bencher_client::Client) -> $result + Send> {
+            let json_perf_query: JsonPerfQuery = json_perf_query.into();
+            Box::new(move |client: bencher_client::Client| {
+                let project = project.clone();
+                let json_perf_query = json_perf_query.clone();
+                Box::pin(async move {
+                    let mut client = client
+                        .$endpoint()
+                        .project(project.clone())
+                        .branches(json_perf_query.branches())
+                        .testbeds(json_perf_query.testbeds())
+                        .benchmarks(json_perf_query.benchmarks())
+                        .measures(json_perf_query.measures());
 
-            client.send().await
-        })
-    })
+                    if let Some(heads) = json_perf_query.heads() {
+                        client = client.heads(heads);
+                    }
+
+                    if let Some(start_time) = json_perf_query.start_time() {
+                        client = client.start_time(start_time);
+                    }
+                    if let Some(end_time) = json_perf_query.end_time() {
+                        client = client.end_time(end_time);
+                    }
+                    #[allow(clippy::absolute_paths)]
+                    if let Some(x_axis) = json_perf_query.x_axis {
+                        client = client.x_axis(match x_axis {
+                            XAxis::DateTime => bencher_client::types::XAxis::DateTime,
+                            XAxis::Version => bencher_client::types::XAxis::Version,
+                        });
+                    }
+
+                    client.send().await
+                })
+            })
+        }
+    };
 }
+
+perf_sender!(perf_sender, PerfQueryResult, JsonPerf, proj_perf_get);
+perf_sender!(
+    perf_summary_sender,
+    PerfSummaryQueryResult,
+    JsonPerfSummary,
+    proj_perf_summary_get
+);
@@ -98,6 +98,10 @@ impl From<Create> for JsonNewReport {
                 adapter,
                 average,
                 fold,
+                labels: None,
+                measures: None,
+                benchmark_prefix: None,
+                benchmark_suffix: None,
             }),
         }
     }
@@ -7,6 +7,8 @@ impl From<CliRunAdapter> for Adapter {
         match adapter {
             CliRunAdapter::Magic => Self::Magic,
             CliRunAdapter::Json => Self::Json,
+            CliRunAdapter::OpenMetrics => Self::OpenMetrics,
+            CliRunAdapter::Junit => Self::Junit,
             CliRunAdapter::CSharp => Self::CSharp,
             CliRunAdapter::CSharpDotNet => Self::CSharpDotNet,
             CliRunAdapter::Cpp => Self::Cpp,
@@ -19,9 +21,11 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::Js => Self::Js,
             CliRunAdapter::JsBenchmark => Self::JsBenchmark,
             CliRunAdapter::JsTime => Self::JsTime,
+            CliRunAdapter::JuliaBenchmarkTools => Self::JuliaBenchmarkTools,
             CliRunAdapter::Python => Self::Python,
             CliRunAdapter::PythonAsv => Self::PythonAsv,
             CliRunAdapter::PythonPytest => Self::PythonPytest,
+            CliRunAdapter::PythonPyperf => Self::PythonPyperf,
             CliRunAdapter::Ruby => Self::Ruby,
             CliRunAdapter::RubyBenchmark => Self::RubyBenchmark,
             CliRunAdapter::Rust => Self::Rust,
@@ -29,8 +33,12 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::RustCriterion => Self::RustCriterion,
             CliRunAdapter::RustIai => Self::RustIai,
             CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            CliRunAdapter::RustDivan => Self::RustDivan,
             CliRunAdapter::Shell => Self::Shell,
             CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
+            CliRunAdapter::ShellK6 => Self::ShellK6,
+            CliRunAdapter::ShellWrk => Self::ShellWrk,
+            CliRunAdapter::ShellVegeta => Self::ShellVegeta,
         }
     }
 }
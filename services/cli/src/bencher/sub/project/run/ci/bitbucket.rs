@@ -0,0 +1,446 @@
+use bencher_comment::ReportComment;
+use serde::Deserialize;
+use url::Url;
+
+use crate::cli_println_quietable;
+
+// Bitbucket Server does not have a built-in CI/CD system.
+// These are the pull request environment variables set by Jenkins multibranch pipelines
+// when using the Bitbucket Server branch source.
+// https://www.jenkins.io/doc/book/pipeline/multibranch/#additional-environment-variables
+const CHANGE_URL: &str = "CHANGE_URL";
+const CHANGE_FORK: &str = "CHANGE_FORK";
+
+const AUTHORIZATION: &str = "Authorization";
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct Bitbucket {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+    pub ci_i_am_vulnerable_to_pwn_requests: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BitbucketError {
+    #[error("Failed to parse Bitbucket Server pull request URL ({0}): {1}")]
+    BadPullRequestUrl(String, url::ParseError),
+    #[error("Bitbucket Server pull request URL is not of the form `{{server}}/projects/{{project}}/repos/{{repo}}/pull-requests/{{id}}`: {0}")]
+    InvalidPullRequestUrl(String),
+    #[error("{}", pwn_requests(_0))]
+    PwnRequest(String),
+    #[error("Failed to create Bitbucket Server API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list Bitbucket Server pull request comments: {0}")]
+    Comments(reqwest::Error),
+    #[error("Failed to create Bitbucket Server pull request comment: {0}")]
+    CreateComment(reqwest::Error),
+    #[error("Failed to update Bitbucket Server pull request comment: {0}")]
+    UpdateComment(reqwest::Error),
+    #[error("{}", permissions_help(_0))]
+    BadCommentPermissions(reqwest::Error),
+}
+
+fn permissions_help(err: &reqwest::Error) -> String {
+    format!(
+        "Bitbucket Server API token does not have permission to comment on pull requests.\nTo fix, use an HTTP access token with `Repository read` permissions (ie `--ci-bitbucket $BENCHER_BITBUCKET_TOKEN`).\nSee: https://bencher.dev/docs/explanation/bencher-run/#--ci-bitbucket-bitbucket_token\nError: {err}",
+    )
+}
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--ci-bitbucket-bitbucket_token",
+    )
+}
+
+fn pwn_requests(fork: &str) -> String {
+    format!(
+        "WARNING! Unsafe use of a Bitbucket Server pull request from a fork!\nThis is a pull request from a forked repository ({fork}). This is a major security risk!\nFor more information on pwn requests, see: https://securitylab.github.com/research/github-actions-preventing-pwn-requests/",
+    )
+}
+
+impl Bitbucket {
+    pub fn safety_check(&self, log: bool) -> Result<(), BitbucketError> {
+        // The name of the forked repository, if the pull request is from a fork.
+        let Ok(fork) = std::env::var(CHANGE_FORK) else {
+            return Ok(());
+        };
+        if fork.is_empty() {
+            return Ok(());
+        }
+
+        if self.ci_i_am_vulnerable_to_pwn_requests {
+            cli_println_quietable!(log, "{}", pwn_requests(&fork));
+            Ok(())
+        } else {
+            Err(BitbucketError::PwnRequest(fork))
+        }
+    }
+
+    pub async fn run(
+        &self,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), BitbucketError> {
+        // The pull request URL. For example, https://bitbucket.example.com/projects/PROJ/repos/repo/pull-requests/1.
+        let Ok(pull_request_url) = std::env::var(CHANGE_URL) else {
+            cli_println_quietable!(
+                log,
+                "Not running as a Bitbucket Server pull request build. Skipping CI integration.\n{}",
+                docker_env(CHANGE_URL)
+            );
+            return Ok(());
+        };
+
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        let mut pull_request = PullRequest::parse(&pull_request_url)?;
+        if let Some(pull_request_id) = self.ci_number {
+            pull_request.id = pull_request_id;
+        }
+        let comments = PullRequestComments::new(&pull_request, &self.token)?;
+
+        // Bitbucket Server comments are Markdown and do not render HTML,
+        // so the Bencher tag is hidden in a Markdown link reference definition.
+        let bencher_tag = bitbucket_tag(&report_comment.bencher_tag(self.ci_id.as_deref()));
+        // Get the comment ID and version if it exists
+        let comment = comments.get_comment(&bencher_tag).await?;
+
+        // Update or create the comment
+        let text = format!("{}\n\n{bencher_tag}", report_comment.human());
+        // Always update the comment if it exists
+        if let Some((comment_id, version)) = comment {
+            comments.update_comment(comment_id, version, &text).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            comments.create_comment(&text).await
+        }
+    }
+}
+
+fn bitbucket_tag(bencher_tag: &str) -> String {
+    format!("[bencher.dev]: # ({bencher_tag})")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PullRequest {
+    server_url: Url,
+    project_key: String,
+    repo_slug: String,
+    id: u64,
+}
+
+impl PullRequest {
+    // Parse a pull request URL of the form:
+    // `{server}/projects/{project}/repos/{repo}/pull-requests/{id}`
+    // or for personal repositories:
+    // `{server}/users/{user}/repos/{repo}/pull-requests/{id}`
+    fn parse(pull_request_url: &str) -> Result<Self, BitbucketError> {
+        let invalid = || BitbucketError::InvalidPullRequestUrl(pull_request_url.to_owned());
+        let url = Url::parse(pull_request_url)
+            .map_err(|e| BitbucketError::BadPullRequestUrl(pull_request_url.to_owned(), e))?;
+        let segments = url.path_segments().ok_or_else(invalid)?.collect::<Vec<_>>();
+        let repos = segments
+            .iter()
+            .position(|segment| *segment == "repos")
+            .ok_or_else(invalid)?;
+        let (context_path, owner) = segments.split_at(repos.checked_sub(2).ok_or_else(invalid)?);
+        let project_key = match owner {
+            ["projects", project_key, ..] => (*project_key).to_owned(),
+            ["users", user_slug, ..] => format!("~{user_slug}"),
+            _ => return Err(invalid()),
+        };
+        let (repo_slug, id) = match segments.get(repos + 1..repos + 4) {
+            Some([repo_slug, "pull-requests", id]) => {
+                ((*repo_slug).to_owned(), id.parse().map_err(|_e| invalid())?)
+            },
+            _ => return Err(invalid()),
+        };
+        let mut server_url = url.clone();
+        server_url.set_query(None);
+        server_url.set_fragment(None);
+        server_url
+            .path_segments_mut()
+            .map_err(|()| invalid())?
+            .clear()
+            .extend(context_path);
+        Ok(Self {
+            server_url,
+            project_key,
+            repo_slug,
+            id,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Page<T> {
+    values: Vec<T>,
+    #[serde(rename = "isLastPage")]
+    is_last: bool,
+    next_page_start: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Activity {
+    comment: Option<Comment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Comment {
+    id: u64,
+    version: u64,
+    text: String,
+}
+
+// https://developer.atlassian.com/server/bitbucket/rest/v906/api-group-pull-requests/
+#[derive(Debug)]
+pub struct PullRequestComments {
+    client: reqwest::Client,
+    pull_request_url: Url,
+    token: String,
+}
+
+impl PullRequestComments {
+    pub fn new(pull_request: &PullRequest, token: &str) -> Result<Self, BitbucketError> {
+        let mut pull_request_url = pull_request.server_url.clone();
+        if let Ok(mut segments) = pull_request_url.path_segments_mut() {
+            segments.pop_if_empty().extend([
+                "rest",
+                "api",
+                "1.0",
+                "projects",
+                &pull_request.project_key,
+                "repos",
+                &pull_request.repo_slug,
+                "pull-requests",
+                &pull_request.id.to_string(),
+            ]);
+        }
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(BitbucketError::Client)?;
+        Ok(Self {
+            client,
+            pull_request_url,
+            token: token.to_owned(),
+        })
+    }
+
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.pull_request_url.clone();
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.extend(segments);
+        }
+        url
+    }
+
+    fn authorization(&self) -> String {
+        format!("Bearer {}", self.token)
+    }
+
+    pub async fn get_comment(
+        &self,
+        bencher_tag: &str,
+    ) -> Result<Option<(u64, u64)>, BitbucketError> {
+        const LIMIT: usize = 100;
+
+        let mut start = 0;
+        loop {
+            let activities = self
+                .client
+                .get(self.url(&["activities"]))
+                .header(AUTHORIZATION, self.authorization())
+                .query(&[("start", start.to_string()), ("limit", LIMIT.to_string())])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| comment_error(e, BitbucketError::Comments))?
+                .json::<Page<Activity>>()
+                .await
+                .map_err(BitbucketError::Comments)?;
+
+            for comment in activities
+                .values
+                .into_iter()
+                .filter_map(|activity| activity.comment)
+            {
+                if comment.text.ends_with(bencher_tag) {
+                    return Ok(Some((comment.id, comment.version)));
+                }
+            }
+
+            match activities.next_page_start {
+                Some(next_page_start) if !activities.is_last => start = next_page_start,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    pub async fn create_comment(&self, text: &str) -> Result<(), BitbucketError> {
+        self.client
+            .post(self.url(&["comments"]))
+            .header(AUTHORIZATION, self.authorization())
+            .json(&serde_json::json!({ "text": text }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| comment_error(e, BitbucketError::CreateComment))
+    }
+
+    pub async fn update_comment(
+        &self,
+        comment_id: u64,
+        version: u64,
+        text: &str,
+    ) -> Result<(), BitbucketError> {
+        self.client
+            .put(self.url(&["comments", &comment_id.to_string()]))
+            .header(AUTHORIZATION, self.authorization())
+            .json(&serde_json::json!({ "text": text, "version": version }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| comment_error(e, BitbucketError::UpdateComment))
+    }
+}
+
+fn comment_error(
+    err: reqwest::Error,
+    map_err: fn(reqwest::Error) -> BitbucketError,
+) -> BitbucketError {
+    if matches!(
+        err.status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+    ) {
+        BitbucketError::BadCommentPermissions(err)
+    } else {
+        map_err(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{bitbucket_tag, PullRequest, PullRequestComments, AUTHORIZATION};
+    use crate::bencher::sub::project::run::ci::mock::{mock_forge, MockRequest, BENCHER_TAG};
+
+    #[test]
+    fn test_parse_pull_request() {
+        assert_eq!(
+            PullRequest::parse(
+                "https://bitbucket.example.com/projects/PROJ/repos/repo/pull-requests/42"
+            )
+            .unwrap(),
+            PullRequest {
+                server_url: "https://bitbucket.example.com/".parse().unwrap(),
+                project_key: "PROJ".into(),
+                repo_slug: "repo".into(),
+                id: 42,
+            }
+        );
+        assert_eq!(
+            PullRequest::parse(
+                "https://example.com/bitbucket/users/jane/repos/repo/pull-requests/7/overview"
+            )
+            .unwrap(),
+            PullRequest {
+                server_url: "https://example.com/bitbucket".parse().unwrap(),
+                project_key: "~jane".into(),
+                repo_slug: "repo".into(),
+                id: 7,
+            }
+        );
+        assert!(
+            PullRequest::parse("https://bitbucket.example.com/projects/PROJ/repos/repo").is_err()
+        );
+        assert!(
+            PullRequest::parse("https://bitbucket.example.com/repos/repo/pull-requests/1").is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_comment() {
+        let (server_url, requests) = mock_forge(
+            AUTHORIZATION,
+            r#"{"values": [{"action": "OPENED"}], "isLastPage": true}"#,
+        );
+        let pull_request = PullRequest::parse(&format!(
+            "{server_url}/projects/PROJ/repos/repo/pull-requests/7"
+        ))
+        .unwrap();
+        let comments = PullRequestComments::new(&pull_request, "token").unwrap();
+
+        let comment = comments
+            .get_comment(&bitbucket_tag(BENCHER_TAG))
+            .await
+            .unwrap();
+        assert_eq!(comment, None);
+        comments.create_comment("report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                MockRequest {
+                    method: "GET".into(),
+                    path: "/rest/api/1.0/projects/PROJ/repos/repo/pull-requests/7/activities?start=0&limit=100".into(),
+                    auth: Some("Bearer token".into()),
+                    body: String::new(),
+                },
+                MockRequest {
+                    method: "POST".into(),
+                    path: "/rest/api/1.0/projects/PROJ/repos/repo/pull-requests/7/comments".into(),
+                    auth: Some("Bearer token".into()),
+                    body: r#"{"text":"report"}"#.into(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_comment() {
+        let (server_url, requests) = mock_forge(
+            AUTHORIZATION,
+            r#"{"values": [{"action": "COMMENTED", "comment": {"id": 5, "version": 2, "text": "report\n\n[bencher.dev]: # (<div id=\"bencher.dev/projects/project/id/ci\"></div>)"}}], "isLastPage": true}"#,
+        );
+        let pull_request = PullRequest::parse(&format!(
+            "{server_url}/projects/PROJ/repos/repo/pull-requests/7"
+        ))
+        .unwrap();
+        let comments = PullRequestComments::new(&pull_request, "token").unwrap();
+
+        let comment = comments
+            .get_comment(&bitbucket_tag(BENCHER_TAG))
+            .await
+            .unwrap();
+        assert_eq!(comment, Some((5, 2)));
+        comments.update_comment(5, 2, "new report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests.last(),
+            Some(&MockRequest {
+                method: "PUT".into(),
+                path: "/rest/api/1.0/projects/PROJ/repos/repo/pull-requests/7/comments/5".into(),
+                auth: Some("Bearer token".into()),
+                body: r#"{"text":"new report","version":2}"#.into(),
+            })
+        );
+    }
+}
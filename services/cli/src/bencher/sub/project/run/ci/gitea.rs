@@ -0,0 +1,419 @@
+use bencher_comment::ReportComment;
+use serde::Deserialize;
+use url::Url;
+
+use crate::cli_println_quietable;
+
+// Gitea and Forgejo Actions set the same default environment variables as GitHub Actions.
+// https://docs.gitea.com/usage/actions/comparison#context-availability
+const GITEA_ACTIONS: &str = "GITEA_ACTIONS";
+const FORGEJO_ACTIONS: &str = "FORGEJO_ACTIONS";
+const GITHUB_API_URL: &str = "GITHUB_API_URL";
+const GITHUB_SERVER_URL: &str = "GITHUB_SERVER_URL";
+const GITHUB_EVENT_PATH: &str = "GITHUB_EVENT_PATH";
+const GITHUB_EVENT_NAME: &str = "GITHUB_EVENT_NAME";
+
+const PULL_REQUEST: &str = "pull_request";
+const PULL_REQUEST_TARGET: &str = "pull_request_target";
+
+const FULL_NAME: &str = "full_name";
+
+const AUTHORIZATION: &str = "Authorization";
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct Gitea {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+    pub ci_i_am_vulnerable_to_pwn_requests: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GiteaError {
+    #[error(
+        "Failed to get Gitea Actions event path\n{}",
+        docker_env(GITHUB_EVENT_PATH)
+    )]
+    NoEventPath,
+    #[error("Failed to read Gitea Actions event path ({0}): {1}")]
+    BadEventPath(String, std::io::Error),
+    #[error("Failed to parse Gitea Actions event ({0}): {1}")]
+    BadEvent(String, serde_json::Error),
+    #[error("Gitea Actions event pull request head repo full name is missing: {0}")]
+    NoHeadFullName(String),
+    #[error("{}", pwn_requests(head, base))]
+    PwnRequest { head: String, base: String },
+    #[error("Gitea Actions event ({1}) PR number is missing: {0}")]
+    NoPRNumber(String, String),
+    #[error("Gitea Actions event repository full name is missing: {0}")]
+    NoFullName(String),
+    #[error("Gitea Actions event repository full name is not of the form `owner/repo`: ({0})")]
+    InvalidFullName(String),
+
+    #[error("Failed to get Gitea API URL\n{}", docker_env(GITHUB_SERVER_URL))]
+    NoApiUrl,
+    #[error("Failed to parse Gitea API URL ({0}): {1}")]
+    BadApiUrl(String, url::ParseError),
+    #[error("Gitea API URL cannot be a base: {0}")]
+    CannotBeABase(String),
+    #[error("Failed to create Gitea API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list Gitea PR comments: {0}")]
+    Comments(reqwest::Error),
+    #[error("Failed to create Gitea PR comment: {0}")]
+    CreateComment(reqwest::Error),
+    #[error("Failed to update Gitea PR comment: {0}")]
+    UpdateComment(reqwest::Error),
+    #[error("{}", permissions_help(_0))]
+    BadCommentPermissions(reqwest::Error),
+}
+
+fn permissions_help(err: &reqwest::Error) -> String {
+    format!(
+        "Gitea API token does not have `write` permissions for issues.\nTo fix, use an access token with the `write:issue` scope (ie `--ci-gitea ${{{{ secrets.BENCHER_GITEA_TOKEN }}}}`).\nSee: https://bencher.dev/docs/explanation/bencher-run/#--ci-gitea-gitea_token\nError: {err}",
+    )
+}
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--ci-gitea-gitea_token",
+    )
+}
+
+fn pwn_requests(base: &str, head: &str) -> String {
+    format!(
+        "WARNING! Unsafe use of Gitea Actions `pull_request` event!\nThis is a pull request from a forked repository owned by {head} to you ({base}). This is a major security risk!\nFor more information on pwn requests, see: https://securitylab.github.com/research/github-actions-preventing-pwn-requests/",
+    )
+}
+
+impl Gitea {
+    pub fn safety_check(&self, log: bool) -> Result<(), GiteaError> {
+        if !is_gitea_actions() {
+            return Ok(());
+        }
+
+        if let Ok(PULL_REQUEST) = std::env::var(GITHUB_EVENT_NAME).as_deref() {
+            let (event_str, event) = gitea_event()?;
+            let head = event
+                .pointer("/pull_request/head/repo/full_name")
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| GiteaError::NoHeadFullName(event_str.clone()))?;
+            let (head_owner, _) = split_full_name(head)?;
+
+            let base = repository_full_name(&event_str, &event)?;
+            let (base_owner, _) = split_full_name(base)?;
+
+            if head_owner != base_owner {
+                if self.ci_i_am_vulnerable_to_pwn_requests {
+                    cli_println_quietable!(log, "{}", pwn_requests(head_owner, base_owner));
+                } else {
+                    return Err(GiteaError::PwnRequest {
+                        head: head.to_owned(),
+                        base: base.to_owned(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GiteaError> {
+        if !is_gitea_actions() {
+            cli_println_quietable!(
+                log,
+                "Not running as a Gitea or Forgejo Action. Skipping CI integration.\n{}",
+                docker_env(GITEA_ACTIONS)
+            );
+            return Ok(());
+        }
+
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        let (event_str, event) = gitea_event()?;
+        let issue_number = if let Some(issue_number) = self.ci_number {
+            issue_number
+        } else if let Ok(event_name @ (PULL_REQUEST | PULL_REQUEST_TARGET)) =
+            std::env::var(GITHUB_EVENT_NAME).as_deref()
+        {
+            event
+                .get("number")
+                .and_then(serde_json::Value::as_u64)
+                .ok_or_else(|| GiteaError::NoPRNumber(event_str.clone(), event_name.into()))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running as a Gitea or Forgejo Action pull request event (`pull_request` or `pull_request_target`) and the `--ci-number` option was not set. Skipping CI integration.\n{}",
+                docker_env(GITHUB_EVENT_NAME)
+            );
+            return Ok(());
+        };
+
+        let full_name = repository_full_name(&event_str, &event)?;
+        let (owner, repo) = split_full_name(full_name)?;
+        let comments = IssueComments::new(&api_url()?, owner, repo, issue_number, &self.token)?;
+
+        // Get the comment ID if it exists
+        let comment_id = comments
+            .get_comment(&report_comment.bencher_tag(self.ci_id.as_deref()))
+            .await?;
+
+        // Update or create the comment
+        let body = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
+        // Always update the comment if it exists
+        if let Some(comment_id) = comment_id {
+            comments.update_comment(comment_id, &body).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            comments.create_comment(&body).await
+        }
+    }
+}
+
+// Forgejo sets both `FORGEJO_ACTIONS` and `GITEA_ACTIONS` to `true`.
+fn is_gitea_actions() -> bool {
+    std::env::var(GITEA_ACTIONS).as_deref() == Ok("true")
+        || std::env::var(FORGEJO_ACTIONS).as_deref() == Ok("true")
+}
+
+fn gitea_event() -> Result<(String, serde_json::Value), GiteaError> {
+    let Ok(event_path) = std::env::var(GITHUB_EVENT_PATH) else {
+        return Err(GiteaError::NoEventPath);
+    };
+    let event_str = std::fs::read_to_string(&event_path)
+        .map_err(|e| GiteaError::BadEventPath(event_path, e))?;
+    let event =
+        serde_json::from_str(&event_str).map_err(|e| GiteaError::BadEvent(event_str.clone(), e))?;
+    Ok((event_str, event))
+}
+
+fn api_url() -> Result<String, GiteaError> {
+    if let Ok(api_url) = std::env::var(GITHUB_API_URL) {
+        Ok(api_url)
+    } else if let Ok(server_url) = std::env::var(GITHUB_SERVER_URL) {
+        Ok(format!("{}/api/v1", server_url.trim_end_matches('/')))
+    } else {
+        Err(GiteaError::NoApiUrl)
+    }
+}
+
+fn repository_full_name<'e>(
+    event_str: &str,
+    event: &'e serde_json::Value,
+) -> Result<&'e str, GiteaError> {
+    event
+        .get("repository")
+        .and_then(|repository| repository.get(FULL_NAME))
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| GiteaError::NoFullName(event_str.to_owned()))
+}
+
+fn split_full_name(full_name: &str) -> Result<(&str, &str), GiteaError> {
+    full_name
+        .split_once('/')
+        .ok_or_else(|| GiteaError::InvalidFullName(full_name.to_owned()))
+}
+
+#[derive(Debug, Deserialize)]
+struct Comment {
+    id: u64,
+    body: String,
+}
+
+// https://docs.gitea.com/api/1.22/#tag/issue/operation/issueGetComments
+#[derive(Debug)]
+pub struct IssueComments {
+    client: reqwest::Client,
+    repo_url: Url,
+    issue_number: u64,
+    token: String,
+}
+
+impl IssueComments {
+    pub fn new(
+        api_url: &str,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        token: &str,
+    ) -> Result<Self, GiteaError> {
+        let mut repo_url =
+            Url::parse(api_url).map_err(|e| GiteaError::BadApiUrl(api_url.to_owned(), e))?;
+        repo_url
+            .path_segments_mut()
+            .map_err(|()| GiteaError::CannotBeABase(api_url.to_owned()))?
+            .pop_if_empty()
+            .extend(["repos", owner, repo]);
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(GiteaError::Client)?;
+        Ok(Self {
+            client,
+            repo_url,
+            issue_number,
+            token: token.to_owned(),
+        })
+    }
+
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.repo_url.clone();
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.extend(segments);
+        }
+        url
+    }
+
+    fn comments_url(&self) -> Url {
+        self.url(&["issues", &self.issue_number.to_string(), "comments"])
+    }
+
+    fn authorization(&self) -> String {
+        format!("token {}", self.token)
+    }
+
+    pub async fn get_comment(&self, bencher_tag: &str) -> Result<Option<u64>, GiteaError> {
+        const LIMIT: usize = 50;
+
+        let mut page: u32 = 1;
+        loop {
+            let comments = self
+                .client
+                .get(self.comments_url())
+                .header(AUTHORIZATION, self.authorization())
+                .query(&[("limit", LIMIT.to_string()), ("page", page.to_string())])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| comment_error(e, GiteaError::Comments))?
+                .json::<Vec<Comment>>()
+                .await
+                .map_err(GiteaError::Comments)?;
+
+            let comments_len = comments.len();
+            if comments_len == 0 {
+                return Ok(None);
+            }
+
+            for comment in comments {
+                if comment.body.ends_with(bencher_tag) {
+                    return Ok(Some(comment.id));
+                }
+            }
+
+            if comments_len < LIMIT {
+                return Ok(None);
+            }
+
+            page += 1;
+        }
+    }
+
+    pub async fn create_comment(&self, body: &str) -> Result<(), GiteaError> {
+        self.client
+            .post(self.comments_url())
+            .header(AUTHORIZATION, self.authorization())
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| comment_error(e, GiteaError::CreateComment))
+    }
+
+    pub async fn update_comment(&self, comment_id: u64, body: &str) -> Result<(), GiteaError> {
+        self.client
+            .patch(self.url(&["issues", "comments", &comment_id.to_string()]))
+            .header(AUTHORIZATION, self.authorization())
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| comment_error(e, GiteaError::UpdateComment))
+    }
+}
+
+fn comment_error(err: reqwest::Error, map_err: fn(reqwest::Error) -> GiteaError) -> GiteaError {
+    if matches!(
+        err.status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+    ) {
+        GiteaError::BadCommentPermissions(err)
+    } else {
+        map_err(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{IssueComments, AUTHORIZATION};
+    use crate::bencher::sub::project::run::ci::mock::{mock_forge, MockRequest, BENCHER_TAG};
+
+    #[tokio::test]
+    async fn test_create_comment() {
+        let (server_url, requests) = mock_forge(AUTHORIZATION, "[]");
+        let api_url = format!("{server_url}/api/v1");
+        let comments = IssueComments::new(&api_url, "owner", "repo", 7, "token").unwrap();
+
+        let comment_id = comments.get_comment(BENCHER_TAG).await.unwrap();
+        assert_eq!(comment_id, None);
+        comments.create_comment("report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                MockRequest {
+                    method: "GET".into(),
+                    path: "/api/v1/repos/owner/repo/issues/7/comments?limit=50&page=1".into(),
+                    auth: Some("token token".into()),
+                    body: String::new(),
+                },
+                MockRequest {
+                    method: "POST".into(),
+                    path: "/api/v1/repos/owner/repo/issues/7/comments".into(),
+                    auth: Some("token token".into()),
+                    body: r#"{"body":"report"}"#.into(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_comment() {
+        let (server_url, requests) = mock_forge(
+            AUTHORIZATION,
+            r#"[{"id": 3, "body": "report<div id=\"bencher.dev/projects/project/id/ci\"></div>"}]"#,
+        );
+        let api_url = format!("{server_url}/api/v1");
+        let comments = IssueComments::new(&api_url, "owner", "repo", 7, "token").unwrap();
+
+        let comment_id = comments.get_comment(BENCHER_TAG).await.unwrap();
+        assert_eq!(comment_id, Some(3));
+        comments.update_comment(3, "new report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests.last(),
+            Some(&MockRequest {
+                method: "PATCH".into(),
+                path: "/api/v1/repos/owner/repo/issues/comments/3".into(),
+                auth: Some("token token".into()),
+                body: r#"{"body":"new report"}"#.into(),
+            })
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use bencher_comment::ReportComment;
 use octocrab::{
     models::CommentId,
-    params::checks::{CheckRunConclusion, CheckRunOutput},
+    params::checks::{
+        CheckRunConclusion, CheckRunOutput, CheckRunOutputAnnotation, CheckRunOutputAnnotationLevel,
+    },
     Octocrab,
 };
 
@@ -12,6 +14,7 @@ const GITHUB_EVENT_PATH: &str = "GITHUB_EVENT_PATH";
 const GITHUB_EVENT_NAME: &str = "GITHUB_EVENT_NAME";
 const GITHUB_SHA: &str = "GITHUB_SHA";
 const GITHUB_STEP_SUMMARY: &str = "GITHUB_STEP_SUMMARY";
+const GITHUB_WORKFLOW_REF: &str = "GITHUB_WORKFLOW_REF";
 
 const PULL_REQUEST: &str = "pull_request";
 const PULL_REQUEST_TARGET: &str = "pull_request_target";
@@ -21,11 +24,15 @@ const FULL_NAME: &str = "full_name";
 // https://docs.github.com/en/rest/checks/runs?apiVersion=2022-11-28#create-a-check-run
 #[allow(clippy::decimal_literal_representation)]
 const CHECK_MAX_LENGTH: usize = 65_536;
+// https://docs.github.com/en/rest/checks/runs?apiVersion=2022-11-28#update-a-check-run
+const CHECK_MAX_ANNOTATIONS: usize = 50;
+const CHECK_NAME: &str = "Bencher Report";
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug)]
 pub struct GitHubActions {
     pub token: String,
+    pub ci_github_checks: bool,
     pub ci_only_thresholds: bool,
     pub ci_only_on_alert: bool,
     pub ci_public_links: bool,
@@ -92,6 +99,8 @@ pub enum GitHubError {
     NoSha,
     #[error("Failed to create GitHub Check: {0}")]
     CreateCheck(octocrab::Error),
+    #[error("Failed to update GitHub Check: {0}")]
+    UpdateCheck(octocrab::Error),
     #[error("{}", permissions_help("checks", "base-branch", _0))]
     BadCheckPermissions(octocrab::Error),
 }
@@ -208,7 +217,14 @@ impl GitHubActions {
         };
 
         self.create_pull_request_comment(report_comment, log, &event_str, &event, issue_number)
-            .await
+            .await?;
+
+        if self.ci_github_checks {
+            self.create_github_check(report_comment, &event_str, &event)
+                .await?;
+        }
+
+        Ok(())
     }
 
     fn create_job_summary(&self, report_comment: &ReportComment, log: bool) {
@@ -232,47 +248,83 @@ impl GitHubActions {
     ) -> Result<(), GitHubError> {
         let full_name = repository_full_name(event_str, event)?;
         let (owner, repo) = split_full_name(full_name)?;
-        let Ok(head_sha) = std::env::var(GITHUB_SHA) else {
+        // For pull request events, `GITHUB_SHA` is the merge commit
+        // so the check needs to go on the head commit of the pull request instead.
+        let head_sha = if let Some(head_sha) = event
+            .pointer("/pull_request/head/sha")
+            .and_then(serde_json::Value::as_str)
+        {
+            head_sha.to_owned()
+        } else if let Ok(head_sha) = std::env::var(GITHUB_SHA) {
+            head_sha
+        } else {
             return Err(GitHubError::NoSha);
         };
+        // Use a separate check for each `--ci-id`, so each can be required by branch protection
+        let name = self.ci_id.as_ref().map_or_else(
+            || CHECK_NAME.to_owned(),
+            |ci_id| format!("{CHECK_NAME} ({ci_id})"),
+        );
         let summary = report_comment.html_with_max_length(
             self.ci_only_thresholds,
             self.ci_id.as_deref(),
             CHECK_MAX_LENGTH,
         );
+        let text = report_comment
+            .markdown_alerts_table()
+            .filter(|text| text.len() <= CHECK_MAX_LENGTH);
+        let mut annotations = check_annotations(report_comment, full_name);
+        let title = check_title(annotations.len());
+        let remaining_annotations =
+            annotations.split_off(annotations.len().min(CHECK_MAX_ANNOTATIONS));
+
         let report = CheckRunOutput {
-            title: String::new(),
-            summary,
-            text: None,
-            annotations: Vec::new(),
+            title: title.clone(),
+            summary: summary.clone(),
+            text,
+            annotations,
             images: Vec::new(),
         };
-        let check = Octocrab::builder()
+        let github_client = Octocrab::builder()
             .user_access_token(self.token.clone())
             .build()
-            .map_err(GitHubError::Auth)?
-            .checks(owner, repo)
-            .create_check_run("Bencher Report", head_sha)
+            .map_err(GitHubError::Auth)?;
+        let checks = github_client.checks(owner, repo);
+        let check_run = checks
+            .create_check_run(name, head_sha)
             .output(report)
-            .conclusion(if report_comment.has_alert() {
-                CheckRunConclusion::Failure
-            } else {
-                CheckRunConclusion::Success
-            })
+            .conclusion(check_conclusion(
+                report_comment,
+                self.ci_github_checks,
+                self.ci_only_thresholds,
+            ))
             .send()
-            .await;
-        if let Err(e) = check {
-            Err(
-                // https://github.blog/changelog/2023-02-02-github-actions-updating-the-default-github_token-permissions-to-read-only/
-                if is_permissions_error(&e) {
-                    GitHubError::BadCheckPermissions(e)
-                } else {
-                    GitHubError::CreateCheck(e)
-                },
-            )
-        } else {
-            Ok(())
+            .await
+            .map_err(|e| check_error(e, GitHubError::CreateCheck))?;
+
+        // Only a limited number of annotations can be sent per request,
+        // so any remaining annotations are appended by updating the check.
+        let mut remaining_annotations = remaining_annotations.into_iter().peekable();
+        while remaining_annotations.peek().is_some() {
+            let report = CheckRunOutput {
+                title: title.clone(),
+                summary: summary.clone(),
+                text: None,
+                annotations: remaining_annotations
+                    .by_ref()
+                    .take(CHECK_MAX_ANNOTATIONS)
+                    .collect(),
+                images: Vec::new(),
+            };
+            checks
+                .update_check_run(check_run.id)
+                .output(report)
+                .send()
+                .await
+                .map_err(|e| check_error(e, GitHubError::UpdateCheck))?;
         }
+
+        Ok(())
     }
 
     pub async fn create_pull_request_comment(
@@ -331,6 +383,85 @@ impl GitHubActions {
     }
 }
 
+// With `--ci-github-checks`, active alerts fail the check, so branch protection can block the merge.
+// Warnings are neutral, so they are visible without blocking the merge.
+// Otherwise, any alert fails the check.
+fn check_conclusion(
+    report_comment: &ReportComment,
+    ci_github_checks: bool,
+    require_threshold: bool,
+) -> CheckRunConclusion {
+    if !ci_github_checks {
+        if report_comment.has_alert() {
+            CheckRunConclusion::Failure
+        } else {
+            CheckRunConclusion::Success
+        }
+    } else if report_comment.has_active_alert() {
+        CheckRunConclusion::Failure
+    } else if report_comment.has_warning(require_threshold) {
+        CheckRunConclusion::Neutral
+    } else {
+        CheckRunConclusion::Success
+    }
+}
+
+fn check_title(alerts_len: usize) -> String {
+    match alerts_len {
+        0 => "No Alerts".to_owned(),
+        1 => "1 Alert".to_owned(),
+        _ => format!("{alerts_len} Alerts"),
+    }
+}
+
+fn check_annotations(
+    report_comment: &ReportComment,
+    full_name: &str,
+) -> Vec<CheckRunOutputAnnotation> {
+    let path = workflow_path(
+        full_name,
+        std::env::var(GITHUB_WORKFLOW_REF).ok().as_deref(),
+    );
+    report_comment
+        .check_alerts()
+        .into_iter()
+        .map(|check_alert| CheckRunOutputAnnotation {
+            path: path.clone(),
+            start_line: 1,
+            end_line: 1,
+            start_column: None,
+            end_column: None,
+            annotation_level: if check_alert.active {
+                CheckRunOutputAnnotationLevel::Failure
+            } else {
+                CheckRunOutputAnnotationLevel::Warning
+            },
+            message: check_alert.message,
+            title: Some(check_alert.title),
+            raw_details: None,
+        })
+        .collect()
+}
+
+// Benchmarks do not map to a source file, so annotations are attached to the workflow file.
+// The ref path to the workflow. For example, octocat/hello-world/.github/workflows/my-workflow.yml@refs/heads/my_branch.
+fn workflow_path(full_name: &str, workflow_ref: Option<&str>) -> String {
+    workflow_ref
+        .and_then(|workflow_ref| workflow_ref.strip_prefix(full_name))
+        .and_then(|path| path.strip_prefix('/'))
+        .and_then(|path| path.split('@').next())
+        .map_or_else(|| ".github".to_owned(), ToOwned::to_owned)
+}
+
+fn check_error(err: octocrab::Error, map_err: fn(octocrab::Error) -> GitHubError) -> GitHubError {
+    // https://github.blog/changelog/2023-02-02-github-actions-updating-the-default-github_token-permissions-to-read-only/
+    if is_permissions_error(&err) {
+        GitHubError::BadCheckPermissions(err)
+    } else {
+        map_err(err)
+    }
+}
+
 // https://docs.github.com/en/actions/learn-github-actions/variables#default-environment-variables
 // Always set to `true` when GitHub Actions is running the workflow. You can use this variable to differentiate when tests are being run locally or by GitHub Actions.
 fn is_github_actions() -> bool {
@@ -419,3 +550,36 @@ fn is_permissions_error(err: &octocrab::Error) -> bool {
     err.to_string()
         .contains("Resource not accessible by integration")
 }
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::workflow_path;
+
+    #[test]
+    fn test_workflow_path() {
+        assert_eq!(
+            workflow_path(
+                "octocat/hello-world",
+                Some("octocat/hello-world/.github/workflows/my-workflow.yml@refs/heads/my_branch")
+            ),
+            ".github/workflows/my-workflow.yml"
+        );
+        assert_eq!(
+            workflow_path(
+                "octocat/hello-world",
+                Some("octocat/hello-world/.github/workflows/my-workflow.yml@refs/pull/1/merge")
+            ),
+            ".github/workflows/my-workflow.yml"
+        );
+        assert_eq!(
+            workflow_path(
+                "octocat/hello-world",
+                Some("octocat/other/.github/workflows/reusable.yml@refs/heads/main")
+            ),
+            ".github"
+        );
+        assert_eq!(workflow_path("octocat/hello-world", None), ".github");
+    }
+}
@@ -0,0 +1,308 @@
+use bencher_comment::ReportComment;
+use serde::Deserialize;
+use url::Url;
+
+use crate::cli_println_quietable;
+
+const CI_API_V4_URL: &str = "CI_API_V4_URL";
+const CI_PROJECT_ID: &str = "CI_PROJECT_ID";
+const CI_MERGE_REQUEST_IID: &str = "CI_MERGE_REQUEST_IID";
+
+// https://docs.gitlab.com/ee/api/rest/authentication.html#personalprojectgroup-access-tokens
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct GitLab {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitLabError {
+    #[error(
+        "Failed to parse GitLab API URL ({0}): {1}\n{help}",
+        help = docker_env(CI_API_V4_URL)
+    )]
+    BadApiUrl(String, url::ParseError),
+    #[error("GitLab API URL cannot be a base: {0}")]
+    CannotBeABase(String),
+    #[error("Failed to get GitLab project ID\n{}", docker_env(CI_PROJECT_ID))]
+    NoProjectId,
+    #[error("Failed to parse GitLab merge request IID ({0}): {1}")]
+    BadMergeRequestIid(String, std::num::ParseIntError),
+    #[error("Failed to create GitLab API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list GitLab merge request notes: {0}")]
+    Notes(reqwest::Error),
+    #[error("Failed to create GitLab merge request note: {0}")]
+    CreateNote(reqwest::Error),
+    #[error("Failed to update GitLab merge request note: {0}")]
+    UpdateNote(reqwest::Error),
+    #[error("{}", permissions_help(_0))]
+    BadNotePermissions(reqwest::Error),
+}
+
+fn permissions_help(err: &reqwest::Error) -> String {
+    format!(
+        "GitLab API token does not have permission to comment on merge requests.\nTo fix, use a project access token with the `api` scope and at least the `Reporter` role (ie `--ci-gitlab $BENCHER_GITLAB_TOKEN`).\nSee: https://bencher.dev/docs/explanation/bencher-run/#--ci-gitlab-gitlab_token\nError: {err}",
+    )
+}
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--ci-gitlab-gitlab_token",
+    )
+}
+
+impl GitLab {
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitLabError> {
+        // The GitLab API root URL. For example, https://gitlab.example.com/api/v4.
+        let Ok(api_url) = std::env::var(CI_API_V4_URL) else {
+            cli_println_quietable!(
+                log,
+                "Not running in GitLab CI/CD. Skipping CI integration.\n{}",
+                docker_env(CI_API_V4_URL)
+            );
+            return Ok(());
+        };
+
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        let merge_request_iid = if let Some(merge_request_iid) = self.ci_number {
+            merge_request_iid
+        } else if let Ok(merge_request_iid) = std::env::var(CI_MERGE_REQUEST_IID) {
+            // The project-level internal ID of the merge request.
+            // Only available in merge request pipelines.
+            merge_request_iid
+                .parse()
+                .map_err(|e| GitLabError::BadMergeRequestIid(merge_request_iid, e))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running in a GitLab CI/CD merge request pipeline and the `--ci-number` option was not set. Skipping CI integration.\n{}",
+                docker_env(CI_MERGE_REQUEST_IID)
+            );
+            return Ok(());
+        };
+        // The ID of the current project. This ID is unique across all projects on the GitLab instance.
+        let Ok(project_id) = std::env::var(CI_PROJECT_ID) else {
+            return Err(GitLabError::NoProjectId);
+        };
+
+        let notes = MergeRequestNotes::new(&api_url, &project_id, merge_request_iid, &self.token)?;
+
+        // Get the note ID if it exists
+        let note_id = notes
+            .get_note(&report_comment.bencher_tag(self.ci_id.as_deref()))
+            .await?;
+
+        // Update or create the note
+        let body = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
+        // Always update the note if it exists
+        if let Some(note_id) = note_id {
+            notes.update_note(note_id, &body).await
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            notes.create_note(&body).await
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Note {
+    id: u64,
+    body: String,
+}
+
+// https://docs.gitlab.com/ee/api/notes.html#merge-requests
+#[derive(Debug)]
+pub struct MergeRequestNotes {
+    client: reqwest::Client,
+    notes_url: Url,
+    token: String,
+}
+
+impl MergeRequestNotes {
+    pub fn new(
+        api_url: &str,
+        project_id: &str,
+        merge_request_iid: u64,
+        token: &str,
+    ) -> Result<Self, GitLabError> {
+        let mut notes_url =
+            Url::parse(api_url).map_err(|e| GitLabError::BadApiUrl(api_url.to_owned(), e))?;
+        notes_url
+            .path_segments_mut()
+            .map_err(|()| GitLabError::CannotBeABase(api_url.to_owned()))?
+            .pop_if_empty()
+            .extend([
+                "projects",
+                project_id,
+                "merge_requests",
+                &merge_request_iid.to_string(),
+                "notes",
+            ]);
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(GitLabError::Client)?;
+        Ok(Self {
+            client,
+            notes_url,
+            token: token.to_owned(),
+        })
+    }
+
+    pub async fn get_note(&self, bencher_tag: &str) -> Result<Option<u64>, GitLabError> {
+        const PER_PAGE: usize = 100;
+
+        let mut page: u32 = 1;
+        loop {
+            let notes = self
+                .client
+                .get(self.notes_url.clone())
+                .header(PRIVATE_TOKEN, &self.token)
+                .query(&[
+                    ("per_page", PER_PAGE.to_string()),
+                    ("page", page.to_string()),
+                ])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| note_error(e, GitLabError::Notes))?
+                .json::<Vec<Note>>()
+                .await
+                .map_err(GitLabError::Notes)?;
+
+            let notes_len = notes.len();
+            if notes_len == 0 {
+                return Ok(None);
+            }
+
+            for note in notes {
+                if note.body.ends_with(bencher_tag) {
+                    return Ok(Some(note.id));
+                }
+            }
+
+            if notes_len < PER_PAGE {
+                return Ok(None);
+            }
+
+            page += 1;
+        }
+    }
+
+    pub async fn create_note(&self, body: &str) -> Result<(), GitLabError> {
+        self.client
+            .post(self.notes_url.clone())
+            .header(PRIVATE_TOKEN, &self.token)
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| note_error(e, GitLabError::CreateNote))
+    }
+
+    pub async fn update_note(&self, note_id: u64, body: &str) -> Result<(), GitLabError> {
+        let mut note_url = self.notes_url.clone();
+        if let Ok(mut segments) = note_url.path_segments_mut() {
+            segments.push(&note_id.to_string());
+        }
+        self.client
+            .put(note_url)
+            .header(PRIVATE_TOKEN, &self.token)
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| note_error(e, GitLabError::UpdateNote))
+    }
+}
+
+fn note_error(err: reqwest::Error, map_err: fn(reqwest::Error) -> GitLabError) -> GitLabError {
+    if matches!(
+        err.status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+    ) {
+        GitLabError::BadNotePermissions(err)
+    } else {
+        map_err(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{MergeRequestNotes, PRIVATE_TOKEN};
+    use crate::bencher::sub::project::run::ci::mock::{mock_forge, MockRequest, BENCHER_TAG};
+
+    #[tokio::test]
+    async fn test_create_note() {
+        let (server_url, requests) = mock_forge(PRIVATE_TOKEN, r#"[{"id": 1, "body": "LGTM"}]"#);
+        let api_url = format!("{server_url}/api/v4");
+        let notes = MergeRequestNotes::new(&api_url, "42", 7, "token").unwrap();
+
+        let note_id = notes.get_note(BENCHER_TAG).await.unwrap();
+        assert_eq!(note_id, None);
+        notes.create_note("report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                MockRequest {
+                    method: "GET".into(),
+                    path: "/api/v4/projects/42/merge_requests/7/notes?per_page=100&page=1".into(),
+                    auth: Some("token".into()),
+                    body: String::new(),
+                },
+                MockRequest {
+                    method: "POST".into(),
+                    path: "/api/v4/projects/42/merge_requests/7/notes".into(),
+                    auth: Some("token".into()),
+                    body: r#"{"body":"report"}"#.into(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_note() {
+        let (server_url, requests) = mock_forge(
+            PRIVATE_TOKEN,
+            r#"[{"id": 1, "body": "LGTM"}, {"id": 2, "body": "report<div id=\"bencher.dev/projects/project/id/ci\"></div>"}]"#,
+        );
+        let api_url = format!("{server_url}/api/v4");
+        let notes = MergeRequestNotes::new(&api_url, "group/project", 7, "token").unwrap();
+
+        let note_id = notes.get_note(BENCHER_TAG).await.unwrap();
+        assert_eq!(note_id, Some(2));
+        notes.update_note(2, "new report").await.unwrap();
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests.last(),
+            Some(&MockRequest {
+                method: "PUT".into(),
+                path: "/api/v4/projects/group%2Fproject/merge_requests/7/notes/2".into(),
+                auth: Some("token".into()),
+                body: r#"{"body":"new report"}"#.into(),
+            })
+        );
+    }
+}
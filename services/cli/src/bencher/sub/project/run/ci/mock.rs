@@ -0,0 +1,71 @@
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+pub const BENCHER_TAG: &str = "<div id=\"bencher.dev/projects/project/id/ci\"></div>";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub auth: Option<String>,
+    pub body: String,
+}
+
+pub type MockRequests = Arc<Mutex<Vec<MockRequest>>>;
+
+// A minimal mock of a forge comments API that records each request
+// and responds with the given comments when they are listed.
+// The value of the `auth_header` is recorded for each request.
+pub fn mock_forge(auth_header: &'static str, comments: &'static str) -> (String, MockRequests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let server_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let thread_requests = requests.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_owned();
+            let path = parts.next().unwrap_or_default().to_owned();
+            let mut auth = None;
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                let (key, value) = header.split_once(": ").unwrap();
+                let key = key.to_lowercase();
+                if key == auth_header.to_lowercase() {
+                    auth = Some(value.to_owned());
+                } else if key == "content-length" {
+                    content_length = value.parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let response_body = if method == "GET" { comments } else { "{}" };
+            thread_requests.lock().unwrap().push(MockRequest {
+                method,
+                path,
+                auth,
+                body: String::from_utf8(body).unwrap(),
+            });
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response_body}",
+                response_body.len()
+            )
+            .unwrap();
+        }
+    });
+    (server_url, requests)
+}
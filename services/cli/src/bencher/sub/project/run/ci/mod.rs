@@ -4,19 +4,36 @@ use bencher_comment::ReportComment;
 
 use crate::parser::project::run::CliRunCi;
 
+mod bitbucket;
+mod gitea;
 mod github_actions;
+mod gitlab;
+#[cfg(test)]
+mod mock;
 
+use bitbucket::{Bitbucket, BitbucketError};
+use gitea::{Gitea, GiteaError};
 use github_actions::{GitHubActions, GitHubError};
+use gitlab::{GitLab, GitLabError};
 
 #[derive(Debug)]
 pub enum Ci {
     GitHubActions(GitHubActions),
+    GitLab(GitLab),
+    Gitea(Gitea),
+    Bitbucket(Bitbucket),
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CiError {
     #[error("{0}")]
     GitHub(#[from] GitHubError),
+    #[error("{0}")]
+    GitLab(#[from] GitLabError),
+    #[error("{0}")]
+    Gitea(#[from] GiteaError),
+    #[error("{0}")]
+    Bitbucket(#[from] BitbucketError),
 }
 
 impl TryFrom<CliRunCi> for Option<Ci> {
@@ -25,6 +42,10 @@ impl TryFrom<CliRunCi> for Option<Ci> {
     fn try_from(ci: CliRunCi) -> Result<Self, Self::Error> {
         let CliRunCi {
             github_actions,
+            ci_gitlab,
+            ci_gitea,
+            ci_bitbucket,
+            ci_github_checks,
             ci_only_thresholds,
             ci_only_on_alert,
             ci_public_links,
@@ -33,17 +54,49 @@ impl TryFrom<CliRunCi> for Option<Ci> {
             ci_i_am_vulnerable_to_pwn_requests,
             ci_deprecated: _,
         } = ci;
-        Ok(github_actions.map(|token| {
-            Ci::GitHubActions(GitHubActions {
+        Ok(if let Some(token) = github_actions {
+            Some(Ci::GitHubActions(GitHubActions {
                 token,
+                ci_github_checks,
                 ci_only_thresholds,
                 ci_only_on_alert,
                 ci_public_links,
                 ci_id,
                 ci_number,
                 ci_i_am_vulnerable_to_pwn_requests,
+            }))
+        } else if let Some(token) = ci_gitlab {
+            Some(Ci::GitLab(GitLab {
+                token,
+                ci_only_thresholds,
+                ci_only_on_alert,
+                ci_public_links,
+                ci_id,
+                ci_number,
+            }))
+        } else if let Some(token) = ci_gitea {
+            Some(Ci::Gitea(Gitea {
+                token,
+                ci_only_thresholds,
+                ci_only_on_alert,
+                ci_public_links,
+                ci_id,
+                ci_number,
+                ci_i_am_vulnerable_to_pwn_requests,
+            }))
+        } else {
+            ci_bitbucket.map(|token| {
+                Ci::Bitbucket(Bitbucket {
+                    token,
+                    ci_only_thresholds,
+                    ci_only_on_alert,
+                    ci_public_links,
+                    ci_id,
+                    ci_number,
+                    ci_i_am_vulnerable_to_pwn_requests,
+                })
             })
-        }))
+        })
     }
 }
 
@@ -53,6 +106,11 @@ impl Ci {
             Self::GitHubActions(github_actions) => {
                 github_actions.safety_check(log).map_err(Into::into)
             },
+            // GitLab only runs merge request pipelines from forks in the fork project,
+            // unless explicitly run in the parent project by a maintainer.
+            Self::GitLab(_) => Ok(()),
+            Self::Gitea(gitea) => gitea.safety_check(log).map_err(Into::into),
+            Self::Bitbucket(bitbucket) => bitbucket.safety_check(log).map_err(Into::into),
         }
     }
 
@@ -62,12 +120,20 @@ impl Ci {
                 .run(report_comment, log)
                 .await
                 .map_err(Into::into),
+            Self::GitLab(gitlab) => gitlab.run(report_comment, log).await.map_err(Into::into),
+            Self::Gitea(gitea) => gitea.run(report_comment, log).await.map_err(Into::into),
+            Self::Bitbucket(bitbucket) => {
+                bitbucket.run(report_comment, log).await.map_err(Into::into)
+            },
         }
     }
 
     pub fn source(&self) -> String {
         match self {
             Self::GitHubActions(_) => "github".to_owned(),
+            Self::GitLab(_) => "gitlab".to_owned(),
+            Self::Gitea(_) => "gitea".to_owned(),
+            Self::Bitbucket(_) => "bitbucket".to_owned(),
         }
     }
 }
@@ -10,6 +10,10 @@ pub enum RunError {
     Branch(#[from] super::branch::BranchError),
     #[error("{0}")]
     Thresholds(#[from] super::thresholds::ThresholdsError),
+    #[error("{0}")]
+    Measures(#[from] super::measures::MeasuresError),
+    #[error("{0}")]
+    Plugin(#[from] super::plugin::PluginError),
 
     #[error("No default shell command path for target family. Try setting a custom shell with the `--shell` argument.")]
     Shell,
@@ -63,6 +67,12 @@ pub enum RunError {
     OutputFileSize(std::io::Error),
     #[error("Failed to serialize file size results: {0}")]
     SerializeFileSize(serde_json::Error),
+    #[error("Failed to parse Criterion directory: {0}")]
+    CriterionDir(bencher_adapter::AdapterError),
+    #[error("No Criterion results found in: {0}")]
+    NoCriterionResults(camino::Utf8PathBuf),
+    #[error("Failed to serialize Criterion results: {0}")]
+    SerializeCriterion(serde_json::Error),
 
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
@@ -0,0 +1,104 @@
+use bencher_client::types::JsonAdapterMeasure;
+use bencher_json::{NameId, ResourceName};
+
+use crate::parser::{project::run::CliRunMeasures, ElidedOption};
+
+#[derive(Debug, Clone)]
+pub struct Measures {
+    remaps: Option<Vec<JsonAdapterMeasure>>,
+    benchmark_prefix: Option<String>,
+    benchmark_suffix: Option<String>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MeasuresError {
+    #[error("The {0} Measure has no rename, scale, or units. Use the `--measure-rename`, `--measure-scale`, or `--measure-units` options.")]
+    NoSettings(NameId),
+    #[error("The scale for the {measure} Measure must be a finite number: {scale}")]
+    BadScale { measure: NameId, scale: f64 },
+    #[error("There are more renames than Measures")]
+    ExtraRenames(Vec<ElidedOption<NameId>>),
+    #[error("There are more scales than Measures")]
+    ExtraScales(Vec<ElidedOption<f64>>),
+    #[error("There are more units than Measures")]
+    ExtraUnits(Vec<ElidedOption<ResourceName>>),
+}
+
+impl TryFrom<CliRunMeasures> for Measures {
+    type Error = MeasuresError;
+
+    fn try_from(measures: CliRunMeasures) -> Result<Self, Self::Error> {
+        let CliRunMeasures {
+            measures,
+            measure_rename,
+            measure_scale,
+            measure_units,
+            benchmark_prefix,
+            benchmark_suffix,
+        } = measures;
+
+        let mut adapter_measures = Vec::with_capacity(measures.len());
+
+        let mut renames = measure_rename.into_iter();
+        let mut scales = measure_scale.into_iter();
+        let mut units = measure_units.into_iter();
+        for measure in measures {
+            let rename: Option<NameId> = renames.next().and_then(Into::into);
+            let scale: Option<f64> = scales.next().and_then(Into::into);
+            let unit: Option<ResourceName> = units.next().and_then(Into::into);
+
+            if rename.is_none() && scale.is_none() && unit.is_none() {
+                return Err(MeasuresError::NoSettings(measure));
+            }
+            if let Some(scale) = scale {
+                if !scale.is_finite() {
+                    return Err(MeasuresError::BadScale { measure, scale });
+                }
+            }
+
+            adapter_measures.push(JsonAdapterMeasure {
+                measure: measure.into(),
+                rename: rename.map(Into::into),
+                scale,
+                units: unit.map(Into::into),
+            });
+        }
+
+        let remaining_renames = renames.collect::<Vec<_>>();
+        if !remaining_renames.is_empty() {
+            return Err(MeasuresError::ExtraRenames(remaining_renames));
+        }
+        let remaining_scales = scales.collect::<Vec<_>>();
+        if !remaining_scales.is_empty() {
+            return Err(MeasuresError::ExtraScales(remaining_scales));
+        }
+        let remaining_units = units.collect::<Vec<_>>();
+        if !remaining_units.is_empty() {
+            return Err(MeasuresError::ExtraUnits(remaining_units));
+        }
+
+        Ok(Self {
+            remaps: if adapter_measures.is_empty() {
+                None
+            } else {
+                Some(adapter_measures)
+            },
+            benchmark_prefix,
+            benchmark_suffix,
+        })
+    }
+}
+
+impl Measures {
+    pub fn measures(&self) -> Option<Vec<JsonAdapterMeasure>> {
+        self.remaps.clone()
+    }
+
+    pub fn benchmark_prefix(&self) -> Option<String> {
+        self.benchmark_prefix.clone()
+    }
+
+    pub fn benchmark_suffix(&self) -> Option<String> {
+        self.benchmark_suffix.clone()
+    }
+}
@@ -7,7 +7,7 @@ use bencher_json::{DateTime, JsonReport, NameId, ResourceId};
 use crate::{
     bencher::backend::AuthBackend,
     cli_eprintln_quietable, cli_println, cli_println_quietable,
-    parser::project::run::{CliRun, CliRunOutput},
+    parser::project::run::{CliRun, CliRunAdapterKind, CliRunOutput},
     CliError,
 };
 
@@ -18,6 +18,8 @@ mod ci;
 mod error;
 mod fold;
 mod format;
+mod measures;
+mod plugin;
 pub mod runner;
 mod sub_adapter;
 pub mod thresholds;
@@ -26,6 +28,8 @@ use branch::Branch;
 use ci::Ci;
 pub use error::RunError;
 use format::Format;
+use measures::Measures;
+use plugin::AdapterPlugin;
 use runner::Runner;
 use sub_adapter::SubAdapter;
 use thresholds::Thresholds;
@@ -39,8 +43,11 @@ pub struct Run {
     branch: Branch,
     testbed: NameId,
     adapter: Adapter,
+    plugin: Option<AdapterPlugin>,
     sub_adapter: SubAdapter,
     average: Option<JsonAverage>,
+    labels: Option<Vec<String>>,
+    measures: Measures,
     iter: usize,
     fold: Option<JsonFold>,
     backdate: Option<DateTime>,
@@ -66,6 +73,8 @@ impl TryFrom<CliRun> for Run {
             testbed,
             adapter,
             average,
+            labels,
+            measures,
             iter,
             fold,
             backdate,
@@ -78,13 +87,24 @@ impl TryFrom<CliRun> for Run {
             dry_run,
             backend,
         } = run;
+        // Adapter plugins output Bencher Metric Format JSON
+        let (adapter, plugin) = match adapter {
+            CliRunAdapterKind::Adapter(adapter) => (adapter.into(), None),
+            CliRunAdapterKind::Exec(program) => (
+                Adapter::Json,
+                Some(AdapterPlugin::new(program).map_err(RunError::Plugin)?),
+            ),
+        };
         Ok(Self {
             project,
             branch: branch.try_into().map_err(RunError::Branch)?,
             testbed,
-            adapter: adapter.into(),
+            adapter,
+            plugin,
             sub_adapter: (&cmd).into(),
-            average: average.map(Into::into),
+            average: average.clone().map(Into::into),
+            labels,
+            measures: measures.try_into().map_err(RunError::Measures)?,
             iter,
             fold: fold.map(Into::into),
             backdate,
@@ -94,7 +114,7 @@ impl TryFrom<CliRun> for Run {
             format: format.into(),
             log: !quiet,
             ci: ci.try_into().map_err(RunError::Ci)?,
-            runner: cmd.try_into()?,
+            runner: Runner::new(cmd, average)?,
             dry_run,
             backend: AuthBackend::try_from(backend)?.log(false),
         })
@@ -177,6 +197,16 @@ impl Run {
             cli_println_quietable!(self.log, "{result}");
         }
 
+        let results = if let Some(plugin) = &self.plugin {
+            let mut plugin_results = Vec::with_capacity(results.len());
+            for result in results {
+                plugin_results.push(plugin.convert(result).await?);
+            }
+            plugin_results
+        } else {
+            results
+        };
+
         let end_time = DateTime::now();
         // If a backdate is set then use it as the start time and calculate the end time from there
         let (start_time, end_time) = if let Some(backdate) = self.backdate {
@@ -200,6 +230,10 @@ impl Run {
                 adapter: Some(self.adapter),
                 average: self.average,
                 fold: self.fold,
+                labels: self.labels.clone(),
+                measures: self.measures.measures(),
+                benchmark_prefix: self.measures.benchmark_prefix(),
+                benchmark_suffix: self.measures.benchmark_suffix(),
             }),
         }))
     }
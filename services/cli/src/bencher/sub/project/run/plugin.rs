@@ -0,0 +1,204 @@
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+    process::{ExitStatus, Stdio},
+};
+
+use bencher_adapter::{adapters::json::AdapterJson, Adaptable, Settings};
+use tokio::io::AsyncWriteExt;
+
+/// The prefix for adapter plugin executables discovered on the PATH
+pub const ADAPTER_PLUGIN_PREFIX: &str = "bencher-adapter-";
+
+#[derive(Debug, Clone)]
+pub struct AdapterPlugin {
+    path: PathBuf,
+}
+
+#[allow(clippy::absolute_paths)]
+#[derive(thiserror::Error, Debug)]
+pub enum PluginError {
+    #[error("Failed to find adapter plugin `{program}`. Expected an executable named `{ADAPTER_PLUGIN_PREFIX}{program}` or `{program}` on the PATH.")]
+    NotFound { program: String },
+    #[error("Failed to spawn adapter plugin `{plugin}`: {err}")]
+    Spawn {
+        plugin: AdapterPlugin,
+        err: std::io::Error,
+    },
+    #[error("Failed to pipe stdin for adapter plugin `{0}`")]
+    PipeStdin(AdapterPlugin),
+    #[error("Failed to write results to adapter plugin `{plugin}`: {err}")]
+    WriteStdin {
+        plugin: AdapterPlugin,
+        err: std::io::Error,
+    },
+    #[error("Failed to run adapter plugin `{plugin}`: {err}")]
+    Run {
+        plugin: AdapterPlugin,
+        err: std::io::Error,
+    },
+    #[error("Adapter plugin `{plugin}` failed with a non-zero exit code ({status}): {stderr}")]
+    ExitStatus {
+        plugin: AdapterPlugin,
+        status: ExitStatus,
+        stderr: String,
+    },
+    #[error("Adapter plugin `{plugin}` output is not valid UTF-8: {err}")]
+    Utf8 {
+        plugin: AdapterPlugin,
+        err: std::string::FromUtf8Error,
+    },
+    #[error("Adapter plugin `{plugin}` output is not valid Bencher Metric Format JSON: {output}")]
+    BencherMetricFormat {
+        plugin: AdapterPlugin,
+        output: String,
+    },
+}
+
+impl fmt::Display for AdapterPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())
+    }
+}
+
+impl AdapterPlugin {
+    pub fn new(program: String) -> Result<Self, PluginError> {
+        find_plugin(&program)
+            .map(|path| Self { path })
+            .ok_or(PluginError::NotFound { program })
+    }
+
+    /// Pipe the raw benchmark harness results into the adapter plugin,
+    /// and return its standard output as validated Bencher Metric Format JSON.
+    pub async fn convert(&self, results: String) -> Result<String, PluginError> {
+        let mut child = tokio::process::Command::new(&self.path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| PluginError::Spawn {
+                plugin: self.clone(),
+                err,
+            })?;
+
+        // Write the results concurrently with reading the output,
+        // so a plugin that streams its output does not block on a full pipe.
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| PluginError::PipeStdin(self.clone()))?;
+        let stdin = tokio::spawn(async move {
+            stdin.write_all(results.as_bytes()).await?;
+            stdin.shutdown().await
+        });
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|err| PluginError::Run {
+                plugin: self.clone(),
+                err,
+            })?;
+        if !output.status.success() {
+            return Err(PluginError::ExitStatus {
+                plugin: self.clone(),
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+        // A plugin that exits successfully without reading all of its input is allowed.
+        if let Ok(Err(err)) = stdin.await {
+            if err.kind() != std::io::ErrorKind::BrokenPipe {
+                return Err(PluginError::WriteStdin {
+                    plugin: self.clone(),
+                    err,
+                });
+            }
+        }
+
+        let stdout = String::from_utf8(output.stdout).map_err(|err| PluginError::Utf8 {
+            plugin: self.clone(),
+            err,
+        })?;
+        if matches!(
+            AdapterJson::parse_reader(stdout.as_bytes(), Settings::default()),
+            Ok(Some(_))
+        ) {
+            Ok(stdout)
+        } else {
+            Err(PluginError::BencherMetricFormat {
+                plugin: self.clone(),
+                output: stdout,
+            })
+        }
+    }
+}
+
+// A program with a path is used as is.
+// Otherwise, look for a `bencher-adapter-<program>` executable on the PATH,
+// and then fall back to the program itself on the PATH.
+fn find_plugin(program: &str) -> Option<PathBuf> {
+    let program_path = Path::new(program);
+    if program_path.components().count() > 1 {
+        return program_path.is_file().then(|| program_path.to_path_buf());
+    }
+    find_on_path(&format!("{ADAPTER_PLUGIN_PREFIX}{program}")).or_else(|| find_on_path(program))
+}
+
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let file_name = format!("{name}{suffix}", suffix = env::consts::EXE_SUFFIX);
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join(&file_name))
+        .find(|path| path.is_file())
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::{fs, os::unix::fs::PermissionsExt, path::PathBuf};
+
+    use pretty_assertions::assert_eq;
+
+    use super::{AdapterPlugin, PluginError, ADAPTER_PLUGIN_PREFIX};
+
+    #[allow(clippy::unwrap_used)]
+    fn mock_plugin(name: &str, script: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bencher_cli_plugin_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{ADAPTER_PLUGIN_PREFIX}{name}"));
+        fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_plugin_convert() {
+        let bmf = r#"{"bench": {"latency": {"value": 1.0}}}"#;
+        let path = mock_plugin("valid", &format!("cat > /dev/null\necho '{bmf}'"));
+        let plugin = AdapterPlugin::new(path.display().to_string()).unwrap();
+        let output = plugin.convert("results".into()).await.unwrap();
+        assert_eq!(output.trim(), bmf);
+    }
+
+    #[tokio::test]
+    async fn test_plugin_invalid_json() {
+        let path = mock_plugin("invalid", "echo 'not json'");
+        let plugin = AdapterPlugin::new(path.display().to_string()).unwrap();
+        // The plugin does not read its input, which is allowed.
+        let err = plugin.convert("results".repeat(100_000)).await.unwrap_err();
+        assert!(matches!(err, PluginError::BencherMetricFormat { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_plugin_exit_status() {
+        let path = mock_plugin("failure", "echo 'oops' >&2\nexit 1");
+        let plugin = AdapterPlugin::new(path.display().to_string()).unwrap();
+        let err = plugin.convert("results".into()).await.unwrap_err();
+        assert!(matches!(err, PluginError::ExitStatus { stderr, .. } if stderr.trim() == "oops"));
+    }
+
+    #[test]
+    fn test_plugin_not_found() {
+        let err = AdapterPlugin::new("bencher_cli_plugin_not_found".into()).unwrap_err();
+        assert!(matches!(err, PluginError::NotFound { .. }));
+    }
+}
@@ -1,9 +1,10 @@
-use std::{fmt, process::Stdio};
+use std::{fmt, process::Stdio, time::SystemTime};
 
 use chrono::Utc;
 use tokio::io::{AsyncBufReadExt, BufReader};
 
 use super::build_time::{BuildCommand, BuildTime};
+use super::criterion_dir::CriterionDir;
 use super::file_path::FilePath;
 use super::file_size::FileSize;
 use super::{flag::Flag, output::Output, shell::Shell};
@@ -209,4 +210,14 @@ impl CommandOutput {
         self.output.result = Some(results);
         Ok(self.output)
     }
+
+    pub fn build_with_criterion_dir(
+        mut self,
+        criterion_dir: &CriterionDir,
+        since: Option<SystemTime>,
+    ) -> Result<Output, RunError> {
+        let results = criterion_dir.get_results(since)?;
+        self.output.result = Some(results);
+        Ok(self.output)
+    }
 }
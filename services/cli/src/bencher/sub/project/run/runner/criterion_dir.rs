@@ -0,0 +1,38 @@
+use std::{fmt, time::SystemTime};
+
+use bencher_adapter::{
+    adapters::rust::criterion_dir::AdapterRustCriterionDir, AdapterResults, Settings,
+};
+use bencher_json::project::report::JsonAverage;
+use camino::Utf8PathBuf;
+
+use crate::RunError;
+
+#[derive(Debug, Clone)]
+pub struct CriterionDir {
+    path: Utf8PathBuf,
+    average: Option<JsonAverage>,
+}
+
+impl fmt::Display for CriterionDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path)
+    }
+}
+
+impl CriterionDir {
+    pub fn new(path: Utf8PathBuf, average: Option<JsonAverage>) -> Self {
+        Self { path, average }
+    }
+
+    pub fn get_results(&self, since: Option<SystemTime>) -> Result<String, RunError> {
+        let results: AdapterResults = AdapterRustCriterionDir::parse_dir(
+            self.path.as_std_path(),
+            since,
+            Settings::new(self.average),
+        )
+        .map_err(RunError::CriterionDir)?
+        .ok_or_else(|| RunError::NoCriterionResults(self.path.clone()))?;
+        serde_json::to_string(&results).map_err(RunError::SerializeCriterion)
+    }
+}
@@ -1,11 +1,14 @@
 #![allow(clippy::result_large_err)]
 
-use std::fmt;
+use std::{fmt, time::SystemTime};
 
-use crate::parser::project::run::CliRunCommand;
+use bencher_json::project::report::JsonAverage;
+
+use crate::parser::project::run::{CliRunAverage, CliRunCommand};
 
 mod build_time;
 pub mod command;
+mod criterion_dir;
 mod file_path;
 mod file_size;
 mod flag;
@@ -15,6 +18,7 @@ mod shell;
 
 use build_time::BuildTime;
 use command::{Command, CommandOutput};
+use criterion_dir::CriterionDir;
 use file_path::FilePath;
 use file_size::FileSize;
 use output::Output;
@@ -28,14 +32,58 @@ pub enum Runner {
     Command(Command, Option<BuildTime>),
     CommandToFile(Command, FilePath),
     CommandToFileSize(Command, Option<BuildTime>, FileSize),
+    CommandToCriterionDir(Command, CriterionDir),
     File(FilePath),
     FileSize(FileSize),
+    CriterionDir(CriterionDir),
 }
 
-impl TryFrom<CliRunCommand> for Runner {
-    type Error = RunError;
+impl fmt::Display for Runner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pipe(pipe) => write!(f, "{pipe}"),
+            Self::Command(command, build_time) => write!(
+                f,
+                "{command}{build_time}",
+                build_time = if build_time.is_some() {
+                    " (build time)"
+                } else {
+                    ""
+                }
+            ),
+            Self::CommandToFile(command, file_path) => {
+                write!(f, "{command} > {file_path}")
+            },
+            Self::CommandToFileSize(command, build_time, file_path) => {
+                write!(
+                    f,
+                    "{command}{build_time} > {file_path} (size)",
+                    build_time = if build_time.is_some() {
+                        " (build time)"
+                    } else {
+                        ""
+                    }
+                )
+            },
+            Self::CommandToCriterionDir(command, criterion_dir) => {
+                write!(f, "{command} > {criterion_dir} (criterion)")
+            },
+            Self::File(file_path) => write!(f, "{file_path}"),
+            Self::FileSize(file_path) => write!(f, "{file_path} (size)"),
+            Self::CriterionDir(criterion_dir) => write!(f, "{criterion_dir} (criterion)"),
+        }
+    }
+}
 
-    fn try_from(cmd: CliRunCommand) -> Result<Self, Self::Error> {
+impl Runner {
+    pub fn new(cmd: CliRunCommand, average: Option<CliRunAverage>) -> Result<Self, RunError> {
+        let criterion_dir = cmd.criterion_dir.map(|path| {
+            let average = average.map(|average| match average {
+                CliRunAverage::Mean => JsonAverage::Mean,
+                CliRunAverage::Median => JsonAverage::Median,
+            });
+            CriterionDir::new(path, average)
+        });
         let program_arguments = cmd.command.and_then(|c| {
             let mut c = c.into_iter();
             c.next().map(|program| (program, c.collect::<Vec<_>>()))
@@ -56,6 +104,8 @@ impl TryFrom<CliRunCommand> for Runner {
                 Self::CommandToFile(command, FilePath::new(file_path))
             } else if let Some(file_paths) = cmd.file_size {
                 Self::CommandToFileSize(command, build_time, FileSize::new(file_paths))
+            } else if let Some(criterion_dir) = criterion_dir {
+                Self::CommandToCriterionDir(command, criterion_dir)
             } else {
                 Self::Command(command, build_time)
             })
@@ -63,48 +113,15 @@ impl TryFrom<CliRunCommand> for Runner {
             Ok(Self::File(FilePath::new(file_path)))
         } else if let Some(file_paths) = cmd.file_size {
             Ok(Self::FileSize(FileSize::new(file_paths)))
+        } else if let Some(criterion_dir) = criterion_dir {
+            Ok(Self::CriterionDir(criterion_dir))
         } else if let Some(pipe) = Pipe::new() {
             Ok(Self::Pipe(pipe))
         } else {
             Err(RunError::NoCommand)
         }
     }
-}
 
-impl fmt::Display for Runner {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Pipe(pipe) => write!(f, "{pipe}"),
-            Self::Command(command, build_time) => write!(
-                f,
-                "{command}{build_time}",
-                build_time = if build_time.is_some() {
-                    " (build time)"
-                } else {
-                    ""
-                }
-            ),
-            Self::CommandToFile(command, file_path) => {
-                write!(f, "{command} > {file_path}")
-            },
-            Self::CommandToFileSize(command, build_time, file_path) => {
-                write!(
-                    f,
-                    "{command}{build_time} > {file_path} (size)",
-                    build_time = if build_time.is_some() {
-                        " (build time)"
-                    } else {
-                        ""
-                    }
-                )
-            },
-            Self::File(file_path) => write!(f, "{file_path}"),
-            Self::FileSize(file_path) => write!(f, "{file_path} (size)"),
-        }
-    }
-}
-
-impl Runner {
     pub async fn run(&self, log: bool) -> Result<Output, RunError> {
         match self {
             Self::Pipe(pipe) => Ok(pipe.output()),
@@ -117,8 +134,19 @@ impl Runner {
                 .run(log, *build_time)
                 .await?
                 .build_with_file_size(file_size),
+            Self::CommandToCriterionDir(command, criterion_dir) => {
+                // Only read the estimates saved by this run of the command
+                let since = SystemTime::now();
+                command
+                    .run(log, None)
+                    .await?
+                    .build_with_criterion_dir(criterion_dir, Some(since))
+            },
             Self::File(file_path) => CommandOutput::default().build_with_file_path(file_path),
             Self::FileSize(file_size) => CommandOutput::default().build_with_file_size(file_size),
+            Self::CriterionDir(criterion_dir) => {
+                CommandOutput::default().build_with_criterion_dir(criterion_dir, None)
+            },
         }
     }
 }
@@ -1,14 +1,14 @@
 use std::collections::HashMap;
 
 use bencher_client::types::JsonReportThresholds;
-use bencher_json::{Boundary, NameId, SampleSize, Window};
+use bencher_json::{Boundary, NameId, ResolveVersions, SampleSize, Window};
 
 use crate::{
     bencher::sub::project::threshold::model::Model,
     parser::{
         project::{
             run::CliRunThresholds,
-            threshold::{CliModel, CliModelTest},
+            threshold::{CliModel, CliModelBaseline, CliModelTest},
         },
         ElidedOption,
     },
@@ -42,11 +42,18 @@ pub enum ThresholdsError {
     ExtraLowerBoundaries(Vec<ElidedOption<Boundary>>),
     #[error("There are more upper boundaries than model tests")]
     ExtraUpperBoundaries(Vec<ElidedOption<Boundary>>),
+    #[error("There are more baselines than model tests")]
+    ExtraBaselines(Vec<ElidedOption<CliModelBaseline>>),
+    #[error("There are more maximum versions than model tests")]
+    ExtraMaxVersions(Vec<ElidedOption<SampleSize>>),
+    #[error("There are more resolve versions than model tests")]
+    ExtraResolveVersions(Vec<ElidedOption<ResolveVersions>>),
 }
 
 impl TryFrom<CliRunThresholds> for Thresholds {
     type Error = ThresholdsError;
 
+    #[allow(clippy::too_many_lines)]
     fn try_from(thresholds: CliRunThresholds) -> Result<Self, Self::Error> {
         let CliRunThresholds {
             threshold_measure,
@@ -56,6 +63,9 @@ impl TryFrom<CliRunThresholds> for Thresholds {
             threshold_window,
             threshold_lower_boundary,
             threshold_upper_boundary,
+            threshold_baseline,
+            threshold_max_versions,
+            threshold_resolve_versions,
             thresholds_reset,
         } = thresholds;
 
@@ -67,6 +77,9 @@ impl TryFrom<CliRunThresholds> for Thresholds {
         let mut windows = threshold_window.into_iter();
         let mut lower_boundaries = threshold_lower_boundary.into_iter();
         let mut upper_boundaries = threshold_upper_boundary.into_iter();
+        let mut baselines = threshold_baseline.into_iter();
+        let mut max_versions = threshold_max_versions.into_iter();
+        let mut resolve_versions = threshold_resolve_versions.into_iter();
         for measure in threshold_measure {
             let test = tests
                 .next()
@@ -76,6 +89,9 @@ impl TryFrom<CliRunThresholds> for Thresholds {
             let window = windows.next();
             let lower_boundary = lower_boundaries.next();
             let upper_boundary = upper_boundaries.next();
+            let baseline = baselines.next();
+            let max_version = max_versions.next();
+            let resolve_version = resolve_versions.next();
 
             let cli_model = CliModel {
                 test,
@@ -84,6 +100,9 @@ impl TryFrom<CliRunThresholds> for Thresholds {
                 window: window.and_then(Into::into),
                 lower_boundary: lower_boundary.and_then(Into::into),
                 upper_boundary: upper_boundary.and_then(Into::into),
+                baseline: baseline.and_then(Into::into),
+                max_versions: max_version.and_then(Into::into),
+                resolve_versions: resolve_version.and_then(Into::into),
             };
             let model = Model::try_from(cli_model).map_err(|err| ThresholdsError::BadModel {
                 measure: measure.clone(),
@@ -125,6 +144,20 @@ impl TryFrom<CliRunThresholds> for Thresholds {
                 remaining_upper_boundaries,
             ));
         }
+        let remaining_baselines = baselines.collect::<Vec<_>>();
+        if !remaining_baselines.is_empty() {
+            return Err(ThresholdsError::ExtraBaselines(remaining_baselines));
+        }
+        let remaining_max_versions = max_versions.collect::<Vec<_>>();
+        if !remaining_max_versions.is_empty() {
+            return Err(ThresholdsError::ExtraMaxVersions(remaining_max_versions));
+        }
+        let remaining_resolve_versions = resolve_versions.collect::<Vec<_>>();
+        if !remaining_resolve_versions.is_empty() {
+            return Err(ThresholdsError::ExtraResolveVersions(
+                remaining_resolve_versions,
+            ));
+        }
 
         Ok(Self {
             // Do not short circuit early if there are no measures
@@ -67,6 +67,9 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         } = model;
         Self {
             branch: branch.into(),
@@ -78,6 +81,9 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         }
     }
 }
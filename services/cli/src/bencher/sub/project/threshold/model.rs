@@ -1,6 +1,8 @@
-use bencher_client::types::{Boundary, ModelTest, SampleSize, Window};
+use bencher_client::types::{
+    Boundary, ModelBaseline, ModelTest, ResolveVersions, SampleSize, Window,
+};
 
-use crate::parser::project::threshold::{CliModel, CliModelTest};
+use crate::parser::project::threshold::{CliModel, CliModelBaseline, CliModelTest};
 
 use super::ThresholdError;
 
@@ -12,6 +14,9 @@ pub struct Model {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub baseline: Option<ModelBaseline>,
+    pub max_versions: Option<SampleSize>,
+    pub resolve_versions: Option<ResolveVersions>,
 }
 
 impl TryFrom<CliModel> for Model {
@@ -25,6 +30,9 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         } = model;
         bencher_json::Model {
             test: test.into(),
@@ -33,6 +41,9 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            baseline: baseline.map(Into::into),
+            max_versions,
+            resolve_versions,
         }
         .validate()
         .map_err(ThresholdError::BadModel)?;
@@ -43,6 +54,9 @@ impl TryFrom<CliModel> for Model {
             window: window.map(Into::into),
             lower_boundary: lower_boundary.map(Into::into),
             upper_boundary: upper_boundary.map(Into::into),
+            baseline: baseline.map(Into::into),
+            max_versions: max_versions.map(Into::into),
+            resolve_versions: resolve_versions.map(Into::into),
         })
     }
 }
@@ -57,6 +71,8 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
         }
     }
 }
@@ -71,6 +87,26 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
+        }
+    }
+}
+
+impl From<CliModelBaseline> for bencher_json::ModelBaseline {
+    fn from(baseline: CliModelBaseline) -> Self {
+        match baseline {
+            CliModelBaseline::Head => Self::Head,
+            CliModelBaseline::StartPoint => Self::StartPoint,
+        }
+    }
+}
+
+impl From<CliModelBaseline> for ModelBaseline {
+    fn from(baseline: CliModelBaseline) -> Self {
+        match baseline {
+            CliModelBaseline::Head => Self::Head,
+            CliModelBaseline::StartPoint => Self::StartPoint,
         }
     }
 }
@@ -84,6 +120,9 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         } = model;
         #[allow(clippy::inconsistent_struct_constructor)]
         bencher_client::types::Model {
@@ -93,6 +132,9 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            baseline,
+            max_versions,
+            resolve_versions,
         }
     }
 }
@@ -31,6 +31,9 @@ impl TryFrom<CliThresholdUpdate> for Update {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    baseline,
+                    max_versions,
+                    resolve_versions,
                     remove_model,
                 },
             backend,
@@ -44,6 +47,9 @@ impl TryFrom<CliThresholdUpdate> for Update {
                 window,
                 lower_boundary,
                 upper_boundary,
+                baseline,
+                max_versions,
+                resolve_versions,
             };
             Some(cli_model.try_into()?)
         } else if remove_model {
@@ -72,6 +78,9 @@ impl From<Update> for JsonUpdateThreshold {
                 window,
                 lower_boundary,
                 upper_boundary,
+                baseline,
+                max_versions,
+                resolve_versions,
             } = model;
             #[allow(clippy::inconsistent_struct_constructor)]
             Self {
@@ -82,6 +91,9 @@ impl From<Update> for JsonUpdateThreshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    baseline,
+                    max_versions,
+                    resolve_versions,
                 }),
                 subtype_1: None,
             }
@@ -0,0 +1,71 @@
+use bencher_client::types::{JsonNewWebhook, WebhookEvent};
+use bencher_json::{ResourceId, Secret, Url};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookCreate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub url: Url,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub secret: Option<Secret>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliWebhookCreate) -> Result<Self, Self::Error> {
+        let CliWebhookCreate {
+            project,
+            url,
+            event,
+            secret,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            url,
+            events: (!event.is_empty()).then(|| event.into_iter().map(Into::into).collect()),
+            secret,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewWebhook {
+    fn from(create: Create) -> Self {
+        let Create {
+            url,
+            events,
+            secret,
+            ..
+        } = create;
+        Self {
+            url: url.into(),
+            events,
+            secret: secret.map(Into::into),
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
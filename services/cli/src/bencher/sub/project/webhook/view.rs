@@ -0,0 +1,48 @@
+use bencher_json::{ResourceId, WebhookUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ResourceId,
+    pub webhook: WebhookUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliWebhookView) -> Result<Self, Self::Error> {
+        let CliWebhookView {
+            project,
+            webhook,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            webhook,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_get()
+                    .project(self.project.clone())
+                    .webhook(self.webhook)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@ pub enum CliError {
     #[error("{0}")]
     Thresholds(#[from] crate::bencher::sub::ThresholdsError),
     #[error("{0}")]
+    Export(#[from] crate::bencher::sub::ExportError),
+    #[error("{0}")]
     Mock(#[from] crate::bencher::sub::MockError),
     #[error("{0}")]
     Docker(#[from] crate::bencher::sub::DockerError),
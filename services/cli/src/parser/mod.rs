@@ -14,9 +14,10 @@ use docker::{CliDown, CliLogs, CliUp};
 use mock::CliMock;
 use organization::{member::CliMember, CliOrganization};
 use project::{
-    alert::CliAlert, archive::CliArchive, benchmark::CliBenchmark, branch::CliBranch,
-    measure::CliMeasure, metric::CliMetric, perf::CliPerf, plot::CliPlot, report::CliReport,
-    run::CliRun, testbed::CliTestbed, threshold::CliThreshold, CliProject,
+    alert::CliAlert, alert_rule::CliAlertRule, archive::CliArchive, benchmark::CliBenchmark,
+    branch::CliBranch, compare::CliCompare, export::CliExport, measure::CliMeasure,
+    metric::CliMetric, perf::CliPerf, plot::CliPlot, report::CliReport, run::CliRun,
+    testbed::CliTestbed, threshold::CliThreshold, webhook::CliWebhook, CliProject,
 };
 use system::{auth::CliAuth, server::CliServer};
 use user::{token::CliToken, CliUser};
@@ -69,6 +70,10 @@ pub enum CliSub {
     Report(CliReport),
     /// Query benchmark data
     Perf(CliPerf),
+    /// Compare benchmark data between a baseline and a candidate
+    Compare(CliCompare),
+    /// Export benchmark data as CSV or Apache Parquet
+    Export(CliExport),
     /// Manage plots
     #[clap(subcommand)]
     Plot(CliPlot),
@@ -95,6 +100,12 @@ pub enum CliSub {
     /// Manage alerts
     #[clap(subcommand)]
     Alert(CliAlert),
+    /// Manage composite alert rules
+    #[clap(subcommand)]
+    AlertRule(CliAlertRule),
+    /// Manage project webhooks
+    #[clap(subcommand)]
+    Webhook(CliWebhook),
 
     /// Manage user
     #[clap(subcommand)]
@@ -54,6 +54,8 @@ pub enum CliAlertStatus {
     Dismissed,
     /// Silenced
     Silenced,
+    /// Resolved
+    Resolved,
 }
 
 #[derive(Parser, Debug)]
@@ -0,0 +1,93 @@
+use bencher_json::{AlertRuleUuid, NameId, ResourceId, ResourceName};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliAlertRule {
+    /// List alert rules
+    #[clap(alias = "ls")]
+    List(CliAlertRuleList),
+    /// Create an alert rule
+    #[clap(alias = "add")]
+    Create(CliAlertRuleCreate),
+    /// View an alert rule
+    #[clap(alias = "get")]
+    View(CliAlertRuleView),
+    /// Delete an alert rule
+    #[clap(alias = "rm")]
+    Delete(CliAlertRuleDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAlertRuleList {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Alert rule name
+    #[clap(long)]
+    pub name: Option<ResourceName>,
+
+    /// Alert rule search string
+    #[clap(long, value_name = "QUERY")]
+    pub search: Option<String>,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliAlertRulesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertRulesSort {
+    /// Name of the alert rule
+    Name,
+    /// Creation date time of the alert rule
+    Created,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAlertRuleCreate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Alert rule name
+    #[clap(long)]
+    pub name: ResourceName,
+
+    /// The measure names, slugs, or UUIDs to include in the alert rule.
+    /// At least two measures must be specified.
+    /// An alert is only generated when all of the measures
+    /// exceed their threshold boundaries for the same benchmark in the same report.
+    #[clap(long, required = true, value_name = "MEASURE")]
+    pub measures: Vec<NameId>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAlertRuleView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Alert rule UUID
+    pub alert_rule: AlertRuleUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAlertRuleDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Alert rule UUID
+    pub alert_rule: AlertRuleUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -0,0 +1,54 @@
+use bencher_json::{BranchUuid, GitHash, HeadUuid, MeasureUuid, ResourceId, TestbedUuid};
+use clap::Parser;
+
+use crate::parser::{project::perf::CliPerfTableStyle, CliBackend};
+
+#[derive(Parser, Debug)]
+#[allow(clippy::option_option)]
+pub struct CliCompare {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Baseline branch UUID
+    #[clap(long, value_name = "BRANCH")]
+    pub baseline_branch: BranchUuid,
+
+    /// Baseline branch head UUID (default current branch head)
+    #[clap(long, value_name = "HEAD")]
+    pub baseline_head: Option<HeadUuid>,
+
+    /// Baseline testbed UUID
+    #[clap(long, value_name = "TESTBED")]
+    pub baseline_testbed: TestbedUuid,
+
+    /// Baseline git hash (default most recent version)
+    #[clap(long, value_name = "HASH")]
+    pub baseline_hash: Option<GitHash>,
+
+    /// Candidate branch UUID
+    #[clap(long, value_name = "BRANCH")]
+    pub candidate_branch: BranchUuid,
+
+    /// Candidate branch head UUID (default current branch head)
+    #[clap(long, value_name = "HEAD")]
+    pub candidate_head: Option<HeadUuid>,
+
+    /// Candidate testbed UUID
+    #[clap(long, value_name = "TESTBED")]
+    pub candidate_testbed: TestbedUuid,
+
+    /// Candidate git hash (default most recent version)
+    #[clap(long, value_name = "HASH")]
+    pub candidate_hash: Option<GitHash>,
+
+    /// Measure UUIDs
+    #[clap(long, required = true, value_name = "MEASURE")]
+    pub measures: Vec<MeasureUuid>,
+
+    /// Output results in a table
+    #[clap(long)]
+    pub table: Option<Option<CliPerfTableStyle>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
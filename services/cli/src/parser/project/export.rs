@@ -0,0 +1,63 @@
+use bencher_json::{
+    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ResourceId, TestbedUuid,
+};
+use camino::Utf8PathBuf;
+use clap::{Parser, ValueEnum};
+
+use crate::parser::{CliBackend, ElidedOption};
+
+#[derive(Parser, Debug)]
+pub struct CliExport {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Branch UUIDs (default all branches)
+    #[clap(long, value_name = "BRANCH")]
+    pub branches: Vec<BranchUuid>,
+
+    /// Optional branch head UUIDs in the same order as `branches`.
+    /// Use an underscore (`_`) to specify the current branch head.
+    #[clap(long, requires = "branches", value_name = "HEAD")]
+    pub heads: Vec<ElidedOption<HeadUuid>>,
+
+    /// Testbed UUIDs (default all testbeds)
+    #[clap(long, value_name = "TESTBED")]
+    pub testbeds: Vec<TestbedUuid>,
+
+    /// Benchmark UUIDs (default all benchmarks)
+    #[clap(long, value_name = "BENCHMARK")]
+    pub benchmarks: Vec<BenchmarkUuid>,
+
+    /// Measure UUIDs (default all measures)
+    #[clap(long, value_name = "MEASURE")]
+    pub measures: Vec<MeasureUuid>,
+
+    /// Start time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub start_time: Option<DateTime>,
+
+    /// End time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub end_time: Option<DateTime>,
+
+    /// Export file format
+    #[clap(long, default_value = "csv")]
+    pub format: CliExportFormat,
+
+    /// Output file path (default stdout)
+    #[clap(long, value_name = "FILE")]
+    pub output: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Supported Export Formats
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliExportFormat {
+    /// Comma separated values
+    Csv,
+    /// Apache Parquet
+    Parquet,
+}
@@ -6,9 +6,12 @@ use crate::parser::CliBackend;
 use super::{CliPagination, ElidedOption};
 
 pub mod alert;
+pub mod alert_rule;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
+pub mod export;
 pub mod measure;
 pub mod metric;
 pub mod perf;
@@ -17,6 +20,7 @@ pub mod report;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -3,7 +3,7 @@ use bencher_json::{
 };
 use clap::{Parser, ValueEnum};
 
-use crate::parser::{CliBackend, ElidedOption};
+use crate::parser::{project::plot::CliXAxis, CliBackend, ElidedOption};
 
 #[derive(Parser, Debug)]
 #[allow(clippy::option_option)]
@@ -40,6 +40,14 @@ pub struct CliPerf {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
+    /// The x-axis to order results by (default `version`)
+    #[clap(long)]
+    pub x_axis: Option<CliXAxis>,
+
+    /// Summarize the metrics for each result instead of listing them
+    #[clap(long)]
+    pub summary: bool,
+
     /// Output results in a table
     #[clap(long)]
     pub table: Option<Option<CliPerfTableStyle>>,
@@ -1,13 +1,18 @@
+use std::ffi::OsStr;
+
 use bencher_json::{
-    project::testbed::TESTBED_LOCALHOST_STR, Boundary, DateTime, GitHash, NameId, ResourceId,
-    SampleSize, Window,
+    project::testbed::TESTBED_LOCALHOST_STR, Boundary, DateTime, GitHash, NameId, ResolveVersions,
+    ResourceId, ResourceName, SampleSize, Window,
 };
 use camino::Utf8PathBuf;
-use clap::{ArgGroup, Args, Parser, ValueEnum};
+use clap::{
+    builder::{EnumValueParser, PossibleValue, TypedValueParser},
+    ArgGroup, Args, Parser, ValueEnum,
+};
 
 use crate::parser::{CliBackend, ElidedOption};
 
-use super::threshold::CliModelTest;
+use super::threshold::{CliModelBaseline, CliModelTest};
 
 #[derive(Parser, Debug)]
 #[allow(clippy::option_option, clippy::struct_excessive_bools)]
@@ -25,13 +30,28 @@ pub struct CliRun {
     pub testbed: NameId,
 
     /// Benchmark harness adapter
-    #[clap(value_enum, long, env = "BENCHER_ADAPTER", default_value = "magic")]
-    pub adapter: CliRunAdapter,
+    /// Use `exec:<PROGRAM>` to convert the results with an external adapter plugin
+    /// (ie `exec:foo` for a `bencher-adapter-foo` executable on the PATH)
+    #[clap(
+        long,
+        env = "BENCHER_ADAPTER",
+        default_value = "magic",
+        value_parser = CliRunAdapterParser
+    )]
+    pub adapter: CliRunAdapterKind,
 
     /// Benchmark harness suggested central tendency (ie average)
     #[clap(value_enum, long)]
     pub average: Option<CliRunAverage>,
 
+    /// Label to include in the benchmark name for labeled metrics (ie `OpenMetrics`)
+    /// Use multiple times to include multiple labels (default all labels)
+    #[clap(long = "label", value_name = "LABEL")]
+    pub labels: Option<Vec<String>>,
+
+    #[clap(flatten)]
+    pub measures: CliRunMeasures,
+
     /// Number of run iterations
     #[clap(long, value_name = "COUNT", default_value = "1")]
     pub iter: usize,
@@ -134,6 +154,38 @@ pub struct CliRunHash {
     pub no_hash: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CliRunMeasures {
+    /// Adapter Measure name, slug, or UUID to rename, scale, or set the units for
+    /// When specifying multiple Measures, all of the same options must be used for each Measure.
+    /// To ignore an option for a specific Measure, use an underscore (`_`).
+    #[clap(long = "measure", value_name = "MEASURE")]
+    pub measures: Vec<NameId>,
+
+    /// Rename the Measure to this name, slug, or UUID
+    /// To ignore this option when specifying multiple Measures, use an underscore (`_`).
+    #[clap(long, requires = "measures", value_name = "MEASURE")]
+    pub measure_rename: Vec<ElidedOption<NameId>>,
+
+    /// Multiply the Measure metrics by this factor (ie `0.001` for nanoseconds to microseconds)
+    /// To ignore this option when specifying multiple Measures, use an underscore (`_`).
+    #[clap(long, requires = "measures", value_name = "FACTOR")]
+    pub measure_scale: Vec<ElidedOption<f64>>,
+
+    /// Units for the Measure, if it needs to be created
+    /// To ignore this option when specifying multiple Measures, use an underscore (`_`).
+    #[clap(long, requires = "measures", value_name = "UNITS")]
+    pub measure_units: Vec<ElidedOption<ResourceName>>,
+
+    /// Prefix to add to the start of every benchmark name
+    #[clap(long, value_name = "PREFIX")]
+    pub benchmark_prefix: Option<String>,
+
+    /// Suffix to add to the end of every benchmark name
+    #[clap(long, value_name = "SUFFIX")]
+    pub benchmark_suffix: Option<String>,
+}
+
 #[derive(Args, Debug)]
 pub struct CliRunThresholds {
     /// Threshold Measure name, slug, or UUID
@@ -171,6 +223,21 @@ pub struct CliRunThresholds {
     #[clap(long, requires = "threshold_test")]
     pub threshold_upper_boundary: Vec<ElidedOption<Boundary>>,
 
+    /// Baseline to compare against (`head` or `start_point`)
+    /// To ignore a this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_baseline: Vec<ElidedOption<CliModelBaseline>>,
+
+    /// Maximum number of versions
+    /// To ignore a this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_max_versions: Vec<ElidedOption<SampleSize>>,
+
+    /// Number of consecutive versions within the threshold limits before an active alert is resolved
+    /// To ignore a this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_resolve_versions: Vec<ElidedOption<ResolveVersions>>,
+
     /// Reset all unspecified Thresholds for the `branch` and `testbed`
     /// If a Threshold already exists and is not specified, its current Model will be removed.
     #[clap(long)]
@@ -180,17 +247,27 @@ pub struct CliRunThresholds {
 #[derive(Args, Debug)]
 pub struct CliRunCommand {
     /// Track the build time of the benchmark command
-    #[clap(long, requires = "command", conflicts_with = "file")]
+    #[clap(
+        long,
+        requires = "command",
+        conflicts_with = "file",
+        conflicts_with = "criterion_dir"
+    )]
     pub build_time: bool,
 
     /// Benchmark command output file path
-    #[clap(long, conflicts_with = "file_size")]
+    #[clap(long, conflicts_with = "file_size", conflicts_with = "criterion_dir")]
     pub file: Option<Utf8PathBuf>,
 
     /// Track the size of a file at the given file path
-    #[clap(long, conflicts_with = "file")]
+    #[clap(long, conflicts_with = "file", conflicts_with = "criterion_dir")]
     pub file_size: Option<Vec<Utf8PathBuf>>,
 
+    /// Read the Criterion estimates saved in the given directory (ie `target/criterion`)
+    /// If a benchmark command is given, only the estimates saved while it ran are read.
+    #[clap(long)]
+    pub criterion_dir: Option<Utf8PathBuf>,
+
     #[clap(flatten)]
     pub sh_c: CliRunShell,
 
@@ -231,6 +308,10 @@ pub enum CliRunAdapter {
     Magic,
     /// {...} JSON
     Json,
+    /// 📈 `OpenMetrics`
+    OpenMetrics,
+    /// 🧪 `JUnit` XML
+    Junit,
     // TODO remove in due time
     #[clap(hide = true)]
     CSharp,
@@ -260,6 +341,8 @@ pub enum CliRunAdapter {
     JsBenchmark,
     /// 🕸 JavaScript Time
     JsTime,
+    /// 🟣 Julia `BenchmarkTools`
+    JuliaBenchmarkTools,
     // TODO remove in due time
     #[clap(hide = true)]
     Python,
@@ -267,6 +350,8 @@ pub enum CliRunAdapter {
     PythonAsv,
     /// 🐍 Python Pytest
     PythonPytest,
+    /// 🐍 Python Pyperf
+    PythonPyperf,
     // TODO remove in due time
     #[clap(hide = true)]
     Ruby,
@@ -283,11 +368,73 @@ pub enum CliRunAdapter {
     RustIai,
     /// 🦀 Rust Iai-Callgrind
     RustIaiCallgrind,
+    /// 🦀 Rust Divan
+    RustDivan,
     // TODO remove in due time
     #[clap(hide = true)]
     Shell,
     /// ❯_ Shell Hyperfine
     ShellHyperfine,
+    /// ❯_ Shell k6
+    ShellK6,
+    /// ❯_ Shell wrk
+    ShellWrk,
+    /// ❯_ Shell Vegeta
+    ShellVegeta,
+}
+
+/// The prefix for an external adapter plugin
+pub const ADAPTER_PLUGIN_EXEC: &str = "exec:";
+
+#[derive(Debug, Clone)]
+pub enum CliRunAdapterKind {
+    /// Built-in adapter
+    Adapter(CliRunAdapter),
+    /// External adapter plugin program
+    Exec(String),
+}
+
+/// Parse either a built-in adapter or an external adapter plugin,
+/// while still listing the built-in adapters as the possible values.
+#[derive(Debug, Clone, Copy)]
+pub struct CliRunAdapterParser;
+
+impl TypedValueParser for CliRunAdapterParser {
+    type Value = CliRunAdapterKind;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        if let Some(program) = value
+            .to_str()
+            .and_then(|value| value.strip_prefix(ADAPTER_PLUGIN_EXEC))
+        {
+            return if program.is_empty() {
+                Err(clap::Error::raw(
+                    clap::error::ErrorKind::InvalidValue,
+                    format!("The adapter plugin program for `{ADAPTER_PLUGIN_EXEC}` is empty\n"),
+                ))
+            } else {
+                Ok(CliRunAdapterKind::Exec(program.to_owned()))
+            };
+        }
+        EnumValueParser::<CliRunAdapter>::new()
+            .parse_ref(cmd, arg, value)
+            .map(CliRunAdapterKind::Adapter)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        let exec = PossibleValue::new("exec:<PROGRAM>").help("🔌 External adapter plugin");
+        Some(Box::new(
+            CliRunAdapter::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value)
+                .chain(std::iter::once(exec)),
+        ))
+    }
 }
 
 /// Suggested Central Tendency (Average)
@@ -341,28 +488,40 @@ pub enum CliRunFormat {
 #[clap(group(
     ArgGroup::new("ci_cd")
         .multiple(false)
-        .args(&["github_actions"]),
+        .args(&["github_actions", "ci_gitlab", "ci_gitea", "ci_bitbucket"]),
 ))]
 pub struct CliRunCi {
     /// GitHub API authentication token for GitHub Actions to comment on PRs (ie `--github-actions ${{ secrets.GITHUB_TOKEN }}`)
     #[clap(long)]
     pub github_actions: Option<String>,
-    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`)
+    /// GitLab API authentication token for GitLab CI/CD to comment on merge requests (ie `--ci-gitlab $BENCHER_GITLAB_TOKEN`)
+    #[clap(long)]
+    pub ci_gitlab: Option<String>,
+    /// Gitea or Forgejo API authentication token for Gitea or Forgejo Actions to comment on PRs (ie `--ci-gitea ${{ secrets.BENCHER_GITEA_TOKEN }}`)
+    #[clap(long)]
+    pub ci_gitea: Option<String>,
+    /// Bitbucket Server API authentication token to comment on PRs from a Jenkins multibranch pipeline (ie `--ci-bitbucket $BENCHER_BITBUCKET_TOKEN`)
+    #[clap(long)]
+    pub ci_bitbucket: Option<String>,
+    /// Also create a GitHub Check for pull requests, named by `--ci-id` if set, with an annotation for each Alert (requires: `--github-actions`)
+    #[clap(long, requires = "github_actions")]
+    pub ci_github_checks: bool,
+    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`, `--ci-gitlab`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_thresholds: bool,
-    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`)
+    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`, `--ci-gitlab`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_on_alert: bool,
-    /// All links should be to public URLs that do not require a login (requires: `--github-actions`)
+    /// All links should be to public URLs that do not require a login (requires: `--github-actions`, `--ci-gitlab`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd")]
     pub ci_public_links: bool,
-    /// Custom ID for posting results to CI (requires: `--github-actions`)
+    /// Custom ID for posting results to CI (requires: `--github-actions`, `--ci-gitlab`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd")]
     pub ci_id: Option<String>,
-    /// Issue number for posting results to CI (requires: `--github-actions`)
+    /// Issue or merge request number for posting results to CI (requires: `--github-actions`, `--ci-gitlab`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd")]
     pub ci_number: Option<u64>,
-    /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`)
+    /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`, `--ci-gitea`, or `--ci-bitbucket`)
     #[clap(long, requires = "ci_cd", hide = true)]
     pub ci_i_am_vulnerable_to_pwn_requests: bool,
     /// Deprecated: Do not use. This will soon be removed.
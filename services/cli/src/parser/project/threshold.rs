@@ -1,4 +1,6 @@
-use bencher_json::{Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window};
+use bencher_json::{
+    Boundary, ModelUuid, NameId, ResolveVersions, ResourceId, SampleSize, ThresholdUuid, Window,
+};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -125,6 +127,18 @@ pub struct CliModel {
     /// Upper boundary
     #[clap(long, value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
+
+    /// Baseline to compare against
+    #[clap(value_enum, long)]
+    pub baseline: Option<CliModelBaseline>,
+
+    /// Max number of versions
+    #[clap(long, value_name = "VERSIONS")]
+    pub max_versions: Option<SampleSize>,
+
+    /// Number of consecutive versions within the threshold limits before an active alert is resolved
+    #[clap(long, value_name = "VERSIONS")]
+    pub resolve_versions: Option<ResolveVersions>,
 }
 
 /// Supported threshold model tests
@@ -147,6 +161,28 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Change point detection (PELT)
+    ChangePoint,
+    /// Mann-Whitney U test (non-parametric, multi-iteration)
+    MannWhitneyU,
+}
+
+/// Supported threshold model baselines
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliModelBaseline {
+    /// Current branch head
+    Head,
+    /// Start point branch head, up to and including the start point version
+    StartPoint,
+}
+
+impl std::str::FromStr for CliModelBaseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Self as ValueEnum>::from_str(s, false)
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -212,6 +248,18 @@ pub struct CliUpdateModel {
     #[clap(long, requires = "test", value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
 
+    /// Baseline to compare against
+    #[clap(value_enum, long, requires = "test")]
+    pub baseline: Option<CliModelBaseline>,
+
+    /// Max number of versions
+    #[clap(long, requires = "test", value_name = "VERSIONS")]
+    pub max_versions: Option<SampleSize>,
+
+    /// Number of consecutive versions within the threshold limits before an active alert is resolved
+    #[clap(long, requires = "test", value_name = "VERSIONS")]
+    pub resolve_versions: Option<ResolveVersions>,
+
     /// Remove the threshold model
     #[clap(long)]
     pub remove_model: bool,
@@ -0,0 +1,127 @@
+use bencher_json::{ResourceId, Secret, Url, WebhookUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliWebhook {
+    /// List webhooks
+    #[clap(alias = "ls")]
+    List(CliWebhookList),
+    /// Create a webhook
+    #[clap(alias = "add")]
+    Create(CliWebhookCreate),
+    /// View a webhook
+    #[clap(alias = "get")]
+    View(CliWebhookView),
+    /// Update a webhook
+    #[clap(alias = "edit")]
+    Update(CliWebhookUpdate),
+    /// Delete a webhook
+    #[clap(alias = "rm")]
+    Delete(CliWebhookDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookList {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhooksSort {
+    /// Creation date time of the webhook
+    Created,
+    /// URL of the webhook
+    Url,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookCreate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// The URL that the webhook payloads are sent to
+    #[clap(long)]
+    pub url: Url,
+
+    /// The events to subscribe the webhook to.
+    /// If not specified, the webhook is subscribed to all events.
+    #[clap(value_enum, long, value_name = "EVENT")]
+    pub event: Vec<CliWebhookEvent>,
+
+    /// The secret used to sign the webhook payloads.
+    /// If not specified, a random secret will be generated.
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Webhook events
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookEvent {
+    /// A report was created
+    ReportCreated,
+    /// An alert was created
+    AlertCreated,
+    /// The status of an alert was changed
+    AlertStatusChanged,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookUpdate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    /// The new URL that the webhook payloads are sent to
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// The new events to subscribe the webhook to
+    #[clap(value_enum, long, value_name = "EVENT")]
+    pub event: Vec<CliWebhookEvent>,
+
+    /// The new secret used to sign the webhook payloads
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
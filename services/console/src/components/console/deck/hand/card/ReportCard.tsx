@@ -727,6 +727,8 @@ const alertStatus = (alert: JsonAlert) => {
 		case AlertStatus.Dismissed:
 		case AlertStatus.Silenced:
 			return "🔕";
+		case AlertStatus.Resolved:
+			return "✅";
 	}
 };
 
@@ -341,6 +341,10 @@ const AdapterCard = (props: Props) => {
 						return "-magic-default";
 					case Adapter.Json:
 						return "-json";
+					case Adapter.OpenMetrics:
+						return "-openmetrics";
+					case Adapter.Junit:
+						return "-junit-xml";
 					case Adapter.CSharpDotNet:
 						return "%EF%B8%8F⃣-c-dotnet";
 					case Adapter.CppCatch2:
@@ -355,10 +359,14 @@ const AdapterCard = (props: Props) => {
 						return "-javascript-benchmark";
 					case Adapter.JsTime:
 						return "-javascript-time";
+					case Adapter.JuliaBenchmarkTools:
+						return "-julia-benchmarktools";
 					case Adapter.PythonAsv:
 						return "-python-asv";
 					case Adapter.PythonPytest:
 						return "-python-pytest";
+					case Adapter.PythonPyperf:
+						return "-python-pyperf";
 					case Adapter.RubyBenchmark:
 						return "%EF%B8%8F-ruby-benchmark";
 					case Adapter.RustBench:
@@ -369,8 +377,16 @@ const AdapterCard = (props: Props) => {
 						return "-rust-iai";
 					case Adapter.RustIaiCallgrind:
 						return "-rust-iai-callgrind";
+					case Adapter.RustDivan:
+						return "-rust-divan";
 					case Adapter.ShellHyperfine:
 						return "_%EF%B8%8F-shell-hyperfine";
+					case Adapter.ShellK6:
+						return "_%EF%B8%8F-shell-k6";
+					case Adapter.ShellWrk:
+						return "_%EF%B8%8F-shell-wrk";
+					case Adapter.ShellVegeta:
+						return "_%EF%B8%8F-shell-vegeta";
 					default:
 						return "";
 				}
@@ -386,6 +402,10 @@ const AdapterCard = (props: Props) => {
 							return "Magic";
 						case Adapter.Json:
 							return "JSON";
+						case Adapter.OpenMetrics:
+							return "OpenMetrics";
+						case Adapter.Junit:
+							return "JUnit XML";
 						case Adapter.CSharpDotNet:
 							return "C# BenchmarkDotNet";
 						case Adapter.CppCatch2:
@@ -400,10 +420,14 @@ const AdapterCard = (props: Props) => {
 							return "JavaScript Benchmark.js";
 						case Adapter.JsTime:
 							return "JavaScript console.time/console.timeEnd";
+						case Adapter.JuliaBenchmarkTools:
+							return "Julia BenchmarkTools";
 						case Adapter.PythonAsv:
 							return "Python airspeed velocity (asv)";
 						case Adapter.PythonPytest:
 							return "Python pytest-benchmark";
+						case Adapter.PythonPyperf:
+							return "Python pyperf";
 						case Adapter.RubyBenchmark:
 							return "Ruby Benchmark";
 						case Adapter.RustBench:
@@ -414,8 +438,16 @@ const AdapterCard = (props: Props) => {
 							return "Rust Iai";
 						case Adapter.RustIaiCallgrind:
 							return "Rust Iai-Callgrind";
+						case Adapter.RustDivan:
+							return "Rust Divan";
 						case Adapter.ShellHyperfine:
 							return "Shell Hyperfine";
+						case Adapter.ShellK6:
+							return "Shell k6";
+						case Adapter.ShellWrk:
+							return "Shell wrk";
+						case Adapter.ShellVegeta:
+							return "Shell Vegeta";
 						default:
 							return `${props.value}`;
 					}
@@ -455,6 +487,10 @@ const ModelTestCard = (props: Props) => {
 							return "Interquartile Range (IQR)";
 						case ModelTest.DeltaIqr:
 							return "Delta Interquartile Range (ΔIQR)";
+						case ModelTest.ChangePoint:
+							return "Change Point";
+						case ModelTest.MannWhitneyU:
+							return "Mann-Whitney U";
 						default:
 							return `${props.value}`;
 					}
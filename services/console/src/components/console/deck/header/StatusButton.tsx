@@ -27,6 +27,7 @@ const StatusButton = (props: Props) => {
 				return { status: AlertStatus.Dismissed };
 			case AlertStatus.Dismissed:
 			case AlertStatus.Silenced:
+			case AlertStatus.Resolved:
 				return { status: AlertStatus.Active };
 			default:
 				console.error("Unknown status");
@@ -509,6 +509,7 @@ const PerfPanel = (props: Props) => {
 			measures: measures(),
 			start_time: start_time(),
 			end_time: end_time(),
+			x_axis: x_axis(),
 		} as JsonPerfQuery;
 	});
 
@@ -84,7 +84,8 @@ const Table = (props: Props) => {
 								<Match
 									when={
 										datum?.status === AlertStatus.Dismissed ||
-										datum?.status === AlertStatus.Silenced
+										datum?.status === AlertStatus.Silenced ||
+										datum?.status === AlertStatus.Resolved
 									}
 								>
 									<span class="icon-text">
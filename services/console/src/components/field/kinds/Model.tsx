@@ -1,5 +1,5 @@
 import { For, createMemo, createSignal } from "solid-js";
-import { ModelTest } from "../../../types/bencher";
+import { ModelBaseline, ModelTest } from "../../../types/bencher";
 import {
 	validBoundary,
 	validCdfBoundary,
@@ -113,6 +113,23 @@ const STATISTIC_FIELDS = {
 		help: "Must be an integer greater than zero",
 		validate: validU32,
 	},
+	baseline: {
+		icon: "fas fa-code-branch",
+	},
+	max_versions: {
+		type: "number",
+		placeholder: "10",
+		icon: "fas fa-code-commit",
+		help: "Must be an integer greater than or equal to 2",
+		validate: validSampleSize,
+	},
+	resolve_versions: {
+		type: "number",
+		placeholder: "3",
+		icon: "fas fa-check",
+		help: "Must be an integer greater than zero",
+		validate: validU32,
+	},
 };
 
 const testValue = (selected: ModelTest) => {
@@ -147,6 +164,30 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: "Delta Interquartile Range (ΔIQR)",
 			},
+			{
+				value: ModelTest.ChangePoint,
+				option: "Change Point",
+			},
+			{
+				value: ModelTest.MannWhitneyU,
+				option: "Mann-Whitney U",
+			},
+		],
+	};
+};
+
+const baselineValue = (selected: ModelBaseline) => {
+	return {
+		selected,
+		options: [
+			{
+				value: ModelBaseline.Head,
+				option: "Branch Head",
+			},
+			{
+				value: ModelBaseline.StartPoint,
+				option: "Start Point",
+			},
 		],
 	};
 };
@@ -197,6 +238,10 @@ export const testFragment = (modelTest: ModelTest) => {
 			return "iqr-thresholds";
 		case ModelTest.DeltaIqr:
 			return "delta-iqr-thresholds";
+		case ModelTest.ChangePoint:
+			return "change-point-thresholds";
+		case ModelTest.MannWhitneyU:
+			return "mann-whitney-u-thresholds";
 	}
 };
 
@@ -285,6 +330,36 @@ const SAMPLE_SIZE = [
 		nullable: true,
 		config: STATISTIC_FIELDS.window,
 	},
+	{
+		kind: FieldKind.SELECT,
+		label: "Baseline",
+		key: "baseline",
+		value: baselineValue(ModelBaseline.Head),
+		valid: true,
+		validate: false,
+		nullable: true,
+		config: STATISTIC_FIELDS.baseline,
+	},
+	{
+		kind: FieldKind.NUMBER,
+		label: "Maximum Versions",
+		key: "max_versions",
+		value: "",
+		valid: true,
+		validate: true,
+		nullable: true,
+		config: STATISTIC_FIELDS.max_versions,
+	},
+	{
+		kind: FieldKind.NUMBER,
+		label: "Resolve Versions",
+		key: "resolve_versions",
+		value: "",
+		valid: true,
+		validate: true,
+		nullable: true,
+		config: STATISTIC_FIELDS.resolve_versions,
+	},
 ];
 
 const FIELDS = {
@@ -340,6 +415,8 @@ const FIELDS = {
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.ChangePoint]: cdfConfig(ModelTest.ChangePoint),
+	[ModelTest.MannWhitneyU]: cdfConfig(ModelTest.MannWhitneyU),
 };
 
 const initForm = (fields: object[]) => {
@@ -207,6 +207,24 @@ const alertsConfig = {
 					keys: ["threshold", "model", "window"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Baseline",
+					keys: ["threshold", "model", "baseline"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Maximum Versions",
+					keys: ["threshold", "model", "max_versions"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Resolve Versions",
+					keys: ["threshold", "model", "resolve_versions"],
+					display: Display.RAW,
+				},
 			],
 			buttons: [
 				{
@@ -84,6 +84,24 @@ const thresholdsPubConfig = {
 				keys: ["model", "window"],
 				display: Display.RAW,
 			},
+			{
+				kind: Card.NESTED_FIELD,
+				label: "Baseline",
+				keys: ["model", "baseline"],
+				display: Display.RAW,
+			},
+			{
+				kind: Card.NESTED_FIELD,
+				label: "Maximum Versions",
+				keys: ["model", "max_versions"],
+				display: Display.RAW,
+			},
+			{
+				kind: Card.NESTED_FIELD,
+				label: "Resolve Versions",
+				keys: ["model", "resolve_versions"],
+				display: Display.RAW,
+			},
 		],
 	},
 };
@@ -124,12 +124,27 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	ChangePoint = "change_point",
+	MannWhitneyU = "mann_whitney_u",
+}
+
+/** The historical metrics that a threshold model compares against. */
+export enum ModelBaseline {
+	/** Compare against the history of the current branch head. */
+	Head = "head",
+	/**
+	 * Compare against the start point branch head, up to and including the start point version.
+	 * If the branch does not have a start point, then the current branch head is used.
+	 */
+	StartPoint = "start_point",
 }
 
 export type SampleSize = number;
 
 export type Window = number;
 
+export type ResolveVersions = number;
+
 export interface JsonModel {
 	uuid: Uuid;
 	test: ModelTest;
@@ -138,6 +153,9 @@ export interface JsonModel {
 	window?: Window;
 	lower_boundary?: Boundary;
 	upper_boundary?: Boundary;
+	baseline?: ModelBaseline;
+	max_versions?: SampleSize;
+	resolve_versions?: ResolveVersions;
 	created: string;
 	replaced?: string;
 }
@@ -171,6 +189,8 @@ export enum AlertStatus {
 	Dismissed = "dismissed",
 	/** The alert has been silenced by the system. */
 	Silenced = "silenced",
+	/** The alert has been resolved automatically by the system. */
+	Resolved = "resolved",
 }
 
 export interface JsonAlert {
@@ -183,12 +203,78 @@ export interface JsonAlert {
 	boundary: JsonBoundary;
 	limit: BoundaryLimit;
 	status: AlertStatus;
+	resolved_report?: Uuid;
 	created: string;
 	modified: string;
 }
 
 export type JsonReportAlerts = JsonAlert[];
 
+export interface JsonAlertRule {
+	uuid: Uuid;
+	project: Uuid;
+	name: ResourceName;
+	measures: JsonMeasure[];
+	created: string;
+	modified: string;
+}
+
+export enum WebhookEvent {
+	/** A report was created. */
+	ReportCreated = "report_created",
+	/** An alert was created. */
+	AlertCreated = "alert_created",
+	/** The status of an alert was changed. */
+	AlertStatusChanged = "alert_status_changed",
+}
+
+export interface JsonNewWebhook {
+	/** The URL that the webhook payloads are sent to. */
+	url: Url;
+	/**
+	 * The events that the webhook is subscribed to.
+	 * If not provided, the webhook is subscribed to all events.
+	 */
+	events?: WebhookEvent[];
+	/**
+	 * The secret used to sign the webhook payloads.
+	 * If not provided, a random secret will be generated.
+	 */
+	secret?: Secret;
+}
+
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	events: WebhookEvent[];
+	secret: Secret;
+	created: string;
+	modified: string;
+}
+
+export interface JsonUpdateWebhook {
+	/** The new URL that the webhook payloads are sent to. */
+	url?: Url;
+	/** The new events that the webhook is subscribed to. */
+	events?: WebhookEvent[];
+	/** The new secret used to sign the webhook payloads. */
+	secret?: Secret;
+}
+
+export interface JsonWebhookDelivery {
+	uuid: Uuid;
+	webhook: Uuid;
+	event: WebhookEvent;
+	/** The number of attempts made to deliver the payload. */
+	attempts: number;
+	/** The HTTP status code of the last attempt, if a response was received. */
+	status_code?: number;
+	/** The error from the last attempt, if the delivery failed. */
+	error?: string;
+	created: string;
+}
+
 export interface JsonThresholdModel {
 	uuid: Uuid;
 	project: Uuid;
@@ -548,6 +634,76 @@ export interface JsonPerfQuery {
 	measures: Uuid[];
 	start_time?: string;
 	end_time?: string;
+	x_axis?: XAxis;
+}
+
+export interface JsonMetricsSummary {
+	/** The number of metrics. */
+	count: number;
+	mean: number;
+	median: number;
+	/** The standard deviation, if there are at least two metrics. */
+	std_dev?: number;
+	/** The 5th percentile. */
+	p5: number;
+	/** The 95th percentile. */
+	p95: number;
+	/** The slope of the linear trend, in measure units per metric. */
+	trend?: number;
+	/** The percent change between the mean of the first and last quartile of metrics. */
+	change?: number;
+}
+
+export interface JsonPerfSummaryMetrics {
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	summary: JsonMetricsSummary;
+}
+
+export interface JsonPerfSummary {
+	project: JsonProject;
+	start_time?: string;
+	end_time?: string;
+	results: JsonPerfSummaryMetrics[];
+}
+
+export interface JsonMetricsSample {
+	/** The number of metrics. */
+	count: number;
+	mean: number;
+	/** The standard deviation, if there are at least two metrics. */
+	std_dev?: number;
+}
+
+export interface JsonPerfCompareMetrics {
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	baseline: JsonMetricsSample;
+	candidate: JsonMetricsSample;
+	/** The candidate mean minus the baseline mean. */
+	difference: number;
+	/** The percent change from the baseline mean to the candidate mean. */
+	change?: number;
+	/**
+	 * The two-tailed p-value from the Mann-Whitney U test.
+	 * It is not calculated if all of the baseline and candidate metrics are equal.
+	 */
+	p_value?: number;
+	/** Whether the p-value is less than the 0.05 significance level. */
+	significant: boolean;
+}
+
+export interface JsonPerfCompare {
+	project: JsonProject;
+	/** The baseline branch, including the branch head and version that was compared. */
+	baseline_branch: JsonBranch;
+	baseline_testbed: JsonTestbed;
+	/** The candidate branch, including the branch head and version that was compared. */
+	candidate_branch: JsonBranch;
+	candidate_testbed: JsonTestbed;
+	results: JsonPerfCompareMetrics[];
 }
 
 export enum PlanStatus {
@@ -600,11 +756,14 @@ export interface JsonPubUser {
 export enum Adapter {
 	Magic = "magic",
 	Json = "json",
+	OpenMetrics = "open_metrics",
+	Junit = "junit",
 	Rust = "rust",
 	RustBench = "rust_bench",
 	RustCriterion = "rust_criterion",
 	RustIai = "rust_iai",
 	RustIaiCallgrind = "rust_iai_callgrind",
+	RustDivan = "rust_divan",
 	Cpp = "cpp",
 	CppGoogle = "cpp_google",
 	CppCatch2 = "cpp_catch2",
@@ -620,10 +779,19 @@ export enum Adapter {
 	Python = "python",
 	PythonAsv = "python_asv",
 	PythonPytest = "python_pytest",
+	PythonPyperf = "python_pyperf",
+	PythonPyperf = "python_pyperf",
 	Ruby = "ruby",
 	RubyBenchmark = "ruby_benchmark",
 	Shell = "shell",
 	ShellHyperfine = "shell_hyperfine",
+	ShellK6 = "shell_k6",
+	ShellWrk = "shell_wrk",
+	ShellVegeta = "shell_vegeta",
+	Julia = "julia",
+	JuliaBenchmarkTools = "julia_benchmark_tools",
+	Julia = "julia",
+	JuliaBenchmarkTools = "julia_benchmark_tools",
 }
 
 export interface JsonReport {
@@ -671,6 +839,32 @@ export interface JsonUpdateAlert {
 	status?: UpdateAlertStatus;
 }
 
+export enum AlertDigest {
+	/** Never send an alert digest. */
+	Never = "never",
+	/** Send an alert digest once a day. */
+	Daily = "daily",
+	/** Send an alert digest once a week. */
+	Weekly = "weekly",
+}
+
+export interface JsonNotifications {
+	user: Uuid;
+	/**
+	 * How often to receive an email digest of new active alerts
+	 * for all of the projects that the user is a member of.
+	 */
+	alert_digest: AlertDigest;
+}
+
+export interface JsonUpdateNotifications {
+	/**
+	 * How often to receive an email digest of new active alerts.
+	 * Set to `never` to opt out of the alert digest.
+	 */
+	alert_digest?: AlertDigest;
+}
+
 export interface JsonUpdateUser {
 	/**
 	 * The new name of the user.
@@ -749,6 +943,7 @@ export enum PerfQueryKey {
 	Measures = "measures",
 	StartTime = "start_time",
 	EndTime = "end_time",
+	XAxis = "x_axis",
 }
 
 export enum PlotKey {
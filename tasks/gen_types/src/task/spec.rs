@@ -56,7 +56,9 @@ impl Spec {
                 "thresholds" => TagDetails { description: Some("Thresholds".into()), external_docs: None},
                 "models" => TagDetails { description: Some("Models".into()), external_docs: None},
                 "alerts" => TagDetails { description: Some("Alerts".into()), external_docs: None},
+                "webhooks" => TagDetails { description: Some("Webhooks".into()), external_docs: None},
                 "users" => TagDetails { description: Some("Users".into()), external_docs: None},
+                "notifications" => TagDetails { description: Some("Notifications".into()), external_docs: None},
                 "tokens" => TagDetails { description: Some("API Tokens".into()), external_docs: None},
                 "server" => TagDetails { description: Some("Server".into()), external_docs: None},
         }})